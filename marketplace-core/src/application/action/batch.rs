@@ -0,0 +1,244 @@
+use async_trait::async_trait;
+use marketplace_domain::{Error as DomainError, *};
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait Usecase: Send + Sync {
+	async fn execute(
+		&self,
+		actions: Vec<Action>,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
+	) -> Result<HexPrefixedString, DomainError>;
+	async fn estimate(&self, actions: Vec<Action>) -> Result<FeeEstimate, DomainError>;
+}
+
+pub struct ExecuteActionBatch {
+	command_dispatcher: Arc<CommandDispatcher>,
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+}
+
+impl ExecuteActionBatch {
+	pub fn new_usecase_boxed(
+		command_dispatcher: Arc<CommandDispatcher>,
+		onchain_contribution_service: Arc<dyn OnchainContributionService>,
+	) -> Box<dyn Usecase> {
+		Box::new(Self {
+			command_dispatcher,
+			onchain_contribution_service,
+		})
+	}
+}
+
+#[async_trait]
+impl Usecase for ExecuteActionBatch {
+	async fn execute(
+		&self,
+		actions: Vec<Action>,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
+	) -> Result<HexPrefixedString, DomainError> {
+		self.command_dispatcher
+			.dispatch(
+				ExecuteActionsCommand { actions },
+				issuer,
+				idempotency_key,
+			)
+			.await
+			.map(|submitted_transaction| submitted_transaction.transaction_hash)
+	}
+
+	async fn estimate(&self, actions: Vec<Action>) -> Result<FeeEstimate, DomainError> {
+		if actions.is_empty() {
+			return Err(DomainError::EmptyActionBatch);
+		}
+
+		let fee_estimate = self.onchain_contribution_service.estimate_fee(actions).await?;
+		Ok(fee_estimate)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use futures::FutureExt;
+	use mockall::predicate::*;
+	use rstest::*;
+	use std::str::FromStr;
+
+	#[fixture]
+	fn onchain_contribution_service() -> MockOnchainContributionService {
+		MockOnchainContributionService::new()
+	}
+
+	#[fixture]
+	fn contribution_projection_repository() -> MockContributionProjectionRepository {
+		MockContributionProjectionRepository::new()
+	}
+
+	#[fixture]
+	fn actions() -> Vec<Action> {
+		vec![
+			Action::CreateContribution {
+				project_id: 1,
+				issue_number: 2,
+				gate: 0,
+			},
+			Action::AssignContributor {
+				contribution_id: 12.into(),
+				contributor_id: 42.into(),
+			},
+			Action::ValidateContribution {
+				contribution_id: 12.into(),
+			},
+		]
+	}
+
+	fn usecase(
+		onchain_contribution_service: MockOnchainContributionService,
+		contribution_projection_repository: MockContributionProjectionRepository,
+	) -> Box<dyn Usecase> {
+		let onchain_contribution_service = Arc::new(onchain_contribution_service);
+		ExecuteActionBatch::new_usecase_boxed(
+			Arc::new(
+				CommandDispatcher::default().with_handler(ExecuteActionsHandler::new(
+					onchain_contribution_service.clone(),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			onchain_contribution_service,
+		)
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn sends_all_actions_in_a_single_transaction(
+		mut onchain_contribution_service: MockOnchainContributionService,
+		mut contribution_projection_repository: MockContributionProjectionRepository,
+		actions: Vec<Action>,
+	) {
+		contribution_projection_repository
+			.expect_find_by_id()
+			.returning(|_| Ok(Some(ContributionProjection::default())));
+
+		onchain_contribution_service
+			.expect_execute_actions()
+			.with(eq(actions.clone()))
+			.times(1)
+			.returning(|_| async { Ok(HexPrefixedString::from_str("0x1234").unwrap()) }.boxed());
+
+		let result = usecase(
+			onchain_contribution_service,
+			contribution_projection_repository,
+		)
+		.execute(actions, Issuer::Admin, None)
+		.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(
+			HexPrefixedString::from_str("0x1234").unwrap(),
+			result.unwrap()
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn empty_batch_fails(
+		onchain_contribution_service: MockOnchainContributionService,
+		contribution_projection_repository: MockContributionProjectionRepository,
+	) {
+		let result = usecase(
+			onchain_contribution_service,
+			contribution_projection_repository,
+		)
+		.execute(vec![], Issuer::Admin, None)
+		.await;
+
+		assert_matches!(result, Err(DomainError::EmptyActionBatch));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn unknown_contribution_fails(
+		mut onchain_contribution_service: MockOnchainContributionService,
+		mut contribution_projection_repository: MockContributionProjectionRepository,
+		actions: Vec<Action>,
+	) {
+		contribution_projection_repository.expect_find_by_id().returning(|_| Ok(None));
+		onchain_contribution_service.expect_execute_actions().never();
+
+		let result = usecase(
+			onchain_contribution_service,
+			contribution_projection_repository,
+		)
+		.execute(actions, Issuer::Admin, None)
+		.await;
+
+		assert_matches!(
+			result,
+			Err(DomainError::ContributionProjectionRepository(
+				ContributionProjectionRepositoryError::NotFound
+			))
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn estimates_the_fee_of_all_actions(
+		mut onchain_contribution_service: MockOnchainContributionService,
+		contribution_projection_repository: MockContributionProjectionRepository,
+		actions: Vec<Action>,
+	) {
+		onchain_contribution_service.expect_execute_actions().never();
+		onchain_contribution_service
+			.expect_estimate_fee()
+			.with(eq(actions.clone()))
+			.times(1)
+			.returning(|_| {
+				async {
+					Ok(FeeEstimate {
+						estimated_fee: 100,
+						max_fee: 150,
+					})
+				}
+				.boxed()
+			});
+
+		let result = usecase(
+			onchain_contribution_service,
+			contribution_projection_repository,
+		)
+		.estimate(actions)
+		.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(
+			FeeEstimate {
+				estimated_fee: 100,
+				max_fee: 150
+			},
+			result.unwrap()
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn estimating_an_empty_batch_fails(
+		mut onchain_contribution_service: MockOnchainContributionService,
+		contribution_projection_repository: MockContributionProjectionRepository,
+	) {
+		onchain_contribution_service.expect_estimate_fee().never();
+
+		let result = usecase(
+			onchain_contribution_service,
+			contribution_projection_repository,
+		)
+		.estimate(vec![])
+		.await;
+
+		assert_matches!(result, Err(DomainError::EmptyActionBatch));
+	}
+}
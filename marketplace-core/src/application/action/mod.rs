@@ -0,0 +1,4 @@
+mod batch;
+pub use batch::{
+	ExecuteActionBatch, MockUsecase as MockExecuteActionBatch, Usecase as ExecuteActionBatchUsecase,
+};
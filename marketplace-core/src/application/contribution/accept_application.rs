@@ -1,7 +1,6 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use mapinto::ResultMapErrInto;
 
 use marketplace_domain::{Error as DomainError, *};
 
@@ -10,40 +9,18 @@ pub trait Usecase: Send + Sync {
 	async fn accept_application(
 		&self,
 		application_id: &ApplicationId,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError>;
 }
 
 pub struct AcceptApplication {
-	onchain_contribution_service: Arc<dyn OnchainContributionService>,
-	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
-	application_repository: Arc<dyn ApplicationProjectionRepository>,
+	command_dispatcher: Arc<CommandDispatcher>,
 }
 
 impl AcceptApplication {
-	pub fn new(
-		onchain_contribution_service: Arc<dyn OnchainContributionService>,
-		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
-		application_repository: Arc<dyn ApplicationProjectionRepository>,
-	) -> Self {
-		Self {
-			onchain_contribution_service,
-			contribution_projection_repository,
-			application_repository,
-		}
-	}
-}
-
-impl AcceptApplication {
-	pub fn new_usecase_boxed(
-		onchain_contribution_service: Arc<dyn OnchainContributionService>,
-		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
-		application_repository: Arc<dyn ApplicationProjectionRepository>,
-	) -> Box<dyn Usecase> {
-		Box::new(Self {
-			onchain_contribution_service,
-			contribution_projection_repository,
-			application_repository,
-		})
+	pub fn new_usecase_boxed(command_dispatcher: Arc<CommandDispatcher>) -> Box<dyn Usecase> {
+		Box::new(Self { command_dispatcher })
 	}
 }
 
@@ -52,24 +29,19 @@ impl Usecase for AcceptApplication {
 	async fn accept_application(
 		&self,
 		application_id: &ApplicationId,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError> {
-		let application = self
-			.application_repository
-			.find(application_id)
-			.map_err(DomainError::from)?
-			.ok_or_else(|| DomainError::from(ApplicationProjectionRepositoryError::NotFound))?;
-
-		// TODO: use contribution aggregate root instead of projection as source of truth
-		let contribution = self
-			.contribution_projection_repository
-			.find_by_id(application.contribution_id())
-			.map_err(DomainError::from)?
-			.ok_or_else(|| DomainError::from(ContributionProjectionRepositoryError::NotFound))?;
-
-		self.onchain_contribution_service
-			.assign_contributor(contribution.id, application.contributor_id().to_owned())
+		self.command_dispatcher
+			.dispatch(
+				AcceptApplicationCommand {
+					application_id: *application_id,
+				},
+				issuer,
+				idempotency_key,
+			)
 			.await
-			.map_err_into()
+			.map(|submitted_transaction| submitted_transaction.transaction_hash)
 	}
 }
 
@@ -136,13 +108,15 @@ mod test {
 			.with(eq(contribution_id), eq(ContributorId::from(42)))
 			.returning(|_, _| async { Ok(HexPrefixedString::default()) }.boxed());
 
-		let usecase = AcceptApplication::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
-			Arc::new(application_repository),
-		);
+		let usecase = AcceptApplication::new_usecase_boxed(Arc::new(
+			CommandDispatcher::default().with_handler(AcceptApplicationHandler::new(
+				Arc::new(onchain_contribution_service),
+				Arc::new(contribution_projection_repository),
+				Arc::new(application_repository),
+			)),
+		));
 
-		let result = usecase.accept_application(&application_id).await;
+		let result = usecase.accept_application(&application_id, Issuer::Admin, None).await;
 		assert!(result.is_ok(), "{}", result.err().unwrap());
 	}
 
@@ -156,13 +130,15 @@ mod test {
 		let application_id = Uuid::from_u128(12).into();
 		application_repository.expect_find().returning(|_| Ok(None));
 
-		let usecase = AcceptApplication::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
-			Arc::new(application_repository),
-		);
+		let usecase = AcceptApplication::new_usecase_boxed(Arc::new(
+			CommandDispatcher::default().with_handler(AcceptApplicationHandler::new(
+				Arc::new(onchain_contribution_service),
+				Arc::new(contribution_projection_repository),
+				Arc::new(application_repository),
+			)),
+		));
 
-		let result = usecase.accept_application(&application_id).await;
+		let result = usecase.accept_application(&application_id, Issuer::Admin, None).await;
 		assert!(result.is_err());
 		assert_eq!(
 			"Application repository error",
@@ -188,13 +164,15 @@ mod test {
 
 		contribution_projection_repository.expect_find_by_id().returning(|_| Ok(None));
 
-		let usecase = AcceptApplication::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
-			Arc::new(application_repository),
-		);
+		let usecase = AcceptApplication::new_usecase_boxed(Arc::new(
+			CommandDispatcher::default().with_handler(AcceptApplicationHandler::new(
+				Arc::new(onchain_contribution_service),
+				Arc::new(contribution_projection_repository),
+				Arc::new(application_repository),
+			)),
+		));
 
-		let result = usecase.accept_application(&application_id).await;
+		let result = usecase.accept_application(&application_id, Issuer::Admin, None).await;
 		assert!(result.is_err());
 		assert_eq!(
 			"Contribution projection repository error",
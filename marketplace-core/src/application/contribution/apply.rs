@@ -11,45 +11,17 @@ pub trait Usecase: Send + Sync {
 		&self,
 		contribution_id: &ContributionId,
 		contributor_id: &ContributorId,
+		issuer: Issuer,
 	) -> Result<(), DomainError>;
 }
 
 pub struct ApplyToContribution {
-	contribution_repository: AggregateRootRepository<Contribution>,
-	event_store: Arc<dyn EventStore<Contribution>>,
-	application_projector: Arc<ApplicationProjector>,
-	uuid_generator: Arc<dyn UuidGenerator>,
+	command_dispatcher: Arc<CommandDispatcher>,
 }
 
 impl ApplyToContribution {
-	pub fn new(
-		contribution_repository: AggregateRootRepository<Contribution>,
-		event_store: Arc<dyn EventStore<Contribution>>,
-		application_projector: Arc<ApplicationProjector>,
-		uuid_generator: Arc<dyn UuidGenerator>,
-	) -> Self {
-		Self {
-			contribution_repository,
-			event_store,
-			application_projector,
-			uuid_generator,
-		}
-	}
-}
-
-impl ApplyToContribution {
-	pub fn new_usecase_boxed(
-		contribution_repository: AggregateRootRepository<Contribution>,
-		event_store: Arc<dyn EventStore<Contribution>>,
-		application_projector: Arc<ApplicationProjector>,
-		uuid_generator: Arc<dyn UuidGenerator>,
-	) -> Box<dyn Usecase> {
-		Box::new(Self::new(
-			contribution_repository,
-			event_store,
-			application_projector,
-			uuid_generator,
-		))
+	pub fn new_usecase_boxed(command_dispatcher: Arc<CommandDispatcher>) -> Box<dyn Usecase> {
+		Box::new(Self { command_dispatcher })
 	}
 }
 
@@ -59,24 +31,154 @@ impl Usecase for ApplyToContribution {
 		&self,
 		contribution_id: &ContributionId,
 		contributor_id: &ContributorId,
+		issuer: Issuer,
 	) -> Result<(), DomainError> {
-		let contribution = self.contribution_repository.find_by_id(contribution_id)?;
-		let contribution_id = contribution_id.clone();
-		let events = contribution.apply(contributor_id)?;
-		let storable_events: Vec<StorableEvent<Contribution>> = events
-			.iter()
-			.map(|event| StorableEvent {
-				deduplication_id: self.uuid_generator.new_uuid().to_string(),
-				event: event.to_owned(),
+		self.command_dispatcher
+			.dispatch(
+				ApplyToContributionCommand {
+					contribution_id: contribution_id.to_owned(),
+					contributor_id: contributor_id.to_owned(),
+				},
+				issuer,
+				None,
+			)
+			.await
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use mockall::predicate::eq;
+	use rstest::*;
+	use std::str::FromStr;
+
+	#[fixture]
+	fn contribution_id() -> ContributionId {
+		ContributionId::from_str("0x12").unwrap()
+	}
+
+	#[fixture]
+	fn contributor_id() -> ContributorId {
+		ContributorId::from_str("0x34").unwrap()
+	}
+
+	fn usecase(
+		event_store: MockEventStore<Contribution>,
+		projector: MockProjector<Contribution>,
+	) -> Box<dyn Usecase> {
+		let event_store = Arc::new(event_store);
+		let mut uuid_generator = MockUuidGenerator::new();
+		uuid_generator.expect_new_uuid().returning(uuid::Uuid::new_v4);
+
+		ApplyToContribution::new_usecase_boxed(Arc::new(
+			CommandDispatcher::default()
+				.with_middleware(Arc::new(AuthorizationMiddleware))
+				.with_handler(ApplyToContributionHandler::new(
+					AggregateRootRepository::new(event_store.clone()),
+					event_store,
+					Arc::new(projector),
+					Arc::new(uuid_generator),
+				)),
+		))
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn apply_to_contribution_stores_and_projects_event(
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	) {
+		let applied_event = ContributionEvent::Applied {
+			id: contribution_id.clone(),
+			contributor_id: contributor_id.clone(),
+		};
+
+		let mut event_store = MockEventStore::new();
+		let created_contribution_id = contribution_id.clone();
+		event_store.expect_list_by_id().returning(move |_| {
+			Ok(vec![ContributionEvent::Created {
+				id: created_contribution_id.clone(),
+				project_id: 42,
+				issue_number: 7,
+				gate: 0,
+			}])
+		});
+		let expected_event = applied_event.clone();
+		event_store
+			.expect_append()
+			.withf(move |id, events| {
+				id == &ContributionId::from_str("0x12").unwrap()
+					&& events.len() == 1
+					&& events[0].event == expected_event
 			})
-			.collect();
-		self.event_store.append(&contribution_id, storable_events)?;
-		// TODO: the usecase shouldn't know about the projectors, it should just push the events to
-		// a bus
-		for event in &events {
-			self.application_projector.project(event).await;
-		}
-
-		Ok(())
+			.once()
+			.returning(|_, _| Ok(()));
+
+		let mut projector = MockProjector::<Contribution>::new();
+		projector.expect_project().with(eq(applied_event)).once().returning(|_| Ok(()));
+
+		let result = usecase(event_store, projector)
+			.apply_to_contribution(
+				&contribution_id,
+				&contributor_id,
+				Issuer::Contributor(contributor_id.clone()),
+			)
+			.await;
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn contributors_cannot_apply_on_behalf_of_others(
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	) {
+		let mut event_store = MockEventStore::new();
+		event_store.expect_list_by_id().never();
+		event_store.expect_append().never();
+
+		let result = usecase(event_store, MockProjector::<Contribution>::new())
+			.apply_to_contribution(
+				&contribution_id,
+				&contributor_id,
+				Issuer::Contributor(ContributorId::from_str("0x56").unwrap()),
+			)
+			.await;
+		assert_matches!(result, Err(DomainError::Unauthorized(_)));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn apply_to_closed_contribution_fails(
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	) {
+		let mut event_store = MockEventStore::new();
+		let created_contribution_id = contribution_id.clone();
+		event_store.expect_list_by_id().returning(move |_| {
+			Ok(vec![
+				ContributionEvent::Created {
+					id: created_contribution_id.clone(),
+					project_id: 42,
+					issue_number: 7,
+					gate: 0,
+				},
+				ContributionEvent::Validated {
+					id: created_contribution_id.clone(),
+				},
+			])
+		});
+		event_store.expect_append().never();
+
+		let result = usecase(event_store, MockProjector::<Contribution>::new())
+			.apply_to_contribution(
+				&contribution_id,
+				&contributor_id,
+				Issuer::Contributor(contributor_id.clone()),
+			)
+			.await;
+		assert_matches!(result, Err(DomainError::ContributionError(_)));
 	}
 }
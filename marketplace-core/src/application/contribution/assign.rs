@@ -1,5 +1,4 @@
 use async_trait::async_trait;
-use mapinto::ResultMapErrInto;
 use marketplace_domain::{Error as DomainError, *};
 use mockall::automock;
 use std::sync::Arc;
@@ -13,7 +12,15 @@ pub trait Usecase: Send + Sync {
 		&self,
 		contribution_id: &ContributionId,
 		contributor_id: &ContributorId,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError>;
+	/// Predict the outcome of the request without sending it
+	async fn simulate_assign_request(
+		&self,
+		contribution_id: &ContributionId,
+		contributor_id: &ContributorId,
+	) -> Result<ContributionSimulation, DomainError>;
 }
 
 #[deprecated(
@@ -21,18 +28,18 @@ pub trait Usecase: Send + Sync {
 	note = "please use `accept_application` usecase instead"
 )]
 pub struct AssignContribution {
-	onchain_contribution_service: Arc<dyn OnchainContributionService>,
-	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+	command_dispatcher: Arc<CommandDispatcher>,
+	contribution_simulator: Arc<ContributionSimulator>,
 }
 
 impl AssignContribution {
 	pub fn new_usecase_boxed(
-		onchain_contribution_service: Arc<dyn OnchainContributionService>,
-		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+		command_dispatcher: Arc<CommandDispatcher>,
+		contribution_simulator: Arc<ContributionSimulator>,
 	) -> Box<dyn Usecase> {
 		Box::new(Self {
-			onchain_contribution_service,
-			contribution_projection_repository,
+			command_dispatcher,
+			contribution_simulator,
 		})
 	}
 }
@@ -43,26 +50,39 @@ impl Usecase for AssignContribution {
 		&self,
 		contribution_id: &ContributionId,
 		contributor_id: &ContributorId,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError> {
-		// TODO: use contribution aggregate root instead of projection as source of truth
-		let contribution = self.contribution_projection_repository.find_by_id(contribution_id)?;
-
-		match contribution {
-			Some(contribution) => self
-				.onchain_contribution_service
-				.assign_contributor(contribution.id, contributor_id.to_owned())
-				.await
-				.map_err_into(),
-			None => Err(DomainError::ContributionProjectionRepository(
-				ContributionProjectionRepositoryError::NotFound,
-			)),
-		}
+		self.command_dispatcher
+			.dispatch(
+				AssignContributorCommand {
+					contribution_id: contribution_id.to_owned(),
+					contributor_id: contributor_id.to_owned(),
+				},
+				issuer,
+				idempotency_key,
+			)
+			.await
+			.map(|submitted_transaction| submitted_transaction.transaction_hash)
+	}
+
+	async fn simulate_assign_request(
+		&self,
+		contribution_id: &ContributionId,
+		contributor_id: &ContributorId,
+	) -> Result<ContributionSimulation, DomainError> {
+		self.contribution_simulator
+			.simulate(Action::AssignContributor {
+				contribution_id: contribution_id.to_owned(),
+				contributor_id: contributor_id.to_owned(),
+			})
+			.await
 	}
 }
 
 #[cfg(test)]
 mod test {
-	use super::*;
+	use super::{super::test_support::simulator, *};
 	use futures::FutureExt;
 	use rstest::*;
 	use thiserror::Error;
@@ -97,11 +117,16 @@ mod test {
 			.returning(|_, _| async { Ok(HexPrefixedString::default()) }.boxed());
 
 		let usecase = AssignContribution::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
+			Arc::new(
+				CommandDispatcher::default().with_handler(AssignContributorHandler::new(
+					Arc::new(onchain_contribution_service),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			simulator(),
 		);
 
-		let result = usecase.send_assign_request(&contribution_id, &ContributorId::from(34)).await;
+		let result = usecase.send_assign_request(&contribution_id, &ContributorId::from(34), Issuer::Admin, None).await;
 		assert!(result.is_ok(), "{}", result.err().unwrap());
 	}
 
@@ -118,11 +143,16 @@ mod test {
 		});
 
 		let usecase = AssignContribution::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
+			Arc::new(
+				CommandDispatcher::default().with_handler(AssignContributorHandler::new(
+					Arc::new(onchain_contribution_service),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			simulator(),
 		);
 
-		let result = usecase.send_assign_request(&12.into(), &ContributorId::from(34)).await;
+		let result = usecase.send_assign_request(&12.into(), &ContributorId::from(34), Issuer::Admin, None).await;
 
 		assert!(result.is_err());
 		assert_eq!(
@@ -140,11 +170,16 @@ mod test {
 		contribution_projection_repository.expect_find_by_id().returning(|_| Ok(None));
 
 		let usecase = AssignContribution::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
+			Arc::new(
+				CommandDispatcher::default().with_handler(AssignContributorHandler::new(
+					Arc::new(onchain_contribution_service),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			simulator(),
 		);
 
-		let result = usecase.send_assign_request(&12.into(), &ContributorId::from(34)).await;
+		let result = usecase.send_assign_request(&12.into(), &ContributorId::from(34), Issuer::Admin, None).await;
 
 		assert!(result.is_err());
 		assert_eq!(
@@ -174,11 +209,16 @@ mod test {
 		});
 
 		let usecase = AssignContribution::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
+			Arc::new(
+				CommandDispatcher::default().with_handler(AssignContributorHandler::new(
+					Arc::new(onchain_contribution_service),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			simulator(),
 		);
 
-		let result = usecase.send_assign_request(&contribution_id, &ContributorId::from(34)).await;
+		let result = usecase.send_assign_request(&contribution_id, &ContributorId::from(34), Issuer::Admin, None).await;
 
 		assert!(result.is_err());
 		assert_eq!(
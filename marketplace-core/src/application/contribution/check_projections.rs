@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use log::warn;
+use marketplace_domain::{Error as DomainError, *};
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+// Usecase must be `Send` and `Sync` as it is managed in a rocket State<T> that requires T to be
+// `Send` and `Sync`
+pub trait Usecase: Send + Sync {
+	async fn check(&self, repair: bool) -> Result<ProjectionCheckReport, DomainError>;
+}
+
+pub struct CheckProjections {
+	projection_checker: ProjectionChecker,
+}
+
+impl CheckProjections {
+	pub fn new_usecase_boxed(projection_checker: ProjectionChecker) -> Box<dyn Usecase> {
+		Box::new(Self { projection_checker })
+	}
+}
+
+#[async_trait]
+impl Usecase for CheckProjections {
+	async fn check(&self, repair: bool) -> Result<ProjectionCheckReport, DomainError> {
+		let report = self.projection_checker.check()?;
+
+		for mismatch in report.mismatches.iter() {
+			warn!("Projection mismatch: {mismatch}");
+		}
+
+		if repair && !report.mismatches.is_empty() {
+			return self.projection_checker.repair(report).await;
+		}
+
+		Ok(report)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use mockall::predicate::eq;
+	use rstest::*;
+	use std::{str::FromStr, sync::Arc};
+
+	fn contribution_id() -> ContributionId {
+		ContributionId::from_str("0x12").unwrap()
+	}
+
+	fn created_event() -> ContributionEvent {
+		ContributionEvent::Created {
+			id: contribution_id(),
+			project_id: 42,
+			issue_number: 7,
+			gate: 1,
+		}
+	}
+
+	fn usecase(
+		mut event_store: MockEventStore<Contribution>,
+		mut contribution_projection_repository: MockContributionProjectionRepository,
+		projector: MockProjector<Contribution>,
+	) -> Box<dyn Usecase> {
+		event_store.expect_list().returning(|| Ok(vec![created_event()]));
+		contribution_projection_repository.expect_list().returning(|| Ok(vec![]));
+
+		let mut application_projection_repository = MockApplicationProjectionRepository::new();
+		application_projection_repository
+			.expect_list_by_contributor()
+			.returning(|_| Ok(vec![]));
+		application_projection_repository
+			.expect_delete_by_contribution()
+			.returning(|_| Ok(()));
+
+		CheckProjections::new_usecase_boxed(ProjectionChecker::new(
+			Arc::new(event_store),
+			Arc::new(contribution_projection_repository),
+			Arc::new(application_projection_repository),
+			vec![Arc::new(projector)],
+		))
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn check_does_not_touch_projections_without_repair() {
+		let mut contribution_projection_repository = MockContributionProjectionRepository::new();
+		contribution_projection_repository.expect_delete().never();
+		let mut projector = MockProjector::<Contribution>::new();
+		projector.expect_project().never();
+
+		let report = usecase(
+			MockEventStore::new(),
+			contribution_projection_repository,
+			projector,
+		)
+		.check(false)
+		.await
+		.unwrap();
+
+		assert_eq!(
+			vec![ProjectionMismatch::MissingContribution {
+				contribution_id: contribution_id()
+			}],
+			report.mismatches
+		);
+		assert!(report.repaired_contributions.is_empty());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn check_projects_events_again_with_repair() {
+		let mut event_store = MockEventStore::new();
+		event_store
+			.expect_list_by_id()
+			.with(eq(contribution_id()))
+			.returning(|_| Ok(vec![created_event()]));
+		let mut contribution_projection_repository = MockContributionProjectionRepository::new();
+		contribution_projection_repository
+			.expect_delete()
+			.with(eq(contribution_id()))
+			.once()
+			.returning(|_| Ok(()));
+		let mut projector = MockProjector::<Contribution>::new();
+		projector
+			.expect_project()
+			.with(eq(created_event()))
+			.once()
+			.returning(|_| Ok(()));
+
+		let report = usecase(event_store, contribution_projection_repository, projector)
+			.check(true)
+			.await
+			.unwrap();
+
+		assert_eq!(1, report.mismatches.len());
+		assert_eq!(vec![contribution_id()], report.repaired_contributions);
+	}
+}
@@ -1,5 +1,4 @@
 use async_trait::async_trait;
-use mapinto::ResultMapErrInto;
 use marketplace_domain::{Error as DomainError, *};
 use mockall::automock;
 use std::sync::Arc;
@@ -10,20 +9,18 @@ pub trait Usecase: Send + Sync {
 	async fn send_creation_request(
 		&self,
 		contribution: ContributionProjection,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError>;
 }
 
 pub struct CreateContribution {
-	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+	command_dispatcher: Arc<CommandDispatcher>,
 }
 
 impl CreateContribution {
-	pub fn new_usecase_boxed(
-		onchain_contribution_service: Arc<dyn OnchainContributionService>,
-	) -> Box<dyn Usecase> {
-		Box::new(Self {
-			onchain_contribution_service,
-		})
+	pub fn new_usecase_boxed(command_dispatcher: Arc<CommandDispatcher>) -> Box<dyn Usecase> {
+		Box::new(Self { command_dispatcher })
 	}
 }
 
@@ -32,8 +29,21 @@ impl Usecase for CreateContribution {
 	async fn send_creation_request(
 		&self,
 		contribution: ContributionProjection,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError> {
-		self.onchain_contribution_service.create(contribution).await.map_err_into()
+		self.command_dispatcher
+			.dispatch(
+				CreateContributionCommand {
+					project_id: contribution.project_id,
+					issue_number: contribution.issue_number,
+					gate: contribution.gate,
+				},
+				issuer,
+				idempotency_key,
+			)
+			.await
+			.map(|submitted_transaction| submitted_transaction.transaction_hash)
 	}
 }
 
@@ -59,9 +69,13 @@ mod test {
 			.with(eq(contribution.clone()))
 			.returning(|_| async { Ok(HexPrefixedString::default()) }.boxed());
 
-		let usecase = CreateContribution::new_usecase_boxed(Arc::new(onchain_contribution_service));
+		let usecase = CreateContribution::new_usecase_boxed(Arc::new(
+			CommandDispatcher::default().with_handler(CreateContributionHandler::new(Arc::new(
+				onchain_contribution_service,
+			))),
+		));
 
-		let result = usecase.send_creation_request(contribution).await;
+		let result = usecase.send_creation_request(contribution, Issuer::Admin, None).await;
 		assert!(result.is_ok(), "{}", result.err().unwrap());
 	}
 
@@ -80,9 +94,13 @@ mod test {
 			.boxed()
 		});
 
-		let usecase = CreateContribution::new_usecase_boxed(Arc::new(onchain_contribution_service));
+		let usecase = CreateContribution::new_usecase_boxed(Arc::new(
+			CommandDispatcher::default().with_handler(CreateContributionHandler::new(Arc::new(
+				onchain_contribution_service,
+			))),
+		));
 
-		let result = usecase.send_creation_request(contribution).await;
+		let result = usecase.send_creation_request(contribution, Issuer::Admin, None).await;
 		assert!(result.is_err());
 		assert_eq!(
 			"Onchain contribution service error",
@@ -28,3 +28,11 @@ pub use accept_application::{AcceptApplication, Usecase as AcceptApplicationUsec
 
 mod refresh;
 pub use refresh::{RefreshApplications, RefreshContributions};
+
+mod check_projections;
+pub use check_projections::{
+	CheckProjections, MockUsecase as MockCheckProjections, Usecase as CheckProjectionsUsecase,
+};
+
+#[cfg(test)]
+mod test_support;
@@ -38,6 +38,9 @@ mod test {
 			StorableEvent {
 				event: self,
 				deduplication_id: RandomUuidGenerator.new_uuid().to_string(),
+				transaction_hash: None,
+				block_number: None,
+				block_hash: None,
 			}
 		}
 	}
@@ -76,65 +79,65 @@ mod test {
 		// events for contribution #1
 		{
 			let contribution_id = ContributionId::from_str("0x17267621").unwrap();
-			database
-				.append(
-					&contribution_id,
-					vec![
-						ContributionEvent::Created {
-							id: contribution_id.clone(),
-							project_id: STARKONQUEST,
-							issue_number: 51,
-							gate: 0,
-						},
-						ContributionEvent::Applied {
-							id: contribution_id.clone(),
-							contributor_id: contributor_id.clone(),
-						},
-						ContributionEvent::Assigned {
-							id: contribution_id.clone(),
-							contributor_id: contributor_id.clone(),
-						},
-						ContributionEvent::Validated {
-							id: contribution_id.clone(),
-						},
-					]
-					.into_iter()
-					.map(Storable::into_storable)
-					.collect(),
-				)
-				.expect("Unable to add events in event store");
+			EventStore::<Contribution>::append(
+				&*database,
+				&contribution_id,
+				vec![
+					ContributionEvent::Created {
+						id: contribution_id.clone(),
+						project_id: STARKONQUEST,
+						issue_number: 51,
+						gate: 0,
+					},
+					ContributionEvent::Applied {
+						id: contribution_id.clone(),
+						contributor_id: contributor_id.clone(),
+					},
+					ContributionEvent::Assigned {
+						id: contribution_id.clone(),
+						contributor_id: contributor_id.clone(),
+					},
+					ContributionEvent::Validated {
+						id: contribution_id.clone(),
+					},
+				]
+				.into_iter()
+				.map(Storable::into_storable)
+				.collect(),
+			)
+			.expect("Unable to add events in event store");
 		}
 
 		// events for contribution #2
 		{
 			let contribution_id = ContributionId::from_str("0x17267622").unwrap();
-			database
-				.append(
-					&contribution_id,
-					vec![
-						ContributionEvent::Created {
-							id: contribution_id.clone(),
-							project_id: STARKONQUEST,
-							issue_number: 52,
-							gate: 0,
-						},
-						ContributionEvent::Applied {
-							id: contribution_id.clone(),
-							contributor_id: contributor_id.clone(),
-						},
-						ContributionEvent::Assigned {
-							id: contribution_id.clone(),
-							contributor_id,
-						},
-						ContributionEvent::Unassigned {
-							id: contribution_id.clone(),
-						},
-					]
-					.into_iter()
-					.map(Storable::into_storable)
-					.collect(),
-				)
-				.expect("Unable to add events in event store");
+			EventStore::<Contribution>::append(
+				&*database,
+				&contribution_id,
+				vec![
+					ContributionEvent::Created {
+						id: contribution_id.clone(),
+						project_id: STARKONQUEST,
+						issue_number: 52,
+						gate: 0,
+					},
+					ContributionEvent::Applied {
+						id: contribution_id.clone(),
+						contributor_id: contributor_id.clone(),
+					},
+					ContributionEvent::Assigned {
+						id: contribution_id.clone(),
+						contributor_id,
+					},
+					ContributionEvent::Unassigned {
+						id: contribution_id.clone(),
+					},
+				]
+				.into_iter()
+				.map(Storable::into_storable)
+				.collect(),
+			)
+			.expect("Unable to add events in event store");
 		}
 
 		database
@@ -0,0 +1,10 @@
+use marketplace_domain::*;
+use std::sync::Arc;
+
+/// Simulator of the usecases whose simulation is not under test
+pub fn simulator() -> Arc<ContributionSimulator> {
+	Arc::new(ContributionSimulator::new(
+		AggregateRootRepository::new(Arc::new(MockEventStore::new())),
+		Arc::new(MockOnchainContributionService::new()),
+	))
+}
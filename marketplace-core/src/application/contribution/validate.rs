@@ -1,5 +1,4 @@
 use async_trait::async_trait;
-use mapinto::ResultMapErrInto;
 use marketplace_domain::{Error as DomainError, *};
 use mockall::automock;
 use std::sync::Arc;
@@ -12,22 +11,29 @@ pub trait Usecase: Send + Sync {
 	async fn send_validate_request(
 		&self,
 		contribution_id: &ContributionId,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError>;
+	/// Predict the outcome of the request without sending it
+	async fn simulate_validate_request(
+		&self,
+		contribution_id: &ContributionId,
+	) -> Result<ContributionSimulation, DomainError>;
 }
 
 pub struct ValidateContribution {
-	onchain_contribution_service: Arc<dyn OnchainContributionService>,
-	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+	command_dispatcher: Arc<CommandDispatcher>,
+	contribution_simulator: Arc<ContributionSimulator>,
 }
 
 impl ValidateContribution {
 	pub fn new_usecase_boxed(
-		onchain_contribution_service: Arc<dyn OnchainContributionService>,
-		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+		command_dispatcher: Arc<CommandDispatcher>,
+		contribution_simulator: Arc<ContributionSimulator>,
 	) -> Box<dyn Usecase> {
 		Box::new(Self {
-			onchain_contribution_service,
-			contribution_projection_repository,
+			command_dispatcher,
+			contribution_simulator,
 		})
 	}
 }
@@ -37,20 +43,36 @@ impl Usecase for ValidateContribution {
 	async fn send_validate_request(
 		&self,
 		contribution_id: &ContributionId,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError> {
-		// TODO: use contribution aggregate root instead of projection as source of truth
-		let contribution = self.contribution_projection_repository.find_by_id(contribution_id)?;
-		match contribution {
-			Some(contribution) =>
-				self.onchain_contribution_service.validate(contribution.id).await.map_err_into(),
-			None => Err(ContributionProjectionRepositoryError::NotFound.into()),
-		}
+		self.command_dispatcher
+			.dispatch(
+				ValidateContributionCommand {
+					contribution_id: contribution_id.to_owned(),
+				},
+				issuer,
+				idempotency_key,
+			)
+			.await
+			.map(|submitted_transaction| submitted_transaction.transaction_hash)
+	}
+
+	async fn simulate_validate_request(
+		&self,
+		contribution_id: &ContributionId,
+	) -> Result<ContributionSimulation, DomainError> {
+		self.contribution_simulator
+			.simulate(Action::ValidateContribution {
+				contribution_id: contribution_id.to_owned(),
+			})
+			.await
 	}
 }
 
 #[cfg(test)]
 mod test {
-	use super::*;
+	use super::{super::test_support::simulator, *};
 	use futures::FutureExt;
 	use rstest::*;
 	use thiserror::Error;
@@ -85,11 +107,16 @@ mod test {
 			.returning(|_| async { Ok(HexPrefixedString::default()) }.boxed());
 
 		let usecase = ValidateContribution::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
+			Arc::new(
+				CommandDispatcher::default().with_handler(ValidateContributionHandler::new(
+					Arc::new(onchain_contribution_service),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			simulator(),
 		);
 
-		let result = usecase.send_validate_request(&contribution_id).await;
+		let result = usecase.send_validate_request(&contribution_id, Issuer::Admin, None).await;
 		assert!(result.is_ok(), "{}", result.err().unwrap());
 	}
 
@@ -106,11 +133,16 @@ mod test {
 		});
 
 		let usecase = ValidateContribution::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
+			Arc::new(
+				CommandDispatcher::default().with_handler(ValidateContributionHandler::new(
+					Arc::new(onchain_contribution_service),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			simulator(),
 		);
 
-		let result = usecase.send_validate_request(&12.into()).await;
+		let result = usecase.send_validate_request(&12.into(), Issuer::Admin, None).await;
 
 		assert!(result.is_err());
 		assert_eq!(
@@ -128,11 +160,16 @@ mod test {
 		contribution_projection_repository.expect_find_by_id().returning(|_| Ok(None));
 
 		let usecase = ValidateContribution::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
+			Arc::new(
+				CommandDispatcher::default().with_handler(ValidateContributionHandler::new(
+					Arc::new(onchain_contribution_service),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			simulator(),
 		);
 
-		let result = usecase.send_validate_request(&12.into()).await;
+		let result = usecase.send_validate_request(&12.into(), Issuer::Admin, None).await;
 
 		assert!(result.is_err());
 		assert_eq!(
@@ -162,11 +199,16 @@ mod test {
 		});
 
 		let usecase = ValidateContribution::new_usecase_boxed(
-			Arc::new(onchain_contribution_service),
-			Arc::new(contribution_projection_repository),
+			Arc::new(
+				CommandDispatcher::default().with_handler(ValidateContributionHandler::new(
+					Arc::new(onchain_contribution_service),
+					Arc::new(contribution_projection_repository),
+				)),
+			),
+			simulator(),
 		);
 
-		let result = usecase.send_validate_request(&contribution_id).await;
+		let result = usecase.send_validate_request(&contribution_id, Issuer::Admin, None).await;
 
 		assert!(result.is_err());
 		assert_eq!(
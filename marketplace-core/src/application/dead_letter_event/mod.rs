@@ -0,0 +1,5 @@
+mod retry;
+pub use retry::{
+	MockUsecase as MockRetryDeadLetterEvent, RetryDeadLetterEvent,
+	Usecase as RetryDeadLetterEventUsecase,
+};
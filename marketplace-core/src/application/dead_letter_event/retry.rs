@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use marketplace_domain::{Error as DomainError, *};
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+// Usecase must be `Send` and `Sync` as it is managed in a rocket State<T> that requires T to be
+// `Send` and `Sync`
+pub trait Usecase: Send + Sync {
+	async fn retry(&self, dead_letter_event_id: &DeadLetterEventId) -> Result<(), DomainError>;
+}
+
+pub struct RetryDeadLetterEvent {
+	dead_letter_event_repository: Arc<dyn DeadLetterEventRepository>,
+	dispatchers: Vec<Arc<ProjectionDispatcher>>,
+}
+
+impl RetryDeadLetterEvent {
+	pub fn new_usecase_boxed(
+		dead_letter_event_repository: Arc<dyn DeadLetterEventRepository>,
+		dispatchers: Vec<Arc<ProjectionDispatcher>>,
+	) -> Box<dyn Usecase> {
+		Box::new(Self {
+			dead_letter_event_repository,
+			dispatchers,
+		})
+	}
+}
+
+#[async_trait]
+impl Usecase for RetryDeadLetterEvent {
+	async fn retry(&self, dead_letter_event_id: &DeadLetterEventId) -> Result<(), DomainError> {
+		let dead_letter_event = self
+			.dead_letter_event_repository
+			.find_by_id(dead_letter_event_id)?
+			.ok_or(DeadLetterEventRepositoryError::NotFound)?;
+
+		let dispatcher = self
+			.dispatchers
+			.iter()
+			.find(|dispatcher| dispatcher.name() == dead_letter_event.projector)
+			.ok_or_else(|| DomainError::UnknownProjector(dead_letter_event.projector.clone()))?;
+
+		dispatcher.redeliver(dead_letter_event).await
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use mockall::predicate::eq;
+	use rstest::*;
+	use std::time::SystemTime;
+	use uuid::Uuid;
+
+	#[fixture]
+	fn dead_letter_event_id() -> DeadLetterEventId {
+		Uuid::from_u128(12).into()
+	}
+
+	#[fixture]
+	fn dead_letter_event(dead_letter_event_id: DeadLetterEventId) -> DeadLetterEvent {
+		DeadLetterEvent {
+			id: dead_letter_event_id,
+			projector: String::from("applications"),
+			event: Event::Contribution(ContributionEvent::Unassigned { id: 12.into() }),
+			deduplication_id: None,
+			error: String::from("Oops"),
+			attempts: 5,
+			failed_at: SystemTime::UNIX_EPOCH,
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn retry_dead_letter_event_not_found(dead_letter_event_id: DeadLetterEventId) {
+		let mut dead_letter_event_repository = MockDeadLetterEventRepository::new();
+		dead_letter_event_repository
+			.expect_find_by_id()
+			.with(eq(dead_letter_event_id))
+			.returning(|_| Ok(None));
+
+		let usecase =
+			RetryDeadLetterEvent::new_usecase_boxed(Arc::new(dead_letter_event_repository), vec![]);
+
+		let result = usecase.retry(&dead_letter_event_id).await;
+		assert_matches!(
+			result,
+			Err(DomainError::DeadLetterEventRepository(
+				DeadLetterEventRepositoryError::NotFound
+			))
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn retry_dead_letter_event_with_unknown_projector(
+		dead_letter_event_id: DeadLetterEventId,
+		dead_letter_event: DeadLetterEvent,
+	) {
+		let mut dead_letter_event_repository = MockDeadLetterEventRepository::new();
+		dead_letter_event_repository
+			.expect_find_by_id()
+			.returning(move |_| Ok(Some(dead_letter_event.clone())));
+
+		let usecase =
+			RetryDeadLetterEvent::new_usecase_boxed(Arc::new(dead_letter_event_repository), vec![]);
+
+		let result = usecase.retry(&dead_letter_event_id).await;
+		assert_matches!(result, Err(DomainError::UnknownProjector(name)) if name == "applications");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn retry_dead_letter_event_with_matching_projector(
+		dead_letter_event_id: DeadLetterEventId,
+		dead_letter_event: DeadLetterEvent,
+	) {
+		let event = dead_letter_event.event.clone();
+		let mut dead_letter_event_repository = MockDeadLetterEventRepository::new();
+		dead_letter_event_repository
+			.expect_find_by_id()
+			.returning(move |_| Ok(Some(dead_letter_event.clone())));
+		dead_letter_event_repository
+			.expect_delete()
+			.with(eq(dead_letter_event_id))
+			.once()
+			.returning(|_| Ok(()));
+		let dead_letter_event_repository = Arc::new(dead_letter_event_repository);
+
+		let mut projector = MockProjector::<Contribution>::new();
+		projector
+			.expect_project()
+			.withf(move |projected_event| Event::Contribution(projected_event.clone()) == event)
+			.once()
+			.returning(|_| Ok(()));
+
+		let dispatcher = ProjectionDispatcher::new(
+			"applications",
+			Arc::new(projector),
+			dead_letter_event_repository.clone(),
+			Arc::new(RandomUuidGenerator),
+		);
+
+		let usecase = RetryDeadLetterEvent::new_usecase_boxed(
+			dead_letter_event_repository,
+			vec![Arc::new(dispatcher)],
+		);
+
+		let result = usecase.retry(&dead_letter_event_id).await;
+		assert!(result.is_ok(), "{:?}", result.err());
+	}
+}
@@ -1,5 +1,11 @@
+mod action;
+pub use action::*;
+
 mod contribution;
 pub use contribution::*;
 
+mod dead_letter_event;
+pub use dead_letter_event::*;
+
 mod refresh;
 pub use refresh::Error as RefreshError;
@@ -9,6 +9,8 @@ pub enum Error {
 	ProjectionRepository(#[from] ProjectionRepositoryError),
 	#[error(transparent)]
 	EventStore(#[from] EventStoreError),
+	#[error(transparent)]
+	Projector(#[from] ProjectorError),
 }
 
 pub struct Refresh<P: Projection, A: Aggregate> {
@@ -36,7 +38,7 @@ impl<P: Projection, A: Aggregate> Refresh<P, A> {
 		let events = self.event_store.list()?;
 
 		for event in events.iter() {
-			self.projector.project(event).await;
+			self.projector.project(event).await?;
 		}
 
 		Ok(())
@@ -0,0 +1,60 @@
+//! Compare the `contributions` and `applications` projections with the event store.
+//! Run with `--repair` to rebuild the projections of the contributions that drifted.
+
+use dotenv::dotenv;
+use marketplace_core::application::{CheckProjections, CheckProjectionsUsecase};
+use marketplace_domain::*;
+use marketplace_infrastructure::{
+	database::{self, init_pool},
+	github,
+};
+use std::{process::ExitCode, sync::Arc};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+	dotenv().ok();
+	env_logger::init();
+	github::Client::initialize();
+
+	let repair = std::env::args().skip(1).any(|arg| arg == "--repair");
+
+	let database = Arc::new(database::Client::new(init_pool()));
+	let projectors: Vec<Arc<dyn Projector<Contribution>>> = vec![
+		Arc::new(ContributionProjector::new(
+			database.clone(),
+			Arc::new(github::Client::new()),
+		)),
+		Arc::new(ApplicationProjector::new(
+			database.clone(),
+			Arc::new(RandomUuidGenerator),
+		)),
+	];
+
+	let usecase = CheckProjections::new_usecase_boxed(ProjectionChecker::new(
+		database.clone(),
+		database.clone(),
+		database,
+		projectors,
+	));
+
+	let report = match usecase.check(repair).await {
+		Ok(report) => report,
+		Err(error) => {
+			eprintln!("Unable to check projections: {error}");
+			return ExitCode::FAILURE;
+		},
+	};
+
+	println!("Checked {} contributions", report.checked_contributions);
+	for mismatch in report.mismatches.iter() {
+		println!("{mismatch}");
+	}
+	for contribution_id in report.repaired_contributions.iter() {
+		println!("Repaired contribution {contribution_id}");
+	}
+
+	match report.mismatches.is_empty() || repair {
+		true => ExitCode::SUCCESS,
+		false => ExitCode::FAILURE,
+	}
+}
@@ -0,0 +1,71 @@
+use marketplace_domain as domain;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ActionSummary {
+	pub index: usize,
+	pub action: String,
+	pub contribution_id: Option<String>,
+	pub description: String,
+}
+
+impl ActionSummary {
+	pub(crate) fn new(index: usize, action: &domain::Action) -> Self {
+		let name = match action {
+			domain::Action::CreateContribution { .. } => "create_contribution",
+			domain::Action::AssignContributor { .. } => "assign_contributor",
+			domain::Action::UnassignContributor { .. } => "unassign_contributor",
+			domain::Action::ValidateContribution { .. } => "validate_contribution",
+		};
+
+		Self {
+			index,
+			action: name.to_string(),
+			contribution_id: action.contribution_id().map(|id| id.to_string()),
+			description: action.to_string(),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ActionBatchReceipt {
+	pub transaction_hash: String,
+	pub actions: Vec<ActionSummary>,
+}
+
+impl ActionBatchReceipt {
+	pub fn new(transaction_hash: domain::HexPrefixedString, actions: &[domain::Action]) -> Self {
+		Self {
+			transaction_hash: transaction_hash.to_string(),
+			actions: summaries(actions),
+		}
+	}
+}
+
+/// Fees are given in wei, as strings because they may exceed the safe integer range of JSON
+/// clients
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ActionBatchFeeEstimate {
+	pub estimated_fee: String,
+	pub max_fee: String,
+	pub actions: Vec<ActionSummary>,
+}
+
+impl ActionBatchFeeEstimate {
+	pub fn new(fee_estimate: domain::FeeEstimate, actions: &[domain::Action]) -> Self {
+		Self {
+			estimated_fee: fee_estimate.estimated_fee.to_string(),
+			max_fee: fee_estimate.max_fee.to_string(),
+			actions: summaries(actions),
+		}
+	}
+}
+
+fn summaries(actions: &[domain::Action]) -> Vec<ActionSummary> {
+	actions
+		.iter()
+		.enumerate()
+		.map(|(index, action)| ActionSummary::new(index, action))
+		.collect()
+}
@@ -1,5 +1,7 @@
+use marketplace_domain as domain;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Deserialize, Serialize, JsonSchema)]
 pub struct ContributionCreation {
@@ -29,3 +31,85 @@ impl ContributionCreation {
 		self.gate
 	}
 }
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ContributionDetails {
+	pub id: String,
+	pub project_id: u64,
+	pub issue_number: u64,
+	pub gate: u8,
+	pub status: String,
+	pub contributor_id: Option<String>,
+	pub applicants: Vec<String>,
+}
+
+impl From<domain::Contribution> for ContributionDetails {
+	fn from(contribution: domain::Contribution) -> Self {
+		Self {
+			id: contribution.id().to_string(),
+			project_id: contribution.project_id(),
+			issue_number: contribution.issue_number(),
+			gate: contribution.gate(),
+			status: contribution.status().to_string(),
+			contributor_id: contribution.contributor_id().map(|id| id.to_string()),
+			applicants: contribution.applicants().iter().map(|id| id.to_string()).collect(),
+		}
+	}
+}
+
+/// Fees are given in wei, as strings like in `ActionBatchFeeEstimate`
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ContributionSimulation {
+	pub succeeded: bool,
+	pub revert_reason: Option<String>,
+	pub estimated_fee: Option<String>,
+	pub max_fee: Option<String>,
+	pub contribution: ContributionDetails,
+}
+
+impl From<domain::ContributionSimulation> for ContributionSimulation {
+	fn from(simulation: domain::ContributionSimulation) -> Self {
+		let (estimated_fee, max_fee) = match &simulation.transaction {
+			domain::TransactionSimulation::Succeeded(fee_estimate) => (
+				Some(fee_estimate.estimated_fee.to_string()),
+				Some(fee_estimate.max_fee.to_string()),
+			),
+			domain::TransactionSimulation::Reverted { .. } => (None, None),
+		};
+
+		Self {
+			succeeded: simulation.transaction.revert_reason().is_none(),
+			revert_reason: simulation.transaction.revert_reason().map(String::from),
+			estimated_fee,
+			max_fee,
+			contribution: simulation.contribution.into(),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ContributionEventRecord {
+	pub index: u64,
+	pub timestamp: String,
+	pub event: Value,
+	pub transaction_hash: Option<String>,
+	pub block_number: Option<u64>,
+	pub block_hash: Option<String>,
+}
+
+impl TryFrom<domain::RecordedEvent<domain::Contribution>> for ContributionEventRecord {
+	type Error = serde_json::Error;
+
+	fn try_from(
+		recorded_event: domain::RecordedEvent<domain::Contribution>,
+	) -> Result<Self, Self::Error> {
+		Ok(Self {
+			index: recorded_event.index,
+			timestamp: humantime::format_rfc3339_micros(recorded_event.timestamp).to_string(),
+			event: serde_json::to_value(recorded_event.event)?,
+			transaction_hash: recorded_event.transaction_hash.map(|hash| hash.to_string()),
+			block_number: recorded_event.block_number,
+			block_hash: recorded_event.block_hash.map(|hash| hash.to_string()),
+		})
+	}
+}
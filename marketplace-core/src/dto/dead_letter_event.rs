@@ -0,0 +1,29 @@
+use marketplace_domain as domain;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct DeadLetterEvent {
+	pub id: String,
+	pub projector: String,
+	pub event: Value,
+	pub error: String,
+	pub attempts: u32,
+	pub failed_at: String,
+}
+
+impl TryFrom<domain::DeadLetterEvent> for DeadLetterEvent {
+	type Error = serde_json::Error;
+
+	fn try_from(dead_letter_event: domain::DeadLetterEvent) -> Result<Self, Self::Error> {
+		Ok(Self {
+			id: dead_letter_event.id.to_string(),
+			projector: dead_letter_event.projector,
+			event: serde_json::to_value(dead_letter_event.event)?,
+			error: dead_letter_event.error,
+			attempts: dead_letter_event.attempts,
+			failed_at: humantime::format_rfc3339_micros(dead_letter_event.failed_at).to_string(),
+		})
+	}
+}
@@ -9,3 +9,15 @@ pub use contact_information::*;
 
 mod contributions;
 pub use contributions::*;
+
+mod dead_letter_event;
+pub use dead_letter_event::*;
+
+mod projection_check_report;
+pub use projection_check_report::*;
+
+mod action_batch;
+pub use action_batch::*;
+
+mod onchain_transaction;
+pub use onchain_transaction::*;
@@ -0,0 +1,47 @@
+use super::ActionSummary;
+use marketplace_domain as domain;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct SubmittedTransaction {
+	pub transaction_hash: String,
+}
+
+impl From<domain::HexPrefixedString> for SubmittedTransaction {
+	fn from(transaction_hash: domain::HexPrefixedString) -> Self {
+		Self {
+			transaction_hash: transaction_hash.to_string(),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct OnchainTransaction {
+	pub hash: String,
+	pub command: String,
+	pub issuer: String,
+	pub status: String,
+	pub actions: Vec<ActionSummary>,
+	pub submitted_at: String,
+	pub updated_at: String,
+}
+
+impl From<domain::OnchainTransaction> for OnchainTransaction {
+	fn from(transaction: domain::OnchainTransaction) -> Self {
+		Self {
+			hash: transaction.hash.to_string(),
+			command: transaction.command,
+			issuer: transaction.issuer,
+			status: transaction.status.to_string(),
+			actions: transaction
+				.actions
+				.iter()
+				.enumerate()
+				.map(|(index, action)| ActionSummary::new(index, action))
+				.collect(),
+			submitted_at: humantime::format_rfc3339_micros(transaction.submitted_at).to_string(),
+			updated_at: humantime::format_rfc3339_micros(transaction.updated_at).to_string(),
+		}
+	}
+}
@@ -0,0 +1,39 @@
+use marketplace_domain as domain;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ProjectionMismatch {
+	pub contribution_id: String,
+	pub description: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ProjectionCheckReport {
+	pub checked_contributions: usize,
+	pub mismatches: Vec<ProjectionMismatch>,
+	pub repaired_contributions: Vec<String>,
+}
+
+impl From<domain::ProjectionMismatch> for ProjectionMismatch {
+	fn from(mismatch: domain::ProjectionMismatch) -> Self {
+		Self {
+			contribution_id: mismatch.contribution_id().to_string(),
+			description: mismatch.to_string(),
+		}
+	}
+}
+
+impl From<domain::ProjectionCheckReport> for ProjectionCheckReport {
+	fn from(report: domain::ProjectionCheckReport) -> Self {
+		Self {
+			checked_contributions: report.checked_contributions,
+			mismatches: report.mismatches.into_iter().map(ProjectionMismatch::from).collect(),
+			repaired_contributions: report
+				.repaired_contributions
+				.iter()
+				.map(ToString::to_string)
+				.collect(),
+		}
+	}
+}
@@ -15,11 +15,18 @@ use marketplace_domain::*;
 use rocket::{routes, Build, Rocket};
 use rocket_okapi::{openapi_get_routes, swagger_ui::make_swagger_ui};
 use slog::{o, Drain, Logger};
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 #[macro_use]
 extern crate rocket;
 
+/// Commands sent again with the same `Idempotency-Key` header within this window are only handled
+/// once
+const COMMAND_IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(10);
+
+/// Delay between two refreshes of the status of pending transactions
+const TRANSACTION_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
 fn get_root_logger() -> Logger {
 	let drain = match std::env::var("LOGS") {
 		Ok(logs) if logs == *"terminal" => slog_async::Async::default(slog_envlogger::new(
@@ -35,6 +42,32 @@ fn get_root_logger() -> Logger {
 	slog::Logger::root(drain.fuse(), o!("version" => env!("CARGO_PKG_VERSION")))
 }
 
+enum ContributionServiceMode {
+	Onchain,
+	Offchain,
+}
+
+fn contribution_service_mode() -> ContributionServiceMode {
+	match std::env::var("CONTRIBUTION_SERVICE") {
+		Ok(mode) if mode == *"offchain" => ContributionServiceMode::Offchain,
+		Ok(mode) if mode != *"onchain" => panic!("Invalid value for CONTRIBUTION_SERVICE"),
+		_ => ContributionServiceMode::Onchain,
+	}
+}
+
+async fn starknet_client() -> starknet::SingleAdminClient {
+	let network_profile =
+		starknet::NetworkProfile::from_env().expect("Invalid StarkNet network profile");
+	network_profile
+		.verify_node()
+		.await
+		.expect("JSON-RPC node does not serve the configured StarkNet network");
+	info!("Using StarkNet network {}", network_profile.network);
+
+	starknet::SingleAdminClient::from_profile(&network_profile)
+		.expect("Unable to set up the signer of the admin account")
+}
+
 #[tokio::main]
 async fn main() {
 	dotenv().ok();
@@ -45,8 +78,6 @@ async fn main() {
 	let database = Arc::new(database::Client::new(init_pool()));
 	database.run_migrations().expect("Unable to run database migrations");
 
-	let starknet = Arc::new(starknet::Client::default());
-
 	let github_client = Arc::new(github::Client::new());
 	let uuid_generator = Arc::new(RandomUuidGenerator);
 	let contribution_repository: AggregateRootRepository<Contribution> =
@@ -54,29 +85,74 @@ async fn main() {
 	let contact_information_service = Arc::new(ContactInformationServiceImplementation::new(
 		database.clone(),
 	));
-	let application_projector: Arc<ApplicationProjector> = Arc::new(ApplicationProjector::new(
+	let application_projector: Arc<dyn Projector<Contribution>> = Arc::new(
+		ApplicationProjector::new(database.clone(), uuid_generator.clone()),
+	);
+	let application_dispatcher = Arc::new(ProjectionDispatcher::new(
+		"applications",
+		application_projector.clone(),
 		database.clone(),
 		uuid_generator.clone(),
 	));
 
-	let contribution_projector = Arc::new(ContributionProjector::new(
+	let contribution_projector: Arc<dyn Projector<Contribution>> = Arc::new(
+		ContributionProjector::new(database.clone(), github_client.clone()),
+	);
+	let contribution_dispatcher = Arc::new(ProjectionDispatcher::new(
+		"contributions",
+		contribution_projector.clone(),
 		database.clone(),
-		github_client.clone(),
+		uuid_generator.clone(),
 	));
 
+	let (starknet, onchain_contribution_service, onchain_transaction_service) =
+		match contribution_service_mode() {
+			ContributionServiceMode::Onchain => {
+				let starknet = Arc::new(starknet_client().await);
+				(
+					Some(starknet.clone()),
+					starknet.clone() as Arc<dyn OnchainContributionService>,
+					starknet as Arc<dyn OnchainTransactionService>,
+				)
+			},
+			ContributionServiceMode::Offchain => {
+				info!("Contributions are handled off-chain");
+				let offchain_service = Arc::new(OffchainContributionService::new(
+					database.clone(),
+					database.clone(),
+					vec![
+						contribution_dispatcher.clone() as Arc<dyn Projector<Contribution>>,
+						application_dispatcher.clone(),
+					],
+					uuid_generator.clone(),
+				));
+				(
+					None,
+					offchain_service.clone() as Arc<dyn OnchainContributionService>,
+					offchain_service as Arc<dyn OnchainTransactionService>,
+				)
+			},
+		};
+
+	let transaction_tracker =
+		TransactionTracker::new(database.clone(), onchain_transaction_service);
+	tokio::spawn(async move { transaction_tracker.run(TRANSACTION_POLL_INTERVAL).await });
+
 	let rocket_handler = inject_app(
 		rocket::build(),
 		database.clone(),
-		starknet,
+		onchain_contribution_service,
 		contribution_repository,
 		contact_information_service,
-		application_projector,
-		contribution_projector,
+		application_dispatcher,
+		contribution_dispatcher,
+		vec![contribution_projector, application_projector],
 		uuid_generator,
 	)
 	.manage(database.clone())
 	.manage(RepoCache::default())
 	.manage(ContributorCache::default())
+	.manage(starknet)
 	.manage(github_client)
 	.attach(routes::cors::Cors)
 	.mount(
@@ -92,17 +168,30 @@ async fn main() {
 			routes::new_project,
 			routes::list_projects,
 			routes::create_contribution,
+			routes::find_contribution,
+			routes::list_contribution_events,
 			routes::assign_contributor,
+			routes::simulate_contributor_assignment,
 			routes::validate_contribution,
+			routes::simulate_contribution_validation,
 			routes::unassign_contributor,
+			routes::simulate_contributor_unassignment,
 			routes::apply_to_contribution,
 			routes::list_applications,
 			routes::refresh_applications,
 			routes::accept_application,
 			routes::list_contributor_applications,
 			routes::refresh_contributions,
+			routes::check_projections,
 			routes::contact_information::find_contact_information,
 			routes::contact_information::put_contact_information,
+			routes::list_dead_letter_events,
+			routes::retry_dead_letter_event,
+			routes::discard_dead_letter_event,
+			routes::execute_action_batch,
+			routes::estimate_action_batch,
+			routes::find_transaction,
+			routes::rotate_admin_account,
 		],
 	)
 	.mount("/swagger", make_swagger_ui(&routes::get_docs()))
@@ -117,48 +206,111 @@ async fn main() {
 fn inject_app(
 	rocket: Rocket<Build>,
 	database: Arc<database::Client>,
-	starknet: Arc<starknet::SingleAdminClient>,
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
 	contribution_repository: AggregateRootRepository<Contribution>,
 	contact_information_service: Arc<dyn ContactInformationService>,
-	application_projector: Arc<ApplicationProjector>,
-	contribution_projector: Arc<ContributionProjector>,
+	application_dispatcher: Arc<ProjectionDispatcher>,
+	contribution_dispatcher: Arc<ProjectionDispatcher>,
+	contribution_projectors: Vec<Arc<dyn Projector<Contribution>>>,
 	uuid_generator: Arc<dyn UuidGenerator>,
 ) -> Rocket<Build> {
+	let contribution_simulator = Arc::new(ContributionSimulator::new(
+		contribution_repository.clone(),
+		onchain_contribution_service.clone(),
+	));
+
+	let command_dispatcher = Arc::new(
+		CommandDispatcher::default()
+			.with_middleware(Arc::new(LoggingMiddleware))
+			.with_middleware(Arc::new(AuthorizationMiddleware))
+			.with_middleware(Arc::new(IdempotencyMiddleware::new(
+				COMMAND_IDEMPOTENCY_WINDOW,
+			)))
+			.with_middleware(Arc::new(TransactionTrackingMiddleware::new(
+				database.clone(),
+			)))
+			.with_handler(CreateContributionHandler::new(
+				onchain_contribution_service.clone(),
+			))
+			.with_handler(AssignContributorHandler::new(
+				onchain_contribution_service.clone(),
+				database.clone(),
+			))
+			.with_handler(UnassignContributorHandler::new(
+				onchain_contribution_service.clone(),
+				database.clone(),
+			))
+			.with_handler(ValidateContributionHandler::new(
+				onchain_contribution_service.clone(),
+				database.clone(),
+			))
+			.with_handler(ExecuteActionsHandler::new(
+				onchain_contribution_service.clone(),
+				database.clone(),
+			))
+			.with_handler(AcceptApplicationHandler::new(
+				onchain_contribution_service.clone(),
+				database.clone(),
+				database.clone(),
+			))
+			.with_handler(ApplyToContributionHandler::new(
+				contribution_repository.clone(),
+				database.clone(),
+				application_dispatcher.clone(),
+				uuid_generator,
+			)),
+	);
+
 	rocket
-		.manage(CreateContribution::new_usecase_boxed(starknet.clone()))
+		.manage(CreateContribution::new_usecase_boxed(
+			command_dispatcher.clone(),
+		))
 		.manage(AssignContribution::new_usecase_boxed(
-			starknet.clone(),
-			database.clone(),
+			command_dispatcher.clone(),
+			contribution_simulator.clone(),
 		))
 		.manage(UnassignContribution::new_usecase_boxed(
-			starknet.clone(),
-			database.clone(),
+			command_dispatcher.clone(),
+			contribution_simulator.clone(),
 		))
 		.manage(ApplyToContribution::new_usecase_boxed(
-			contribution_repository,
-			database.clone(),
-			application_projector.clone(),
-			uuid_generator,
+			command_dispatcher.clone(),
 		))
 		.manage(ValidateContribution::new_usecase_boxed(
-			starknet.clone(),
-			database.clone(),
+			command_dispatcher.clone(),
+			contribution_simulator.clone(),
 		))
 		.manage(AcceptApplication::new_usecase_boxed(
-			starknet,
-			database.clone(),
-			database.clone(),
+			command_dispatcher.clone(),
+		))
+		.manage(ExecuteActionBatch::new_usecase_boxed(
+			command_dispatcher,
+			onchain_contribution_service,
 		))
 		.manage(RefreshContributions::new(
 			database.clone(),
-			contribution_projector,
+			contribution_dispatcher.clone(),
 			database.clone(),
 		))
 		.manage(RefreshApplications::new(
 			database.clone(),
-			application_projector,
+			application_dispatcher.clone(),
+			database.clone(),
+		))
+		.manage(CheckProjections::new_usecase_boxed(ProjectionChecker::new(
+			database.clone(),
+			database.clone(),
+			database.clone(),
+			contribution_projectors,
+		)))
+		.manage(RetryDeadLetterEvent::new_usecase_boxed(
 			database.clone(),
+			vec![contribution_dispatcher, application_dispatcher],
 		))
+		.manage(database.clone() as Arc<dyn DeadLetterEventRepository>)
+		.manage(database.clone() as Arc<dyn OnchainTransactionRepository>)
+		.manage(database.clone() as Arc<dyn EventStore<Contribution>>)
 		.manage(database as Arc<dyn ApplicationProjectionRepository>)
 		.manage(contact_information_service)
+		.manage(contribution_repository)
 }
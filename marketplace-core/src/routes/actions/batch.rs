@@ -0,0 +1,300 @@
+use http_api_problem::HttpApiProblem;
+use marketplace_core::{application::ExecuteActionBatchUsecase, dto};
+use marketplace_domain::{Action, ParseHexPrefixedStringError};
+use rocket::{
+	response::status,
+	serde::{json::Json, Deserialize},
+	State,
+};
+use rocket_okapi::{openapi, JsonSchema};
+
+use crate::routes::{
+	api_key::ApiKey, idempotency_key::IdempotencyKey, to_http_api_problem::ToHttpApiProblem,
+	u256::U256Param,
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(crate = "rocket::serde", tag = "type", rename_all = "snake_case")]
+pub enum ActionDto {
+	CreateContribution {
+		project_id: u64,
+		github_issue_number: u64,
+		gate: u8,
+	},
+	AssignContributor {
+		contribution_id: String,
+		contributor_id: U256Param,
+	},
+	UnassignContributor {
+		contribution_id: String,
+	},
+	ValidateContribution {
+		contribution_id: String,
+	},
+}
+
+impl TryFrom<ActionDto> for Action {
+	type Error = ParseHexPrefixedStringError;
+
+	fn try_from(action: ActionDto) -> Result<Self, Self::Error> {
+		Ok(match action {
+			ActionDto::CreateContribution {
+				project_id,
+				github_issue_number,
+				gate,
+			} => Action::CreateContribution {
+				project_id,
+				issue_number: github_issue_number,
+				gate,
+			},
+			ActionDto::AssignContributor {
+				contribution_id,
+				contributor_id,
+			} => Action::AssignContributor {
+				contribution_id: contribution_id.parse()?,
+				contributor_id: contributor_id.into(),
+			},
+			ActionDto::UnassignContributor { contribution_id } => Action::UnassignContributor {
+				contribution_id: contribution_id.parse()?,
+			},
+			ActionDto::ValidateContribution { contribution_id } => Action::ValidateContribution {
+				contribution_id: contribution_id.parse()?,
+			},
+		})
+	}
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(crate = "rocket::serde")]
+pub struct ActionBatchDto {
+	actions: Vec<ActionDto>,
+}
+
+impl TryFrom<ActionBatchDto> for Vec<Action> {
+	type Error = ParseHexPrefixedStringError;
+
+	fn try_from(batch: ActionBatchDto) -> Result<Self, Self::Error> {
+		batch.actions.into_iter().map(Action::try_from).collect()
+	}
+}
+
+#[openapi(tag = "Actions")]
+#[post("/actions/batch", format = "application/json", data = "<body>")]
+pub async fn execute_action_batch(
+	api_key: ApiKey,
+	idempotency_key: IdempotencyKey,
+	body: Json<ActionBatchDto>,
+	usecase: &State<Box<dyn ExecuteActionBatchUsecase>>,
+) -> Result<status::Accepted<Json<dto::ActionBatchReceipt>>, HttpApiProblem> {
+	let actions =
+		Vec::<Action>::try_from(body.into_inner()).map_err(|e| e.to_http_api_problem())?;
+
+	let transaction_hash = usecase
+		.execute(actions.clone(), api_key.into(), idempotency_key.into())
+		.await
+		.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(status::Accepted(Some(Json(dto::ActionBatchReceipt::new(
+		transaction_hash,
+		&actions,
+	)))))
+}
+
+#[openapi(tag = "Actions")]
+#[post(
+	"/actions/batch/estimate",
+	format = "application/json",
+	data = "<body>"
+)]
+pub async fn estimate_action_batch(
+	_api_key: ApiKey,
+	body: Json<ActionBatchDto>,
+	usecase: &State<Box<dyn ExecuteActionBatchUsecase>>,
+) -> Result<Json<dto::ActionBatchFeeEstimate>, HttpApiProblem> {
+	let actions =
+		Vec::<Action>::try_from(body.into_inner()).map_err(|e| e.to_http_api_problem())?;
+
+	let fee_estimate =
+		usecase.estimate(actions.clone()).await.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(Json(dto::ActionBatchFeeEstimate::new(
+		fee_estimate,
+		&actions,
+	)))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use http_api_problem::StatusCode;
+	use marketplace_core::application::MockExecuteActionBatch;
+	use marketplace_domain::{Error as DomainError, *};
+	use mockall::predicate::*;
+	use rocket::serde::json::serde_json;
+	use std::str::FromStr;
+
+	fn body() -> Json<ActionBatchDto> {
+		Json(
+			serde_json::from_str(
+				r#"{
+					"actions": [
+						{"type": "create_contribution", "project_id": 1, "github_issue_number": 2, "gate": 0},
+						{"type": "assign_contributor", "contribution_id": "0x12", "contributor_id": "0x2a"},
+						{"type": "validate_contribution", "contribution_id": "0x12"}
+					]
+				}"#,
+			)
+			.unwrap(),
+		)
+	}
+
+	#[tokio::test]
+	async fn batch_should_return_transaction_hash_and_summary() {
+		let mut usecase = MockExecuteActionBatch::new();
+
+		usecase
+			.expect_execute()
+			.with(
+				eq(vec![
+					Action::CreateContribution {
+						project_id: 1,
+						issue_number: 2,
+						gate: 0,
+					},
+					Action::AssignContributor {
+						contribution_id: ContributionId::from_str("0x12").unwrap(),
+						contributor_id: ContributorId::from(42),
+					},
+					Action::ValidateContribution {
+						contribution_id: ContributionId::from_str("0x12").unwrap(),
+					},
+				]),
+				eq(Issuer::Admin),
+				eq(None),
+			)
+			.returning(|_, _, _| Ok(HexPrefixedString::from_str("0x1234").unwrap()));
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn ExecuteActionBatchUsecase>);
+
+		let result = execute_action_batch(
+			ApiKey::default(),
+			IdempotencyKey::default(),
+			body(),
+			State::get(&rocket).unwrap(),
+		)
+		.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let receipt = result.unwrap().0.unwrap().into_inner();
+		assert_eq!("0x1234", receipt.transaction_hash);
+		assert_eq!(3, receipt.actions.len());
+		assert_eq!("create_contribution", receipt.actions[0].action);
+		assert_eq!(None, receipt.actions[0].contribution_id);
+		assert_eq!("assign_contributor", receipt.actions[1].action);
+		assert_eq!(
+			Some("0x0012".to_string()),
+			receipt.actions[1].contribution_id
+		);
+		assert_eq!(2, receipt.actions[2].index);
+		assert_eq!(
+			"Validate contribution 0x0012.",
+			receipt.actions[2].description
+		);
+	}
+
+	#[tokio::test]
+	async fn batch_should_return_400_upon_invalid_contribution_id() {
+		let mut usecase = MockExecuteActionBatch::new();
+		usecase.expect_execute().never();
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn ExecuteActionBatchUsecase>);
+
+		let body = Json(
+			serde_json::from_str(
+				r#"{"actions": [{"type": "validate_contribution", "contribution_id": "12"}]}"#,
+			)
+			.unwrap(),
+		);
+
+		let result = execute_action_batch(
+			ApiKey::default(),
+			IdempotencyKey::default(),
+			body,
+			State::get(&rocket).unwrap(),
+		)
+		.await;
+
+		assert!(result.is_err());
+		assert_eq!(StatusCode::BAD_REQUEST, result.unwrap_err().status.unwrap());
+	}
+
+	#[tokio::test]
+	async fn batch_should_return_400_upon_empty_batch() {
+		let mut usecase = MockExecuteActionBatch::new();
+		usecase.expect_execute().returning(|_, _, _| Err(DomainError::EmptyActionBatch));
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn ExecuteActionBatchUsecase>);
+
+		let result = execute_action_batch(
+			ApiKey::default(),
+			IdempotencyKey::default(),
+			Json(serde_json::from_str(r#"{"actions": []}"#).unwrap()),
+			State::get(&rocket).unwrap(),
+		)
+		.await;
+
+		assert!(result.is_err());
+		assert_eq!(StatusCode::BAD_REQUEST, result.unwrap_err().status.unwrap());
+	}
+
+	#[tokio::test]
+	async fn estimate_should_return_fees_and_summary() {
+		let mut usecase = MockExecuteActionBatch::new();
+		usecase.expect_execute().never();
+		usecase.expect_estimate().times(1).returning(|_| {
+			Ok(FeeEstimate {
+				estimated_fee: 20_000_000_000_000_000,
+				max_fee: 30_000_000_000_000_000,
+			})
+		});
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn ExecuteActionBatchUsecase>);
+
+		let result =
+			estimate_action_batch(ApiKey::default(), body(), State::get(&rocket).unwrap()).await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let estimate = result.unwrap().into_inner();
+		assert_eq!("20000000000000000", estimate.estimated_fee);
+		assert_eq!("30000000000000000", estimate.max_fee);
+		assert_eq!(3, estimate.actions.len());
+	}
+
+	#[tokio::test]
+	async fn estimate_should_return_422_upon_fee_above_cap() {
+		let mut usecase = MockExecuteActionBatch::new();
+		usecase.expect_estimate().returning(|_| {
+			Err(DomainError::OnchainContributionService(
+				OnchainContributionServiceError::FeeTooHigh(Box::new(std::fmt::Error)),
+			))
+		});
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn ExecuteActionBatchUsecase>);
+
+		let result =
+			estimate_action_batch(ApiKey::default(), body(), State::get(&rocket).unwrap()).await;
+
+		assert!(result.is_err());
+		assert_eq!(
+			StatusCode::UNPROCESSABLE_ENTITY,
+			result.unwrap_err().status.unwrap()
+		);
+	}
+}
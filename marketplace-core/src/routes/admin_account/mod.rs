@@ -0,0 +1,3 @@
+mod rotate;
+
+pub use rotate::*;
@@ -0,0 +1,56 @@
+use http_api_problem::{HttpApiProblem, StatusCode};
+use marketplace_infrastructure::starknet::SingleAdminClient;
+use rocket::{
+	http::Status,
+	serde::{json::Json, Deserialize},
+	State,
+};
+use rocket_okapi::{openapi, JsonSchema};
+use starknet::core::types::FieldElement;
+use std::{sync::Arc, time::Duration};
+
+use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem};
+
+/// Time given to the transactions signed with the previous account to be sent
+const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(crate = "rocket::serde")]
+pub struct AdminAccountRotationDto {
+	account_address: String,
+	/// Name of a signer declared in `ADMIN_SIGNERS`
+	signer: String,
+	drain_timeout_seconds: Option<u64>,
+}
+
+/// The rotation is not persisted: on restart, the admin account is read again from
+/// `ACCOUNT_ADDRESS` and `SIGNER`
+#[openapi(tag = "Admin account")]
+#[post("/admin-account/rotate", format = "application/json", data = "<body>")]
+pub async fn rotate_admin_account(
+	_api_key: ApiKey,
+	body: Json<AdminAccountRotationDto>,
+	starknet: &State<Option<Arc<SingleAdminClient>>>,
+) -> Result<Status, HttpApiProblem> {
+	let body = body.into_inner();
+
+	let account_address = FieldElement::from_hex_be(&body.account_address).map_err(|_| {
+		HttpApiProblem::new(StatusCode::BAD_REQUEST).title("Invalid account address")
+	})?;
+	let drain_timeout = body
+		.drain_timeout_seconds
+		.map(Duration::from_secs)
+		.unwrap_or(DEFAULT_DRAIN_TIMEOUT);
+
+	let starknet = starknet.as_ref().ok_or_else(|| {
+		HttpApiProblem::new(StatusCode::CONFLICT)
+			.title("No admin account is used by the off-chain contribution service")
+	})?;
+
+	starknet
+		.rotate_admin_account(account_address, &body.signer, drain_timeout)
+		.await
+		.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(Status::Ok)
+}
@@ -2,28 +2,30 @@
 mod tests;
 
 use http_api_problem::HttpApiProblem;
-use marketplace_core::application::AcceptApplicationUsecase;
+use marketplace_core::{application::AcceptApplicationUsecase, dto};
 use marketplace_domain::ApplicationId;
-use rocket::{response::status, State};
+use rocket::{response::status, serde::json::Json, State};
 use rocket_okapi::openapi;
 
-use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem, uuid::UuidParam};
+use crate::routes::{
+	api_key::ApiKey, idempotency_key::IdempotencyKey, to_http_api_problem::ToHttpApiProblem,
+	uuid::UuidParam,
+};
 
 #[openapi(tag = "Contributions")]
 #[put("/applications/<application_id>/accept")]
 pub async fn accept_application(
-	_api_key: ApiKey,
+	api_key: ApiKey,
+	idempotency_key: IdempotencyKey,
 	application_id: UuidParam,
 	usecase: &State<Box<dyn AcceptApplicationUsecase>>,
-) -> Result<status::Accepted<()>, HttpApiProblem> {
+) -> Result<status::Accepted<Json<dto::SubmittedTransaction>>, HttpApiProblem> {
 	let application_id: ApplicationId = (*application_id.as_uuid()).into();
 
-	usecase
-		.accept_application(&application_id)
+	let transaction_hash = usecase
+		.accept_application(&application_id, api_key.into(), idempotency_key.into())
 		.await
 		.map_err(|e| e.to_http_api_problem())?;
 
-	// TODO after action queue is removed:
-	// return the hash of the on-chain transaction containing the call
-	Ok(status::Accepted(None))
+	Ok(status::Accepted(Some(Json(transaction_hash.into()))))
 }
@@ -2,6 +2,7 @@ use super::accept_application;
 
 use marketplace_domain::{
 	ApplicationId, ApplicationProjectionRepositoryError, Error as DomainError, HexPrefixedString,
+	Issuer,
 };
 
 use marketplace_core::application::AcceptApplicationUsecase;
@@ -10,8 +11,10 @@ use rocket::{
 	local::blocking::Client,
 	Build,
 };
+use std::str::FromStr;
 
 const URI: &str = "/applications/a6127643-1344-4a44-bbfb-7142c17a4ef0/accept";
+const IDEMPOTENCY_KEY: &str = "3b0f4a5e-8c2d-4f1b-9a6e-7d5c4b3a2f10";
 
 struct SuccessfulUsecase;
 
@@ -20,8 +23,10 @@ impl AcceptApplicationUsecase for SuccessfulUsecase {
 	async fn accept_application(
 		&self,
 		_application_id: &ApplicationId,
+		_issuer: Issuer,
+		_idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError> {
-		Ok(HexPrefixedString::default())
+		Ok(HexPrefixedString::from_str("0x1234").unwrap())
 	}
 }
 
@@ -32,6 +37,8 @@ impl AcceptApplicationUsecase for FailedUsecase {
 	async fn accept_application(
 		&self,
 		_application_id: &ApplicationId,
+		_issuer: Issuer,
+		_idempotency_key: Option<String>,
 	) -> Result<HexPrefixedString, DomainError> {
 		Err(DomainError::ApplicationProjectionRepository(
 			ApplicationProjectionRepositoryError::NotFound,
@@ -39,6 +46,21 @@ impl AcceptApplicationUsecase for FailedUsecase {
 	}
 }
 
+struct IdempotentUsecase;
+
+#[async_trait]
+impl AcceptApplicationUsecase for IdempotentUsecase {
+	async fn accept_application(
+		&self,
+		_application_id: &ApplicationId,
+		_issuer: Issuer,
+		idempotency_key: Option<String>,
+	) -> Result<HexPrefixedString, DomainError> {
+		assert_eq!(Some(String::from(IDEMPOTENCY_KEY)), idempotency_key);
+		Ok(HexPrefixedString::from_str("0x1234").unwrap())
+	}
+}
+
 fn rocket(state: Box<dyn AcceptApplicationUsecase>) -> rocket::Rocket<Build> {
 	rocket::build().mount("/", routes![accept_application]).manage(state)
 }
@@ -53,7 +75,26 @@ fn succes() {
 	let response = client.put(URI).header(Header::new("Api-Key", "ROOT")).dispatch();
 
 	assert_eq!(response.status(), Status::Accepted);
-	assert!(response.body().is_none());
+	assert_eq!(
+		Some(String::from(r#"{"transaction_hash":"0x1234"}"#)),
+		response.into_string()
+	);
+}
+
+#[test]
+fn idempotency_key_is_forwarded() {
+	std::env::set_var("API_KEY", "ROOT");
+
+	let client =
+		Client::untracked(rocket(Box::new(IdempotentUsecase))).expect("valid rocket instance");
+
+	let response = client
+		.put(URI)
+		.header(Header::new("Api-Key", "ROOT"))
+		.header(Header::new("Idempotency-Key", IDEMPOTENCY_KEY))
+		.dispatch();
+
+	assert_eq!(response.status(), Status::Accepted);
 }
 
 #[test]
@@ -27,6 +27,13 @@ impl ApplicationProjectionRepository for EmptyDatabase {
 		unimplemented!()
 	}
 
+	fn delete_by_contribution(
+		&self,
+		_contribution_id: &ContributionId,
+	) -> Result<(), ApplicationProjectionRepositoryError> {
+		unimplemented!()
+	}
+
 	fn find(
 		&self,
 		_id: &ApplicationId,
@@ -73,6 +80,13 @@ impl ApplicationProjectionRepository for FilledDatabase {
 		unimplemented!()
 	}
 
+	fn delete_by_contribution(
+		&self,
+		_contribution_id: &ContributionId,
+	) -> Result<(), ApplicationProjectionRepositoryError> {
+		unimplemented!()
+	}
+
 	fn find(
 		&self,
 		_id: &ApplicationId,
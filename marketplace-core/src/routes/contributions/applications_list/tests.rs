@@ -26,6 +26,13 @@ impl ApplicationProjectionRepository for EmptyDatabase {
 		unimplemented!()
 	}
 
+	fn delete_by_contribution(
+		&self,
+		_contribution_id: &ContributionId,
+	) -> Result<(), ApplicationProjectionRepositoryError> {
+		unimplemented!()
+	}
+
 	fn find(
 		&self,
 		_id: &ApplicationId,
@@ -72,6 +79,13 @@ impl ApplicationProjectionRepository for FilledDatabase {
 		unimplemented!()
 	}
 
+	fn delete_by_contribution(
+		&self,
+		_contribution_id: &ContributionId,
+	) -> Result<(), ApplicationProjectionRepositoryError> {
+		unimplemented!()
+	}
+
 	fn find(
 		&self,
 		_id: &ApplicationId,
@@ -9,7 +9,9 @@ use rocket_okapi::openapi;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
-use crate::routes::{to_http_api_problem::ToHttpApiProblem, u256::U256Param};
+use crate::routes::{
+	issuer::AuthenticatedIssuer, to_http_api_problem::ToHttpApiProblem, u256::U256Param,
+};
 
 #[derive(Deserialize, JsonSchema)]
 #[serde(crate = "rocket::serde")]
@@ -24,6 +26,7 @@ pub struct ApplyDto {
 	data = "<body>"
 )]
 pub async fn apply_to_contribution(
+	issuer: AuthenticatedIssuer,
 	contribution_id: String,
 	body: Json<ApplyDto>,
 	usecase: &State<Box<dyn ApplyToContributionUsecase>>,
@@ -35,7 +38,7 @@ pub async fn apply_to_contribution(
 	debug!("contributor_id {}", contributor_id.to_string());
 
 	usecase
-		.apply_to_contribution(&contribution_id, &contributor_id)
+		.apply_to_contribution(&contribution_id, &contributor_id, issuer.into())
 		.await
 		.map_err(|e| e.to_http_api_problem())?;
 
@@ -3,7 +3,7 @@ use std::{collections::HashMap, str::FromStr, sync::RwLock};
 use super::*;
 use marketplace_domain::{Error as DomainError, *};
 use rocket::{
-	http::{ContentType, Status},
+	http::{ContentType, Header, Status},
 	local::blocking::Client,
 	Build,
 };
@@ -28,6 +28,7 @@ impl ApplyToContributionUsecase for ApplyToContribution {
 		&self,
 		contribution_id: &ContributionId,
 		contributor_id: &ContributorId,
+		_issuer: Issuer,
 	) -> Result<(), DomainError> {
 		let mut lock = self.0.write().unwrap();
 		let contribution_db = lock.get_mut(contribution_id).ok_or_else(|| {
@@ -56,12 +57,28 @@ fn rocket() -> rocket::Rocket<Build> {
 	let mut database = HashMap::new();
 	database.insert(contribution_id_1, HashMap::new());
 
+	std::env::set_var("API_KEY", "ROOT");
 	rocket::build()
 		.mount("/", routes![apply_to_contribution])
 		.manage(Box::new(ApplyToContribution(RwLock::new(database)))
 			as Box<dyn ApplyToContributionUsecase>)
 }
 
+fn api_key() -> Header<'static> {
+	Header::new("Api-Key", "ROOT")
+}
+
+#[test]
+fn should_return_401_without_authentication() {
+	let uri = format!("/contributions/{CONTRIBUTION_ID_1}/applications");
+	let contributor_id = "0x0000000000000000000000000000000000000000000000000000000000000000";
+	let body = json!({ "contributor_id": contributor_id }).to_string();
+
+	let client = Client::untracked(rocket()).expect("valid rocket instance");
+	let response = client.post(uri).header(ContentType::JSON).body(body).dispatch();
+	assert_eq!(response.status(), Status::Unauthorized);
+}
+
 #[test]
 fn should_return_200_when_ok() {
 	let api_url = "0.0.0.0:8000";
@@ -72,7 +89,12 @@ fn should_return_200_when_ok() {
 	let body = json!({ "contributor_id": contributor_id }).to_string();
 
 	let client = Client::untracked(rocket()).expect("valid rocket instance");
-	let response = client.post(uri.clone()).header(ContentType::JSON).body(body).dispatch();
+	let response = client
+		.post(uri.clone())
+		.header(ContentType::JSON)
+		.header(api_key())
+		.body(body)
+		.dispatch();
 	assert_eq!(response.status(), Status::Created);
 	assert_ne!(
 		format!("{api_url}/{uri}/{contributor_id}"),
@@ -90,10 +112,20 @@ fn should_return_409_if_already_exist() {
 	let body = json!({ "contributor_id": contributor_id }).to_string();
 
 	let client = Client::untracked(rocket()).expect("valid rocket instance");
-	let response = client.post(uri.clone()).header(ContentType::JSON).body(body.clone()).dispatch();
+	let response = client
+		.post(uri.clone())
+		.header(ContentType::JSON)
+		.header(api_key())
+		.body(body.clone())
+		.dispatch();
 	assert_eq!(response.status(), Status::Created);
 
-	let response = client.post(uri).header(ContentType::JSON).body(body).dispatch();
+	let response = client
+		.post(uri)
+		.header(ContentType::JSON)
+		.header(api_key())
+		.body(body)
+		.dispatch();
 	assert_eq!(response.status(), Status::Conflict);
 	let http_api_problem_response = response.into_json::<HttpApiProblem>().unwrap();
 	assert_eq!(
@@ -119,7 +151,12 @@ fn should_return_400_if_invalid_parameters() {
 	let body = json!({ "contributor_id": contributor_id }).to_string();
 
 	let client = Client::untracked(rocket()).expect("valid rocket instance");
-	let response = client.post(uri).header(ContentType::JSON).body(body).dispatch();
+	let response = client
+		.post(uri)
+		.header(ContentType::JSON)
+		.header(api_key())
+		.body(body)
+		.dispatch();
 	assert_eq!(response.status(), Status::BadRequest);
 	let http_api_problem_response = response.into_json::<HttpApiProblem>().unwrap();
 	assert_eq!(
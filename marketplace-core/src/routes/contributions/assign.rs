@@ -1,5 +1,5 @@
 use http_api_problem::HttpApiProblem;
-use marketplace_core::application::AssignContributionUsecase;
+use marketplace_core::{application::AssignContributionUsecase, dto};
 use marketplace_domain::ParseHexPrefixedStringError;
 use rocket::{
 	response::status,
@@ -8,7 +8,10 @@ use rocket::{
 };
 use rocket_okapi::{openapi, JsonSchema};
 
-use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem, u256::U256Param};
+use crate::routes::{
+	api_key::ApiKey, idempotency_key::IdempotencyKey, to_http_api_problem::ToHttpApiProblem,
+	u256::U256Param,
+};
 
 #[derive(Deserialize, JsonSchema)]
 #[serde(crate = "rocket::serde")]
@@ -24,22 +27,53 @@ pub struct AssignContributorDto {
 )]
 #[deprecated(since = "0.1.0", note = "please use `accept_application` instead")]
 pub async fn assign_contributor(
+	api_key: ApiKey,
+	idempotency_key: IdempotencyKey,
+	contribution_id: String,
+	body: Json<AssignContributorDto>,
+	usecase: &State<Box<dyn AssignContributionUsecase>>,
+) -> Result<status::Accepted<Json<dto::SubmittedTransaction>>, HttpApiProblem> {
+	let contributor_id = body.into_inner().contributor_id.into();
+	let contribution_id = contribution_id
+		.parse()
+		.map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
+
+	let transaction_hash = usecase
+		.send_assign_request(
+			&contribution_id,
+			&contributor_id,
+			api_key.into(),
+			idempotency_key.into(),
+		)
+		.await
+		.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(status::Accepted(Some(Json(transaction_hash.into()))))
+}
+
+#[openapi(tag = "Contributions")]
+#[post(
+	"/contributions/<contribution_id>/contributor/simulate",
+	format = "application/json",
+	data = "<body>"
+)]
+pub async fn simulate_contributor_assignment(
 	_api_key: ApiKey,
 	contribution_id: String,
 	body: Json<AssignContributorDto>,
 	usecase: &State<Box<dyn AssignContributionUsecase>>,
-) -> Result<status::Accepted<()>, HttpApiProblem> {
+) -> Result<Json<dto::ContributionSimulation>, HttpApiProblem> {
 	let contributor_id = body.into_inner().contributor_id.into();
 	let contribution_id = contribution_id
 		.parse()
 		.map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
 
-	usecase
-		.send_assign_request(&contribution_id, &contributor_id)
+	let simulation = usecase
+		.simulate_assign_request(&contribution_id, &contributor_id)
 		.await
 		.map_err(|e| e.to_http_api_problem())?;
 
-	Ok(status::Accepted(None))
+	Ok(Json(simulation.into()))
 }
 
 #[cfg(test)]
@@ -67,14 +101,17 @@ mod test {
 			.with(
 				eq(ContributionId::from_str("0x12").unwrap()),
 				eq(ContributorId::from(34)),
+				eq(Issuer::Admin),
+				eq(None),
 			)
-			.returning(|_, _| Ok(HexPrefixedString::default()));
+			.returning(|_, _, _, _| Ok(HexPrefixedString::default()));
 
 		let rocket =
 			rocket::build().manage(Box::new(usecase) as Box<dyn AssignContributionUsecase>);
 
 		let result = assign_contributor(
 			ApiKey::default(),
+			IdempotencyKey::default(),
 			"0x12".into(),
 			AssignContributorDto {
 				contributor_id: U256::from_u128(34).into(),
@@ -86,14 +123,17 @@ mod test {
 
 		assert!(result.is_ok(), "{}", result.err().unwrap());
 
-		assert_eq!(status::Accepted(None), result.unwrap());
+		assert_eq!(
+			HexPrefixedString::default().to_string(),
+			result.unwrap().0.unwrap().into_inner().transaction_hash
+		);
 	}
 
 	#[tokio::test]
 	async fn assign_should_return_500_upon_failure() {
 		let mut usecase = MockAssignContribution::new();
 
-		usecase.expect_send_assign_request().returning(|_, _| {
+		usecase.expect_send_assign_request().returning(|_, _, _, _| {
 			Err(ContributionProjectionRepositoryError::Infrastructure(Box::new(Error)).into())
 		});
 
@@ -102,6 +142,7 @@ mod test {
 
 		let result = assign_contributor(
 			ApiKey::default(),
+			IdempotencyKey::default(),
 			"0x12".into(),
 			AssignContributorDto {
 				contributor_id: U256::from_u128(34).into(),
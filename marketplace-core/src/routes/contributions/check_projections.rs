@@ -0,0 +1,60 @@
+use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem};
+use http_api_problem::HttpApiProblem;
+use marketplace_core::{application::CheckProjectionsUsecase, dto};
+use rocket::{serde::json::Json, State};
+use rocket_okapi::openapi;
+
+#[openapi(tag = "Contributions")]
+#[post("/contributions/projections/check?<repair>")]
+pub async fn check_projections(
+	_api_key: ApiKey,
+	repair: Option<bool>,
+	usecase: &State<Box<dyn CheckProjectionsUsecase>>,
+) -> Result<Json<dto::ProjectionCheckReport>, HttpApiProblem> {
+	let report = usecase
+		.check(repair.unwrap_or_default())
+		.await
+		.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(Json(report.into()))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use marketplace_core::application::MockCheckProjections;
+	use marketplace_domain::*;
+	use mockall::predicate::*;
+	use std::str::FromStr;
+
+	#[tokio::test]
+	async fn check_projections_should_return_the_report() {
+		let mut usecase = MockCheckProjections::new();
+		usecase.expect_check().with(eq(true)).once().returning(|_| {
+			let contribution_id = ContributionId::from_str("0x12").unwrap();
+			Ok(ProjectionCheckReport {
+				checked_contributions: 3,
+				mismatches: vec![ProjectionMismatch::MissingContribution {
+					contribution_id: contribution_id.clone(),
+				}],
+				repaired_contributions: vec![contribution_id],
+			})
+		});
+
+		let rocket = rocket::build().manage(Box::new(usecase) as Box<dyn CheckProjectionsUsecase>);
+
+		let result =
+			check_projections(ApiKey::default(), Some(true), State::get(&rocket).unwrap()).await;
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let report = result.unwrap().into_inner();
+		assert_eq!(3, report.checked_contributions);
+		assert_eq!(1, report.mismatches.len());
+		assert_eq!("0x12", report.mismatches[0].contribution_id);
+		assert_eq!(
+			"Contribution 0x12 is missing from the projection",
+			report.mismatches[0].description
+		);
+		assert_eq!(vec![String::from("0x12")], report.repaired_contributions);
+	}
+}
@@ -1,18 +1,22 @@
-use crate::routes::api_key::ApiKey;
+use crate::routes::{api_key::ApiKey, idempotency_key::IdempotencyKey};
 use http_api_problem::{HttpApiProblem, StatusCode};
-use marketplace_core::{application::CreateContributionUsecase, dto::ContributionCreation};
+use marketplace_core::{
+	application::CreateContributionUsecase,
+	dto::{self, ContributionCreation},
+};
 use marketplace_domain::*;
-use rocket::{http::Status, serde::json::Json, State};
+use rocket::{response::status, serde::json::Json, State};
 use rocket_okapi::openapi;
 use std::result::Result;
 
 #[openapi(tag = "Contributions")]
 #[post("/contributions/github", format = "application/json", data = "<body>")]
 pub async fn create_contribution(
-	_api_key: ApiKey,
+	api_key: ApiKey,
+	idempotency_key: IdempotencyKey,
 	body: Json<ContributionCreation>,
 	usecase: &State<Box<dyn CreateContributionUsecase>>,
-) -> Result<Status, HttpApiProblem> {
+) -> Result<status::Accepted<Json<dto::SubmittedTransaction>>, HttpApiProblem> {
 	let body = body.into_inner();
 
 	let contribution = ContributionProjection {
@@ -22,11 +26,14 @@ pub async fn create_contribution(
 		..Default::default()
 	};
 
-	usecase.send_creation_request(contribution).await.map_err(|error| {
-		HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
-			.title("Unable to send contribution creation request")
-			.detail(error.to_string())
-	})?;
+	let transaction_hash = usecase
+		.send_creation_request(contribution, api_key.into(), idempotency_key.into())
+		.await
+		.map_err(|error| {
+			HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
+				.title("Unable to send contribution creation request")
+				.detail(error.to_string())
+		})?;
 
-	Ok(Status::Accepted)
+	Ok(status::Accepted(Some(Json(transaction_hash.into()))))
 }
@@ -0,0 +1,134 @@
+use http_api_problem::{HttpApiProblem, StatusCode};
+use marketplace_core::dto;
+use marketplace_domain::{
+	AggregateRootRepositoryError, Contribution, EventStore, ParseHexPrefixedStringError,
+};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use std::sync::Arc;
+
+use crate::routes::to_http_api_problem::ToHttpApiProblem;
+
+#[openapi(tag = "Contributions")]
+#[get("/contributions/<contribution_id>/events")]
+pub async fn list_contribution_events(
+	contribution_id: String,
+	event_store: &State<Arc<dyn EventStore<Contribution>>>,
+) -> Result<Json<Vec<dto::ContributionEventRecord>>, HttpApiProblem> {
+	let contribution_id = contribution_id
+		.parse()
+		.map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
+
+	let recorded_events = event_store
+		.list_recorded_by_id(&contribution_id)
+		.map_err(|e| e.to_http_api_problem())?;
+
+	if recorded_events.is_empty() {
+		return Err(AggregateRootRepositoryError::NotFound.to_http_api_problem());
+	}
+
+	let events = recorded_events
+		.into_iter()
+		.map(dto::ContributionEventRecord::try_from)
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|e| {
+			HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
+				.title("Internal error")
+				.detail(e.to_string())
+		})?;
+
+	Ok(Json(events))
+}
+
+#[cfg(test)]
+mod test {
+	use std::{
+		str::FromStr,
+		time::{Duration, UNIX_EPOCH},
+	};
+
+	use super::*;
+	use marketplace_domain::*;
+	use mockall::predicate::*;
+	use serde_json::json;
+
+	const CONTRIBUTION_ID: &str = "0x12";
+
+	#[tokio::test]
+	async fn list_contribution_events_should_return_events_in_order() {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		event_store
+			.expect_list_recorded_by_id()
+			.with(eq(ContributionId::from_str(CONTRIBUTION_ID).unwrap()))
+			.returning(|_| {
+				Ok(vec![
+					RecordedEvent {
+						index: 3,
+						timestamp: UNIX_EPOCH,
+						event: ContributionEvent::Created {
+							id: ContributionId::from_str(CONTRIBUTION_ID).unwrap(),
+							project_id: 42,
+							issue_number: 7,
+							gate: 1,
+						},
+						transaction_hash: Some(HexPrefixedString::from_str("0x999").unwrap()),
+						block_number: Some(1000),
+						block_hash: Some(HexPrefixedString::from_str("0xabc").unwrap()),
+					},
+					RecordedEvent {
+						index: 8,
+						timestamp: UNIX_EPOCH + Duration::from_secs(60),
+						event: ContributionEvent::Applied {
+							id: ContributionId::from_str(CONTRIBUTION_ID).unwrap(),
+							contributor_id: ContributorId::from_str("0x34").unwrap(),
+						},
+						transaction_hash: None,
+						block_number: None,
+						block_hash: None,
+					},
+				])
+			});
+
+		let rocket =
+			rocket::build().manage(Arc::new(event_store) as Arc<dyn EventStore<Contribution>>);
+
+		let result =
+			list_contribution_events(CONTRIBUTION_ID.into(), State::get(&rocket).unwrap()).await;
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let events = result.unwrap().into_inner();
+		assert_eq!(2, events.len());
+
+		assert_eq!(3, events[0].index);
+		assert_eq!("1970-01-01T00:00:00.000000Z", events[0].timestamp);
+		assert_eq!(
+			json!({"Created": {"id": "0x12", "project_id": 42, "issue_number": 7, "gate": 1}}),
+			events[0].event
+		);
+		assert_eq!(Some(String::from("0x0999")), events[0].transaction_hash);
+		assert_eq!(Some(1000), events[0].block_number);
+		assert_eq!(Some(String::from("0x0abc")), events[0].block_hash);
+
+		assert_eq!(8, events[1].index);
+		assert_eq!("1970-01-01T00:01:00.000000Z", events[1].timestamp);
+		assert_eq!(None, events[1].transaction_hash);
+		assert_eq!(None, events[1].block_number);
+		assert_eq!(None, events[1].block_hash);
+	}
+
+	#[tokio::test]
+	async fn list_contribution_events_should_return_404_when_not_found() {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		event_store.expect_list_recorded_by_id().returning(|_| Ok(vec![]));
+
+		let rocket =
+			rocket::build().manage(Arc::new(event_store) as Arc<dyn EventStore<Contribution>>);
+
+		let result =
+			list_contribution_events(CONTRIBUTION_ID.into(), State::get(&rocket).unwrap()).await;
+		assert!(result.is_err());
+
+		let problem = result.err().unwrap();
+		assert_eq!(StatusCode::NOT_FOUND, problem.status.unwrap());
+	}
+}
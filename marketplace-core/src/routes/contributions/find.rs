@@ -0,0 +1,124 @@
+use http_api_problem::HttpApiProblem;
+use marketplace_core::dto;
+use marketplace_domain::{AggregateRootRepository, Contribution, ParseHexPrefixedStringError};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+
+use crate::routes::{point_in_time::PointInTimeParam, to_http_api_problem::ToHttpApiProblem};
+
+#[openapi(tag = "Contributions")]
+#[get("/contributions/<contribution_id>?<at>")]
+pub async fn find_contribution(
+	contribution_id: String,
+	at: Option<PointInTimeParam>,
+	contribution_repository: &State<AggregateRootRepository<Contribution>>,
+) -> Result<Json<dto::ContributionDetails>, HttpApiProblem> {
+	let contribution_id = contribution_id
+		.parse()
+		.map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
+
+	let contribution = match at {
+		Some(at) => contribution_repository.find_by_id_at(&contribution_id, &at.into()),
+		None => contribution_repository.find_by_id(&contribution_id),
+	}
+	.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(Json(contribution.into()))
+}
+
+#[cfg(test)]
+mod test {
+	use std::{str::FromStr, sync::Arc};
+
+	use super::*;
+	use http_api_problem::StatusCode;
+	use marketplace_domain::*;
+	use mockall::predicate::*;
+
+	const CONTRIBUTION_ID: &str = "0x12";
+
+	fn created_event() -> ContributionEvent {
+		ContributionEvent::Created {
+			id: ContributionId::from_str(CONTRIBUTION_ID).unwrap(),
+			project_id: 42,
+			issue_number: 7,
+			gate: 1,
+		}
+	}
+
+	fn assigned_event() -> ContributionEvent {
+		ContributionEvent::Assigned {
+			id: ContributionId::from_str(CONTRIBUTION_ID).unwrap(),
+			contributor_id: ContributorId::from_str("0x34").unwrap(),
+		}
+	}
+
+	#[tokio::test]
+	async fn find_contribution_should_return_current_state() {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		event_store
+			.expect_list_by_id()
+			.with(eq(ContributionId::from_str(CONTRIBUTION_ID).unwrap()))
+			.returning(|_| Ok(vec![created_event(), assigned_event()]));
+
+		let rocket = rocket::build().manage(AggregateRootRepository::<Contribution>::new(
+			Arc::new(event_store),
+		));
+
+		let result =
+			find_contribution(CONTRIBUTION_ID.into(), None, State::get(&rocket).unwrap()).await;
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let contribution = result.unwrap();
+		assert_eq!("0x12", contribution.id);
+		assert_eq!(42, contribution.project_id);
+		assert_eq!(7, contribution.issue_number);
+		assert_eq!("ASSIGNED", contribution.status);
+		assert_eq!(Some(String::from("0x34")), contribution.contributor_id);
+	}
+
+	#[tokio::test]
+	async fn find_contribution_should_return_state_at_point_in_time() {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		event_store
+			.expect_list_by_id_at()
+			.with(
+				eq(ContributionId::from_str(CONTRIBUTION_ID).unwrap()),
+				eq(PointInTime::EventNumber(1)),
+			)
+			.returning(|_, _| Ok(vec![created_event()]));
+
+		let rocket = rocket::build().manage(AggregateRootRepository::<Contribution>::new(
+			Arc::new(event_store),
+		));
+
+		let result = find_contribution(
+			CONTRIBUTION_ID.into(),
+			Some(PointInTime::EventNumber(1).into()),
+			State::get(&rocket).unwrap(),
+		)
+		.await;
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let contribution = result.unwrap();
+		assert_eq!("OPEN", contribution.status);
+		assert_eq!(None, contribution.contributor_id);
+	}
+
+	#[tokio::test]
+	async fn find_contribution_should_return_404_when_not_found() {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		event_store.expect_list_by_id().returning(|_| Ok(vec![]));
+
+		let rocket = rocket::build().manage(AggregateRootRepository::<Contribution>::new(
+			Arc::new(event_store),
+		));
+
+		let result =
+			find_contribution(CONTRIBUTION_ID.into(), None, State::get(&rocket).unwrap()).await;
+		assert!(result.is_err());
+
+		let problem = result.err().unwrap();
+		assert_eq!(StatusCode::NOT_FOUND, problem.status.unwrap());
+	}
+}
@@ -1,7 +1,10 @@
 mod applications_list;
 mod apply;
 mod assign;
+mod check_projections;
 mod create;
+mod events;
+mod find;
 mod refresh;
 mod unassign;
 mod validate;
@@ -9,7 +12,10 @@ mod validate;
 pub use applications_list::*;
 pub use apply::*;
 pub use assign::*;
+pub use check_projections::*;
 pub use create::*;
+pub use events::*;
+pub use find::*;
 pub use refresh::*;
 pub use unassign::*;
 pub use validate::*;
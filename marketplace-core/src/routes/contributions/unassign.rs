@@ -1,29 +1,51 @@
 use http_api_problem::HttpApiProblem;
-use marketplace_core::application::UnassignContributionUsecase;
+use marketplace_core::{application::UnassignContributionUsecase, dto};
 use marketplace_domain::ParseHexPrefixedStringError;
-use rocket::{response::status, State};
+use rocket::{response::status, serde::json::Json, State};
 use rocket_okapi::openapi;
 
-use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem};
+use crate::routes::{
+	api_key::ApiKey, idempotency_key::IdempotencyKey, to_http_api_problem::ToHttpApiProblem,
+};
 
 #[openapi(tag = "Contributions")]
 #[delete("/contributions/<contribution_id>/contributor")]
 pub async fn unassign_contributor(
-	_api_key: ApiKey,
+	api_key: ApiKey,
+	idempotency_key: IdempotencyKey,
+
+	contribution_id: String,
+	usecase: &State<Box<dyn UnassignContributionUsecase>>,
+) -> Result<status::Accepted<Json<dto::SubmittedTransaction>>, HttpApiProblem> {
+	let contribution_id = contribution_id
+		.parse()
+		.map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
+
+	let transaction_hash = usecase
+		.send_unassign_request(&contribution_id, api_key.into(), idempotency_key.into())
+		.await
+		.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(status::Accepted(Some(Json(transaction_hash.into()))))
+}
 
+#[openapi(tag = "Contributions")]
+#[delete("/contributions/<contribution_id>/contributor/simulate")]
+pub async fn simulate_contributor_unassignment(
+	_api_key: ApiKey,
 	contribution_id: String,
 	usecase: &State<Box<dyn UnassignContributionUsecase>>,
-) -> Result<status::Accepted<()>, HttpApiProblem> {
+) -> Result<Json<dto::ContributionSimulation>, HttpApiProblem> {
 	let contribution_id = contribution_id
 		.parse()
 		.map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
 
-	usecase
-		.send_unassign_request(&contribution_id)
+	let simulation = usecase
+		.simulate_unassign_request(&contribution_id)
 		.await
 		.map_err(|e| e.to_http_api_problem())?;
 
-	Ok(status::Accepted(None))
+	Ok(Json(simulation.into()))
 }
 
 #[cfg(test)]
@@ -48,14 +70,19 @@ mod test {
 
 		usecase
 			.expect_send_unassign_request()
-			.with(eq(ContributionId::from_str("0x12").unwrap()))
-			.returning(|_| Ok(HexPrefixedString::default()));
+			.with(
+				eq(ContributionId::from_str("0x12").unwrap()),
+				eq(Issuer::Admin),
+				eq(None),
+			)
+			.returning(|_, _, _| Ok(HexPrefixedString::default()));
 
 		let rocket =
 			rocket::build().manage(Box::new(usecase) as Box<dyn UnassignContributionUsecase>);
 
 		let result = unassign_contributor(
 			ApiKey::default(),
+			IdempotencyKey::default(),
 			"0x12".into(),
 			State::get(&rocket).unwrap(),
 		)
@@ -63,14 +90,17 @@ mod test {
 
 		assert!(result.is_ok(), "{}", result.err().unwrap());
 
-		assert_eq!(status::Accepted(None), result.unwrap());
+		assert_eq!(
+			HexPrefixedString::default().to_string(),
+			result.unwrap().0.unwrap().into_inner().transaction_hash
+		);
 	}
 
 	#[tokio::test]
 	async fn unassign_should_return_500_upon_failure() {
 		let mut usecase = MockUnassignContribution::new();
 
-		usecase.expect_send_unassign_request().returning(|_| {
+		usecase.expect_send_unassign_request().returning(|_, _, _| {
 			Err(ContributionProjectionRepositoryError::Infrastructure(Box::new(Error)).into())
 		});
 
@@ -79,6 +109,7 @@ mod test {
 
 		let result = unassign_contributor(
 			ApiKey::default(),
+			IdempotencyKey::default(),
 			"0x12".into(),
 			State::get(&rocket).unwrap(),
 		)
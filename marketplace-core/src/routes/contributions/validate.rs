@@ -1,28 +1,50 @@
 use http_api_problem::HttpApiProblem;
-use marketplace_core::application::ValidateContributionUsecase;
+use marketplace_core::{application::ValidateContributionUsecase, dto};
 use marketplace_domain::ParseHexPrefixedStringError;
-use rocket::{response::status, State};
+use rocket::{response::status, serde::json::Json, State};
 use rocket_okapi::openapi;
 
-use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem};
+use crate::routes::{
+	api_key::ApiKey, idempotency_key::IdempotencyKey, to_http_api_problem::ToHttpApiProblem,
+};
 
 #[openapi(tag = "Contributions")]
 #[post("/contributions/<contribution_id>/validate")]
 pub async fn validate_contribution(
+	api_key: ApiKey,
+	idempotency_key: IdempotencyKey,
+	contribution_id: String,
+	usecase: &State<Box<dyn ValidateContributionUsecase>>,
+) -> Result<status::Accepted<Json<dto::SubmittedTransaction>>, HttpApiProblem> {
+	let contribution_id = contribution_id
+		.parse()
+		.map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
+
+	let transaction_hash = usecase
+		.send_validate_request(&contribution_id, api_key.into(), idempotency_key.into())
+		.await
+		.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(status::Accepted(Some(Json(transaction_hash.into()))))
+}
+
+#[openapi(tag = "Contributions")]
+#[post("/contributions/<contribution_id>/validate/simulate")]
+pub async fn simulate_contribution_validation(
 	_api_key: ApiKey,
 	contribution_id: String,
 	usecase: &State<Box<dyn ValidateContributionUsecase>>,
-) -> Result<status::Accepted<()>, HttpApiProblem> {
+) -> Result<Json<dto::ContributionSimulation>, HttpApiProblem> {
 	let contribution_id = contribution_id
 		.parse()
 		.map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
 
-	usecase
-		.send_validate_request(&contribution_id)
+	let simulation = usecase
+		.simulate_validate_request(&contribution_id)
 		.await
 		.map_err(|e| e.to_http_api_problem())?;
 
-	Ok(status::Accepted(None))
+	Ok(Json(simulation.into()))
 }
 
 #[cfg(test)]
@@ -46,14 +68,19 @@ mod test {
 
 		usecase
 			.expect_send_validate_request()
-			.with(eq(ContributionId::from_str("0x12").unwrap()))
-			.returning(|_| Ok(HexPrefixedString::default()));
+			.with(
+				eq(ContributionId::from_str("0x12").unwrap()),
+				eq(Issuer::Admin),
+				eq(None),
+			)
+			.returning(|_, _, _| Ok(HexPrefixedString::default()));
 
 		let rocket =
 			rocket::build().manage(Box::new(usecase) as Box<dyn ValidateContributionUsecase>);
 
 		let result = validate_contribution(
 			ApiKey::default(),
+			IdempotencyKey::default(),
 			"0x12".into(),
 			State::get(&rocket).unwrap(),
 		)
@@ -61,14 +88,17 @@ mod test {
 
 		assert!(result.is_ok(), "{}", result.err().unwrap());
 
-		assert_eq!(status::Accepted(None), result.unwrap());
+		assert_eq!(
+			HexPrefixedString::default().to_string(),
+			result.unwrap().0.unwrap().into_inner().transaction_hash
+		);
 	}
 
 	#[tokio::test]
 	async fn validate_should_return_500_upon_failure() {
 		let mut usecase = MockValidateContribution::new();
 
-		usecase.expect_send_validate_request().returning(|_| {
+		usecase.expect_send_validate_request().returning(|_, _, _| {
 			Err(ContributionProjectionRepositoryError::Infrastructure(Box::new(Error)).into())
 		});
 
@@ -77,6 +107,7 @@ mod test {
 
 		let result = validate_contribution(
 			ApiKey::default(),
+			IdempotencyKey::default(),
 			"0x12".into(),
 			State::get(&rocket).unwrap(),
 		)
@@ -92,4 +123,75 @@ mod test {
 		);
 		assert_eq!(Error.to_string(), problem.detail.unwrap());
 	}
+
+	#[tokio::test]
+	async fn simulate_validation_should_return_the_revert_reason() {
+		let mut usecase = MockValidateContribution::new();
+
+		usecase.expect_send_validate_request().never();
+		usecase
+			.expect_simulate_validate_request()
+			.with(eq(ContributionId::from_str("0x12").unwrap()))
+			.returning(|_| {
+				Ok(ContributionSimulation {
+					contribution: Contribution::default(),
+					transaction: TransactionSimulation::Reverted {
+						reason: String::from("Contributions: Contribution is not assigned"),
+					},
+				})
+			});
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn ValidateContributionUsecase>);
+
+		let result = simulate_contribution_validation(
+			ApiKey::default(),
+			"0x12".into(),
+			State::get(&rocket).unwrap(),
+		)
+		.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let simulation = result.unwrap().into_inner();
+		assert!(!simulation.succeeded);
+		assert_eq!(
+			Some(String::from("Contributions: Contribution is not assigned")),
+			simulation.revert_reason
+		);
+		assert_eq!(None, simulation.estimated_fee);
+	}
+
+	#[tokio::test]
+	async fn simulate_validation_should_return_the_fee_estimate() {
+		let mut usecase = MockValidateContribution::new();
+
+		usecase.expect_simulate_validate_request().returning(|_| {
+			Ok(ContributionSimulation {
+				contribution: Contribution::default(),
+				transaction: TransactionSimulation::Succeeded(FeeEstimate {
+					estimated_fee: 100,
+					max_fee: 150,
+				}),
+			})
+		});
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn ValidateContributionUsecase>);
+
+		let result = simulate_contribution_validation(
+			ApiKey::default(),
+			"0x12".into(),
+			State::get(&rocket).unwrap(),
+		)
+		.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let simulation = result.unwrap().into_inner();
+		assert!(simulation.succeeded);
+		assert_eq!(None, simulation.revert_reason);
+		assert_eq!(Some(String::from("100")), simulation.estimated_fee);
+		assert_eq!(Some(String::from("150")), simulation.max_fee);
+	}
 }
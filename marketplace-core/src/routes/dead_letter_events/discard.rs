@@ -0,0 +1,77 @@
+use http_api_problem::HttpApiProblem;
+use marketplace_domain::{DeadLetterEventId, DeadLetterEventRepository};
+use rocket::{http::Status, State};
+use rocket_okapi::openapi;
+use std::sync::Arc;
+
+use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem, uuid::UuidParam};
+
+#[openapi(tag = "Dead letter events")]
+#[delete("/dead-letter-events/<dead_letter_event_id>")]
+pub fn discard_dead_letter_event(
+	_api_key: ApiKey,
+	dead_letter_event_id: UuidParam,
+	dead_letter_event_repository: &State<Arc<dyn DeadLetterEventRepository>>,
+) -> Result<Status, HttpApiProblem> {
+	let dead_letter_event_id: DeadLetterEventId = (*dead_letter_event_id.as_uuid()).into();
+
+	dead_letter_event_repository
+		.delete(&dead_letter_event_id)
+		.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(Status::NoContent)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use http_api_problem::StatusCode;
+	use marketplace_domain::*;
+	use mockall::predicate::*;
+	use rocket::request::FromParam;
+	use uuid::Uuid;
+
+	const DEAD_LETTER_EVENT_ID: &str = "00000000-0000-0000-0000-00000000000c";
+
+	#[test]
+	fn discard_dead_letter_event_should_delete_it() {
+		let mut dead_letter_event_repository = MockDeadLetterEventRepository::new();
+		dead_letter_event_repository
+			.expect_delete()
+			.with(eq(DeadLetterEventId::from(Uuid::from_u128(12))))
+			.once()
+			.returning(|_| Ok(()));
+
+		let rocket = rocket::build()
+			.manage(Arc::new(dead_letter_event_repository) as Arc<dyn DeadLetterEventRepository>);
+
+		let result = discard_dead_letter_event(
+			ApiKey::default(),
+			UuidParam::from_param(DEAD_LETTER_EVENT_ID).unwrap(),
+			State::get(&rocket).unwrap(),
+		);
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(Status::NoContent, result.unwrap());
+	}
+
+	#[test]
+	fn discard_dead_letter_event_should_return_404_when_not_found() {
+		let mut dead_letter_event_repository = MockDeadLetterEventRepository::new();
+		dead_letter_event_repository
+			.expect_delete()
+			.returning(|_| Err(DeadLetterEventRepositoryError::NotFound));
+
+		let rocket = rocket::build()
+			.manage(Arc::new(dead_letter_event_repository) as Arc<dyn DeadLetterEventRepository>);
+
+		let result = discard_dead_letter_event(
+			ApiKey::default(),
+			UuidParam::from_param(DEAD_LETTER_EVENT_ID).unwrap(),
+			State::get(&rocket).unwrap(),
+		);
+
+		assert!(result.is_err());
+		assert_eq!(StatusCode::NOT_FOUND, result.err().unwrap().status.unwrap());
+	}
+}
@@ -0,0 +1,76 @@
+use http_api_problem::{HttpApiProblem, StatusCode};
+use marketplace_core::dto;
+use marketplace_domain::DeadLetterEventRepository;
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use std::sync::Arc;
+
+use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem};
+
+#[openapi(tag = "Dead letter events")]
+#[get("/dead-letter-events")]
+pub fn list_dead_letter_events(
+	_api_key: ApiKey,
+	dead_letter_event_repository: &State<Arc<dyn DeadLetterEventRepository>>,
+) -> Result<Json<Vec<dto::DeadLetterEvent>>, HttpApiProblem> {
+	let dead_letter_events =
+		dead_letter_event_repository.list().map_err(|e| e.to_http_api_problem())?;
+
+	let dead_letter_events = dead_letter_events
+		.into_iter()
+		.map(dto::DeadLetterEvent::try_from)
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|e| {
+			HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
+				.title("Internal error")
+				.detail(e.to_string())
+		})?;
+
+	Ok(Json(dead_letter_events))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use marketplace_domain::*;
+	use std::{str::FromStr, time::SystemTime};
+	use uuid::Uuid;
+
+	#[test]
+	fn list_dead_letter_events_should_return_all_dead_letter_events() {
+		let mut dead_letter_event_repository = MockDeadLetterEventRepository::new();
+		dead_letter_event_repository.expect_list().returning(|| {
+			Ok(vec![DeadLetterEvent {
+				id: Uuid::from_u128(12).into(),
+				projector: String::from("applications"),
+				event: Event::Contribution(ContributionEvent::Validated {
+					id: ContributionId::from_str("0x12").unwrap(),
+				}),
+				deduplication_id: None,
+				error: String::from("Oops"),
+				attempts: 5,
+				failed_at: SystemTime::UNIX_EPOCH,
+			}])
+		});
+
+		let rocket = rocket::build()
+			.manage(Arc::new(dead_letter_event_repository) as Arc<dyn DeadLetterEventRepository>);
+
+		let result = list_dead_letter_events(ApiKey::default(), State::get(&rocket).unwrap());
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let dead_letter_events = result.unwrap().into_inner();
+		assert_eq!(1, dead_letter_events.len());
+		assert_eq!(
+			"00000000-0000-0000-0000-00000000000c",
+			dead_letter_events[0].id
+		);
+		assert_eq!("applications", dead_letter_events[0].projector);
+		assert_eq!("Oops", dead_letter_events[0].error);
+		assert_eq!(5, dead_letter_events[0].attempts);
+		assert_eq!(
+			"1970-01-01T00:00:00.000000Z",
+			dead_letter_events[0].failed_at
+		);
+	}
+}
@@ -0,0 +1,7 @@
+mod discard;
+mod list;
+mod retry;
+
+pub use discard::*;
+pub use list::*;
+pub use retry::*;
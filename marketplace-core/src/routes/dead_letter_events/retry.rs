@@ -0,0 +1,80 @@
+use http_api_problem::HttpApiProblem;
+use marketplace_core::application::RetryDeadLetterEventUsecase;
+use marketplace_domain::DeadLetterEventId;
+use rocket::{http::Status, State};
+use rocket_okapi::openapi;
+
+use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem, uuid::UuidParam};
+
+#[openapi(tag = "Dead letter events")]
+#[post("/dead-letter-events/<dead_letter_event_id>/retry")]
+pub async fn retry_dead_letter_event(
+	_api_key: ApiKey,
+	dead_letter_event_id: UuidParam,
+	usecase: &State<Box<dyn RetryDeadLetterEventUsecase>>,
+) -> Result<Status, HttpApiProblem> {
+	let dead_letter_event_id: DeadLetterEventId = (*dead_letter_event_id.as_uuid()).into();
+
+	usecase
+		.retry(&dead_letter_event_id)
+		.await
+		.map_err(|e| e.to_http_api_problem())?;
+
+	Ok(Status::Ok)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use http_api_problem::StatusCode;
+	use marketplace_core::application::MockRetryDeadLetterEvent;
+	use marketplace_domain::*;
+	use mockall::predicate::*;
+	use rocket::request::FromParam;
+	use uuid::Uuid;
+
+	const DEAD_LETTER_EVENT_ID: &str = "00000000-0000-0000-0000-00000000000c";
+
+	#[tokio::test]
+	async fn retry_dead_letter_event_should_return_ok_upon_success() {
+		let mut usecase = MockRetryDeadLetterEvent::new();
+		usecase
+			.expect_retry()
+			.with(eq(DeadLetterEventId::from(Uuid::from_u128(12))))
+			.returning(|_| Ok(()));
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn RetryDeadLetterEventUsecase>);
+
+		let result = retry_dead_letter_event(
+			ApiKey::default(),
+			UuidParam::from_param(DEAD_LETTER_EVENT_ID).unwrap(),
+			State::get(&rocket).unwrap(),
+		)
+		.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(Status::Ok, result.unwrap());
+	}
+
+	#[tokio::test]
+	async fn retry_dead_letter_event_should_return_404_when_not_found() {
+		let mut usecase = MockRetryDeadLetterEvent::new();
+		usecase
+			.expect_retry()
+			.returning(|_| Err(DeadLetterEventRepositoryError::NotFound.into()));
+
+		let rocket =
+			rocket::build().manage(Box::new(usecase) as Box<dyn RetryDeadLetterEventUsecase>);
+
+		let result = retry_dead_letter_event(
+			ApiKey::default(),
+			UuidParam::from_param(DEAD_LETTER_EVENT_ID).unwrap(),
+			State::get(&rocket).unwrap(),
+		)
+		.await;
+
+		assert!(result.is_err());
+		assert_eq!(StatusCode::NOT_FOUND, result.err().unwrap().status.unwrap());
+	}
+}
@@ -1,3 +1,4 @@
+use marketplace_domain::Issuer;
 use okapi::openapi3::{Object, SecurityRequirement, SecurityScheme, SecuritySchemeData};
 use rocket::{
 	http::Status,
@@ -41,6 +42,13 @@ impl<'r> FromRequest<'r> for ApiKey {
 	}
 }
 
+/// Only the admin holds the API key
+impl From<ApiKey> for Issuer {
+	fn from(_api_key: ApiKey) -> Self {
+		Issuer::Admin
+	}
+}
+
 impl<'r> OpenApiFromRequest<'r> for ApiKey {
 	fn from_request_input(
 		_gen: &mut OpenApiGenerator,
@@ -0,0 +1,62 @@
+use okapi::openapi3::{Object, Parameter, ParameterValue};
+use rocket::{
+	outcome::Outcome,
+	request::{self, FromRequest},
+	Request,
+};
+use rocket_okapi::{
+	gen::OpenApiGenerator,
+	request::{OpenApiFromRequest, RequestHeaderInput},
+};
+use std::convert::Infallible;
+
+/// Key supplied by the client in the `Idempotency-Key` header, so that a retried request is not
+/// handled twice
+#[derive(Default)]
+pub struct IdempotencyKey(Option<String>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for IdempotencyKey {
+	type Error = Infallible;
+
+	async fn from_request(request: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+		Outcome::Success(IdempotencyKey(
+			request.headers().get_one("Idempotency-Key").map(String::from),
+		))
+	}
+}
+
+impl From<IdempotencyKey> for Option<String> {
+	fn from(idempotency_key: IdempotencyKey) -> Self {
+		idempotency_key.0
+	}
+}
+
+impl<'r> OpenApiFromRequest<'r> for IdempotencyKey {
+	fn from_request_input(
+		gen: &mut OpenApiGenerator,
+		_name: String,
+		_required: bool,
+	) -> rocket_okapi::Result<RequestHeaderInput> {
+		Ok(RequestHeaderInput::Parameter(Parameter {
+			name: "Idempotency-Key".to_owned(),
+			location: "header".to_owned(),
+			description: Some(
+				"Unique key of the request. Requests sent again with the same key are not handled twice."
+					.to_owned(),
+			),
+			required: false,
+			deprecated: false,
+			allow_empty_value: false,
+			value: ParameterValue::Schema {
+				style: None,
+				explode: None,
+				allow_reserved: false,
+				schema: gen.json_schema::<String>(),
+				example: None,
+				examples: None,
+			},
+			extensions: Object::default(),
+		}))
+	}
+}
@@ -0,0 +1,210 @@
+use super::api_key::ApiKey;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use marketplace_domain::{ContributorId, Issuer};
+use okapi::openapi3::{Object, SecurityRequirement, SecurityScheme, SecuritySchemeData};
+use rocket::{
+	http::Status,
+	outcome::Outcome,
+	request::{self, FromRequest},
+	Request,
+};
+use rocket_okapi::{
+	gen::OpenApiGenerator,
+	request::{OpenApiFromRequest, RequestHeaderInput},
+};
+use serde::Deserialize;
+
+/// Issuer of the request, as authenticated by its headers: the admin with a valid `Api-Key`,
+/// or a contributor with a bearer token signed with `CONTRIBUTOR_TOKEN_SECRET`, whose subject
+/// is their contributor id
+pub struct AuthenticatedIssuer(Issuer);
+
+#[derive(Debug)]
+pub enum AuthenticationError {
+	Missing,
+	InvalidApiKey,
+	InvalidToken,
+}
+
+#[derive(Deserialize)]
+struct Claims {
+	sub: String,
+}
+
+fn verified_contributor_id(authorization: &str) -> Option<ContributorId> {
+	let token = authorization.strip_prefix("Bearer ")?;
+	let secret = std::env::var("CONTRIBUTOR_TOKEN_SECRET").ok()?;
+
+	let token = jsonwebtoken::decode::<Claims>(
+		token,
+		&DecodingKey::from_secret(secret.as_bytes()),
+		&Validation::new(Algorithm::HS256),
+	)
+	.ok()?;
+
+	token.claims.sub.parse().ok()
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AuthenticatedIssuer {
+	type Error = AuthenticationError;
+
+	async fn from_request(request: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+		if request.headers().contains("Api-Key") {
+			return match request.guard::<ApiKey>().await {
+				Outcome::Success(api_key) => Outcome::Success(AuthenticatedIssuer(api_key.into())),
+				_ => Outcome::Failure((Status::Unauthorized, AuthenticationError::InvalidApiKey)),
+			};
+		}
+
+		match request.headers().get_one("Authorization") {
+			Some(authorization) => match verified_contributor_id(authorization) {
+				Some(contributor_id) =>
+					Outcome::Success(AuthenticatedIssuer(Issuer::Contributor(contributor_id))),
+				None => Outcome::Failure((Status::Unauthorized, AuthenticationError::InvalidToken)),
+			},
+			None => Outcome::Failure((Status::Unauthorized, AuthenticationError::Missing)),
+		}
+	}
+}
+
+impl From<AuthenticatedIssuer> for Issuer {
+	fn from(issuer: AuthenticatedIssuer) -> Self {
+		issuer.0
+	}
+}
+
+impl<'r> OpenApiFromRequest<'r> for AuthenticatedIssuer {
+	fn from_request_input(
+		_gen: &mut OpenApiGenerator,
+		_name: String,
+		_required: bool,
+	) -> rocket_okapi::Result<RequestHeaderInput> {
+		let security_scheme = SecurityScheme {
+			description: Some(
+				"Requires a contributor token, or an API key in the `Api-Key` header.".to_owned(),
+			),
+			data: SecuritySchemeData::Http {
+				scheme: "bearer".to_owned(),
+				bearer_format: Some("JWT".to_owned()),
+			},
+			extensions: Object::default(),
+		};
+		let mut security_req = SecurityRequirement::new();
+		security_req.insert("ContributorTokenAuth".to_owned(), Vec::new());
+		Ok(RequestHeaderInput::Security(
+			"ContributorTokenAuth".to_owned(),
+			security_scheme,
+			security_req,
+		))
+	}
+
+	fn get_responses(
+		_gen: &mut OpenApiGenerator,
+	) -> rocket_okapi::Result<okapi::openapi3::Responses> {
+		Ok(okapi::openapi3::Responses::default())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jsonwebtoken::{EncodingKey, Header as TokenHeader};
+	use rocket::{http::Header, local::blocking::Client, Build, Rocket};
+	use serde::Serialize;
+	use std::str::FromStr;
+
+	const CONTRIBUTOR_ID: &str = "0x12";
+
+	#[derive(Serialize)]
+	struct TokenClaims {
+		sub: String,
+		exp: u64,
+	}
+
+	#[get("/issuer")]
+	fn issuer(issuer: AuthenticatedIssuer) -> String {
+		Issuer::from(issuer).to_string()
+	}
+
+	fn rocket() -> Rocket<Build> {
+		std::env::set_var("API_KEY", "ROOT");
+		std::env::set_var("CONTRIBUTOR_TOKEN_SECRET", "secret");
+		rocket::build().mount("/", routes![issuer])
+	}
+
+	fn token(secret: &str) -> String {
+		let claims = TokenClaims {
+			sub: CONTRIBUTOR_ID.to_string(),
+			// 2100-01-01
+			exp: 4_102_444_800,
+		};
+		let token = jsonwebtoken::encode(
+			&TokenHeader::default(),
+			&claims,
+			&EncodingKey::from_secret(secret.as_bytes()),
+		)
+		.unwrap();
+		format!("Bearer {token}")
+	}
+
+	#[test]
+	fn api_key_authenticates_the_admin() {
+		let client = Client::untracked(rocket()).unwrap();
+
+		let response = client.get("/issuer").header(Header::new("Api-Key", "ROOT")).dispatch();
+
+		assert_eq!(Status::Ok, response.status());
+		assert_eq!(Issuer::Admin.to_string(), response.into_string().unwrap());
+	}
+
+	#[test]
+	fn signed_token_authenticates_the_contributor() {
+		let client = Client::untracked(rocket()).unwrap();
+
+		let response = client
+			.get("/issuer")
+			.header(Header::new("Authorization", token("secret")))
+			.dispatch();
+
+		assert_eq!(Status::Ok, response.status());
+		assert_eq!(
+			Issuer::Contributor(ContributorId::from_str(CONTRIBUTOR_ID).unwrap()).to_string(),
+			response.into_string().unwrap()
+		);
+	}
+
+	#[test]
+	fn token_signed_with_another_secret_is_refused() {
+		let client = Client::untracked(rocket()).unwrap();
+
+		let response = client
+			.get("/issuer")
+			.header(Header::new("Authorization", token("other")))
+			.dispatch();
+
+		assert_eq!(Status::Unauthorized, response.status());
+	}
+
+	#[test]
+	fn invalid_api_key_is_refused() {
+		let client = Client::untracked(rocket()).unwrap();
+
+		let response = client
+			.get("/issuer")
+			.header(Header::new("Api-Key", "WRONG"))
+			.header(Header::new("Authorization", token("secret")))
+			.dispatch();
+
+		assert_eq!(Status::Unauthorized, response.status());
+	}
+
+	#[test]
+	fn anonymous_requests_are_refused() {
+		let client = Client::untracked(rocket()).unwrap();
+
+		let response = client.get("/issuer").dispatch();
+
+		assert_eq!(Status::Unauthorized, response.status());
+	}
+}
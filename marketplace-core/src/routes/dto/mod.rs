@@ -1,4 +1,7 @@
 pub mod api_key;
+pub mod idempotency_key;
+pub mod issuer;
+pub mod point_in_time;
 pub mod to_http_api_problem;
 pub mod u256;
 pub mod uuid;
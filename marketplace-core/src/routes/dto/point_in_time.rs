@@ -0,0 +1,93 @@
+use marketplace_domain::PointInTime;
+use rocket::form::{self, FromFormField, ValueField};
+use schemars::{
+	schema::{InstanceType, Metadata, SchemaObject},
+	JsonSchema,
+};
+
+/// Either the number of an event of the aggregate, starting at 1 (eg. `3`), or an RFC 3339
+/// timestamp (eg. `2022-08-01T12:00:00Z`)
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct PointInTimeParam(PointInTime);
+
+impl From<PointInTimeParam> for PointInTime {
+	fn from(param: PointInTimeParam) -> Self {
+		param.0
+	}
+}
+
+impl From<PointInTime> for PointInTimeParam {
+	fn from(point_in_time: PointInTime) -> Self {
+		Self(point_in_time)
+	}
+}
+
+impl JsonSchema for PointInTimeParam {
+	fn schema_name() -> String {
+		"PointInTime".to_string()
+	}
+
+	fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		let schema = SchemaObject {
+			instance_type: Some(InstanceType::String.into()),
+			metadata: Some(Box::new(Metadata {
+				description: Some(
+					"The number of an event of the aggregate, starting at 1, or an RFC 3339 \
+					 timestamp (eg. 2022-08-01T12:00:00Z)"
+						.to_string(),
+				),
+				..Default::default()
+			})),
+			..Default::default()
+		};
+
+		schema.into()
+	}
+}
+
+#[rocket::async_trait]
+impl<'r> FromFormField<'r> for PointInTimeParam {
+	fn from_value(field: ValueField<'r>) -> form::Result<'r, Self> {
+		if let Ok(event_number) = field.value.parse::<u64>() {
+			return Ok(PointInTime::EventNumber(event_number).into());
+		}
+
+		humantime::parse_rfc3339_weak(field.value)
+			.map(|timestamp| PointInTime::Timestamp(timestamp).into())
+			.map_err(|_| {
+				form::Error::validation("expected an event number or an RFC 3339 timestamp").into()
+			})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rocket::form::Form;
+	use std::time::{Duration, UNIX_EPOCH};
+
+	#[derive(FromForm)]
+	struct Query {
+		at: PointInTimeParam,
+	}
+
+	#[test]
+	fn parse_event_number() {
+		let query: Query = Form::parse("at=42").unwrap();
+		assert_eq!(PointInTimeParam(PointInTime::EventNumber(42)), query.at);
+	}
+
+	#[test]
+	fn parse_timestamp() {
+		let query: Query = Form::parse("at=1970-01-01T00:01:00Z").unwrap();
+		assert_eq!(
+			PointInTimeParam(PointInTime::Timestamp(UNIX_EPOCH + Duration::from_secs(60))),
+			query.at
+		);
+	}
+
+	#[test]
+	fn parse_invalid_value() {
+		assert!(Form::<Query>::parse("at=yesterday").is_err());
+	}
+}
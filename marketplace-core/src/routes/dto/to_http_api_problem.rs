@@ -1,6 +1,7 @@
 use crate::RefreshError;
 use http_api_problem::{HttpApiProblem, StatusCode};
 use marketplace_domain::{Error as DomainError, *};
+use marketplace_infrastructure::starknet::{SignerError, StarknetError};
 
 pub(crate) trait ToHttpApiProblem {
 	fn to_http_api_problem(&self) -> HttpApiProblem;
@@ -74,6 +75,19 @@ impl ToHttpApiProblem for ContactInformationRepositoryError {
 	}
 }
 
+impl ToHttpApiProblem for DeadLetterEventRepositoryError {
+	fn to_http_api_problem(&self) -> HttpApiProblem {
+		match self {
+			DeadLetterEventRepositoryError::NotFound =>
+				HttpApiProblem::new(StatusCode::NOT_FOUND).title(self.to_string()),
+			DeadLetterEventRepositoryError::Infrastructure(e) =>
+				HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
+					.title(self.to_string())
+					.detail(e.to_string()),
+		}
+	}
+}
+
 impl ToHttpApiProblem for OnchainContributionServiceError {
 	fn to_http_api_problem(&self) -> HttpApiProblem {
 		match self {
@@ -81,6 +95,46 @@ impl ToHttpApiProblem for OnchainContributionServiceError {
 				HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
 					.title(self.to_string())
 					.detail(e.to_string()),
+			OnchainContributionServiceError::Unavailable(e) =>
+				HttpApiProblem::new(StatusCode::SERVICE_UNAVAILABLE)
+					.title(self.to_string())
+					.detail(e.to_string()),
+			OnchainContributionServiceError::Conflict(e) =>
+				HttpApiProblem::new(StatusCode::CONFLICT)
+					.title(self.to_string())
+					.detail(e.to_string()),
+			OnchainContributionServiceError::FeeTooHigh(e) =>
+				HttpApiProblem::new(StatusCode::UNPROCESSABLE_ENTITY)
+					.title(self.to_string())
+					.detail(e.to_string()),
+			OnchainContributionServiceError::Reverted(e) =>
+				HttpApiProblem::new(StatusCode::UNPROCESSABLE_ENTITY)
+					.title(self.to_string())
+					.detail(e.to_string()),
+		}
+	}
+}
+
+impl ToHttpApiProblem for OnchainTransactionRepositoryError {
+	fn to_http_api_problem(&self) -> HttpApiProblem {
+		match self {
+			OnchainTransactionRepositoryError::NotFound =>
+				HttpApiProblem::new(StatusCode::NOT_FOUND).title(self.to_string()),
+			OnchainTransactionRepositoryError::Infrastructure(e) =>
+				HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
+					.title(self.to_string())
+					.detail(e.to_string()),
+		}
+	}
+}
+
+impl ToHttpApiProblem for OnchainTransactionServiceError {
+	fn to_http_api_problem(&self) -> HttpApiProblem {
+		match self {
+			OnchainTransactionServiceError::Infrastructure(e) =>
+				HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
+					.title(self.to_string())
+					.detail(e.to_string()),
 		}
 	}
 }
@@ -98,6 +152,10 @@ impl ToHttpApiProblem for DomainError {
 				contact_information_repository_error.to_http_api_problem(),
 			DomainError::OnchainContributionService(onchain_contribution_service_error) =>
 				onchain_contribution_service_error.to_http_api_problem(),
+			DomainError::OnchainTransactionRepository(onchain_transaction_repository_error) =>
+				onchain_transaction_repository_error.to_http_api_problem(),
+			DomainError::OnchainTransactionService(onchain_transaction_service_error) =>
+				onchain_transaction_service_error.to_http_api_problem(),
 			DomainError::Lock =>
 				HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR).title(self.to_string()),
 			DomainError::ContributionError(_) => HttpApiProblem::new(StatusCode::BAD_REQUEST)
@@ -107,6 +165,21 @@ impl ToHttpApiProblem for DomainError {
 				HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
 					.title("Internal error")
 					.detail(self.to_string()),
+			DomainError::DeadLetterEventRepository(dead_letter_event_repository_error) =>
+				dead_letter_event_repository_error.to_http_api_problem(),
+			DomainError::Projection(projector_error) => projector_error.to_http_api_problem(),
+			DomainError::UnknownProjector(_)
+			| DomainError::UnsupportedEvent(_)
+			| DomainError::UnknownCommand(_)
+			| DomainError::CommandOutput(_) => HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
+				.title("Internal error")
+				.detail(self.to_string()),
+			DomainError::Unauthorized(_) =>
+				HttpApiProblem::new(StatusCode::FORBIDDEN).title(self.to_string()),
+			DomainError::CommandInProgress(_) =>
+				HttpApiProblem::new(StatusCode::CONFLICT).title(self.to_string()),
+			DomainError::EmptyActionBatch =>
+				HttpApiProblem::new(StatusCode::BAD_REQUEST).title(self.to_string()),
 		}
 	}
 }
@@ -122,6 +195,7 @@ impl ToHttpApiProblem for RefreshError {
 		match self {
 			RefreshError::ProjectionRepository(error) => error.to_http_api_problem(),
 			RefreshError::EventStore(error) => error.to_http_api_problem(),
+			RefreshError::Projector(error) => error.to_http_api_problem(),
 		}
 	}
 }
@@ -141,3 +215,31 @@ impl ToHttpApiProblem for ProjectionRepositoryError {
 			.detail(self.to_string())
 	}
 }
+
+impl ToHttpApiProblem for ProjectorError {
+	fn to_http_api_problem(&self) -> HttpApiProblem {
+		HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
+			.title("Internal error")
+			.detail(self.to_string())
+	}
+}
+
+impl ToHttpApiProblem for StarknetError {
+	fn to_http_api_problem(&self) -> HttpApiProblem {
+		let status = match self {
+			StarknetError::Signer(
+				SignerError::Configuration(_)
+				| SignerError::Unknown(_)
+				| SignerError::PublicKeyMismatch,
+			) => StatusCode::BAD_REQUEST,
+			StarknetError::Signer(_) => StatusCode::BAD_GATEWAY,
+			_ => StatusCode::INTERNAL_SERVER_ERROR,
+		};
+
+		let problem = HttpApiProblem::new(status).title(self.to_string());
+		match std::error::Error::source(self) {
+			Some(source) => problem.detail(source.to_string()),
+			None => problem,
+		}
+	}
+}
@@ -1,16 +1,24 @@
+mod actions;
+mod admin_account;
 mod applications;
 pub mod contact_information;
 mod contributions;
 pub mod cors;
+mod dead_letter_events;
 mod dto;
 pub mod health;
 mod projects;
+mod transactions;
 
+pub use actions::*;
+pub use admin_account::*;
 pub use applications::*;
 pub use contributions::*;
+pub use dead_letter_events::*;
 
 pub use projects::*;
 use rocket_okapi::swagger_ui::SwaggerUIConfig;
+pub use transactions::*;
 
 pub use dto::*;
 pub(crate) fn get_docs() -> SwaggerUIConfig {
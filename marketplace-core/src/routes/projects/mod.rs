@@ -2,12 +2,17 @@ use marketplace_core::{dto, utils::caches};
 use marketplace_domain::*;
 use marketplace_infrastructure::{database, github, starknet};
 
-use futures::future::{self, OptionFuture};
+use futures::future;
 use http_api_problem::{HttpApiProblem, StatusCode};
 use log::{error, warn};
 use rocket::{get, http::Status, post, serde::json::Json, State};
 use rocket_okapi::openapi;
-use std::{error::Error, result::Result, sync::Arc};
+use std::{
+	collections::{HashMap, HashSet},
+	error::Error,
+	result::Result,
+	sync::Arc,
+};
 use url::Url;
 
 use super::api_key::ApiKey;
@@ -50,26 +55,40 @@ pub async fn list_projects(
 	database: &State<Arc<database::Client>>,
 	repo_cache: &State<caches::RepoCache>,
 	contributor_cache: &State<caches::ContributorCache>,
+	starknet: &State<Option<Arc<starknet::SingleAdminClient>>>,
 ) -> Result<Json<Vec<dto::Project>>, HttpApiProblem> {
-	let projects_with_contribution_iterator = database
-		.find_all_with_contributions()
-		.map_err(|error| {
-			let mut problem = HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR)
-				.title("Listing projects failed");
-			if let Some(s) = error.source() {
-				problem.detail = Some(s.to_string());
-			}
-			problem
-		})?
-		.into_iter();
+	let projects_with_contributions = database.find_all_with_contributions().map_err(|error| {
+		let mut problem =
+			HttpApiProblem::new(StatusCode::INTERNAL_SERVER_ERROR).title("Listing projects failed");
+		if let Some(s) = error.source() {
+			problem.detail = Some(s.to_string());
+		}
+		problem
+	})?;
+
+	// Resolve the contributors of all projects at once, rather than one by one
+	let contributor_ids = projects_with_contributions
+		.iter()
+		.flat_map(|project| &project.contributions)
+		.filter_map(|contribution| contribution.contributor_id.clone())
+		.collect();
+	let contributors = Arc::new(
+		resolve_contributors(
+			contributor_cache,
+			database.inner().as_ref(),
+			starknet.as_deref(),
+			contributor_ids,
+		)
+		.await,
+	);
 
 	// Spawn concurent tasks
 	// One for each project
-	let build_project_tasks = projects_with_contribution_iterator.map(|project| {
+	let build_project_tasks = projects_with_contributions.into_iter().map(|project| {
 		let cloned_repo_cache: caches::RepoCache = repo_cache.inner().clone();
-		let cloned_contributor_cache: caches::ContributorCache = contributor_cache.inner().clone();
+		let cloned_contributors = contributors.clone();
 		tokio::spawn(async move {
-			build_project(project, &cloned_repo_cache, &cloned_contributor_cache).await
+			build_project(project, &cloned_repo_cache, &cloned_contributors).await
 		})
 	});
 
@@ -93,7 +112,7 @@ pub async fn list_projects(
 async fn build_project(
 	project: ProjectWithContributions,
 	repo_cache: &caches::RepoCache,
-	contributor_cache: &caches::ContributorCache,
+	contributors: &HashMap<ContributorId, Contributor>,
 ) -> Option<dto::Project> {
 	let github_repository = repo_cache
 		.inner_ref()
@@ -108,27 +127,10 @@ async fn build_project(
 		})
 		.await?;
 
-	// Spawn concurent tasks
-	// One for each contribution
-	let build_contribution_tasks = project.contributions.into_iter().map(|contribution| {
-		let cloned_contributor_cache = contributor_cache.clone();
-		tokio::spawn(
-			async move { build_contribution(contribution, &cloned_contributor_cache).await },
-		)
-	});
-
-	// Merge all tasks into a single vector
-	// Failed task will be ignored
-	let contributions = future::join_all(build_contribution_tasks)
-		.await
+	let contributions = project
+		.contributions
 		.into_iter()
-		.filter_map(|result| match result {
-			Ok(opt_project) => opt_project,
-			Err(e) => {
-				error!("failed to build contribution dto: {}", e.to_string());
-				None
-			},
-		})
+		.map(|contribution| build_contribution(contribution, contributors))
 		.collect();
 
 	let project = dto::Project {
@@ -149,44 +151,108 @@ async fn build_project(
 	Some(project)
 }
 
-async fn build_contribution(
+fn build_contribution(
 	contribution: ContributionProjection,
-	contributor_cache: &caches::ContributorCache,
-) -> Option<dto::Contribution> {
-	let contributor = OptionFuture::from(
-		contribution
-			.contributor_id
-			.clone()
-			.map(|id| build_contributor(contributor_cache, id)),
-	)
-	.await
-	.flatten();
+	contributors: &HashMap<ContributorId, Contributor>,
+) -> dto::Contribution {
+	let contributor = contribution
+		.contributor_id
+		.as_ref()
+		.and_then(|contributor_id| contributors.get(contributor_id));
 
+	let github_username = contributor.map(|contributor| contributor.github_username.clone());
 	let mut contribution = dto::Contribution::from(contribution);
 
-	if contributor.is_some() {
-		contribution.metadata.github_username = contributor.unwrap().github_username;
+	if let Some(github_username) = github_username {
+		contribution.metadata.github_username = github_username;
 	}
 
-	Some(contribution)
+	contribution
 }
 
-async fn build_contributor(
+/// Get the contributors from the cache, then from the indexed contributors.
+/// The ones which have not been indexed yet are fetched from StarkNet in a single batch.
+async fn resolve_contributors(
 	contributor_cache: &caches::ContributorCache,
-	contributor_id: ContributorId,
-) -> Option<Contributor> {
-	contributor_cache
-		.inner_ref()
-		.get_or_insert(&contributor_id, || async {
-			fetch_contributor(&contributor_id).await
-		})
+	contributor_projection_repository: &dyn ContributorProjectionRepository,
+	starknet: Option<&starknet::SingleAdminClient>,
+	contributor_ids: HashSet<ContributorId>,
+) -> HashMap<ContributorId, Contributor> {
+	let mut contributors = HashMap::new();
+	let mut missing_contributor_ids = Vec::new();
+
+	for contributor_id in contributor_ids {
+		match contributor_cache.inner_ref().get(&contributor_id) {
+			Some(Some(contributor)) => {
+				contributors.insert(contributor_id, contributor);
+			},
+			Some(None) => (),
+			None => missing_contributor_ids.push(contributor_id),
+		}
+	}
+
+	if missing_contributor_ids.is_empty() {
+		return contributors;
+	}
+
+	let mut fetched_contributors: HashMap<ContributorId, Contributor> =
+		match contributor_projection_repository.find_by_ids(&missing_contributor_ids) {
+			Ok(indexed_contributors) => indexed_contributors
+				.into_iter()
+				.map(|contributor| (contributor.id.clone(), contributor.into()))
+				.collect(),
+			Err(e) => {
+				warn!("Unable to read indexed contributors: {}", e.to_string());
+				HashMap::new()
+			},
+		};
+
+	let unindexed_contributor_ids: Vec<ContributorId> = missing_contributor_ids
+		.iter()
+		.filter(|contributor_id| !fetched_contributors.contains_key(contributor_id))
+		.cloned()
+		.collect();
+
+	if let (Some(starknet), false) = (starknet, unindexed_contributor_ids.is_empty()) {
+		match starknet.get_users_information(&unindexed_contributor_ids).await {
+			Ok(onchain_contributors) => fetched_contributors.extend(onchain_contributors),
+			Err(e) => {
+				warn!(
+					"Unable to fetch contributors from StarkNet: {}",
+					e.to_string()
+				);
+				// Do not cache them as unknown, they may be found on next call
+				missing_contributor_ids
+					.retain(|contributor_id| fetched_contributors.contains_key(contributor_id));
+			},
+		}
+	}
+
+	let fetched_contributors: HashMap<ContributorId, Contributor> =
+		future::join_all(fetched_contributors.into_iter().map(
+			|(contributor_id, contributor)| async move {
+				(contributor_id, with_github_username(contributor).await)
+			},
+		))
 		.await
-}
+		.into_iter()
+		.collect();
 
-async fn fetch_contributor(contributor_id: &ContributorId) -> Option<Contributor> {
-	let starknet = starknet::Client::default();
-	let mut contributor = starknet.get_user_information(contributor_id).await?;
+	for contributor_id in missing_contributor_ids {
+		let contributor = fetched_contributors.get(&contributor_id).cloned();
+		contributor_cache
+			.inner_ref()
+			.insert(contributor_id.clone(), contributor.clone())
+			.await;
+		if let Some(contributor) = contributor {
+			contributors.insert(contributor_id, contributor);
+		}
+	}
+
+	contributors
+}
 
+async fn with_github_username(mut contributor: Contributor) -> Contributor {
 	if let Some(github_handle) = &contributor.github_handle {
 		let github_user = match github::Client::new().user(github_handle).await {
 			Ok(user) => Some(user),
@@ -199,5 +265,5 @@ async fn fetch_contributor(contributor_id: &ContributorId) -> Option<Contributor
 		contributor.github_username = github_user.map(|u| u.login);
 	}
 
-	Some(contributor)
+	contributor
 }
@@ -0,0 +1,94 @@
+use http_api_problem::HttpApiProblem;
+use marketplace_core::dto;
+use marketplace_domain::{
+	HexPrefixedString, OnchainTransactionRepository, OnchainTransactionRepositoryError,
+	ParseHexPrefixedStringError,
+};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use std::sync::Arc;
+
+use crate::routes::{api_key::ApiKey, to_http_api_problem::ToHttpApiProblem};
+
+#[openapi(tag = "Transactions")]
+#[get("/transactions/<hash>")]
+pub fn find_transaction(
+	_api_key: ApiKey,
+	hash: String,
+	transaction_repository: &State<Arc<dyn OnchainTransactionRepository>>,
+) -> Result<Json<dto::OnchainTransaction>, HttpApiProblem> {
+	let hash: HexPrefixedString =
+		hash.parse().map_err(|e: ParseHexPrefixedStringError| e.to_http_api_problem())?;
+
+	let transaction = transaction_repository
+		.find_by_hash(&hash)
+		.map_err(|e| e.to_http_api_problem())?
+		.ok_or_else(|| OnchainTransactionRepositoryError::NotFound.to_http_api_problem())?;
+
+	Ok(Json(transaction.into()))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use http_api_problem::StatusCode;
+	use marketplace_domain::*;
+	use mockall::predicate::*;
+	use std::{str::FromStr, time::SystemTime};
+
+	#[test]
+	fn find_transaction_should_return_transaction() {
+		let mut transaction_repository = MockOnchainTransactionRepository::new();
+		transaction_repository
+			.expect_find_by_hash()
+			.with(eq(HexPrefixedString::from_str("0x1234").unwrap()))
+			.returning(|hash| {
+				Ok(Some(OnchainTransaction {
+					hash: hash.clone(),
+					command: String::from("validate_contribution"),
+					issuer: String::from("admin"),
+					actions: vec![Action::ValidateContribution {
+						contribution_id: ContributionId::from_str("0x12").unwrap(),
+					}],
+					status: OnchainTransactionStatus::AcceptedOnL2,
+					submitted_at: SystemTime::UNIX_EPOCH,
+					updated_at: SystemTime::UNIX_EPOCH,
+				}))
+			});
+
+		let rocket = rocket::build()
+			.manage(Arc::new(transaction_repository) as Arc<dyn OnchainTransactionRepository>);
+
+		let result = find_transaction(
+			ApiKey::default(),
+			"0x1234".into(),
+			State::get(&rocket).unwrap(),
+		);
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+
+		let transaction = result.unwrap().into_inner();
+		assert_eq!("0x1234", transaction.hash);
+		assert_eq!("ACCEPTED_ON_L2", transaction.status);
+		assert_eq!("admin", transaction.issuer);
+		assert_eq!(1, transaction.actions.len());
+		assert_eq!("validate_contribution", transaction.actions[0].action);
+		assert_eq!("1970-01-01T00:00:00.000000Z", transaction.submitted_at);
+	}
+
+	#[test]
+	fn find_transaction_should_return_404_for_unknown_transaction() {
+		let mut transaction_repository = MockOnchainTransactionRepository::new();
+		transaction_repository.expect_find_by_hash().returning(|_| Ok(None));
+
+		let rocket = rocket::build()
+			.manage(Arc::new(transaction_repository) as Arc<dyn OnchainTransactionRepository>);
+
+		let result = find_transaction(
+			ApiKey::default(),
+			"0x1234".into(),
+			State::get(&rocket).unwrap(),
+		);
+		assert!(result.is_err());
+		assert_eq!(StatusCode::NOT_FOUND, result.unwrap_err().status.unwrap());
+	}
+}
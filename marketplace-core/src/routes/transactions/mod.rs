@@ -0,0 +1,3 @@
+mod find;
+
+pub use find::*;
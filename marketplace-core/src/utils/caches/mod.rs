@@ -41,21 +41,26 @@ where
 	where
 		Fut: Future<Output = Option<VALUE>>,
 	{
-		match self.cache.get(key) {
-			Some(value) => value.value().clone(),
+		match self.get(key) {
+			Some(value) => value,
 			None => {
 				let value = callback().await;
+				self.insert(key.clone(), value.clone()).await;
+				value
+			},
+		}
+	}
 
-				self.cache
-					.insert_with_ttl(key.clone(), value.clone(), self.cost, self.ttl)
-					.await;
+	/// The cached value, which is itself `None` when nothing could be found for the key
+	pub fn get(&self, key: &KEY) -> Option<Option<VALUE>> {
+		self.cache.get(key).map(|value| value.value().clone())
+	}
 
-				if let Err(error) = self.cache.wait().await {
-					error!("{}", error);
-				}
+	pub async fn insert(&self, key: KEY, value: Option<VALUE>) {
+		self.cache.insert_with_ttl(key, value, self.cost, self.ttl).await;
 
-				value
-			},
+		if let Err(error) = self.cache.wait().await {
+			error!("{}", error);
 		}
 	}
 }
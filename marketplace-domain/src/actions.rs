@@ -1,8 +1,10 @@
 use std::fmt::Display;
 
 use crate::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum Action {
 	CreateContribution {
 		project_id: GithubProjectId,
@@ -21,6 +23,20 @@ pub enum Action {
 	},
 }
 
+impl Action {
+	/// The contribution targeted by this action, if it already exists
+	pub fn contribution_id(&self) -> Option<&ContributionId> {
+		match self {
+			Action::CreateContribution { .. } => None,
+			Action::AssignContributor {
+				contribution_id, ..
+			}
+			| Action::UnassignContributor { contribution_id }
+			| Action::ValidateContribution { contribution_id } => Some(contribution_id),
+		}
+	}
+}
+
 impl Display for Action {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		write!(
@@ -1,6 +1,6 @@
 pub trait Aggregate: Send + Sync + Default + Sized {
-	type Id: PartialEq;
-	type Event;
+	type Id: PartialEq + Send + Sync;
+	type Event: Send + Sync;
 
 	fn apply_event(self, event: &Self::Event) -> Self;
 
@@ -30,12 +30,22 @@ impl<A: AggregateRoot> Repository<A> {
 			events => Ok(A::from_events(&events)),
 		}
 	}
+
+	/// Rebuild the aggregate as it was at the given point in time, replaying only the events
+	/// that happened up to it
+	pub fn find_by_id_at(&self, id: &A::Id, at: &PointInTime) -> Result<A, Error> {
+		let events = self.event_store.list_by_id_at(id, at)?;
+		match events {
+			_ if events.is_empty() => Err(Error::NotFound),
+			events => Ok(A::from_events(&events)),
+		}
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use assert_matches::assert_matches;
-	use std::str::FromStr;
+	use std::{str::FromStr, time::SystemTime};
 
 	use super::*;
 	use anyhow::anyhow;
@@ -98,4 +108,41 @@ mod tests {
 		assert_eq!(&ContributionStatus::Open, result.as_ref().unwrap().status());
 		assert_eq!(&contribution_id, result.as_ref().unwrap().id());
 	}
+
+	#[rstest]
+	fn test_not_found_at(mut event_store: MockEventStore<Contribution>) {
+		let contribution_id = ContributionId::from_str("0xaf").unwrap();
+		event_store
+			.expect_list_by_id_at()
+			.with(eq(contribution_id.clone()), eq(PointInTime::EventNumber(3)))
+			.returning(|_, _| Ok(vec![]));
+
+		let repository = Repository::new(Arc::new(event_store));
+		let result = repository.find_by_id_at(&contribution_id, &PointInTime::EventNumber(3));
+		assert!(result.is_err());
+		assert_matches!(result.unwrap_err(), Error::NotFound);
+	}
+
+	#[rstest]
+	fn test_found_at(mut event_store: MockEventStore<Contribution>) {
+		let contribution_id = ContributionId::from_str("0xaf").unwrap();
+		let creation_event = ContributionEvent::Created {
+			id: contribution_id.clone(),
+			project_id: Default::default(),
+			issue_number: Default::default(),
+			gate: Default::default(),
+		};
+		let at = PointInTime::Timestamp(SystemTime::UNIX_EPOCH);
+		event_store.expect_list_by_id().never();
+		event_store
+			.expect_list_by_id_at()
+			.with(eq(contribution_id.clone()), eq(at))
+			.returning(move |_, _| Ok(vec![creation_event.clone()]));
+
+		let repository = Repository::new(Arc::new(event_store));
+		let result = repository.find_by_id_at(&contribution_id, &at);
+		assert!(result.is_ok());
+		assert_eq!(&ContributionStatus::Open, result.as_ref().unwrap().status());
+		assert_eq!(&contribution_id, result.as_ref().unwrap().id());
+	}
 }
@@ -0,0 +1,263 @@
+use super::{Command, Context, Handler, Issuer, Middleware};
+use crate::Error as DomainError;
+use std::{
+	any::{Any, TypeId},
+	collections::HashMap,
+	sync::Arc,
+};
+
+/// Routes each command to the handler registered for its type, through the middlewares
+#[derive(Default)]
+pub struct Dispatcher {
+	handlers: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+	middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl Dispatcher {
+	pub fn with_handler<H: Handler + 'static>(mut self, handler: H) -> Self {
+		let handler: Arc<dyn Handler<Command = H::Command>> = Arc::new(handler);
+		self.handlers.insert(TypeId::of::<H::Command>(), Box::new(handler));
+		self
+	}
+
+	/// Middlewares are called in the order they are added before the command is handled,
+	/// and in the reverse order after
+	pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+		self.middlewares.push(middleware);
+		self
+	}
+
+	pub async fn dispatch<C: Command>(
+		&self,
+		command: C,
+		issuer: Issuer,
+		idempotency_key: Option<String>,
+	) -> Result<C::Output, DomainError> {
+		let handler = self
+			.handlers
+			.get(&TypeId::of::<C>())
+			.and_then(|handler| handler.downcast_ref::<Arc<dyn Handler<Command = C>>>())
+			.cloned()
+			.ok_or(DomainError::UnknownCommand(C::NAME))?;
+
+		let context = Context {
+			command_name: C::NAME,
+			description: command.to_string(),
+			issuer,
+			permission: command.permission(),
+			idempotency_key,
+		};
+
+		for (index, middleware) in self.middlewares.iter().enumerate() {
+			match middleware.before(&context) {
+				Ok(None) => (),
+				Ok(Some(output)) =>
+					return serde_json::from_value(output).map_err(DomainError::CommandOutput),
+				Err(error) => {
+					// Let the middlewares already called release what they reserved
+					self.middlewares[..index].iter().rev().for_each(|middleware| {
+						middleware.after(&context, Err(&error));
+					});
+					return Err(error);
+				},
+			}
+		}
+
+		match handler.handle(command).await {
+			Ok(output) => {
+				let value = serde_json::to_value(&output).map_err(DomainError::CommandOutput)?;
+				self.middlewares.iter().rev().for_each(|middleware| {
+					middleware.after(&context, Ok(&value));
+				});
+				Ok(output)
+			},
+			Err(error) => {
+				self.middlewares.iter().rev().for_each(|middleware| {
+					middleware.after(&context, Err(&error));
+				});
+				Err(error)
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		command::{AuthorizationMiddleware, Permission},
+		ContributorId,
+	};
+	use assert_matches::assert_matches;
+	use async_trait::async_trait;
+	use rstest::rstest;
+	use serde_json::{json, Value};
+	use std::{fmt::Display, str::FromStr, sync::Mutex};
+
+	struct Greet {
+		name: String,
+	}
+
+	impl Display for Greet {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			write!(f, "Greet {}", self.name)
+		}
+	}
+
+	impl Command for Greet {
+		type Output = String;
+
+		const NAME: &'static str = "greet";
+
+		fn permission(&self) -> Permission {
+			Permission::AdminOrContributor(ContributorId::from_str("0x12").unwrap())
+		}
+	}
+
+	struct GreetHandler;
+
+	#[async_trait]
+	impl Handler for GreetHandler {
+		type Command = Greet;
+
+		async fn handle(&self, command: Greet) -> Result<String, DomainError> {
+			match command.name.is_empty() {
+				true => Err(DomainError::Lock),
+				false => Ok(format!("Hello {}", command.name)),
+			}
+		}
+	}
+
+	#[derive(Default)]
+	struct RecordingMiddleware {
+		calls: Mutex<Vec<String>>,
+		output: Option<Value>,
+	}
+
+	impl Middleware for RecordingMiddleware {
+		fn before(&self, context: &Context) -> Result<Option<Value>, DomainError> {
+			self.calls.lock().unwrap().push(format!("before {}", context.description));
+			Ok(self.output.clone())
+		}
+
+		fn after(&self, context: &Context, result: Result<&Value, &DomainError>) {
+			self.calls.lock().unwrap().push(format!(
+				"after {} {}",
+				context.description,
+				result.is_ok()
+			));
+		}
+	}
+
+	struct FailingMiddleware;
+
+	impl Middleware for FailingMiddleware {
+		fn before(&self, _context: &Context) -> Result<Option<Value>, DomainError> {
+			Err(DomainError::Lock)
+		}
+	}
+
+	fn greet(name: &str) -> Greet {
+		Greet {
+			name: name.to_string(),
+		}
+	}
+
+	#[rstest]
+	async fn dispatch_routes_command_to_its_handler() {
+		let dispatcher = Dispatcher::default().with_handler(GreetHandler);
+
+		let output = dispatcher.dispatch(greet("Anthony"), Issuer::Admin, None).await.unwrap();
+
+		assert_eq!("Hello Anthony", output);
+	}
+
+	#[rstest]
+	async fn dispatch_fails_without_handler() {
+		let dispatcher = Dispatcher::default();
+
+		let result = dispatcher.dispatch(greet("Anthony"), Issuer::Admin, None).await;
+
+		assert_matches!(result, Err(DomainError::UnknownCommand("greet")));
+	}
+
+	#[rstest]
+	async fn dispatch_calls_middlewares_around_handler() {
+		let middleware = Arc::new(RecordingMiddleware::default());
+		let dispatcher = Dispatcher::default()
+			.with_handler(GreetHandler)
+			.with_middleware(middleware.clone());
+
+		dispatcher.dispatch(greet("Anthony"), Issuer::Admin, None).await.unwrap();
+		dispatcher.dispatch(greet(""), Issuer::Admin, None).await.unwrap_err();
+
+		assert_eq!(
+			vec![
+				"before Greet Anthony",
+				"after Greet Anthony true",
+				"before Greet ",
+				"after Greet  false",
+			],
+			*middleware.calls.lock().unwrap()
+		);
+	}
+
+	#[rstest]
+	async fn dispatch_returns_middleware_output_without_calling_handler() {
+		let middleware = Arc::new(RecordingMiddleware {
+			output: Some(json!("Hello again")),
+			..Default::default()
+		});
+		let dispatcher = Dispatcher::default()
+			.with_handler(GreetHandler)
+			.with_middleware(middleware.clone());
+
+		let output = dispatcher.dispatch(greet(""), Issuer::Admin, None).await.unwrap();
+
+		assert_eq!("Hello again", output);
+		assert_eq!(vec!["before Greet "], *middleware.calls.lock().unwrap());
+	}
+
+	#[rstest]
+	async fn dispatch_refuses_commands_the_issuer_is_not_permitted_to_issue() {
+		let dispatcher = Dispatcher::default()
+			.with_handler(GreetHandler)
+			.with_middleware(Arc::new(AuthorizationMiddleware));
+
+		let result = dispatcher
+			.dispatch(
+				greet("Anthony"),
+				Issuer::Contributor(ContributorId::from_str("0x34").unwrap()),
+				None,
+			)
+			.await;
+		assert_matches!(result, Err(DomainError::Unauthorized("greet")));
+
+		let output = dispatcher
+			.dispatch(
+				greet("Anthony"),
+				Issuer::Contributor(ContributorId::from_str("0x12").unwrap()),
+				None,
+			)
+			.await
+			.unwrap();
+		assert_eq!("Hello Anthony", output);
+	}
+
+	#[rstest]
+	async fn dispatch_calls_previous_middlewares_back_when_a_middleware_fails() {
+		let middleware = Arc::new(RecordingMiddleware::default());
+		let dispatcher = Dispatcher::default()
+			.with_handler(GreetHandler)
+			.with_middleware(middleware.clone())
+			.with_middleware(Arc::new(FailingMiddleware));
+
+		let result = dispatcher.dispatch(greet("Anthony"), Issuer::Admin, None).await;
+
+		assert_matches!(result, Err(DomainError::Lock));
+		assert_eq!(
+			vec!["before Greet Anthony", "after Greet Anthony false"],
+			*middleware.calls.lock().unwrap()
+		);
+	}
+}
@@ -0,0 +1,306 @@
+use super::{Context, Middleware};
+use crate::{
+	Error as DomainError, OnchainTransaction, OnchainTransactionRepository,
+	OnchainTransactionStatus, SubmittedTransaction,
+};
+use log::{error, info};
+use serde_json::Value;
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant, SystemTime},
+};
+
+pub struct LoggingMiddleware;
+
+impl Middleware for LoggingMiddleware {
+	fn before(&self, context: &Context) -> Result<Option<Value>, DomainError> {
+		info!(
+			"Dispatching command `{}` issued by {}: {}",
+			context.command_name, context.issuer, context.description
+		);
+		Ok(None)
+	}
+
+	fn after(&self, context: &Context, result: Result<&Value, &DomainError>) {
+		match result {
+			Ok(output) => info!("Command `{}` succeeded: {output}", context.command_name),
+			Err(error) => error!("Command `{}` failed: {error}", context.command_name),
+		}
+	}
+}
+
+/// Refuses the commands the issuer is not permitted to issue.
+/// The issuer must come from the authentication of the request, never from its content.
+pub struct AuthorizationMiddleware;
+
+impl Middleware for AuthorizationMiddleware {
+	fn before(&self, context: &Context) -> Result<Option<Value>, DomainError> {
+		match context.permission.allows(&context.issuer) {
+			true => Ok(None),
+			false => Err(DomainError::Unauthorized(context.command_name)),
+		}
+	}
+}
+
+/// Returns the output of the previous command with the same idempotency key, if it succeeded
+/// less than `window` ago, instead of handling it again.
+/// The key is reserved as soon as the command is dispatched, so that a duplicate sent while it is
+/// being handled is refused instead of being handled twice.
+pub struct IdempotencyMiddleware {
+	window: Duration,
+	/// Outputs by key, `None` while the command is being handled
+	outputs: Mutex<HashMap<String, (Instant, Option<Value>)>>,
+}
+
+impl IdempotencyMiddleware {
+	pub fn new(window: Duration) -> Self {
+		Self {
+			window,
+			outputs: Default::default(),
+		}
+	}
+
+	fn key(context: &Context) -> Option<String> {
+		context
+			.idempotency_key
+			.as_ref()
+			.map(|idempotency_key| format!("{}:{idempotency_key}", context.command_name))
+	}
+}
+
+impl Middleware for IdempotencyMiddleware {
+	fn before(&self, context: &Context) -> Result<Option<Value>, DomainError> {
+		let key = match Self::key(context) {
+			Some(key) => key,
+			None => return Ok(None),
+		};
+
+		let mut outputs = self.outputs.lock().map_err(|_| DomainError::Lock)?;
+		outputs.retain(|_, (recorded_at, _)| recorded_at.elapsed() < self.window);
+
+		match outputs.get(&key) {
+			Some((_, Some(output))) => Ok(Some(output.clone())),
+			Some((_, None)) => Err(DomainError::CommandInProgress(context.command_name)),
+			None => {
+				outputs.insert(key, (Instant::now(), None));
+				Ok(None)
+			},
+		}
+	}
+
+	fn after(&self, context: &Context, result: Result<&Value, &DomainError>) {
+		let key = match Self::key(context) {
+			Some(key) => key,
+			None => return,
+		};
+
+		if let Ok(mut outputs) = self.outputs.lock() {
+			match result {
+				Ok(output) => {
+					outputs.insert(key, (Instant::now(), Some(output.clone())));
+				},
+				Err(_) => {
+					outputs.remove(&key);
+				},
+			}
+		}
+	}
+}
+
+/// Records the transactions sent by successful commands, so that their status can be tracked
+pub struct TransactionTrackingMiddleware {
+	transaction_repository: Arc<dyn OnchainTransactionRepository>,
+}
+
+impl TransactionTrackingMiddleware {
+	pub fn new(transaction_repository: Arc<dyn OnchainTransactionRepository>) -> Self {
+		Self {
+			transaction_repository,
+		}
+	}
+}
+
+impl Middleware for TransactionTrackingMiddleware {
+	fn after(&self, context: &Context, result: Result<&Value, &DomainError>) {
+		let submitted_transaction = match result
+			.ok()
+			.and_then(|output| serde_json::from_value::<SubmittedTransaction>(output.clone()).ok())
+		{
+			Some(submitted_transaction) => submitted_transaction,
+			None => return,
+		};
+
+		let now = SystemTime::now();
+		let transaction = OnchainTransaction {
+			hash: submitted_transaction.transaction_hash,
+			command: context.command_name.to_string(),
+			issuer: context.issuer.to_string(),
+			actions: submitted_transaction.actions,
+			status: OnchainTransactionStatus::Pending,
+			submitted_at: now,
+			updated_at: now,
+		};
+
+		if let Err(error) = self.transaction_repository.create(transaction) {
+			error!(
+				"Unable to record transaction sent by command `{}`: {error}",
+				context.command_name
+			);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		command::Permission, Action, ContributorId, HexPrefixedString, Issuer,
+		MockOnchainTransactionRepository,
+	};
+	use assert_matches::assert_matches;
+	use rstest::{fixture, rstest};
+	use serde_json::json;
+	use std::str::FromStr;
+
+	#[fixture]
+	fn contributor_id() -> ContributorId {
+		ContributorId::from_str("0x12").unwrap()
+	}
+
+	#[fixture]
+	fn context(contributor_id: ContributorId) -> Context {
+		Context {
+			command_name: "apply",
+			description: String::from("Apply"),
+			issuer: Issuer::Contributor(contributor_id.clone()),
+			permission: Permission::AdminOrContributor(contributor_id),
+			idempotency_key: Some(String::from("8d8b2a2e-4d2b-4f0c-9c5e-1f3a0e6b7c21")),
+		}
+	}
+
+	#[rstest]
+	fn authorization_allows_permitted_issuers(context: Context) {
+		assert!(AuthorizationMiddleware.before(&context).unwrap().is_none());
+		assert!(AuthorizationMiddleware
+			.before(&Context {
+				issuer: Issuer::Admin,
+				..context
+			})
+			.unwrap()
+			.is_none());
+	}
+
+	#[rstest]
+	fn authorization_rejects_other_issuers(context: Context) {
+		let result = AuthorizationMiddleware.before(&Context {
+			issuer: Issuer::Contributor(ContributorId::from_str("0x34").unwrap()),
+			..context.clone()
+		});
+		assert_matches!(result, Err(DomainError::Unauthorized("apply")));
+
+		let result = AuthorizationMiddleware.before(&Context {
+			issuer: Issuer::Contributor(ContributorId::from_str("0x12").unwrap()),
+			permission: Permission::Admin,
+			..context
+		});
+		assert_matches!(result, Err(DomainError::Unauthorized("apply")));
+	}
+
+	#[rstest]
+	fn idempotency_replays_successful_output(context: Context) {
+		let middleware = IdempotencyMiddleware::new(Duration::from_secs(60));
+
+		assert!(middleware.before(&context).unwrap().is_none());
+		middleware.after(&context, Ok(&json!("0x1234")));
+
+		assert_eq!(Some(json!("0x1234")), middleware.before(&context).unwrap());
+	}
+
+	#[rstest]
+	fn idempotency_refuses_duplicates_while_handling(context: Context) {
+		let middleware = IdempotencyMiddleware::new(Duration::from_secs(60));
+
+		assert!(middleware.before(&context).unwrap().is_none());
+
+		assert_matches!(
+			middleware.before(&context),
+			Err(DomainError::CommandInProgress("apply"))
+		);
+	}
+
+	#[rstest]
+	fn idempotency_releases_key_upon_failure(context: Context) {
+		let middleware = IdempotencyMiddleware::new(Duration::from_secs(60));
+
+		assert!(middleware.before(&context).unwrap().is_none());
+		middleware.after(&context, Err(&DomainError::Lock));
+
+		assert!(middleware.before(&context).unwrap().is_none());
+	}
+
+	#[rstest]
+	fn idempotency_ignores_commands_without_key(context: Context) {
+		let middleware = IdempotencyMiddleware::new(Duration::from_secs(60));
+		let context = Context {
+			idempotency_key: None,
+			..context
+		};
+
+		assert!(middleware.before(&context).unwrap().is_none());
+		middleware.after(&context, Ok(&json!("0x1234")));
+
+		assert!(middleware.before(&context).unwrap().is_none());
+	}
+
+	#[rstest]
+	fn idempotency_forgets_outputs_after_window(context: Context) {
+		let middleware = IdempotencyMiddleware::new(Duration::ZERO);
+
+		assert!(middleware.before(&context).unwrap().is_none());
+		middleware.after(&context, Ok(&json!("0x1234")));
+
+		assert!(middleware.before(&context).unwrap().is_none());
+	}
+
+	#[rstest]
+	fn transaction_tracking_records_submitted_transactions(context: Context) {
+		let submitted_transaction = SubmittedTransaction::new(
+			HexPrefixedString::from_str("0x1234").unwrap(),
+			vec![Action::ValidateContribution {
+				contribution_id: 12.into(),
+			}],
+		);
+
+		let mut transaction_repository = MockOnchainTransactionRepository::new();
+		let expected = submitted_transaction.clone();
+		transaction_repository
+			.expect_create()
+			.withf(move |transaction| {
+				transaction.hash == expected.transaction_hash
+					&& transaction.actions == expected.actions
+					&& transaction.command == "apply"
+					&& transaction.issuer == "contributor 0x0012"
+					&& transaction.status == OnchainTransactionStatus::Pending
+			})
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let middleware = TransactionTrackingMiddleware::new(Arc::new(transaction_repository));
+		middleware.after(
+			&context,
+			Ok(&serde_json::to_value(submitted_transaction).unwrap()),
+		);
+	}
+
+	#[rstest]
+	fn transaction_tracking_ignores_failures_and_other_outputs(context: Context) {
+		let mut transaction_repository = MockOnchainTransactionRepository::new();
+		transaction_repository.expect_create().never();
+
+		let middleware = TransactionTrackingMiddleware::new(Arc::new(transaction_repository));
+		middleware.after(&context, Err(&DomainError::Lock));
+		middleware.after(&context, Ok(&json!("0x1234")));
+		middleware.after(&context, Ok(&Value::Null));
+	}
+}
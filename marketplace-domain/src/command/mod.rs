@@ -0,0 +1,89 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::fmt::Display;
+
+mod dispatcher;
+pub use dispatcher::Dispatcher;
+
+mod middlewares;
+pub use middlewares::{
+	AuthorizationMiddleware, IdempotencyMiddleware, LoggingMiddleware,
+	TransactionTrackingMiddleware,
+};
+
+/// A request to mutate the state of the marketplace
+pub trait Command: Display + Send + Sync + 'static {
+	type Output: Serialize + DeserializeOwned + Send;
+
+	const NAME: &'static str;
+
+	fn permission(&self) -> Permission {
+		Permission::Admin
+	}
+}
+
+#[async_trait]
+pub trait Handler: Send + Sync {
+	type Command: Command;
+
+	async fn handle(
+		&self,
+		command: Self::Command,
+	) -> Result<<Self::Command as Command>::Output, DomainError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issuer {
+	Admin,
+	Contributor(ContributorId),
+}
+
+impl Display for Issuer {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Issuer::Admin => write!(f, "admin"),
+			Issuer::Contributor(contributor_id) => write!(f, "contributor {contributor_id}"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Permission {
+	Admin,
+	AdminOrContributor(ContributorId),
+}
+
+impl Permission {
+	pub fn allows(&self, issuer: &Issuer) -> bool {
+		match (self, issuer) {
+			(_, Issuer::Admin) => true,
+			(Permission::AdminOrContributor(allowed), Issuer::Contributor(contributor_id)) =>
+				allowed == contributor_id,
+			(Permission::Admin, Issuer::Contributor(_)) => false,
+		}
+	}
+}
+
+/// What middlewares know about the command being dispatched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context {
+	pub command_name: &'static str,
+	pub description: String,
+	pub issuer: Issuer,
+	pub permission: Permission,
+	/// Key supplied by the client, commands sharing the same key being considered as duplicates
+	/// by the idempotency middleware
+	pub idempotency_key: Option<String>,
+}
+
+pub trait Middleware: Send + Sync {
+	/// Called before the command is handled. Returning an output skips the handler.
+	fn before(&self, _context: &Context) -> Result<Option<Value>, DomainError> {
+		Ok(None)
+	}
+
+	/// Called once the handler returned, with the serialized output on success
+	fn after(&self, _context: &Context, _result: Result<&Value, &DomainError>) {}
+}
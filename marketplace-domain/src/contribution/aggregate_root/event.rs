@@ -27,6 +27,18 @@ pub enum Event {
 	},
 }
 
+impl Event {
+	pub fn contribution_id(&self) -> &ContributionId {
+		match self {
+			Event::Created { id, .. }
+			| Event::Applied { id, .. }
+			| Event::Assigned { id, .. }
+			| Event::Unassigned { id }
+			| Event::Validated { id } => id,
+		}
+	}
+}
+
 #[cfg(test)]
 impl Default for Event {
 	fn default() -> Self {
@@ -22,6 +22,12 @@ pub enum Error {
 	CannotApply(ContributionStatus),
 	#[error("Contributor `{0}` already applied")]
 	AlreadyApplied(ContributorId),
+	#[error("The current contribution status, `{0}`, does not allow it to be assigned")]
+	CannotAssign(ContributionStatus),
+	#[error("The current contribution status, `{0}`, does not allow it to be unassigned")]
+	CannotUnassign(ContributionStatus),
+	#[error("The current contribution status, `{0}`, does not allow it to be validated")]
+	CannotValidate(ContributionStatus),
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
@@ -52,6 +58,33 @@ impl Contribution {
 		Ok(vec![applied_event])
 	}
 
+	pub fn assign(self, contributor_id: &ContributorId) -> Result<Vec<Event>, Error> {
+		if self.status != Status::Open {
+			return Err(Error::CannotAssign(self.status));
+		}
+
+		Ok(vec![Event::Assigned {
+			id: self.id,
+			contributor_id: contributor_id.clone(),
+		}])
+	}
+
+	pub fn unassign(self) -> Result<Vec<Event>, Error> {
+		if self.status != Status::Assigned {
+			return Err(Error::CannotUnassign(self.status));
+		}
+
+		Ok(vec![Event::Unassigned { id: self.id }])
+	}
+
+	pub fn validate(self) -> Result<Vec<Event>, Error> {
+		if self.status != Status::Assigned {
+			return Err(Error::CannotValidate(self.status));
+		}
+
+		Ok(vec![Event::Validated { id: self.id }])
+	}
+
 	pub fn id(&self) -> &Id {
 		&self.id
 	}
@@ -59,6 +92,26 @@ impl Contribution {
 	pub fn status(&self) -> &ContributionStatus {
 		&self.status
 	}
+
+	pub fn project_id(&self) -> GithubProjectId {
+		self.project_id
+	}
+
+	pub fn issue_number(&self) -> GithubIssueNumber {
+		self.issue_number
+	}
+
+	pub fn gate(&self) -> u8 {
+		self.gate
+	}
+
+	pub fn contributor_id(&self) -> Option<&ContributorId> {
+		self.contributor_id.as_ref()
+	}
+
+	pub fn applicants(&self) -> &[ContributorId] {
+		&self.applicants
+	}
 }
 
 #[cfg(test)]
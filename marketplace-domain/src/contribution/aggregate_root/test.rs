@@ -148,3 +148,85 @@ fn apply_to_contribution_emits_an_event(contribution_created_event: Event) {
 		}
 	);
 }
+
+#[rstest]
+fn assign_open_contribution_emits_an_event(
+	contribution_created_event: Event,
+	contribution_id: Id,
+	contributor_id: ContributorId,
+) {
+	let contribution = Contribution::from_events(&vec![contribution_created_event]);
+
+	let events = contribution.assign(&contributor_id).unwrap();
+	assert_eq!(
+		vec![Event::Assigned {
+			id: contribution_id,
+			contributor_id
+		}],
+		events
+	);
+}
+
+#[rstest]
+fn assign_assigned_contribution(
+	contribution_created_event: Event,
+	contribution_assigned_event: Event,
+	contributor_id: ContributorId,
+) {
+	let contribution = Contribution::from_events(&vec![
+		contribution_created_event,
+		contribution_assigned_event,
+	]);
+
+	assert_matches!(
+		contribution.assign(&contributor_id),
+		Err(Error::CannotAssign(Status::Assigned))
+	);
+}
+
+#[rstest]
+fn unassign_open_contribution(contribution_created_event: Event) {
+	let contribution = Contribution::from_events(&vec![contribution_created_event]);
+
+	assert_matches!(
+		contribution.unassign(),
+		Err(Error::CannotUnassign(Status::Open))
+	);
+}
+
+#[rstest]
+fn validate_assigned_contribution_emits_an_event(
+	contribution_created_event: Event,
+	contribution_assigned_event: Event,
+	contribution_id: Id,
+) {
+	let contribution = Contribution::from_events(&vec![
+		contribution_created_event,
+		contribution_assigned_event,
+	]);
+
+	assert_eq!(
+		vec![Event::Validated {
+			id: contribution_id
+		}],
+		contribution.validate().unwrap()
+	);
+}
+
+#[rstest]
+fn validate_completed_contribution(
+	contribution_created_event: Event,
+	contribution_assigned_event: Event,
+	contribution_validated_event: Event,
+) {
+	let contribution = Contribution::from_events(&vec![
+		contribution_created_event,
+		contribution_assigned_event,
+		contribution_validated_event,
+	]);
+
+	assert_matches!(
+		contribution.validate(),
+		Err(Error::CannotValidate(Status::Completed))
+	);
+}
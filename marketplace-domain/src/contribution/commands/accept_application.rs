@@ -0,0 +1,75 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use mapinto::ResultMapErrInto;
+use std::{fmt::Display, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcceptApplication {
+	pub application_id: ApplicationId,
+}
+
+impl Display for AcceptApplication {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Accept application {}.", self.application_id)
+	}
+}
+
+impl Command for AcceptApplication {
+	type Output = SubmittedTransaction;
+
+	const NAME: &'static str = "accept_application";
+}
+
+pub struct AcceptApplicationHandler {
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+	application_repository: Arc<dyn ApplicationProjectionRepository>,
+}
+
+impl AcceptApplicationHandler {
+	pub fn new(
+		onchain_contribution_service: Arc<dyn OnchainContributionService>,
+		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+		application_repository: Arc<dyn ApplicationProjectionRepository>,
+	) -> Self {
+		Self {
+			onchain_contribution_service,
+			contribution_projection_repository,
+			application_repository,
+		}
+	}
+}
+
+#[async_trait]
+impl CommandHandler for AcceptApplicationHandler {
+	type Command = AcceptApplication;
+
+	async fn handle(
+		&self,
+		command: AcceptApplication,
+	) -> Result<SubmittedTransaction, DomainError> {
+		let application = self
+			.application_repository
+			.find(&command.application_id)
+			.map_err(DomainError::from)?
+			.ok_or_else(|| DomainError::from(ApplicationProjectionRepositoryError::NotFound))?;
+
+		// TODO: use contribution aggregate root instead of projection as source of truth
+		let contribution = self
+			.contribution_projection_repository
+			.find_by_id(application.contribution_id())
+			.map_err(DomainError::from)?
+			.ok_or_else(|| DomainError::from(ContributionProjectionRepositoryError::NotFound))?;
+
+		let action = Action::AssignContributor {
+			contribution_id: contribution.id.clone(),
+			contributor_id: application.contributor_id().to_owned(),
+		};
+
+		self.onchain_contribution_service
+			.assign_contributor(contribution.id, application.contributor_id().to_owned())
+			.await
+			.map(|transaction_hash| SubmittedTransaction::new(transaction_hash, vec![action]))
+			.map_err_into()
+	}
+}
@@ -0,0 +1,80 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use std::{fmt::Display, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyToContribution {
+	pub contribution_id: ContributionId,
+	pub contributor_id: ContributorId,
+}
+
+impl Display for ApplyToContribution {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"Apply contributor {} to contribution {}.",
+			self.contributor_id, self.contribution_id
+		)
+	}
+}
+
+impl Command for ApplyToContribution {
+	type Output = ();
+
+	const NAME: &'static str = "apply_to_contribution";
+
+	fn permission(&self) -> Permission {
+		Permission::AdminOrContributor(self.contributor_id.clone())
+	}
+}
+
+pub struct ApplyToContributionHandler {
+	contribution_repository: AggregateRootRepository<Contribution>,
+	event_store: Arc<dyn EventStore<Contribution>>,
+	application_projector: Arc<dyn Projector<Contribution>>,
+	uuid_generator: Arc<dyn UuidGenerator>,
+}
+
+impl ApplyToContributionHandler {
+	pub fn new(
+		contribution_repository: AggregateRootRepository<Contribution>,
+		event_store: Arc<dyn EventStore<Contribution>>,
+		application_projector: Arc<dyn Projector<Contribution>>,
+		uuid_generator: Arc<dyn UuidGenerator>,
+	) -> Self {
+		Self {
+			contribution_repository,
+			event_store,
+			application_projector,
+			uuid_generator,
+		}
+	}
+}
+
+#[async_trait]
+impl CommandHandler for ApplyToContributionHandler {
+	type Command = ApplyToContribution;
+
+	async fn handle(&self, command: ApplyToContribution) -> Result<(), DomainError> {
+		let contribution = self.contribution_repository.find_by_id(&command.contribution_id)?;
+		let events = contribution.apply(&command.contributor_id)?;
+		let storable_events: Vec<StorableEvent<Contribution>> = events
+			.iter()
+			.map(|event| StorableEvent {
+				deduplication_id: self.uuid_generator.new_uuid().to_string(),
+				event: event.to_owned(),
+				transaction_hash: None,
+				block_number: None,
+				block_hash: None,
+			})
+			.collect();
+		self.event_store.append(&command.contribution_id, storable_events)?;
+		// TODO: the handler shouldn't know about the projectors, it should just push the events to
+		// a bus
+		for event in &events {
+			self.application_projector.project(event).await?;
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,74 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use mapinto::ResultMapErrInto;
+use std::{fmt::Display, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignContributor {
+	pub contribution_id: ContributionId,
+	pub contributor_id: ContributorId,
+}
+
+impl From<&AssignContributor> for Action {
+	fn from(command: &AssignContributor) -> Self {
+		Action::AssignContributor {
+			contribution_id: command.contribution_id.clone(),
+			contributor_id: command.contributor_id.clone(),
+		}
+	}
+}
+
+impl Display for AssignContributor {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		Action::from(self).fmt(f)
+	}
+}
+
+impl Command for AssignContributor {
+	type Output = SubmittedTransaction;
+
+	const NAME: &'static str = "assign_contributor";
+}
+
+pub struct AssignContributorHandler {
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+}
+
+impl AssignContributorHandler {
+	pub fn new(
+		onchain_contribution_service: Arc<dyn OnchainContributionService>,
+		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+	) -> Self {
+		Self {
+			onchain_contribution_service,
+			contribution_projection_repository,
+		}
+	}
+}
+
+#[async_trait]
+impl CommandHandler for AssignContributorHandler {
+	type Command = AssignContributor;
+
+	async fn handle(
+		&self,
+		command: AssignContributor,
+	) -> Result<SubmittedTransaction, DomainError> {
+		let action = Action::from(&command);
+
+		// TODO: use contribution aggregate root instead of projection as source of truth
+		let contribution =
+			self.contribution_projection_repository.find_by_id(&command.contribution_id)?;
+
+		match contribution {
+			Some(contribution) => self
+				.onchain_contribution_service
+				.assign_contributor(contribution.id, command.contributor_id)
+				.await
+				.map(|transaction_hash| SubmittedTransaction::new(transaction_hash, vec![action]))
+				.map_err_into(),
+			None => Err(ContributionProjectionRepositoryError::NotFound.into()),
+		}
+	}
+}
@@ -0,0 +1,69 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use mapinto::ResultMapErrInto;
+use std::{fmt::Display, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateContribution {
+	pub project_id: GithubProjectId,
+	pub issue_number: GithubIssueNumber,
+	pub gate: u8,
+}
+
+impl From<&CreateContribution> for Action {
+	fn from(command: &CreateContribution) -> Self {
+		Action::CreateContribution {
+			project_id: command.project_id,
+			issue_number: command.issue_number,
+			gate: command.gate,
+		}
+	}
+}
+
+impl Display for CreateContribution {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		Action::from(self).fmt(f)
+	}
+}
+
+impl Command for CreateContribution {
+	type Output = SubmittedTransaction;
+
+	const NAME: &'static str = "create_contribution";
+}
+
+pub struct CreateContributionHandler {
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+}
+
+impl CreateContributionHandler {
+	pub fn new(onchain_contribution_service: Arc<dyn OnchainContributionService>) -> Self {
+		Self {
+			onchain_contribution_service,
+		}
+	}
+}
+
+#[async_trait]
+impl CommandHandler for CreateContributionHandler {
+	type Command = CreateContribution;
+
+	async fn handle(
+		&self,
+		command: CreateContribution,
+	) -> Result<SubmittedTransaction, DomainError> {
+		let action = Action::from(&command);
+		let contribution = ContributionProjection {
+			project_id: command.project_id,
+			issue_number: command.issue_number,
+			gate: command.gate,
+			..Default::default()
+		};
+
+		self.onchain_contribution_service
+			.create(contribution)
+			.await
+			.map(|transaction_hash| SubmittedTransaction::new(transaction_hash, vec![action]))
+			.map_err_into()
+	}
+}
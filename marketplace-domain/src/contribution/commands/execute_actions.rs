@@ -0,0 +1,64 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use mapinto::ResultMapErrInto;
+use std::{fmt::Display, sync::Arc};
+
+/// Several actions sent to the contributions contract in a single transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecuteActions {
+	pub actions: Vec<Action>,
+}
+
+impl Display for ExecuteActions {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Execute {} actions:", self.actions.len())?;
+		self.actions.iter().try_for_each(|action| write!(f, " {action}"))
+	}
+}
+
+impl Command for ExecuteActions {
+	type Output = SubmittedTransaction;
+
+	const NAME: &'static str = "execute_actions";
+}
+
+pub struct ExecuteActionsHandler {
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+}
+
+impl ExecuteActionsHandler {
+	pub fn new(
+		onchain_contribution_service: Arc<dyn OnchainContributionService>,
+		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+	) -> Self {
+		Self {
+			onchain_contribution_service,
+			contribution_projection_repository,
+		}
+	}
+}
+
+#[async_trait]
+impl CommandHandler for ExecuteActionsHandler {
+	type Command = ExecuteActions;
+
+	async fn handle(&self, command: ExecuteActions) -> Result<SubmittedTransaction, DomainError> {
+		if command.actions.is_empty() {
+			return Err(DomainError::EmptyActionBatch);
+		}
+
+		// TODO: use contribution aggregate root instead of projection as source of truth
+		for contribution_id in command.actions.iter().filter_map(Action::contribution_id) {
+			if self.contribution_projection_repository.find_by_id(contribution_id)?.is_none() {
+				return Err(ContributionProjectionRepositoryError::NotFound.into());
+			}
+		}
+
+		self.onchain_contribution_service
+			.execute_actions(command.actions.clone())
+			.await
+			.map(|transaction_hash| SubmittedTransaction::new(transaction_hash, command.actions))
+			.map_err_into()
+	}
+}
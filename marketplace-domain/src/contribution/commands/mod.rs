@@ -0,0 +1,24 @@
+mod create;
+pub use create::{CreateContribution as CreateContributionCommand, CreateContributionHandler};
+
+mod assign;
+pub use assign::{AssignContributor as AssignContributorCommand, AssignContributorHandler};
+
+mod unassign;
+pub use unassign::{UnassignContributor as UnassignContributorCommand, UnassignContributorHandler};
+
+mod validate;
+pub use validate::{
+	ValidateContribution as ValidateContributionCommand, ValidateContributionHandler,
+};
+
+mod accept_application;
+pub use accept_application::{
+	AcceptApplication as AcceptApplicationCommand, AcceptApplicationHandler,
+};
+
+mod apply;
+pub use apply::{ApplyToContribution as ApplyToContributionCommand, ApplyToContributionHandler};
+
+mod execute_actions;
+pub use execute_actions::{ExecuteActions as ExecuteActionsCommand, ExecuteActionsHandler};
@@ -0,0 +1,72 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use mapinto::ResultMapErrInto;
+use std::{fmt::Display, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnassignContributor {
+	pub contribution_id: ContributionId,
+}
+
+impl From<&UnassignContributor> for Action {
+	fn from(command: &UnassignContributor) -> Self {
+		Action::UnassignContributor {
+			contribution_id: command.contribution_id.clone(),
+		}
+	}
+}
+
+impl Display for UnassignContributor {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		Action::from(self).fmt(f)
+	}
+}
+
+impl Command for UnassignContributor {
+	type Output = SubmittedTransaction;
+
+	const NAME: &'static str = "unassign_contributor";
+}
+
+pub struct UnassignContributorHandler {
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+}
+
+impl UnassignContributorHandler {
+	pub fn new(
+		onchain_contribution_service: Arc<dyn OnchainContributionService>,
+		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+	) -> Self {
+		Self {
+			onchain_contribution_service,
+			contribution_projection_repository,
+		}
+	}
+}
+
+#[async_trait]
+impl CommandHandler for UnassignContributorHandler {
+	type Command = UnassignContributor;
+
+	async fn handle(
+		&self,
+		command: UnassignContributor,
+	) -> Result<SubmittedTransaction, DomainError> {
+		let action = Action::from(&command);
+
+		// TODO: use contribution aggregate root instead of projection as source of truth
+		let contribution =
+			self.contribution_projection_repository.find_by_id(&command.contribution_id)?;
+
+		match contribution {
+			Some(contribution) => self
+				.onchain_contribution_service
+				.unassign_contributor(contribution.id)
+				.await
+				.map(|transaction_hash| SubmittedTransaction::new(transaction_hash, vec![action]))
+				.map_err_into(),
+			None => Err(ContributionProjectionRepositoryError::NotFound.into()),
+		}
+	}
+}
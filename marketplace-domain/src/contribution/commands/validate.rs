@@ -0,0 +1,72 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use mapinto::ResultMapErrInto;
+use std::{fmt::Display, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidateContribution {
+	pub contribution_id: ContributionId,
+}
+
+impl From<&ValidateContribution> for Action {
+	fn from(command: &ValidateContribution) -> Self {
+		Action::ValidateContribution {
+			contribution_id: command.contribution_id.clone(),
+		}
+	}
+}
+
+impl Display for ValidateContribution {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		Action::from(self).fmt(f)
+	}
+}
+
+impl Command for ValidateContribution {
+	type Output = SubmittedTransaction;
+
+	const NAME: &'static str = "validate_contribution";
+}
+
+pub struct ValidateContributionHandler {
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+}
+
+impl ValidateContributionHandler {
+	pub fn new(
+		onchain_contribution_service: Arc<dyn OnchainContributionService>,
+		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+	) -> Self {
+		Self {
+			onchain_contribution_service,
+			contribution_projection_repository,
+		}
+	}
+}
+
+#[async_trait]
+impl CommandHandler for ValidateContributionHandler {
+	type Command = ValidateContribution;
+
+	async fn handle(
+		&self,
+		command: ValidateContribution,
+	) -> Result<SubmittedTransaction, DomainError> {
+		let action = Action::from(&command);
+
+		// TODO: use contribution aggregate root instead of projection as source of truth
+		let contribution =
+			self.contribution_projection_repository.find_by_id(&command.contribution_id)?;
+
+		match contribution {
+			Some(contribution) => self
+				.onchain_contribution_service
+				.validate(contribution.id)
+				.await
+				.map(|transaction_hash| SubmittedTransaction::new(transaction_hash, vec![action]))
+				.map_err_into(),
+			None => Err(ContributionProjectionRepositoryError::NotFound.into()),
+		}
+	}
+}
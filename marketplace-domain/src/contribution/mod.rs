@@ -4,6 +4,12 @@ pub use aggregate_root::{
 	Status as ContributionStatus,
 };
 
+mod commands;
+pub use commands::*;
+
+mod simulation;
+pub use simulation::{Simulation as ContributionSimulation, Simulator as ContributionSimulator};
+
 mod projectors;
 pub use projectors::{ApplicationProjector, ContributionProjector};
 
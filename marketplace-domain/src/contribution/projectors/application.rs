@@ -1,6 +1,5 @@
 use crate::*;
 use async_trait::async_trait;
-use log::error;
 use std::sync::Arc;
 
 pub struct ApplicationProjector {
@@ -81,8 +80,11 @@ impl ApplicationProjector {
 
 #[async_trait]
 impl Projector<Contribution> for ApplicationProjector {
-	async fn project(&self, event: &<Contribution as Aggregate>::Event) {
-		let result = match event {
+	async fn project(
+		&self,
+		event: &<Contribution as Aggregate>::Event,
+	) -> Result<(), ProjectorError> {
+		match event {
 			ContributionEvent::Applied {
 				id: contribution_id,
 				contributor_id,
@@ -95,11 +97,14 @@ impl Projector<Contribution> for ApplicationProjector {
 				id: contribution_id,
 			} => self.on_unassigned(contribution_id),
 			_ => Ok(()),
-		};
-
-		if let Err(error) = result {
-			error!("Unable to project event {event}: {}", error.to_string());
 		}
+		.map_err(|error| ProjectorError::Projection(Box::new(error)))
+	}
+
+	async fn reset(&self, contribution_id: &ContributionId) -> Result<(), ProjectorError> {
+		self.application_projection_repository
+			.delete_by_contribution(contribution_id)
+			.map_err(|error| ProjectorError::Projection(Box::new(error)))
 	}
 }
 
@@ -188,7 +193,8 @@ mod tests {
 				id: contribution_id,
 				contributor_id: contributor_1_id,
 			})
-			.await;
+			.await
+			.unwrap();
 	}
 
 	#[rstest]
@@ -232,7 +238,8 @@ mod tests {
 				id: contribution_id,
 				contributor_id: contributor_1_id,
 			})
-			.await;
+			.await
+			.unwrap();
 	}
 
 	#[rstest]
@@ -316,7 +323,8 @@ mod tests {
 				id: contribution_id,
 				contributor_id: contributor_2_id,
 			})
-			.await;
+			.await
+			.unwrap();
 	}
 
 	#[rstest]
@@ -402,6 +410,7 @@ mod tests {
 			.project(&ContributionEvent::Unassigned {
 				id: contribution_id,
 			})
-			.await;
+			.await
+			.unwrap();
 	}
 }
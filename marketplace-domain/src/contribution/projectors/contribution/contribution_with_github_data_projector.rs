@@ -44,13 +44,7 @@ impl WithGithubDataProjector {
 				None
 			},
 
-			Err(e) => {
-				error!(
-					"Error while fetching GitHub issue {project_id}/{issue_number}: {}",
-					e.to_string()
-				);
-				None
-			},
+			Err(e) => return Err(e.into()),
 		};
 
 		let contribution = ContributionProjection {
@@ -100,8 +94,8 @@ impl WithGithubDataProjector {
 
 #[async_trait]
 impl Projector<Contribution> for WithGithubDataProjector {
-	async fn project(&self, event: &ContributionEvent) {
-		let result = match event {
+	async fn project(&self, event: &ContributionEvent) -> Result<(), ProjectorError> {
+		match event {
 			ContributionEvent::Created {
 				id,
 				project_id,
@@ -113,10 +107,13 @@ impl Projector<Contribution> for WithGithubDataProjector {
 			ContributionEvent::Unassigned { id } => self.on_unassign(id),
 			ContributionEvent::Validated { id } => self.on_validate(id),
 			ContributionEvent::Applied { .. } => Ok(()),
-		};
-
-		if let Err(error) = result {
-			error!("Unable to project event {event}: {}", error.to_string());
 		}
+		.map_err(|error| ProjectorError::Projection(Box::new(error)))
+	}
+
+	async fn reset(&self, id: &ContributionId) -> Result<(), ProjectorError> {
+		self.contribution_projection_repository
+			.delete(id)
+			.map_err(|error| ProjectorError::Projection(Box::new(error)))
 	}
 }
@@ -141,7 +141,7 @@ async fn on_contribution_created_event(
 		Arc::new(github_issue_repository),
 	);
 
-	projector.project(&contribution_created_event).await;
+	assert!(projector.project(&contribution_created_event).await.is_ok());
 }
 
 #[rstest]
@@ -166,7 +166,7 @@ async fn on_contribution_assigned_event(
 		Arc::new(github_issue_repository),
 	);
 
-	projector.project(&contribution_assigned_event).await;
+	assert!(projector.project(&contribution_assigned_event).await.is_ok());
 }
 
 #[rstest]
@@ -186,7 +186,7 @@ async fn on_contribution_unassigned_event(
 		Arc::new(github_issue_repository),
 	);
 
-	projector.project(&contribution_unassigned_event).await;
+	assert!(projector.project(&contribution_unassigned_event).await.is_ok());
 }
 
 #[rstest]
@@ -206,5 +206,27 @@ async fn on_contribution_validated_event(
 		Arc::new(github_issue_repository),
 	);
 
-	projector.project(&contribution_validated_event).await;
+	assert!(projector.project(&contribution_validated_event).await.is_ok());
+}
+
+#[rstest]
+async fn on_contribution_created_event_with_github_error(
+	mut contribution_projection_repository: MockContributionProjectionRepository,
+	mut github_issue_repository: MockGithubIssueRepository,
+	contribution_created_event: ContributionEvent,
+) {
+	github_issue_repository.expect_find().returning(|_, _| {
+		Err(GithubIssueRepositoryError::Infrastructure(
+			"Oops".to_string(),
+		))
+	});
+
+	contribution_projection_repository.expect_create().never();
+
+	let projector = ContributionProjector::new(
+		Arc::new(contribution_projection_repository),
+		Arc::new(github_issue_repository),
+	);
+
+	assert!(projector.project(&contribution_created_event).await.is_err());
 }
@@ -0,0 +1,237 @@
+use crate::{Error as DomainError, *};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulation {
+	/// State the contribution would be in once the action is applied.
+	/// Left unchanged when the action would revert.
+	pub contribution: Contribution,
+	pub transaction: TransactionSimulation,
+}
+
+/// Predicts the outcome of an action without submitting it: the aggregate checks its
+/// preconditions, then the resulting transaction is run against the network.
+pub struct Simulator {
+	contribution_repository: AggregateRootRepository<Contribution>,
+	onchain_contribution_service: Arc<dyn OnchainContributionService>,
+}
+
+impl Simulator {
+	pub fn new(
+		contribution_repository: AggregateRootRepository<Contribution>,
+		onchain_contribution_service: Arc<dyn OnchainContributionService>,
+	) -> Self {
+		Self {
+			contribution_repository,
+			onchain_contribution_service,
+		}
+	}
+
+	pub async fn simulate(&self, action: Action) -> Result<Simulation, DomainError> {
+		let contribution = match action.contribution_id() {
+			Some(contribution_id) => self.contribution_repository.find_by_id(contribution_id)?,
+			None => Contribution::default(),
+		};
+
+		let events = match expected_events(contribution.clone(), &action) {
+			Ok(events) => events,
+			Err(error) =>
+				return Ok(Simulation {
+					contribution,
+					transaction: TransactionSimulation::Reverted {
+						reason: error.to_string(),
+					},
+				}),
+		};
+
+		let transaction = self.onchain_contribution_service.simulate(vec![action]).await?;
+		let contribution = match transaction {
+			TransactionSimulation::Succeeded(_) => contribution.apply_events(&events),
+			TransactionSimulation::Reverted { .. } => contribution,
+		};
+
+		Ok(Simulation {
+			contribution,
+			transaction,
+		})
+	}
+}
+
+/// Events the contract is expected to emit for the action.
+/// The id of a created contribution is only known once the transaction is accepted, so it is left
+/// empty.
+fn expected_events(
+	contribution: Contribution,
+	action: &Action,
+) -> Result<Vec<ContributionEvent>, ContributionError> {
+	match action {
+		Action::CreateContribution {
+			project_id,
+			issue_number,
+			gate,
+		} => Ok(vec![ContributionEvent::Created {
+			id: Default::default(),
+			project_id: *project_id,
+			issue_number: *issue_number,
+			gate: *gate,
+		}]),
+		Action::AssignContributor { contributor_id, .. } => contribution.assign(contributor_id),
+		Action::UnassignContributor { .. } => contribution.unassign(),
+		Action::ValidateContribution { .. } => contribution.validate(),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use futures::FutureExt;
+	use mockall::predicate::*;
+	use rstest::*;
+	use std::str::FromStr;
+
+	#[fixture]
+	fn contribution_id() -> ContributionId {
+		ContributionId::from_str("0x12").unwrap()
+	}
+
+	#[fixture]
+	fn contributor_id() -> ContributorId {
+		ContributorId::from(42)
+	}
+
+	#[fixture]
+	fn open_contribution_events(contribution_id: ContributionId) -> Vec<ContributionEvent> {
+		vec![ContributionEvent::Created {
+			id: contribution_id,
+			project_id: 1,
+			issue_number: 2,
+			gate: 0,
+		}]
+	}
+
+	#[fixture]
+	fn onchain_contribution_service() -> MockOnchainContributionService {
+		MockOnchainContributionService::new()
+	}
+
+	fn simulator(
+		events: Vec<ContributionEvent>,
+		onchain_contribution_service: MockOnchainContributionService,
+	) -> Simulator {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		event_store.expect_list_by_id().returning(move |_| Ok(events.clone()));
+
+		Simulator::new(
+			AggregateRootRepository::new(Arc::new(event_store)),
+			Arc::new(onchain_contribution_service),
+		)
+	}
+
+	#[rstest]
+	async fn predicts_the_resulting_contribution(
+		open_contribution_events: Vec<ContributionEvent>,
+		mut onchain_contribution_service: MockOnchainContributionService,
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	) {
+		let action = Action::AssignContributor {
+			contribution_id,
+			contributor_id: contributor_id.clone(),
+		};
+		onchain_contribution_service
+			.expect_simulate()
+			.with(eq(vec![action.clone()]))
+			.times(1)
+			.returning(|_| {
+				async { Ok(TransactionSimulation::Succeeded(FeeEstimate::default())) }.boxed()
+			});
+		onchain_contribution_service.expect_execute_actions().never();
+
+		let simulation = simulator(open_contribution_events, onchain_contribution_service)
+			.simulate(action)
+			.await
+			.unwrap();
+
+		assert_eq!(
+			TransactionSimulation::Succeeded(FeeEstimate::default()),
+			simulation.transaction
+		);
+		assert_eq!(
+			&ContributionStatus::Assigned,
+			simulation.contribution.status()
+		);
+		assert_eq!(
+			Some(&contributor_id),
+			simulation.contribution.contributor_id()
+		);
+	}
+
+	#[rstest]
+	async fn unmet_preconditions_revert_without_reaching_the_network(
+		open_contribution_events: Vec<ContributionEvent>,
+		mut onchain_contribution_service: MockOnchainContributionService,
+		contribution_id: ContributionId,
+	) {
+		onchain_contribution_service.expect_simulate().never();
+
+		let simulation = simulator(open_contribution_events, onchain_contribution_service)
+			.simulate(Action::ValidateContribution { contribution_id })
+			.await
+			.unwrap();
+
+		assert_eq!(
+			Some(ContributionError::CannotValidate(ContributionStatus::Open).to_string().as_str()),
+			simulation.transaction.revert_reason()
+		);
+		assert_eq!(&ContributionStatus::Open, simulation.contribution.status());
+	}
+
+	#[rstest]
+	async fn onchain_reverts_leave_the_contribution_unchanged(
+		open_contribution_events: Vec<ContributionEvent>,
+		mut onchain_contribution_service: MockOnchainContributionService,
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	) {
+		onchain_contribution_service.expect_simulate().returning(|_| {
+			async {
+				Ok(TransactionSimulation::Reverted {
+					reason: String::from("Contributions: Contributor is not eligible"),
+				})
+			}
+			.boxed()
+		});
+
+		let simulation = simulator(open_contribution_events, onchain_contribution_service)
+			.simulate(Action::AssignContributor {
+				contribution_id,
+				contributor_id,
+			})
+			.await
+			.unwrap();
+
+		assert_eq!(
+			Some("Contributions: Contributor is not eligible"),
+			simulation.transaction.revert_reason()
+		);
+		assert_eq!(&ContributionStatus::Open, simulation.contribution.status());
+	}
+
+	#[rstest]
+	async fn unknown_contribution_fails(
+		onchain_contribution_service: MockOnchainContributionService,
+		contribution_id: ContributionId,
+	) {
+		let result = simulator(vec![], onchain_contribution_service)
+			.simulate(Action::UnassignContributor { contribution_id })
+			.await;
+
+		assert_matches!(
+			result,
+			Err(DomainError::ContributionRepository(
+				AggregateRootRepositoryError::NotFound
+			))
+		);
+	}
+}
@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use crate::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+	ProfileMinted {
+		id: ContributorId,
+		owner: ContractAddress,
+	},
+	ProfileTransferred {
+		id: ContributorId,
+		owner: ContractAddress,
+	},
+	Registered {
+		id: ContributorId,
+		account: ContractAddress,
+	},
+	GithubHandleRegistered {
+		id: ContributorId,
+		github_handle: String,
+	},
+}
+
+impl Event {
+	pub fn contributor_id(&self) -> &ContributorId {
+		match self {
+			Event::ProfileMinted { id, owner: _ } => id,
+			Event::ProfileTransferred { id, owner: _ } => id,
+			Event::Registered { id, account: _ } => id,
+			Event::GithubHandleRegistered {
+				id,
+				github_handle: _,
+			} => id,
+		}
+	}
+}
+
+impl Display for Event {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{}",
+			serde_json::to_string(&self).map_err(|_| std::fmt::Error)?
+		)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_json_diff::assert_json_eq;
+	use serde_json::{json, Value};
+	use std::str::FromStr;
+
+	#[test]
+	fn github_handle_registered_event_display_as_json() {
+		let event = Event::GithubHandleRegistered {
+			id: ContributorId::from(12),
+			github_handle: String::from("43214"),
+		};
+
+		assert_json_eq!(
+			json!({
+				"GithubHandleRegistered": {
+					"id": ContributorId::from(12),
+					"github_handle": "43214"
+				}
+			}),
+			serde_json::from_str::<Value>(&event.to_string()).unwrap()
+		);
+	}
+
+	#[test]
+	fn registered_event_display_as_json() {
+		let account = ContractAddress::from_str("0x1234").unwrap();
+		let event = Event::Registered {
+			id: ContributorId::from(12),
+			account: account.clone(),
+		};
+
+		assert_json_eq!(
+			json!({
+				"Registered": {
+					"id": ContributorId::from(12),
+					"account": account
+				}
+			}),
+			serde_json::from_str::<Value>(&event.to_string()).unwrap()
+		);
+	}
+}
@@ -0,0 +1,119 @@
+use crate::*;
+
+mod event;
+pub use event::Event;
+
+/// The on-chain identity of a contributor: the profile NFT, and the registry entries
+/// linking it to an account and a GitHub handle
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ContributorProfile {
+	id: ContributorId,
+	account: Option<ContractAddress>,
+	github_handle: Option<String>,
+}
+
+impl ContributorProfile {
+	pub fn id(&self) -> &ContributorId {
+		&self.id
+	}
+
+	pub fn account(&self) -> Option<&ContractAddress> {
+		self.account.as_ref()
+	}
+
+	pub fn github_handle(&self) -> Option<&String> {
+		self.github_handle.as_ref()
+	}
+}
+
+impl Aggregate for ContributorProfile {
+	type Event = Event;
+	type Id = ContributorId;
+
+	fn apply_event(self, event: &Self::Event) -> Self {
+		match event {
+			Event::ProfileMinted { id, owner } | Event::ProfileTransferred { id, owner } => Self {
+				id: id.clone(),
+				account: Some(owner.clone()),
+				..self
+			},
+			Event::Registered { id, account } => Self {
+				id: id.clone(),
+				account: Some(account.clone()),
+				..self
+			},
+			Event::GithubHandleRegistered { id, github_handle } => Self {
+				id: id.clone(),
+				github_handle: Some(github_handle.clone()),
+				..self
+			},
+		}
+	}
+}
+
+impl AggregateRoot for ContributorProfile {}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use rstest::*;
+	use std::str::FromStr;
+
+	#[fixture]
+	fn contributor_id() -> ContributorId {
+		ContributorId::from(12)
+	}
+
+	#[fixture]
+	fn owner() -> ContractAddress {
+		ContractAddress::from_str("0x1234").unwrap()
+	}
+
+	#[rstest]
+	fn minted_profile_belongs_to_its_owner(contributor_id: ContributorId, owner: ContractAddress) {
+		let profile = ContributorProfile::from_events(&[Event::ProfileMinted {
+			id: contributor_id.clone(),
+			owner: owner.clone(),
+		}]);
+
+		assert_eq!(&contributor_id, profile.id());
+		assert_eq!(Some(&owner), profile.account());
+		assert_eq!(None, profile.github_handle());
+	}
+
+	#[rstest]
+	fn transferred_profile_belongs_to_its_new_owner(
+		contributor_id: ContributorId,
+		owner: ContractAddress,
+	) {
+		let new_owner = ContractAddress::from_str("0x5678").unwrap();
+		let profile = ContributorProfile::from_events(&[
+			Event::ProfileMinted {
+				id: contributor_id.clone(),
+				owner,
+			},
+			Event::ProfileTransferred {
+				id: contributor_id,
+				owner: new_owner.clone(),
+			},
+		]);
+
+		assert_eq!(Some(&new_owner), profile.account());
+	}
+
+	#[rstest]
+	fn github_handle_is_kept_on_transfer(contributor_id: ContributorId, owner: ContractAddress) {
+		let profile = ContributorProfile::from_events(&[
+			Event::GithubHandleRegistered {
+				id: contributor_id.clone(),
+				github_handle: String::from("43214"),
+			},
+			Event::ProfileTransferred {
+				id: contributor_id,
+				owner,
+			},
+		]);
+
+		assert_eq!(Some(&String::from("43214")), profile.github_handle());
+	}
+}
@@ -0,0 +1,8 @@
+mod aggregate_root;
+pub use aggregate_root::{ContributorProfile, Event as ContributorEvent};
+
+mod projection;
+pub use projection::Projection as ContributorProjection;
+
+mod projector;
+pub use projector::Projector as ContributorProjector;
@@ -0,0 +1,29 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Projection {
+	pub id: ContributorId,
+	pub account: Option<ContractAddress>,
+	pub github_handle: Option<String>,
+}
+
+impl crate::Projection for Projection {}
+
+impl Projection {
+	pub fn new(id: ContributorId) -> Self {
+		Self {
+			id,
+			..Default::default()
+		}
+	}
+}
+
+impl From<Projection> for Contributor {
+	fn from(projection: Projection) -> Self {
+		Self {
+			id: projection.id,
+			github_username: None,
+			github_handle: projection.github_handle,
+		}
+	}
+}
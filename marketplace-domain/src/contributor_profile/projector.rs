@@ -0,0 +1,197 @@
+use crate::*;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct Projector {
+	contributor_projection_repository: Arc<dyn ContributorProjectionRepository>,
+}
+
+impl Projector {
+	pub fn new(
+		contributor_projection_repository: Arc<dyn ContributorProjectionRepository>,
+	) -> Self {
+		Self {
+			contributor_projection_repository,
+		}
+	}
+
+	fn on_event(
+		&self,
+		event: &ContributorEvent,
+	) -> Result<(), ContributorProjectionRepositoryError> {
+		let contributor_id = event.contributor_id();
+		let contributor = self
+			.contributor_projection_repository
+			.find_by_id(contributor_id)?
+			.unwrap_or_else(|| ContributorProjection::new(contributor_id.clone()));
+
+		let contributor = match event {
+			ContributorEvent::ProfileMinted { id: _, owner }
+			| ContributorEvent::ProfileTransferred { id: _, owner } => ContributorProjection {
+				account: Some(owner.clone()),
+				..contributor
+			},
+			ContributorEvent::Registered { id: _, account } => ContributorProjection {
+				account: Some(account.clone()),
+				..contributor
+			},
+			ContributorEvent::GithubHandleRegistered {
+				id: _,
+				github_handle,
+			} => ContributorProjection {
+				github_handle: Some(github_handle.clone()),
+				..contributor
+			},
+		};
+
+		self.contributor_projection_repository.upsert(contributor)
+	}
+}
+
+#[async_trait]
+impl crate::Projector<ContributorProfile> for Projector {
+	async fn project(
+		&self,
+		event: &<ContributorProfile as Aggregate>::Event,
+	) -> Result<(), ProjectorError> {
+		self.on_event(event)
+			.map_err(|error| ProjectorError::Projection(Box::new(error)))
+	}
+
+	async fn reset(&self, id: &ContributorId) -> Result<(), ProjectorError> {
+		self.contributor_projection_repository
+			.delete(id)
+			.map_err(|error| ProjectorError::Projection(Box::new(error)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Projector as _;
+	use assert_matches::assert_matches;
+	use mockall::predicate::eq;
+	use rstest::{fixture, rstest};
+	use std::str::FromStr;
+
+	#[fixture]
+	fn contributor_projection_repository() -> MockContributorProjectionRepository {
+		MockContributorProjectionRepository::new()
+	}
+
+	#[fixture]
+	fn contributor_id() -> ContributorId {
+		ContributorId::from_str("0x12").unwrap()
+	}
+
+	#[fixture]
+	fn account() -> ContractAddress {
+		ContractAddress::from_str("0x1234").unwrap()
+	}
+
+	#[rstest]
+	async fn minted_profile_creates_the_contributor(
+		mut contributor_projection_repository: MockContributorProjectionRepository,
+		contributor_id: ContributorId,
+		account: ContractAddress,
+	) {
+		contributor_projection_repository
+			.expect_find_by_id()
+			.with(eq(contributor_id.clone()))
+			.returning(|_| Ok(None));
+		contributor_projection_repository
+			.expect_upsert()
+			.with(eq(ContributorProjection {
+				id: contributor_id.clone(),
+				account: Some(account.clone()),
+				github_handle: None,
+			}))
+			.once()
+			.returning(|_| Ok(()));
+
+		let projector = Projector::new(Arc::new(contributor_projection_repository));
+		let result = projector
+			.project(&ContributorEvent::ProfileMinted {
+				id: contributor_id,
+				owner: account,
+			})
+			.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+	}
+
+	#[rstest]
+	async fn github_handle_registration_keeps_the_account(
+		mut contributor_projection_repository: MockContributorProjectionRepository,
+		contributor_id: ContributorId,
+		account: ContractAddress,
+	) {
+		let existing_contributor = ContributorProjection {
+			id: contributor_id.clone(),
+			account: Some(account.clone()),
+			github_handle: None,
+		};
+		contributor_projection_repository
+			.expect_find_by_id()
+			.with(eq(contributor_id.clone()))
+			.returning(move |_| Ok(Some(existing_contributor.clone())));
+		contributor_projection_repository
+			.expect_upsert()
+			.with(eq(ContributorProjection {
+				id: contributor_id.clone(),
+				account: Some(account),
+				github_handle: Some(String::from("43214")),
+			}))
+			.once()
+			.returning(|_| Ok(()));
+
+		let projector = Projector::new(Arc::new(contributor_projection_repository));
+		let result = projector
+			.project(&ContributorEvent::GithubHandleRegistered {
+				id: contributor_id,
+				github_handle: String::from("43214"),
+			})
+			.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+	}
+
+	#[rstest]
+	async fn repository_errors_are_projection_errors(
+		mut contributor_projection_repository: MockContributorProjectionRepository,
+		contributor_id: ContributorId,
+		account: ContractAddress,
+	) {
+		contributor_projection_repository
+			.expect_find_by_id()
+			.returning(|_| Err(ContributorProjectionRepositoryError::NotFound));
+		contributor_projection_repository.expect_upsert().never();
+
+		let projector = Projector::new(Arc::new(contributor_projection_repository));
+		let result = projector
+			.project(&ContributorEvent::Registered {
+				id: contributor_id,
+				account,
+			})
+			.await;
+
+		assert_matches!(result, Err(ProjectorError::Projection(_)));
+	}
+
+	#[rstest]
+	async fn reset_deletes_the_contributor(
+		mut contributor_projection_repository: MockContributorProjectionRepository,
+		contributor_id: ContributorId,
+	) {
+		contributor_projection_repository
+			.expect_delete()
+			.with(eq(contributor_id.clone()))
+			.once()
+			.returning(|_| Ok(()));
+
+		let projector = Projector::new(Arc::new(contributor_projection_repository));
+		let result = projector.reset(&contributor_id).await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+	}
+}
@@ -0,0 +1,21 @@
+use crate::Event;
+use marketplace_wrappers::UuidWrapper;
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, UuidWrapper, Default)]
+pub struct Id(Uuid);
+
+/// An event that a projector kept failing to handle, parked until it is retried or discarded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadLetterEvent {
+	pub id: Id,
+	pub projector: String,
+	pub event: Event,
+	/// Deduplication id of the event, when it was observed on-chain
+	pub deduplication_id: Option<String>,
+	pub error: String,
+	pub attempts: u32,
+	pub failed_at: SystemTime,
+}
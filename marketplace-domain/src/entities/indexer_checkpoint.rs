@@ -0,0 +1,9 @@
+use crate::HexPrefixedString;
+
+/// A block processed by an indexer, the latest one being where indexing resumes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexerCheckpoint {
+	pub indexer_id: String,
+	pub block_number: u64,
+	pub block_hash: HexPrefixedString,
+}
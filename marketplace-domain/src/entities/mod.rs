@@ -6,3 +6,18 @@ pub use contributor::{Contributor, Id as ContributorId};
 
 mod contact_information;
 pub use contact_information::{ContactInformation, Id as ContactInformationId};
+
+mod dead_letter_event;
+pub use dead_letter_event::{DeadLetterEvent, Id as DeadLetterEventId};
+
+mod onchain_transaction;
+pub use onchain_transaction::{
+	OnchainTransaction, Status as OnchainTransactionStatus,
+	StatusParsingError as OnchainTransactionStatusParsingError, SubmittedTransaction,
+};
+
+mod pending_event;
+pub use pending_event::PendingEvent;
+
+mod indexer_checkpoint;
+pub use indexer_checkpoint::IndexerCheckpoint;
@@ -0,0 +1,106 @@
+use crate::{Action, HexPrefixedString};
+use serde::{Deserialize, Serialize};
+use std::{str::FromStr, time::SystemTime};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+	#[default]
+	Pending,
+	AcceptedOnL2,
+	Rejected,
+	Reverted,
+	/// Never received by the sequencer, the transaction will not be executed
+	Dropped,
+}
+
+impl Status {
+	/// Once final, the status of a transaction is not polled anymore
+	pub fn is_final(&self) -> bool {
+		!matches!(self, Status::Pending)
+	}
+}
+
+impl std::fmt::Display for Status {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Status::Pending => write!(f, "PENDING"),
+			Status::AcceptedOnL2 => write!(f, "ACCEPTED_ON_L2"),
+			Status::Rejected => write!(f, "REJECTED"),
+			Status::Reverted => write!(f, "REVERTED"),
+			Status::Dropped => write!(f, "DROPPED"),
+		}
+	}
+}
+
+#[derive(Debug, Error)]
+#[error("Failed to parse `{0}` as Status")]
+pub struct StatusParsingError(String);
+
+impl FromStr for Status {
+	type Err = StatusParsingError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"PENDING" => Ok(Status::Pending),
+			"ACCEPTED_ON_L2" => Ok(Status::AcceptedOnL2),
+			"REJECTED" => Ok(Status::Rejected),
+			"REVERTED" => Ok(Status::Reverted),
+			"DROPPED" => Ok(Status::Dropped),
+			_ => Err(StatusParsingError(s.to_string())),
+		}
+	}
+}
+
+/// The output of commands sending a transaction to StarkNet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubmittedTransaction {
+	pub transaction_hash: HexPrefixedString,
+	pub actions: Vec<Action>,
+}
+
+impl SubmittedTransaction {
+	pub fn new(transaction_hash: HexPrefixedString, actions: Vec<Action>) -> Self {
+		Self {
+			transaction_hash,
+			actions,
+		}
+	}
+}
+
+/// A transaction sent to StarkNet, tracked until it reaches a final status
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnchainTransaction {
+	pub hash: HexPrefixedString,
+	pub command: String,
+	pub issuer: String,
+	pub actions: Vec<Action>,
+	pub status: Status,
+	pub submitted_at: SystemTime,
+	pub updated_at: SystemTime,
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use rstest::*;
+
+	#[rstest]
+	#[case(Status::Pending, "PENDING")]
+	#[case(Status::AcceptedOnL2, "ACCEPTED_ON_L2")]
+	#[case(Status::Rejected, "REJECTED")]
+	#[case(Status::Reverted, "REVERTED")]
+	#[case(Status::Dropped, "DROPPED")]
+	fn transaction_status_serde(#[case] status: Status, #[case] status_str: &str) {
+		assert_eq!(status, status_str.parse().unwrap());
+		assert_eq!(status_str, status.to_string());
+	}
+
+	#[rstest]
+	#[case("ACCEPTED_ON_L1")]
+	#[case("")]
+	fn parsing_error(#[case] status_str: &str) {
+		assert!(status_str.parse::<Status>().is_err());
+	}
+}
@@ -0,0 +1,13 @@
+use crate::{Event, HexPrefixedString};
+
+/// An event observed on-chain, held back until its block has enough confirmations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingEvent {
+	/// Indexer which observed the event
+	pub indexer_id: String,
+	pub deduplication_id: String,
+	pub event: Event,
+	pub transaction_hash: HexPrefixedString,
+	pub block_hash: HexPrefixedString,
+	pub block_number: u64,
+}
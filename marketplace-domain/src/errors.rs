@@ -13,10 +13,32 @@ pub enum Error {
 	ContactInformationRepository(#[from] ContactInformationRepositoryError),
 	#[error("Onchain contribution service error")]
 	OnchainContributionService(#[from] OnchainContributionServiceError),
+	#[error("Onchain transaction repository error")]
+	OnchainTransactionRepository(#[from] OnchainTransactionRepositoryError),
+	#[error("Onchain transaction service error")]
+	OnchainTransactionService(#[from] OnchainTransactionServiceError),
 	#[error("Failed to take control of a lock")]
 	Lock,
 	#[error("Event store error")]
 	EventStoreError(#[from] EventStoreError),
 	#[error(transparent)]
 	ContributionError(#[from] ContributionError),
+	#[error("Dead letter event repository error")]
+	DeadLetterEventRepository(#[from] DeadLetterEventRepositoryError),
+	#[error("Projection error")]
+	Projection(#[from] ProjectorError),
+	#[error("Event `{0}` is not supported here")]
+	UnsupportedEvent(String),
+	#[error("No projector named `{0}`")]
+	UnknownProjector(String),
+	#[error("Not allowed to issue command `{0}`")]
+	Unauthorized(&'static str),
+	#[error("A command `{0}` with the same idempotency key is already being handled")]
+	CommandInProgress(&'static str),
+	#[error("No handler registered for command `{0}`")]
+	UnknownCommand(&'static str),
+	#[error("An action batch must contain at least one action")]
+	EmptyActionBatch,
+	#[error("Unable to serialize command output")]
+	CommandOutput(#[source] serde_json::Error),
 }
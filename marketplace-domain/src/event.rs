@@ -1,16 +1,46 @@
-use crate::{Aggregate, ContributionEvent};
+use crate::{Aggregate, ContributionEvent, ContributorEvent, HexPrefixedString};
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{fmt::Display, time::SystemTime};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Event {
 	Contribution(ContributionEvent),
+	Contributor(ContributorEvent),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StorableEvent<A: Aggregate> {
 	pub event: A::Event,
 	pub deduplication_id: String,
+	/// Hash of the transaction that emitted the event, if it has been observed on-chain
+	pub transaction_hash: Option<HexPrefixedString>,
+	/// Number of the block containing that transaction, if known
+	pub block_number: Option<u64>,
+	/// Hash of that block, to detect the events orphaned by a chain reorganization
+	pub block_hash: Option<HexPrefixedString>,
+}
+
+/// An event as it has been recorded in the store, along with its metadata
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedEvent<A: Aggregate> {
+	pub index: u64,
+	pub timestamp: SystemTime,
+	pub event: A::Event,
+	pub transaction_hash: Option<HexPrefixedString>,
+	pub block_number: Option<u64>,
+	pub block_hash: Option<HexPrefixedString>,
+}
+
+impl From<ContributionEvent> for Event {
+	fn from(event: ContributionEvent) -> Self {
+		Self::Contribution(event)
+	}
+}
+
+impl From<ContributorEvent> for Event {
+	fn from(event: ContributorEvent) -> Self {
+		Self::Contributor(event)
+	}
 }
 
 impl Display for Event {
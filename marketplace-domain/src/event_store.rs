@@ -1,5 +1,9 @@
-use crate::{event::StorableEvent, Aggregate};
+use crate::{
+	event::{RecordedEvent, StorableEvent},
+	Aggregate, Contribution, ContributionId, ContributorId, ContributorProfile,
+};
 use mockall::automock;
+use std::time::SystemTime;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -12,11 +16,57 @@ pub enum Error {
 	Append(#[source] anyhow::Error),
 	#[error("Unable to list events from the store")]
 	List(#[source] anyhow::Error),
+	#[error("Unable to remove events from the store")]
+	Remove(#[source] anyhow::Error),
+}
+
+/// Inclusive upper bound used to replay only the beginning of an aggregate history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointInTime {
+	/// Number of the event in the history of the aggregate, its first event being 1
+	EventNumber(u64),
+	Timestamp(SystemTime),
 }
 
 #[automock]
 pub trait Store<A: Aggregate>: Send + Sync {
 	fn append(&self, aggregate_id: &A::Id, events: Vec<StorableEvent<A>>) -> Result<(), Error>;
 	fn list_by_id(&self, aggregate_id: &A::Id) -> Result<Vec<A::Event>, Error>;
+	fn list_by_id_at(
+		&self,
+		aggregate_id: &A::Id,
+		point_in_time: &PointInTime,
+	) -> Result<Vec<A::Event>, Error>;
+	fn list_recorded_by_id(&self, aggregate_id: &A::Id) -> Result<Vec<RecordedEvent<A>>, Error>;
 	fn list(&self) -> Result<Vec<A::Event>, Error>;
+	/// Remove the events observed by the indexer in blocks above the given one, returning the ids
+	/// of the aggregates they belonged to along with their deduplication ids
+	fn remove_after_block(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<Vec<(A::Id, String)>, Error>;
+}
+
+/// Events of several aggregates, to be appended to the store together
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventBatch {
+	/// Indexer which observed the events on-chain, if any
+	pub indexer_id: Option<String>,
+	pub contribution_events: Vec<(ContributionId, StorableEvent<Contribution>)>,
+	pub contributor_events: Vec<(ContributorId, StorableEvent<ContributorProfile>)>,
+}
+
+impl EventBatch {
+	pub fn is_empty(&self) -> bool {
+		self.contribution_events.is_empty() && self.contributor_events.is_empty()
+	}
+}
+
+#[automock]
+pub trait BatchStore: Send + Sync {
+	/// Append all the events of the batch in a single transaction. Events whose deduplication id
+	/// has already been stored are skipped, so that a batch can safely be appended again.
+	/// Returns the events which were actually appended.
+	fn append_batch(&self, batch: EventBatch) -> Result<EventBatch, Error>;
 }
@@ -18,16 +18,41 @@ mod actions;
 pub use actions::*;
 
 mod event;
-pub use event::{Event, StorableEvent};
+pub use event::{Event, RecordedEvent, StorableEvent};
 
 mod event_store;
-pub use event_store::{Error as EventStoreError, MockStore as MockEventStore, Store as EventStore};
+pub use event_store::{
+	BatchStore as EventBatchStore, Error as EventStoreError, EventBatch,
+	MockBatchStore as MockEventBatchStore, MockStore as MockEventStore, PointInTime,
+	Store as EventStore,
+};
 
 mod aggregate;
 pub use aggregate::{Aggregate, AggregateRoot};
 
+mod command;
+pub use command::{
+	AuthorizationMiddleware, Command, Context as CommandContext, Dispatcher as CommandDispatcher,
+	Handler as CommandHandler, IdempotencyMiddleware, Issuer, LoggingMiddleware,
+	Middleware as CommandMiddleware, Permission, TransactionTrackingMiddleware,
+};
+
+mod transaction_tracker;
+pub use transaction_tracker::Tracker as TransactionTracker;
+
 mod projector;
-pub use projector::Projector;
+pub use projector::{Error as ProjectorError, MockProjector, Projector};
+
+mod projection_dispatcher;
+pub use projection_dispatcher::{Dispatcher as ProjectionDispatcher, RetryPolicy};
+
+mod reorg_rollback;
+pub use reorg_rollback::Rollback as ReorgRollback;
+
+mod projection_checker;
+pub use projection_checker::{
+	Checker as ProjectionChecker, Mismatch as ProjectionMismatch, Report as ProjectionCheckReport,
+};
 
 mod projection;
 pub use projection::Projection;
@@ -38,6 +63,9 @@ pub use projection_repository::{Error as ProjectionRepositoryError, ProjectionRe
 mod contribution;
 pub use contribution::{AggregateId as ContributionId, *};
 
+mod contributor_profile;
+pub use contributor_profile::*;
+
 mod aggregate_root_repository;
 pub use aggregate_root_repository::{
 	Error as AggregateRootRepositoryError, Repository as AggregateRootRepository,
@@ -0,0 +1,735 @@
+use crate::{Error as DomainError, *};
+use std::{collections::HashMap, fmt::Display, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mismatch {
+	MissingContribution {
+		contribution_id: ContributionId,
+	},
+	UnexpectedContribution {
+		contribution_id: ContributionId,
+	},
+	ContributionField {
+		contribution_id: ContributionId,
+		field: &'static str,
+		expected: String,
+		actual: String,
+	},
+	MissingApplication {
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	},
+	UnexpectedApplication {
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	},
+	ApplicationStatus {
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+		expected: ApplicationStatus,
+		actual: ApplicationStatus,
+	},
+}
+
+impl Mismatch {
+	pub fn contribution_id(&self) -> &ContributionId {
+		match self {
+			Mismatch::MissingContribution { contribution_id }
+			| Mismatch::UnexpectedContribution { contribution_id }
+			| Mismatch::ContributionField {
+				contribution_id, ..
+			}
+			| Mismatch::MissingApplication {
+				contribution_id, ..
+			}
+			| Mismatch::UnexpectedApplication {
+				contribution_id, ..
+			}
+			| Mismatch::ApplicationStatus {
+				contribution_id, ..
+			} => contribution_id,
+		}
+	}
+}
+
+impl Display for Mismatch {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Mismatch::MissingContribution { contribution_id } =>
+				write!(f, "Contribution {contribution_id} is missing from the projection"),
+			Mismatch::UnexpectedContribution { contribution_id } =>
+				write!(f, "Contribution {contribution_id} has no event"),
+			Mismatch::ContributionField {
+				contribution_id,
+				field,
+				expected,
+				actual,
+			} => write!(
+				f,
+				"Contribution {contribution_id} has {field} `{actual}` instead of `{expected}`"
+			),
+			Mismatch::MissingApplication {
+				contribution_id,
+				contributor_id,
+			} => write!(
+				f,
+				"Application of {contributor_id} to contribution {contribution_id} is missing from the projection"
+			),
+			Mismatch::UnexpectedApplication {
+				contribution_id,
+				contributor_id,
+			} => write!(
+				f,
+				"Application of {contributor_id} to contribution {contribution_id} has no event"
+			),
+			Mismatch::ApplicationStatus {
+				contribution_id,
+				contributor_id,
+				expected,
+				actual,
+			} => write!(
+				f,
+				"Application of {contributor_id} to contribution {contribution_id} is {actual} instead of {expected}"
+			),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+	pub checked_contributions: usize,
+	pub mismatches: Vec<Mismatch>,
+	pub repaired_contributions: Vec<ContributionId>,
+}
+
+/// Projection rows the projectors are expected to have written for one contribution.
+/// Only the fields derived from the events are tracked, GitHub data is left aside.
+#[derive(Debug, Default)]
+struct ExpectedProjections {
+	contribution: Option<ContributionProjection>,
+	applications: Vec<(ContributorId, ApplicationStatus)>,
+}
+
+impl ExpectedProjections {
+	fn from_events(events: &[ContributionEvent]) -> Self {
+		events.iter().fold(Self::default(), Self::apply_event)
+	}
+
+	fn apply_event(mut self, event: &ContributionEvent) -> Self {
+		match event {
+			ContributionEvent::Created {
+				id,
+				project_id,
+				issue_number,
+				gate,
+			} =>
+				self.contribution = Some(ContributionProjection {
+					id: id.clone(),
+					project_id: *project_id,
+					issue_number: *issue_number,
+					gate: *gate,
+					status: ContributionStatus::Open,
+					..Default::default()
+				}),
+			ContributionEvent::Applied { contributor_id, .. } => {
+				match self.applications.iter_mut().find(|(id, _)| id == contributor_id) {
+					Some((_, status)) => *status = ApplicationStatus::Pending,
+					None =>
+						self.applications.push((contributor_id.clone(), ApplicationStatus::Pending)),
+				}
+			},
+			ContributionEvent::Assigned { contributor_id, .. } => {
+				if let Some(contribution) = self.contribution.as_mut() {
+					contribution.contributor_id = Some(contributor_id.clone());
+					contribution.status = ContributionStatus::Assigned;
+				}
+				for (id, status) in self.applications.iter_mut() {
+					*status = match id == contributor_id {
+						true => ApplicationStatus::Accepted,
+						false => ApplicationStatus::Refused,
+					};
+				}
+			},
+			ContributionEvent::Unassigned { .. } => {
+				// The projector only resets the status, the previous contributor is kept
+				if let Some(contribution) = self.contribution.as_mut() {
+					contribution.status = ContributionStatus::Open;
+				}
+				for (_, status) in self.applications.iter_mut() {
+					*status = ApplicationStatus::Pending;
+				}
+			},
+			ContributionEvent::Validated { .. } =>
+				if let Some(contribution) = self.contribution.as_mut() {
+					contribution.status = ContributionStatus::Completed;
+				},
+		}
+		self
+	}
+}
+
+/// Replays the events of every contribution in memory and compares the result with the
+/// `contributions` and `applications` projections.
+pub struct Checker {
+	event_store: Arc<dyn EventStore<Contribution>>,
+	contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+	application_projection_repository: Arc<dyn ApplicationProjectionRepository>,
+	projectors: Vec<Arc<dyn Projector<Contribution>>>,
+}
+
+impl Checker {
+	pub fn new(
+		event_store: Arc<dyn EventStore<Contribution>>,
+		contribution_projection_repository: Arc<dyn ContributionProjectionRepository>,
+		application_projection_repository: Arc<dyn ApplicationProjectionRepository>,
+		projectors: Vec<Arc<dyn Projector<Contribution>>>,
+	) -> Self {
+		Self {
+			event_store,
+			contribution_projection_repository,
+			application_projection_repository,
+			projectors,
+		}
+	}
+
+	pub fn check(&self) -> Result<Report, DomainError> {
+		let events_by_contribution = self.events_by_contribution()?;
+
+		let mut stored_contributions: HashMap<ContributionId, ContributionProjection> = self
+			.contribution_projection_repository
+			.list()?
+			.into_iter()
+			.map(|contribution| (contribution.id.clone(), contribution))
+			.collect();
+
+		let mut stored_applications: HashMap<ContributionId, Vec<ApplicationProjection>> =
+			HashMap::new();
+		for application in self.application_projection_repository.list_by_contributor(None)? {
+			stored_applications
+				.entry(application.contribution_id().clone())
+				.or_default()
+				.push(application);
+		}
+
+		let mut mismatches = Vec::new();
+		for (contribution_id, events) in &events_by_contribution {
+			let expected = ExpectedProjections::from_events(events);
+			mismatches.extend(compare_contribution(
+				contribution_id,
+				expected.contribution,
+				stored_contributions.remove(contribution_id),
+			));
+			mismatches.extend(compare_applications(
+				contribution_id,
+				&expected.applications,
+				stored_applications.remove(contribution_id).unwrap_or_default(),
+			));
+		}
+
+		let mut orphans: Vec<Mismatch> = stored_contributions
+			.into_keys()
+			.map(|contribution_id| Mismatch::UnexpectedContribution { contribution_id })
+			.chain(
+				stored_applications.into_values().flatten().map(|application| {
+					Mismatch::UnexpectedApplication {
+						contribution_id: application.contribution_id().clone(),
+						contributor_id: application.contributor_id().clone(),
+					}
+				}),
+			)
+			.collect();
+		orphans.sort_by_key(|mismatch| mismatch.contribution_id().to_string());
+		mismatches.extend(orphans);
+
+		Ok(Report {
+			checked_contributions: events_by_contribution.len(),
+			mismatches,
+			repaired_contributions: Vec::new(),
+		})
+	}
+
+	/// Rebuild the projections of every contribution listed in the report, by removing its rows
+	/// and projecting its events again.
+	/// The projectors must be the raw ones, not dispatchers which would turn a failed projection
+	/// into a dead letter and let the contribution be reported as repaired.
+	pub async fn repair(&self, report: Report) -> Result<Report, DomainError> {
+		let mut repaired_contributions: Vec<ContributionId> = Vec::new();
+
+		for mismatch in &report.mismatches {
+			let contribution_id = mismatch.contribution_id();
+			if repaired_contributions.contains(contribution_id) {
+				continue;
+			}
+
+			self.contribution_projection_repository.delete(contribution_id)?;
+			self.application_projection_repository.delete_by_contribution(contribution_id)?;
+
+			let events = self.event_store.list_by_id(contribution_id)?;
+			for event in events.iter() {
+				for projector in self.projectors.iter() {
+					projector.project(event).await?;
+				}
+			}
+
+			repaired_contributions.push(contribution_id.clone());
+		}
+
+		Ok(Report {
+			repaired_contributions,
+			..report
+		})
+	}
+
+	fn events_by_contribution(
+		&self,
+	) -> Result<Vec<(ContributionId, Vec<ContributionEvent>)>, DomainError> {
+		let mut events_by_contribution: Vec<(ContributionId, Vec<ContributionEvent>)> = Vec::new();
+		let mut positions: HashMap<ContributionId, usize> = HashMap::new();
+
+		for event in self.event_store.list()? {
+			let contribution_id = event.contribution_id().clone();
+			let position = *positions.entry(contribution_id.clone()).or_insert_with(|| {
+				events_by_contribution.push((contribution_id, Vec::new()));
+				events_by_contribution.len() - 1
+			});
+			events_by_contribution[position].1.push(event);
+		}
+
+		Ok(events_by_contribution)
+	}
+}
+
+fn compare_contribution(
+	contribution_id: &ContributionId,
+	expected: Option<ContributionProjection>,
+	actual: Option<ContributionProjection>,
+) -> Vec<Mismatch> {
+	let (expected, actual) = match (expected, actual) {
+		(Some(expected), Some(actual)) => (expected, actual),
+		(Some(_), None) =>
+			return vec![Mismatch::MissingContribution {
+				contribution_id: contribution_id.clone(),
+			}],
+		(None, Some(_)) =>
+			return vec![Mismatch::UnexpectedContribution {
+				contribution_id: contribution_id.clone(),
+			}],
+		(None, None) => return vec![],
+	};
+
+	let format_contributor = |contributor_id: Option<ContributorId>| {
+		contributor_id.map(|id| id.to_string()).unwrap_or_default()
+	};
+
+	[
+		(
+			"project_id",
+			expected.project_id.to_string(),
+			actual.project_id.to_string(),
+		),
+		(
+			"issue_number",
+			expected.issue_number.to_string(),
+			actual.issue_number.to_string(),
+		),
+		("gate", expected.gate.to_string(), actual.gate.to_string()),
+		(
+			"status",
+			expected.status.to_string(),
+			actual.status.to_string(),
+		),
+		(
+			"contributor_id",
+			format_contributor(expected.contributor_id),
+			format_contributor(actual.contributor_id),
+		),
+	]
+	.into_iter()
+	.filter(|(_, expected, actual)| expected != actual)
+	.map(|(field, expected, actual)| Mismatch::ContributionField {
+		contribution_id: contribution_id.clone(),
+		field,
+		expected,
+		actual,
+	})
+	.collect()
+}
+
+fn compare_applications(
+	contribution_id: &ContributionId,
+	expected: &[(ContributorId, ApplicationStatus)],
+	mut actual: Vec<ApplicationProjection>,
+) -> Vec<Mismatch> {
+	let mut mismatches = Vec::new();
+
+	for (contributor_id, expected_status) in expected {
+		match actual
+			.iter()
+			.position(|application| application.contributor_id() == contributor_id)
+		{
+			Some(position) => {
+				let application = actual.remove(position);
+				if application.status() != expected_status {
+					mismatches.push(Mismatch::ApplicationStatus {
+						contribution_id: contribution_id.clone(),
+						contributor_id: contributor_id.clone(),
+						expected: *expected_status,
+						actual: *application.status(),
+					});
+				}
+			},
+			None => mismatches.push(Mismatch::MissingApplication {
+				contribution_id: contribution_id.clone(),
+				contributor_id: contributor_id.clone(),
+			}),
+		}
+	}
+
+	mismatches.extend(
+		actual.into_iter().map(|application| Mismatch::UnexpectedApplication {
+			contribution_id: contribution_id.clone(),
+			contributor_id: application.contributor_id().clone(),
+		}),
+	);
+
+	mismatches
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use mockall::predicate::eq;
+	use rstest::{fixture, rstest};
+	use std::str::FromStr;
+
+	#[fixture]
+	fn contribution_id() -> ContributionId {
+		ContributionId::from_str("0x123").unwrap()
+	}
+
+	#[fixture]
+	fn contributor_id() -> ContributorId {
+		ContributorId::from_str("0x456").unwrap()
+	}
+
+	#[fixture]
+	fn other_contributor_id() -> ContributorId {
+		ContributorId::from_str("0x457").unwrap()
+	}
+
+	#[fixture]
+	fn events(
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+		other_contributor_id: ContributorId,
+	) -> Vec<ContributionEvent> {
+		vec![
+			ContributionEvent::Created {
+				id: contribution_id.clone(),
+				project_id: 42,
+				issue_number: 7,
+				gate: 1,
+			},
+			ContributionEvent::Applied {
+				id: contribution_id.clone(),
+				contributor_id: contributor_id.clone(),
+			},
+			ContributionEvent::Applied {
+				id: contribution_id.clone(),
+				contributor_id: other_contributor_id,
+			},
+			ContributionEvent::Assigned {
+				id: contribution_id,
+				contributor_id,
+			},
+		]
+	}
+
+	#[fixture]
+	fn stored_contribution(
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	) -> ContributionProjection {
+		ContributionProjection {
+			id: contribution_id,
+			project_id: 42,
+			issue_number: 7,
+			gate: 1,
+			status: ContributionStatus::Assigned,
+			contributor_id: Some(contributor_id),
+			title: Some(String::from("Some GitHub issue")),
+			..Default::default()
+		}
+	}
+
+	#[fixture]
+	fn stored_applications(
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+		other_contributor_id: ContributorId,
+	) -> Vec<ApplicationProjection> {
+		vec![
+			ApplicationProjection::new(
+				uuid::Uuid::from_u128(1).into(),
+				contribution_id.clone(),
+				contributor_id,
+			)
+			.as_accepted(),
+			ApplicationProjection::new(
+				uuid::Uuid::from_u128(2).into(),
+				contribution_id,
+				other_contributor_id,
+			)
+			.as_refused(),
+		]
+	}
+
+	fn checker(
+		events: Vec<ContributionEvent>,
+		stored_contributions: Vec<ContributionProjection>,
+		stored_applications: Vec<ApplicationProjection>,
+	) -> Checker {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		event_store.expect_list().returning(move || Ok(events.clone()));
+
+		let mut contribution_projection_repository = MockContributionProjectionRepository::new();
+		contribution_projection_repository
+			.expect_list()
+			.returning(move || Ok(stored_contributions.clone()));
+
+		let mut application_projection_repository = MockApplicationProjectionRepository::new();
+		application_projection_repository
+			.expect_list_by_contributor()
+			.with(eq(None))
+			.returning(move |_| Ok(stored_applications.clone()));
+
+		Checker::new(
+			Arc::new(event_store),
+			Arc::new(contribution_projection_repository),
+			Arc::new(application_projection_repository),
+			vec![],
+		)
+	}
+
+	#[rstest]
+	fn check_reports_nothing_when_projections_match_events(
+		events: Vec<ContributionEvent>,
+		stored_contribution: ContributionProjection,
+		stored_applications: Vec<ApplicationProjection>,
+	) {
+		let report =
+			checker(events, vec![stored_contribution], stored_applications).check().unwrap();
+
+		assert_eq!(1, report.checked_contributions);
+		assert!(report.mismatches.is_empty(), "{:?}", report.mismatches);
+	}
+
+	#[rstest]
+	fn check_keeps_previous_contributor_after_unassignment(
+		mut events: Vec<ContributionEvent>,
+		contribution_id: ContributionId,
+		stored_contribution: ContributionProjection,
+		stored_applications: Vec<ApplicationProjection>,
+	) {
+		events.push(ContributionEvent::Unassigned {
+			id: contribution_id,
+		});
+
+		let report = checker(
+			events,
+			vec![ContributionProjection {
+				status: ContributionStatus::Open,
+				..stored_contribution
+			}],
+			stored_applications.iter().map(ApplicationProjection::as_pending).collect(),
+		)
+		.check()
+		.unwrap();
+
+		assert!(report.mismatches.is_empty(), "{:?}", report.mismatches);
+	}
+
+	#[rstest]
+	fn check_reports_drifted_fields(
+		events: Vec<ContributionEvent>,
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+		stored_contribution: ContributionProjection,
+		stored_applications: Vec<ApplicationProjection>,
+	) {
+		let report = checker(
+			events,
+			vec![ContributionProjection {
+				status: ContributionStatus::Open,
+				gate: 0,
+				..stored_contribution
+			}],
+			vec![stored_applications[0].as_pending()],
+		)
+		.check()
+		.unwrap();
+
+		assert_eq!(
+			vec![
+				Mismatch::ContributionField {
+					contribution_id: contribution_id.clone(),
+					field: "gate",
+					expected: String::from("1"),
+					actual: String::from("0"),
+				},
+				Mismatch::ContributionField {
+					contribution_id: contribution_id.clone(),
+					field: "status",
+					expected: ContributionStatus::Assigned.to_string(),
+					actual: ContributionStatus::Open.to_string(),
+				},
+				Mismatch::ApplicationStatus {
+					contribution_id: contribution_id.clone(),
+					contributor_id,
+					expected: ApplicationStatus::Accepted,
+					actual: ApplicationStatus::Pending,
+				},
+				Mismatch::MissingApplication {
+					contribution_id,
+					contributor_id: ContributorId::from_str("0x457").unwrap(),
+				},
+			],
+			report.mismatches
+		);
+	}
+
+	#[rstest]
+	fn check_reports_missing_and_unexpected_rows(
+		events: Vec<ContributionEvent>,
+		contribution_id: ContributionId,
+		stored_applications: Vec<ApplicationProjection>,
+	) {
+		let orphan_id = ContributionId::from_str("0x999").unwrap();
+		let orphan_contributor_id = ContributorId::from_str("0x111").unwrap();
+
+		let mut applications = stored_applications;
+		applications.push(ApplicationProjection::new(
+			uuid::Uuid::from_u128(3).into(),
+			orphan_id.clone(),
+			orphan_contributor_id.clone(),
+		));
+
+		let report = checker(
+			events,
+			vec![ContributionProjection {
+				id: orphan_id.clone(),
+				..Default::default()
+			}],
+			applications,
+		)
+		.check()
+		.unwrap();
+
+		assert_eq!(
+			vec![
+				Mismatch::MissingContribution { contribution_id },
+				Mismatch::UnexpectedContribution {
+					contribution_id: orphan_id.clone(),
+				},
+				Mismatch::UnexpectedApplication {
+					contribution_id: orphan_id,
+					contributor_id: orphan_contributor_id,
+				},
+			],
+			report.mismatches
+		);
+	}
+
+	#[rstest]
+	async fn repair_projects_again_the_events_of_drifted_contributions(
+		events: Vec<ContributionEvent>,
+		contribution_id: ContributionId,
+	) {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		let contribution_events = events.clone();
+		event_store
+			.expect_list_by_id()
+			.with(eq(contribution_id.clone()))
+			.once()
+			.returning(move |_| Ok(contribution_events.clone()));
+
+		let mut contribution_projection_repository = MockContributionProjectionRepository::new();
+		contribution_projection_repository
+			.expect_delete()
+			.with(eq(contribution_id.clone()))
+			.once()
+			.returning(|_| Ok(()));
+
+		let mut application_projection_repository = MockApplicationProjectionRepository::new();
+		application_projection_repository
+			.expect_delete_by_contribution()
+			.with(eq(contribution_id.clone()))
+			.once()
+			.returning(|_| Ok(()));
+
+		let mut projector = MockProjector::<Contribution>::new();
+		projector.expect_project().times(events.len()).returning(|_| Ok(()));
+
+		let checker = Checker::new(
+			Arc::new(event_store),
+			Arc::new(contribution_projection_repository),
+			Arc::new(application_projection_repository),
+			vec![Arc::new(projector)],
+		);
+
+		let report = Report {
+			checked_contributions: 1,
+			mismatches: vec![
+				Mismatch::MissingContribution {
+					contribution_id: contribution_id.clone(),
+				},
+				Mismatch::UnexpectedApplication {
+					contribution_id: contribution_id.clone(),
+					contributor_id: ContributorId::from_str("0x111").unwrap(),
+				},
+			],
+			..Default::default()
+		};
+
+		let report = checker.repair(report).await.unwrap();
+		assert_eq!(vec![contribution_id], report.repaired_contributions);
+		assert_eq!(2, report.mismatches.len());
+	}
+
+	#[rstest]
+	async fn repair_fails_when_events_cannot_be_projected(
+		events: Vec<ContributionEvent>,
+		contribution_id: ContributionId,
+	) {
+		let mut event_store = MockEventStore::<Contribution>::new();
+		event_store.expect_list_by_id().returning(move |_| Ok(events.clone()));
+
+		let mut contribution_projection_repository = MockContributionProjectionRepository::new();
+		contribution_projection_repository.expect_delete().returning(|_| Ok(()));
+
+		let mut application_projection_repository = MockApplicationProjectionRepository::new();
+		application_projection_repository
+			.expect_delete_by_contribution()
+			.returning(|_| Ok(()));
+
+		let mut projector = MockProjector::<Contribution>::new();
+		projector
+			.expect_project()
+			.returning(|_| Err(ProjectorError::Projection("oops".into())));
+
+		let checker = Checker::new(
+			Arc::new(event_store),
+			Arc::new(contribution_projection_repository),
+			Arc::new(application_projection_repository),
+			vec![Arc::new(projector)],
+		);
+
+		let report = Report {
+			checked_contributions: 1,
+			mismatches: vec![Mismatch::MissingContribution { contribution_id }],
+			..Default::default()
+		};
+
+		assert!(checker.repair(report).await.is_err());
+	}
+}
@@ -0,0 +1,395 @@
+use crate::{Error as DomainError, *};
+use async_trait::async_trait;
+use futures_timer::Delay;
+use log::{error, warn};
+use mapinto::ResultMapErrInto;
+use std::{
+	sync::Arc,
+	time::{Duration, SystemTime},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+	pub max_attempts: u32,
+	pub initial_backoff: Duration,
+	pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 5,
+			initial_backoff: Duration::from_millis(200),
+			max_backoff: Duration::from_secs(5),
+		}
+	}
+}
+
+impl RetryPolicy {
+	/// Delay to wait after the given failed attempt, doubling at each attempt
+	pub fn backoff(&self, attempt: u32) -> Duration {
+		self.initial_backoff
+			.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+			.min(self.max_backoff)
+	}
+}
+
+/// Forwards events to a projector, retrying with backoff when it fails.
+/// Events that still cannot be projected are parked in the dead letter repository.
+pub struct Dispatcher {
+	name: String,
+	projector: Arc<dyn Projector<Contribution>>,
+	dead_letter_event_repository: Arc<dyn DeadLetterEventRepository>,
+	uuid_generator: Arc<dyn UuidGenerator>,
+	retry_policy: RetryPolicy,
+}
+
+impl Dispatcher {
+	pub fn new(
+		name: &str,
+		projector: Arc<dyn Projector<Contribution>>,
+		dead_letter_event_repository: Arc<dyn DeadLetterEventRepository>,
+		uuid_generator: Arc<dyn UuidGenerator>,
+	) -> Self {
+		Self {
+			name: name.to_string(),
+			projector,
+			dead_letter_event_repository,
+			uuid_generator,
+			retry_policy: RetryPolicy::default(),
+		}
+	}
+
+	pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.retry_policy = retry_policy;
+		self
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Try once more to project a dead letter event.
+	/// It is removed from the dead letters on success, and updated with the new error otherwise.
+	pub async fn redeliver(&self, dead_letter_event: DeadLetterEvent) -> Result<(), DomainError> {
+		let event = match &dead_letter_event.event {
+			Event::Contribution(event) => event,
+			event => return Err(DomainError::UnsupportedEvent(event.to_string())),
+		};
+		let result = self.projector.project(event).await;
+
+		match result {
+			Ok(()) =>
+				self.dead_letter_event_repository.delete(&dead_letter_event.id).map_err_into(),
+			Err(error) => {
+				self.dead_letter_event_repository.update(DeadLetterEvent {
+					error: error.to_string(),
+					attempts: dead_letter_event.attempts + 1,
+					failed_at: SystemTime::now(),
+					..dead_letter_event
+				})?;
+				Err(error.into())
+			},
+		}
+	}
+
+	async fn project_with_retries(&self, event: &ContributionEvent) -> Result<(), String> {
+		let mut attempt = 1;
+		loop {
+			let error = match self.projector.project(event).await {
+				Ok(()) => return Ok(()),
+				Err(error) => error.to_string(),
+			};
+
+			if attempt >= self.retry_policy.max_attempts {
+				return Err(error);
+			}
+
+			let backoff = self.retry_policy.backoff(attempt);
+			warn!(
+				"Attempt {attempt} to project event {event} with {} failed, retrying in {backoff:?}: {error}",
+				self.name
+			);
+			Delay::new(backoff).await;
+			attempt += 1;
+		}
+	}
+
+	async fn project_or_park(
+		&self,
+		event: &ContributionEvent,
+		deduplication_id: Option<&str>,
+	) -> Result<(), ProjectorError> {
+		let error = match self.project_with_retries(event).await {
+			Ok(()) => return Ok(()),
+			Err(error) => error,
+		};
+
+		error!(
+			"Unable to project event {event} with {}, moving it to dead letters: {error}",
+			self.name
+		);
+
+		self.dead_letter_event_repository
+			.create(DeadLetterEvent {
+				id: self.uuid_generator.new_uuid().into(),
+				projector: self.name.clone(),
+				event: Event::Contribution(event.clone()),
+				deduplication_id: deduplication_id.map(String::from),
+				error,
+				attempts: self.retry_policy.max_attempts,
+				failed_at: SystemTime::now(),
+			})
+			.map_err(|error| ProjectorError::Projection(Box::new(error)))
+	}
+}
+
+#[async_trait]
+impl Projector<Contribution> for Dispatcher {
+	async fn project(&self, event: &ContributionEvent) -> Result<(), ProjectorError> {
+		self.project_or_park(event, None).await
+	}
+
+	async fn project_observed(
+		&self,
+		event: &ContributionEvent,
+		deduplication_id: &str,
+	) -> Result<(), ProjectorError> {
+		self.project_or_park(event, Some(deduplication_id)).await
+	}
+
+	async fn reset(&self, id: &ContributionId) -> Result<(), ProjectorError> {
+		self.projector.reset(id).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use assert_matches::assert_matches;
+	use mockall::{predicate::eq, Sequence};
+	use rstest::{fixture, rstest};
+	use std::str::FromStr;
+	use thiserror::Error;
+
+	#[derive(Debug, Error)]
+	#[error("Oops")]
+	struct Error;
+
+	#[fixture]
+	fn projector() -> MockProjector<Contribution> {
+		MockProjector::<Contribution>::new()
+	}
+
+	#[fixture]
+	fn dead_letter_event_repository() -> MockDeadLetterEventRepository {
+		MockDeadLetterEventRepository::new()
+	}
+
+	#[fixture]
+	fn uuid_generator() -> MockUuidGenerator {
+		let mut uuid_generator = MockUuidGenerator::new();
+		uuid_generator.expect_new_uuid().returning(uuid::Uuid::new_v4);
+		uuid_generator
+	}
+
+	#[fixture]
+	fn retry_policy() -> RetryPolicy {
+		RetryPolicy {
+			max_attempts: 3,
+			initial_backoff: Duration::ZERO,
+			max_backoff: Duration::ZERO,
+		}
+	}
+
+	#[fixture]
+	fn event() -> ContributionEvent {
+		ContributionEvent::Validated {
+			id: ContributionId::from_str("0x123").unwrap(),
+		}
+	}
+
+	#[fixture]
+	fn dead_letter_event(event: ContributionEvent) -> DeadLetterEvent {
+		DeadLetterEvent {
+			id: uuid::Uuid::from_str("03b4715c-d237-422c-8689-370e4c257f90").unwrap().into(),
+			projector: "applications".to_string(),
+			event: Event::Contribution(event),
+			deduplication_id: None,
+			error: "Oops".to_string(),
+			attempts: 3,
+			failed_at: SystemTime::UNIX_EPOCH,
+		}
+	}
+
+	fn projection_error() -> ProjectorError {
+		ProjectorError::Projection(Box::new(Error))
+	}
+
+	#[rstest]
+	fn backoff_doubles_until_max() {
+		let retry_policy = RetryPolicy {
+			max_attempts: 10,
+			initial_backoff: Duration::from_millis(100),
+			max_backoff: Duration::from_millis(500),
+		};
+
+		assert_eq!(Duration::from_millis(100), retry_policy.backoff(1));
+		assert_eq!(Duration::from_millis(200), retry_policy.backoff(2));
+		assert_eq!(Duration::from_millis(400), retry_policy.backoff(3));
+		assert_eq!(Duration::from_millis(500), retry_policy.backoff(4));
+		assert_eq!(Duration::from_millis(500), retry_policy.backoff(40));
+	}
+
+	#[rstest]
+	async fn project_retries_until_success(
+		mut projector: MockProjector<Contribution>,
+		mut dead_letter_event_repository: MockDeadLetterEventRepository,
+		uuid_generator: MockUuidGenerator,
+		retry_policy: RetryPolicy,
+		event: ContributionEvent,
+	) {
+		let mut sequence = Sequence::new();
+		projector
+			.expect_project()
+			.with(eq(event.clone()))
+			.once()
+			.in_sequence(&mut sequence)
+			.returning(|_| Err(projection_error()));
+		projector
+			.expect_project()
+			.with(eq(event.clone()))
+			.once()
+			.in_sequence(&mut sequence)
+			.returning(|_| Ok(()));
+		dead_letter_event_repository.expect_create().never();
+
+		let dispatcher = Dispatcher::new(
+			"applications",
+			Arc::new(projector),
+			Arc::new(dead_letter_event_repository),
+			Arc::new(uuid_generator),
+		)
+		.with_retry_policy(retry_policy);
+
+		assert!(dispatcher.project(&event).await.is_ok());
+	}
+
+	#[rstest]
+	async fn project_moves_event_to_dead_letters_when_retries_are_exhausted(
+		mut projector: MockProjector<Contribution>,
+		mut dead_letter_event_repository: MockDeadLetterEventRepository,
+		uuid_generator: MockUuidGenerator,
+		retry_policy: RetryPolicy,
+		event: ContributionEvent,
+	) {
+		projector.expect_project().times(3).returning(|_| Err(projection_error()));
+
+		let expected_event = Event::Contribution(event.clone());
+		dead_letter_event_repository
+			.expect_create()
+			.withf(move |dead_letter_event| {
+				dead_letter_event.projector == "applications"
+					&& dead_letter_event.event == expected_event
+					&& dead_letter_event.attempts == 3
+					&& dead_letter_event.error == projection_error().to_string()
+			})
+			.once()
+			.returning(|_| Ok(()));
+
+		let dispatcher = Dispatcher::new(
+			"applications",
+			Arc::new(projector),
+			Arc::new(dead_letter_event_repository),
+			Arc::new(uuid_generator),
+		)
+		.with_retry_policy(retry_policy);
+
+		assert!(dispatcher.project(&event).await.is_ok());
+	}
+
+	#[rstest]
+	async fn dead_letters_of_observed_events_keep_their_deduplication_id(
+		mut projector: MockProjector<Contribution>,
+		mut dead_letter_event_repository: MockDeadLetterEventRepository,
+		uuid_generator: MockUuidGenerator,
+		retry_policy: RetryPolicy,
+		event: ContributionEvent,
+	) {
+		projector.expect_project().times(3).returning(|_| Err(projection_error()));
+
+		dead_letter_event_repository
+			.expect_create()
+			.withf(|dead_letter_event| {
+				dead_letter_event.deduplication_id == Some(String::from("0x1-1"))
+			})
+			.once()
+			.returning(|_| Ok(()));
+
+		let dispatcher = Dispatcher::new(
+			"applications",
+			Arc::new(projector),
+			Arc::new(dead_letter_event_repository),
+			Arc::new(uuid_generator),
+		)
+		.with_retry_policy(retry_policy);
+
+		assert!(dispatcher.project_observed(&event, "0x1-1").await.is_ok());
+	}
+
+	#[rstest]
+	async fn redeliver_removes_event_from_dead_letters_on_success(
+		mut projector: MockProjector<Contribution>,
+		mut dead_letter_event_repository: MockDeadLetterEventRepository,
+		uuid_generator: MockUuidGenerator,
+		event: ContributionEvent,
+		dead_letter_event: DeadLetterEvent,
+	) {
+		projector.expect_project().with(eq(event)).once().returning(|_| Ok(()));
+		dead_letter_event_repository
+			.expect_delete()
+			.with(eq(dead_letter_event.id))
+			.once()
+			.returning(|_| Ok(()));
+
+		let dispatcher = Dispatcher::new(
+			"applications",
+			Arc::new(projector),
+			Arc::new(dead_letter_event_repository),
+			Arc::new(uuid_generator),
+		);
+
+		assert!(dispatcher.redeliver(dead_letter_event).await.is_ok());
+	}
+
+	#[rstest]
+	async fn redeliver_updates_dead_letter_on_failure(
+		mut projector: MockProjector<Contribution>,
+		mut dead_letter_event_repository: MockDeadLetterEventRepository,
+		uuid_generator: MockUuidGenerator,
+		dead_letter_event: DeadLetterEvent,
+	) {
+		projector.expect_project().once().returning(|_| Err(projection_error()));
+		dead_letter_event_repository.expect_delete().never();
+
+		let dead_letter_event_id = dead_letter_event.id;
+		dead_letter_event_repository
+			.expect_update()
+			.withf(move |dead_letter_event| {
+				dead_letter_event.id == dead_letter_event_id && dead_letter_event.attempts == 4
+			})
+			.once()
+			.returning(|_| Ok(()));
+
+		let dispatcher = Dispatcher::new(
+			"applications",
+			Arc::new(projector),
+			Arc::new(dead_letter_event_repository),
+			Arc::new(uuid_generator),
+		);
+
+		let result = dispatcher.redeliver(dead_letter_event).await;
+		assert!(result.is_err());
+		assert_matches!(result.unwrap_err(), DomainError::Projection(_));
+	}
+}
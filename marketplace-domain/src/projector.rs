@@ -1,8 +1,28 @@
 use async_trait::async_trait;
+use mockall::automock;
+use thiserror::Error;
 
 use crate::Aggregate;
 
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("Unable to project event: {0}")]
+	Projection(#[source] Box<dyn std::error::Error>),
+}
+
+#[automock]
 #[async_trait]
 pub trait Projector<A: Aggregate>: Send + Sync {
-	async fn project(&self, event: &A::Event);
+	async fn project(&self, event: &A::Event) -> Result<(), Error>;
+	/// Project an event observed on-chain, identified in the event store by its deduplication id
+	async fn project_observed(
+		&self,
+		event: &A::Event,
+		_deduplication_id: &str,
+	) -> Result<(), Error> {
+		self.project(event).await
+	}
+	/// Forget what has been projected from the events of the aggregate, so they can be
+	/// projected again
+	async fn reset(&self, aggregate_id: &A::Id) -> Result<(), Error>;
 }
@@ -0,0 +1,269 @@
+use crate::{Error as DomainError, *};
+use std::sync::Arc;
+
+/// Undo what has been indexed from the blocks orphaned by a chain reorganization.
+/// Their events are removed from the store along with their dead letters, then the projections
+/// of the aggregates they belonged to are rebuilt from the remaining events.
+pub struct Rollback<A: Aggregate> {
+	event_store: Arc<dyn EventStore<A>>,
+	dead_letter_event_repository: Arc<dyn DeadLetterEventRepository>,
+	projectors: Vec<Arc<dyn Projector<A>>>,
+}
+
+impl<A: Aggregate> Rollback<A> {
+	pub fn new(
+		event_store: Arc<dyn EventStore<A>>,
+		dead_letter_event_repository: Arc<dyn DeadLetterEventRepository>,
+		projectors: Vec<Arc<dyn Projector<A>>>,
+	) -> Self {
+		Self {
+			event_store,
+			dead_letter_event_repository,
+			projectors,
+		}
+	}
+
+	/// Roll back every block indexed by the indexer above the fork point, returning the ids of
+	/// the affected aggregates
+	pub async fn rollback_after_block(
+		&self,
+		indexer_id: &str,
+		fork_block_number: u64,
+	) -> Result<Vec<A::Id>, DomainError> {
+		let removed_events = self.event_store.remove_after_block(indexer_id, fork_block_number)?;
+		if removed_events.is_empty() {
+			return Ok(vec![]);
+		}
+
+		self.discard_dead_letters(&removed_events)?;
+
+		let mut aggregate_ids = Vec::new();
+		for (aggregate_id, _) in removed_events {
+			if !aggregate_ids.contains(&aggregate_id) {
+				aggregate_ids.push(aggregate_id);
+			}
+		}
+
+		for aggregate_id in &aggregate_ids {
+			let events = self.event_store.list_by_id(aggregate_id)?;
+			for projector in &self.projectors {
+				projector.reset(aggregate_id).await?;
+				for event in &events {
+					projector.project(event).await?;
+				}
+			}
+		}
+
+		Ok(aggregate_ids)
+	}
+
+	/// Orphaned events will never be projected, their dead letters cannot be retried anymore.
+	/// They are told apart from identical events of other blocks by their deduplication id.
+	fn discard_dead_letters(&self, removed_events: &[(A::Id, String)]) -> Result<(), DomainError> {
+		for dead_letter_event in self.dead_letter_event_repository.list()? {
+			let orphaned = removed_events.iter().any(|(_, deduplication_id)| {
+				dead_letter_event.deduplication_id.as_ref() == Some(deduplication_id)
+			});
+			if orphaned {
+				self.dead_letter_event_repository.delete(&dead_letter_event.id)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use assert_matches::assert_matches;
+	use mockall::{predicate::eq, Sequence};
+	use rstest::{fixture, rstest};
+	use std::{str::FromStr, time::SystemTime};
+	use uuid::Uuid;
+
+	#[fixture]
+	fn event_store() -> MockEventStore<Contribution> {
+		MockEventStore::<Contribution>::new()
+	}
+
+	#[fixture]
+	fn dead_letter_event_repository() -> MockDeadLetterEventRepository {
+		MockDeadLetterEventRepository::new()
+	}
+
+	#[fixture]
+	fn contribution_id() -> ContributionId {
+		ContributionId::from_str("0x123").unwrap()
+	}
+
+	#[fixture]
+	fn created_event(contribution_id: ContributionId) -> ContributionEvent {
+		ContributionEvent::Created {
+			id: contribution_id,
+			project_id: Default::default(),
+			issue_number: Default::default(),
+			gate: Default::default(),
+		}
+	}
+
+	#[fixture]
+	fn validated_event(contribution_id: ContributionId) -> ContributionEvent {
+		ContributionEvent::Validated {
+			id: contribution_id,
+		}
+	}
+
+	fn dead_letter_event(
+		id: &str,
+		event: ContributionEvent,
+		deduplication_id: Option<&str>,
+	) -> DeadLetterEvent {
+		DeadLetterEvent {
+			id: Uuid::from_str(id).unwrap().into(),
+			projector: "applications".to_string(),
+			event: Event::Contribution(event),
+			deduplication_id: deduplication_id.map(String::from),
+			error: "Oops".to_string(),
+			attempts: 3,
+			failed_at: SystemTime::UNIX_EPOCH,
+		}
+	}
+
+	#[rstest]
+	async fn projections_are_rebuilt_from_remaining_events(
+		mut event_store: MockEventStore<Contribution>,
+		mut dead_letter_event_repository: MockDeadLetterEventRepository,
+		contribution_id: ContributionId,
+		created_event: ContributionEvent,
+	) {
+		let removed_events = vec![
+			(contribution_id.clone(), "0x1-1".to_string()),
+			(contribution_id.clone(), "0x1-2".to_string()),
+		];
+		event_store
+			.expect_remove_after_block()
+			.with(eq("contribution-indexer"), eq(42))
+			.once()
+			.returning(move |_, _| Ok(removed_events.clone()));
+		let cloned_event = created_event.clone();
+		event_store
+			.expect_list_by_id()
+			.with(eq(contribution_id.clone()))
+			.returning(move |_| Ok(vec![cloned_event.clone()]));
+		dead_letter_event_repository.expect_list().returning(|| Ok(vec![]));
+
+		let mut sequence = Sequence::new();
+		let mut projector = MockProjector::<Contribution>::new();
+		projector
+			.expect_reset()
+			.with(eq(contribution_id.clone()))
+			.once()
+			.in_sequence(&mut sequence)
+			.returning(|_| Ok(()));
+		projector
+			.expect_project()
+			.with(eq(created_event))
+			.once()
+			.in_sequence(&mut sequence)
+			.returning(|_| Ok(()));
+
+		let rollback = Rollback::new(
+			Arc::new(event_store),
+			Arc::new(dead_letter_event_repository),
+			vec![Arc::new(projector)],
+		);
+		let result = rollback.rollback_after_block("contribution-indexer", 42).await;
+
+		assert_eq!(vec![contribution_id], result.unwrap());
+	}
+
+	#[rstest]
+	async fn dead_letters_of_removed_events_are_discarded(
+		mut event_store: MockEventStore<Contribution>,
+		mut dead_letter_event_repository: MockDeadLetterEventRepository,
+		contribution_id: ContributionId,
+		created_event: ContributionEvent,
+		validated_event: ContributionEvent,
+	) {
+		let removed_events = vec![(contribution_id, "0x1-1".to_string())];
+		event_store
+			.expect_remove_after_block()
+			.returning(move |_, _| Ok(removed_events.clone()));
+		event_store.expect_list_by_id().returning(|_| Ok(vec![]));
+
+		let orphaned = dead_letter_event(
+			"03b4715c-d237-422c-8689-370e4c257f90",
+			validated_event.clone(),
+			Some("0x1-1"),
+		);
+		// The same event observed in a block that was not orphaned
+		let identical = dead_letter_event(
+			"5a7e0c3b-6f0e-4d6b-9a3c-2f1b8e4d7c10",
+			validated_event,
+			Some("0x2-1"),
+		);
+		let remaining =
+			dead_letter_event("9d1f44b4-2a1a-4b5b-8b0e-7a5b0c1f3e21", created_event, None);
+		let orphaned_id = orphaned.id;
+		dead_letter_event_repository
+			.expect_list()
+			.returning(move || Ok(vec![orphaned.clone(), identical.clone(), remaining.clone()]));
+		dead_letter_event_repository
+			.expect_delete()
+			.with(eq(orphaned_id))
+			.once()
+			.returning(|_| Ok(()));
+
+		let rollback = Rollback::new(
+			Arc::new(event_store),
+			Arc::new(dead_letter_event_repository),
+			vec![],
+		);
+		assert!(rollback.rollback_after_block("contribution-indexer", 42).await.is_ok());
+	}
+
+	#[rstest]
+	async fn nothing_is_rebuilt_without_orphaned_events(
+		mut event_store: MockEventStore<Contribution>,
+		mut dead_letter_event_repository: MockDeadLetterEventRepository,
+	) {
+		event_store.expect_remove_after_block().returning(|_, _| Ok(vec![]));
+		dead_letter_event_repository.expect_list().never();
+
+		let mut projector = MockProjector::<Contribution>::new();
+		projector.expect_reset().never();
+		projector.expect_project().never();
+
+		let rollback = Rollback::new(
+			Arc::new(event_store),
+			Arc::new(dead_letter_event_repository),
+			vec![Arc::new(projector)],
+		);
+		assert!(rollback
+			.rollback_after_block("contribution-indexer", 42)
+			.await
+			.unwrap()
+			.is_empty());
+	}
+
+	#[rstest]
+	async fn event_store_errors_are_reported(
+		mut event_store: MockEventStore<Contribution>,
+		dead_letter_event_repository: MockDeadLetterEventRepository,
+	) {
+		event_store
+			.expect_remove_after_block()
+			.returning(|_, _| Err(EventStoreError::Remove(anyhow::anyhow!("Oops"))));
+
+		let rollback = Rollback::new(
+			Arc::new(event_store),
+			Arc::new(dead_letter_event_repository),
+			vec![],
+		);
+		assert_matches!(
+			rollback.rollback_after_block("contribution-indexer", 42).await,
+			Err(DomainError::EventStoreError(EventStoreError::Remove(_)))
+		);
+	}
+}
@@ -19,6 +19,7 @@ pub enum Error {
 pub trait Repository: Send + Sync {
 	fn create(&self, application: ApplicationProjection) -> Result<(), Error>;
 	fn update(&self, application: ApplicationProjection) -> Result<(), Error>;
+	fn delete_by_contribution(&self, contribution_id: &ContributionId) -> Result<(), Error>;
 	fn find(&self, id: &ApplicationId) -> Result<Option<ApplicationProjection>, Error>;
 	fn find_by_contribution_and_contributor(
 		&self,
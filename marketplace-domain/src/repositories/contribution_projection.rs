@@ -21,7 +21,9 @@ pub trait Repository: Send + Sync {
 		&self,
 		contribution_id: &ContributionId,
 	) -> Result<Option<ContributionProjection>, Error>;
+	fn list(&self) -> Result<Vec<ContributionProjection>, Error>;
 	fn create(&self, contribution: ContributionProjection) -> Result<(), Error>;
+	fn delete(&self, contribution_id: &ContributionId) -> Result<(), Error>;
 
 	fn update_contributor_and_status(
 		&self,
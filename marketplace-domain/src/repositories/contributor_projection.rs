@@ -0,0 +1,28 @@
+use mockall::automock;
+
+use crate::*;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("Contributor not found")]
+	NotFound,
+	#[error("Contributor contains invalid members")]
+	InvalidEntity(#[source] Box<dyn std::error::Error>),
+	#[error("Something happend at the infrastructure level")]
+	Infrastructure(#[source] Box<dyn std::error::Error>),
+}
+
+#[automock]
+pub trait Repository: Send + Sync {
+	fn find_by_id(
+		&self,
+		contributor_id: &ContributorId,
+	) -> Result<Option<ContributorProjection>, Error>;
+	fn find_by_ids(
+		&self,
+		contributor_ids: &[ContributorId],
+	) -> Result<Vec<ContributorProjection>, Error>;
+	fn upsert(&self, contributor: ContributorProjection) -> Result<(), Error>;
+	fn delete(&self, contributor_id: &ContributorId) -> Result<(), Error>;
+}
@@ -0,0 +1,22 @@
+use mockall::automock;
+
+use thiserror::Error;
+
+use crate::*;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("Dead letter event not found")]
+	NotFound,
+	#[error("Something happend at the infrastructure level")]
+	Infrastructure(#[source] Box<dyn std::error::Error>),
+}
+
+#[automock]
+pub trait Repository: Send + Sync {
+	fn create(&self, dead_letter_event: DeadLetterEvent) -> Result<(), Error>;
+	fn update(&self, dead_letter_event: DeadLetterEvent) -> Result<(), Error>;
+	fn delete(&self, id: &DeadLetterEventId) -> Result<(), Error>;
+	fn find_by_id(&self, id: &DeadLetterEventId) -> Result<Option<DeadLetterEvent>, Error>;
+	fn list(&self) -> Result<Vec<DeadLetterEvent>, Error>;
+}
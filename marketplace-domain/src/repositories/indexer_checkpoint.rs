@@ -0,0 +1,24 @@
+use mockall::automock;
+
+use thiserror::Error;
+
+use crate::*;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("Something happend at the infrastructure level")]
+	Infrastructure(#[source] Box<dyn std::error::Error>),
+}
+
+#[automock]
+pub trait Repository: Send + Sync {
+	fn create(&self, checkpoint: IndexerCheckpoint) -> Result<(), Error>;
+	fn find(&self, indexer_id: &str, block_number: u64)
+		-> Result<Option<IndexerCheckpoint>, Error>;
+	fn latest(&self, indexer_id: &str) -> Result<Option<IndexerCheckpoint>, Error>;
+	/// Forget the blocks below the given one, they are too old to be reorganized
+	fn delete_before_block(&self, indexer_id: &str, block_number: u64) -> Result<(), Error>;
+	/// Forget the blocks above the given one, orphaned by a reorganization
+	fn delete_after_block(&self, indexer_id: &str, block_number: u64) -> Result<(), Error>;
+	fn delete(&self, indexer_id: &str) -> Result<(), Error>;
+}
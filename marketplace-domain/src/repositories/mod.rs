@@ -26,3 +26,34 @@ pub use github_issue::{Error as GithubIssueRepositoryError, Repository as Github
 
 #[cfg(test)]
 pub use github_issue::MockRepository as MockGithubIssueRepository;
+
+mod dead_letter_event;
+pub use dead_letter_event::{
+	Error as DeadLetterEventRepositoryError, MockRepository as MockDeadLetterEventRepository,
+	Repository as DeadLetterEventRepository,
+};
+
+mod onchain_transaction;
+pub use onchain_transaction::{
+	Error as OnchainTransactionRepositoryError, MockRepository as MockOnchainTransactionRepository,
+	Repository as OnchainTransactionRepository,
+};
+
+mod contributor_projection;
+pub use contributor_projection::{
+	Error as ContributorProjectionRepositoryError,
+	MockRepository as MockContributorProjectionRepository,
+	Repository as ContributorProjectionRepository,
+};
+
+mod pending_event;
+pub use pending_event::{
+	Error as PendingEventRepositoryError, MockRepository as MockPendingEventRepository,
+	Repository as PendingEventRepository,
+};
+
+mod indexer_checkpoint;
+pub use indexer_checkpoint::{
+	Error as IndexerCheckpointRepositoryError, MockRepository as MockIndexerCheckpointRepository,
+	Repository as IndexerCheckpointRepository,
+};
@@ -0,0 +1,21 @@
+use mockall::automock;
+
+use thiserror::Error;
+
+use crate::*;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("Onchain transaction not found")]
+	NotFound,
+	#[error("Something happend at the infrastructure level")]
+	Infrastructure(#[source] Box<dyn std::error::Error>),
+}
+
+#[automock]
+pub trait Repository: Send + Sync {
+	fn create(&self, transaction: OnchainTransaction) -> Result<(), Error>;
+	fn update(&self, transaction: OnchainTransaction) -> Result<(), Error>;
+	fn find_by_hash(&self, hash: &HexPrefixedString) -> Result<Option<OnchainTransaction>, Error>;
+	fn list_pending(&self) -> Result<Vec<OnchainTransaction>, Error>;
+}
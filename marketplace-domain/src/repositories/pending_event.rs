@@ -0,0 +1,21 @@
+use mockall::automock;
+
+use thiserror::Error;
+
+use crate::*;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("Something happend at the infrastructure level")]
+	Infrastructure(#[source] Box<dyn std::error::Error>),
+}
+
+#[automock]
+pub trait Repository: Send + Sync {
+	fn create(&self, pending_event: PendingEvent) -> Result<(), Error>;
+	fn delete(&self, deduplication_id: &str) -> Result<(), Error>;
+	/// Delete the events observed by the indexer in the blocks above the given one
+	fn delete_after_block(&self, indexer_id: &str, block_number: u64) -> Result<(), Error>;
+	/// List the events pending for the indexer, oldest block first
+	fn list(&self, indexer_id: &str) -> Result<Vec<PendingEvent>, Error>;
+}
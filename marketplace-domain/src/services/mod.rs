@@ -10,6 +10,15 @@ pub use onchain_contribution::{
 	Service as OnchainContributionService,
 };
 
+mod offchain_contribution;
+pub use offchain_contribution::OffchainContributionService;
+
+mod onchain_transaction;
+pub use onchain_transaction::{
+	Error as OnchainTransactionServiceError, MockService as MockOnchainTransactionService,
+	Service as OnchainTransactionService,
+};
+
 mod uuid;
 pub use self::uuid::{
 	MockService as MockUuidGenerator, RandomUuidGenerator, Service as UuidGenerator,
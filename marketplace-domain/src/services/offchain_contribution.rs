@@ -0,0 +1,421 @@
+use crate::*;
+use async_trait::async_trait;
+use log::info;
+use std::sync::Arc;
+
+/// Stands in for the contributions smart contract: actions are turned into the events the
+/// contract would have emitted, which are stored and projected right away.
+/// Meant for demos, local development and hermetic tests, where no StarkNet network is available.
+pub struct OffchainContributionService {
+	event_store: Arc<dyn EventStore<Contribution>>,
+	event_batch_store: Arc<dyn EventBatchStore>,
+	projectors: Vec<Arc<dyn Projector<Contribution>>>,
+	uuid_generator: Arc<dyn UuidGenerator>,
+}
+
+impl OffchainContributionService {
+	pub fn new(
+		event_store: Arc<dyn EventStore<Contribution>>,
+		event_batch_store: Arc<dyn EventBatchStore>,
+		projectors: Vec<Arc<dyn Projector<Contribution>>>,
+		uuid_generator: Arc<dyn UuidGenerator>,
+	) -> Self {
+		Self {
+			event_store,
+			event_batch_store,
+			projectors,
+			uuid_generator,
+		}
+	}
+
+	/// State of the contribution once the events already emitted in the transaction are applied
+	fn contribution(
+		&self,
+		contribution_id: &ContributionId,
+		batch: &EventBatch,
+	) -> Result<Contribution, OnchainContributionServiceError> {
+		let mut events = self
+			.event_store
+			.list_by_id(contribution_id)
+			.map_err(|e| OnchainContributionServiceError::Infrastructure(Box::new(e)))?;
+		events.extend(
+			batch
+				.contribution_events
+				.iter()
+				.filter(|(id, _)| id == contribution_id)
+				.map(|(_, storable_event)| storable_event.event.clone()),
+		);
+
+		match events {
+			_ if events.is_empty() => Err(OnchainContributionServiceError::Reverted(Box::new(
+				AggregateRootRepositoryError::NotFound,
+			))),
+			events => Ok(Contribution::from_events(&events)),
+		}
+	}
+
+	/// Events the contract would emit for the action, provided the contribution allows it
+	fn events_of(
+		&self,
+		action: Action,
+		batch: &EventBatch,
+	) -> Result<Vec<ContributionEvent>, OnchainContributionServiceError> {
+		let events = match action {
+			Action::CreateContribution {
+				project_id,
+				issue_number,
+				gate,
+			} => Ok(vec![ContributionEvent::Created {
+				id: self.uuid_generator.new_uuid().as_u128().into(),
+				project_id,
+				issue_number,
+				gate,
+			}]),
+			Action::AssignContributor {
+				contribution_id,
+				contributor_id,
+			} => self.contribution(&contribution_id, batch)?.assign(&contributor_id),
+			Action::UnassignContributor { contribution_id } =>
+				self.contribution(&contribution_id, batch)?.unassign(),
+			Action::ValidateContribution { contribution_id } =>
+				self.contribution(&contribution_id, batch)?.validate(),
+		};
+
+		events.map_err(|e| OnchainContributionServiceError::Reverted(Box::new(e)))
+	}
+}
+
+#[async_trait]
+impl OnchainContributionService for OffchainContributionService {
+	async fn create(
+		&self,
+		contribution: ContributionProjection,
+	) -> Result<HexPrefixedString, OnchainContributionServiceError> {
+		self.execute_actions(vec![Action::CreateContribution {
+			project_id: contribution.project_id,
+			issue_number: contribution.issue_number,
+			gate: contribution.gate,
+		}])
+		.await
+	}
+
+	async fn assign_contributor(
+		&self,
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+	) -> Result<HexPrefixedString, OnchainContributionServiceError> {
+		self.execute_actions(vec![Action::AssignContributor {
+			contribution_id,
+			contributor_id,
+		}])
+		.await
+	}
+
+	async fn unassign_contributor(
+		&self,
+		contribution_id: ContributionId,
+	) -> Result<HexPrefixedString, OnchainContributionServiceError> {
+		self.execute_actions(vec![Action::UnassignContributor { contribution_id }])
+			.await
+	}
+
+	async fn validate(
+		&self,
+		contribution_id: ContributionId,
+	) -> Result<HexPrefixedString, OnchainContributionServiceError> {
+		self.execute_actions(vec![Action::ValidateContribution { contribution_id }])
+			.await
+	}
+
+	async fn execute_actions(
+		&self,
+		actions: Vec<Action>,
+	) -> Result<HexPrefixedString, OnchainContributionServiceError> {
+		let transaction_hash =
+			HexPrefixedString::from_bytes(self.uuid_generator.new_uuid().as_bytes().to_vec());
+
+		let mut batch = EventBatch::default();
+		for action in actions {
+			for event in self.events_of(action, &batch)? {
+				batch.contribution_events.push((
+					event.contribution_id().clone(),
+					StorableEvent {
+						event,
+						deduplication_id: self.uuid_generator.new_uuid().to_string(),
+						transaction_hash: Some(transaction_hash.clone()),
+						block_number: None,
+						block_hash: None,
+					},
+				));
+			}
+		}
+
+		let events: Vec<ContributionEvent> = self
+			.event_batch_store
+			.append_batch(batch)
+			.map_err(|e| OnchainContributionServiceError::Infrastructure(Box::new(e)))?
+			.contribution_events
+			.into_iter()
+			.map(|(_, storable_event)| storable_event.event)
+			.collect();
+
+		for event in &events {
+			for projector in &self.projectors {
+				projector
+					.project(event)
+					.await
+					.map_err(|e| OnchainContributionServiceError::Infrastructure(Box::new(e)))?;
+			}
+		}
+
+		info!(
+			"Applied {} events off-chain in transaction {transaction_hash}",
+			events.len()
+		);
+
+		Ok(transaction_hash)
+	}
+
+	async fn estimate_fee(
+		&self,
+		_actions: Vec<Action>,
+	) -> Result<FeeEstimate, OnchainContributionServiceError> {
+		Ok(FeeEstimate::default())
+	}
+
+	async fn simulate(
+		&self,
+		_actions: Vec<Action>,
+	) -> Result<TransactionSimulation, OnchainContributionServiceError> {
+		Ok(TransactionSimulation::Succeeded(FeeEstimate::default()))
+	}
+}
+
+#[async_trait]
+impl OnchainTransactionService for OffchainContributionService {
+	/// Off-chain transactions are applied as soon as they are sent
+	async fn status(
+		&self,
+		_hash: &HexPrefixedString,
+	) -> Result<Option<OnchainTransactionStatus>, OnchainTransactionServiceError> {
+		Ok(Some(OnchainTransactionStatus::AcceptedOnL2))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use mockall::predicate::*;
+	use rstest::*;
+	use std::{str::FromStr, sync::Mutex};
+	use uuid::Uuid;
+
+	#[fixture]
+	fn contribution_id() -> ContributionId {
+		ContributionId::from_str("0x12").unwrap()
+	}
+
+	#[fixture]
+	fn event_store(contribution_id: ContributionId) -> MockEventStore<Contribution> {
+		let mut event_store = MockEventStore::new();
+		event_store.expect_list_by_id().returning(move |_| {
+			Ok(vec![ContributionEvent::Created {
+				id: contribution_id.clone(),
+				project_id: 1,
+				issue_number: 2,
+				gate: 0,
+			}])
+		});
+		event_store
+	}
+
+	#[fixture]
+	fn event_batch_store() -> MockEventBatchStore {
+		MockEventBatchStore::new()
+	}
+
+	#[fixture]
+	fn projector() -> MockProjector<Contribution> {
+		MockProjector::<Contribution>::new()
+	}
+
+	#[fixture]
+	fn uuid_generator() -> MockUuidGenerator {
+		let mut uuid_generator = MockUuidGenerator::new();
+		uuid_generator.expect_new_uuid().returning(|| Uuid::from_u128(0x1234));
+		uuid_generator
+	}
+
+	fn service(
+		event_store: MockEventStore<Contribution>,
+		event_batch_store: MockEventBatchStore,
+		projector: MockProjector<Contribution>,
+		uuid_generator: MockUuidGenerator,
+	) -> OffchainContributionService {
+		OffchainContributionService::new(
+			Arc::new(event_store),
+			Arc::new(event_batch_store),
+			vec![Arc::new(projector)],
+			Arc::new(uuid_generator),
+		)
+	}
+
+	#[rstest]
+	async fn actions_are_stored_together_and_projected_as_events(
+		event_store: MockEventStore<Contribution>,
+		mut event_batch_store: MockEventBatchStore,
+		mut projector: MockProjector<Contribution>,
+		uuid_generator: MockUuidGenerator,
+		contribution_id: ContributionId,
+	) {
+		let stored_batches = Arc::new(Mutex::new(Vec::new()));
+		let cloned_stored_batches = stored_batches.clone();
+		event_batch_store.expect_append_batch().times(1).returning(move |batch| {
+			cloned_stored_batches.lock().unwrap().push(batch.clone());
+			Ok(batch)
+		});
+		projector
+			.expect_project()
+			.with(eq(ContributionEvent::Assigned {
+				id: contribution_id.clone(),
+				contributor_id: ContributorId::from(42),
+			}))
+			.times(1)
+			.returning(|_| Ok(()));
+		projector
+			.expect_project()
+			.with(eq(ContributionEvent::Validated {
+				id: contribution_id.clone(),
+			}))
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let result = service(event_store, event_batch_store, projector, uuid_generator)
+			.execute_actions(vec![
+				Action::AssignContributor {
+					contribution_id: contribution_id.clone(),
+					contributor_id: ContributorId::from(42),
+				},
+				Action::ValidateContribution {
+					contribution_id: contribution_id.clone(),
+				},
+			])
+			.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		let transaction_hash = result.unwrap();
+		assert_eq!(
+			HexPrefixedString::from_str("0x1234").unwrap(),
+			transaction_hash
+		);
+
+		let stored_batches = stored_batches.lock().unwrap();
+		let stored_events = &stored_batches.first().unwrap().contribution_events;
+		assert_eq!(2, stored_events.len());
+		assert!(stored_events.iter().all(|(id, event)| {
+			*id == contribution_id && event.transaction_hash == Some(transaction_hash.clone())
+		}));
+	}
+
+	#[rstest]
+	async fn created_contributions_get_a_new_id(
+		event_store: MockEventStore<Contribution>,
+		mut event_batch_store: MockEventBatchStore,
+		mut projector: MockProjector<Contribution>,
+		uuid_generator: MockUuidGenerator,
+	) {
+		let created_event = ContributionEvent::Created {
+			id: ContributionId::from(0x1234),
+			project_id: 1,
+			issue_number: 2,
+			gate: 0,
+		};
+
+		event_batch_store
+			.expect_append_batch()
+			.withf(|batch| batch.contribution_events[0].0 == ContributionId::from(0x1234))
+			.times(1)
+			.returning(Ok);
+		projector
+			.expect_project()
+			.with(eq(created_event))
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let result = service(event_store, event_batch_store, projector, uuid_generator)
+			.create(ContributionProjection {
+				project_id: 1,
+				issue_number: 2,
+				gate: 0,
+				..Default::default()
+			})
+			.await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+	}
+
+	#[rstest]
+	#[case::open_contribution(vec![ContributionEvent::Created {
+		id: contribution_id(),
+		project_id: 1,
+		issue_number: 2,
+		gate: 0,
+	}])]
+	#[case::unknown_contribution(vec![])]
+	async fn actions_refused_by_the_contribution_are_reverted(
+		#[case] events: Vec<ContributionEvent>,
+		mut event_batch_store: MockEventBatchStore,
+		mut projector: MockProjector<Contribution>,
+		uuid_generator: MockUuidGenerator,
+		contribution_id: ContributionId,
+	) {
+		let mut event_store = MockEventStore::new();
+		event_store.expect_list_by_id().returning(move |_| Ok(events.clone()));
+		event_batch_store.expect_append_batch().never();
+		projector.expect_project().never();
+
+		let result = service(event_store, event_batch_store, projector, uuid_generator)
+			.validate(contribution_id)
+			.await;
+
+		assert_matches!(result, Err(OnchainContributionServiceError::Reverted(_)));
+	}
+
+	#[rstest]
+	async fn events_are_not_projected_when_they_cannot_be_stored(
+		event_store: MockEventStore<Contribution>,
+		mut event_batch_store: MockEventBatchStore,
+		mut projector: MockProjector<Contribution>,
+		uuid_generator: MockUuidGenerator,
+		contribution_id: ContributionId,
+	) {
+		event_batch_store
+			.expect_append_batch()
+			.returning(|_| Err(EventStoreError::Append(anyhow::anyhow!("oops"))));
+		projector.expect_project().never();
+
+		let result = service(event_store, event_batch_store, projector, uuid_generator)
+			.assign_contributor(contribution_id, ContributorId::from(42))
+			.await;
+
+		assert_matches!(
+			result,
+			Err(OnchainContributionServiceError::Infrastructure(_))
+		);
+	}
+
+	#[rstest]
+	async fn offchain_transactions_are_accepted(
+		event_store: MockEventStore<Contribution>,
+		event_batch_store: MockEventBatchStore,
+		projector: MockProjector<Contribution>,
+		uuid_generator: MockUuidGenerator,
+	) {
+		let status = service(event_store, event_batch_store, projector, uuid_generator)
+			.status(&HexPrefixedString::from_str("0x1234").unwrap())
+			.await;
+
+		assert_matches!(status, Ok(Some(OnchainTransactionStatus::AcceptedOnL2)));
+	}
+}
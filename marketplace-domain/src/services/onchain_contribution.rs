@@ -7,6 +7,14 @@ use thiserror::Error;
 pub enum Error {
 	#[error("Something happend at the infrastructure level")]
 	Infrastructure(#[source] Box<dyn std::error::Error>),
+	#[error("The network is temporarily unavailable")]
+	Unavailable(#[source] Box<dyn std::error::Error>),
+	#[error("The transaction conflicts with other pending transactions")]
+	Conflict(#[source] Box<dyn std::error::Error>),
+	#[error("The transaction fee is above the allowed maximum")]
+	FeeTooHigh(#[source] Box<dyn std::error::Error>),
+	#[error("The transaction was reverted")]
+	Reverted(#[source] Box<dyn std::error::Error>),
 }
 
 #[async_trait]
@@ -26,4 +34,8 @@ pub trait Service: Send + Sync {
 		contribution_id: ContributionId,
 	) -> Result<HexPrefixedString, Error>;
 	async fn validate(&self, contribution_id: ContributionId) -> Result<HexPrefixedString, Error>;
+	async fn execute_actions(&self, actions: Vec<Action>) -> Result<HexPrefixedString, Error>;
+	async fn estimate_fee(&self, actions: Vec<Action>) -> Result<FeeEstimate, Error>;
+	/// Run the actions against the current state of the network without submitting them
+	async fn simulate(&self, actions: Vec<Action>) -> Result<TransactionSimulation, Error>;
 }
@@ -0,0 +1,20 @@
+use crate::*;
+use async_trait::async_trait;
+use mockall::automock;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("Something happend at the infrastructure level")]
+	Infrastructure(#[source] Box<dyn std::error::Error>),
+}
+
+#[async_trait]
+#[automock]
+pub trait Service: Send + Sync {
+	/// Status of the transaction, None when the sequencer has not received it
+	async fn status(
+		&self,
+		hash: &HexPrefixedString,
+	) -> Result<Option<OnchainTransactionStatus>, Error>;
+}
@@ -0,0 +1,234 @@
+use crate::{Error as DomainError, *};
+use futures_timer::Delay;
+use log::{error, info, warn};
+use std::{
+	sync::Arc,
+	time::{Duration, SystemTime},
+};
+
+/// Time given to the sequencer to receive a transaction, after which it is considered dropped
+const NOT_RECEIVED_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Polls StarkNet for the status of pending transactions until they reach a final status
+pub struct Tracker {
+	transaction_repository: Arc<dyn OnchainTransactionRepository>,
+	transaction_service: Arc<dyn OnchainTransactionService>,
+}
+
+impl Tracker {
+	pub fn new(
+		transaction_repository: Arc<dyn OnchainTransactionRepository>,
+		transaction_service: Arc<dyn OnchainTransactionService>,
+	) -> Self {
+		Self {
+			transaction_repository,
+			transaction_service,
+		}
+	}
+
+	/// Refresh the status of every pending transaction and return those whose status changed
+	pub async fn refresh(&self) -> Result<Vec<OnchainTransaction>, DomainError> {
+		let pending_transactions = self.transaction_repository.list_pending()?;
+		let mut updated_transactions = Vec::new();
+
+		for transaction in pending_transactions {
+			let status = match self.transaction_service.status(&transaction.hash).await {
+				Ok(Some(status)) => status,
+				Ok(None) => match transaction.submitted_at.elapsed() {
+					Ok(elapsed) if elapsed >= NOT_RECEIVED_TIMEOUT => {
+						warn!(
+							"Transaction {} was not received after {elapsed:?}",
+							transaction.hash
+						);
+						OnchainTransactionStatus::Dropped
+					},
+					_ => OnchainTransactionStatus::Pending,
+				},
+				Err(error) => {
+					warn!(
+						"Unable to get status of transaction {}: {error}",
+						transaction.hash
+					);
+					continue;
+				},
+			};
+
+			if status == transaction.status {
+				continue;
+			}
+
+			info!("Transaction {} is now {status}", transaction.hash);
+			let transaction = OnchainTransaction {
+				status,
+				updated_at: SystemTime::now(),
+				..transaction
+			};
+			self.transaction_repository.update(transaction.clone())?;
+			updated_transactions.push(transaction);
+		}
+
+		Ok(updated_transactions)
+	}
+
+	/// Refresh pending transactions forever, waiting `poll_interval` between each refresh
+	pub async fn run(&self, poll_interval: Duration) {
+		loop {
+			if let Err(error) = self.refresh().await {
+				error!("Unable to refresh pending transactions: {error}");
+			}
+			Delay::new(poll_interval).await;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::FutureExt;
+	use mockall::predicate::*;
+	use rstest::{fixture, rstest};
+	use std::str::FromStr;
+	use thiserror::Error;
+
+	#[derive(Debug, Error)]
+	#[error("Oops")]
+	struct Error;
+
+	fn transaction(hash: &str) -> OnchainTransaction {
+		OnchainTransaction {
+			hash: HexPrefixedString::from_str(hash).unwrap(),
+			command: String::from("validate_contribution"),
+			issuer: String::from("admin"),
+			actions: vec![Action::ValidateContribution {
+				contribution_id: 12.into(),
+			}],
+			status: OnchainTransactionStatus::Pending,
+			submitted_at: SystemTime::UNIX_EPOCH,
+			updated_at: SystemTime::UNIX_EPOCH,
+		}
+	}
+
+	#[fixture]
+	fn transaction_repository() -> MockOnchainTransactionRepository {
+		let mut transaction_repository = MockOnchainTransactionRepository::new();
+		transaction_repository.expect_list_pending().returning(|| {
+			Ok(vec![
+				transaction("0x01"),
+				transaction("0x02"),
+				transaction("0x03"),
+			])
+		});
+		transaction_repository
+	}
+
+	#[rstest]
+	async fn updates_transactions_whose_status_changed(
+		mut transaction_repository: MockOnchainTransactionRepository,
+	) {
+		let mut transaction_service = MockOnchainTransactionService::new();
+		transaction_service
+			.expect_status()
+			.with(eq(HexPrefixedString::from_str("0x01").unwrap()))
+			.returning(|_| async { Ok(Some(OnchainTransactionStatus::AcceptedOnL2)) }.boxed());
+		transaction_service
+			.expect_status()
+			.with(eq(HexPrefixedString::from_str("0x02").unwrap()))
+			.returning(|_| async { Ok(Some(OnchainTransactionStatus::Pending)) }.boxed());
+		transaction_service
+			.expect_status()
+			.with(eq(HexPrefixedString::from_str("0x03").unwrap()))
+			.returning(|_| async { Ok(Some(OnchainTransactionStatus::Reverted)) }.boxed());
+
+		transaction_repository
+			.expect_update()
+			.withf(|transaction| {
+				transaction.hash == HexPrefixedString::from_str("0x01").unwrap()
+					&& transaction.status == OnchainTransactionStatus::AcceptedOnL2
+			})
+			.times(1)
+			.returning(|_| Ok(()));
+		transaction_repository
+			.expect_update()
+			.withf(|transaction| {
+				transaction.hash == HexPrefixedString::from_str("0x03").unwrap()
+					&& transaction.status == OnchainTransactionStatus::Reverted
+			})
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let tracker = Tracker::new(
+			Arc::new(transaction_repository),
+			Arc::new(transaction_service),
+		);
+
+		let updated_transactions = tracker.refresh().await.unwrap();
+		assert_eq!(2, updated_transactions.len());
+	}
+
+	#[rstest]
+	async fn drops_transactions_not_received_for_too_long() {
+		let mut transaction_repository = MockOnchainTransactionRepository::new();
+		transaction_repository.expect_list_pending().returning(|| {
+			Ok(vec![
+				transaction("0x01"),
+				OnchainTransaction {
+					submitted_at: SystemTime::now(),
+					..transaction("0x02")
+				},
+			])
+		});
+		transaction_repository
+			.expect_update()
+			.withf(|transaction| {
+				transaction.hash == HexPrefixedString::from_str("0x01").unwrap()
+					&& transaction.status == OnchainTransactionStatus::Dropped
+			})
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let mut transaction_service = MockOnchainTransactionService::new();
+		transaction_service.expect_status().returning(|_| async { Ok(None) }.boxed());
+
+		let tracker = Tracker::new(
+			Arc::new(transaction_repository),
+			Arc::new(transaction_service),
+		);
+
+		let updated_transactions = tracker.refresh().await.unwrap();
+		assert_eq!(1, updated_transactions.len());
+	}
+
+	#[rstest]
+	async fn skips_transactions_whose_status_is_unavailable(
+		mut transaction_repository: MockOnchainTransactionRepository,
+	) {
+		let mut transaction_service = MockOnchainTransactionService::new();
+		transaction_service
+			.expect_status()
+			.with(eq(HexPrefixedString::from_str("0x01").unwrap()))
+			.returning(|_| {
+				async {
+					Err(OnchainTransactionServiceError::Infrastructure(Box::new(
+						Error,
+					)))
+				}
+				.boxed()
+			});
+		transaction_service
+			.expect_status()
+			.returning(|_| async { Ok(Some(OnchainTransactionStatus::Rejected)) }.boxed());
+
+		transaction_repository.expect_update().times(2).returning(|_| Ok(()));
+
+		let tracker = Tracker::new(
+			Arc::new(transaction_repository),
+			Arc::new(transaction_service),
+		);
+
+		let updated_transactions = tracker.refresh().await.unwrap();
+		assert_eq!(2, updated_transactions.len());
+		assert!(updated_transactions
+			.iter()
+			.all(|transaction| transaction.status == OnchainTransactionStatus::Rejected));
+	}
+}
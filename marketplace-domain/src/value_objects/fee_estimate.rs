@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Fees of a transaction, in wei
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct FeeEstimate {
+	/// Fee the transaction is expected to cost
+	pub estimated_fee: u64,
+	/// Maximum fee the transaction is allowed to cost
+	pub max_fee: u64,
+}
@@ -10,6 +10,12 @@ pub use u256::{u256_from_string, ParseU256Error};
 mod contract_address;
 pub use contract_address::ContractAddress;
 
+mod fee_estimate;
+pub use fee_estimate::FeeEstimate;
+
+mod transaction_simulation;
+pub use transaction_simulation::TransactionSimulation;
+
 mod github;
 pub use github::{
 	Issue as GithubIssue, IssueNumber as GithubIssueNumber, ProjectId as GithubProjectId,
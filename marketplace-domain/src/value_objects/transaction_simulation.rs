@@ -0,0 +1,17 @@
+use crate::*;
+
+/// Outcome of a transaction that was simulated instead of being sent
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TransactionSimulation {
+	Succeeded(FeeEstimate),
+	Reverted { reason: String },
+}
+
+impl TransactionSimulation {
+	pub fn revert_reason(&self) -> Option<&str> {
+		match self {
+			Self::Succeeded(_) => None,
+			Self::Reverted { reason } => Some(reason),
+		}
+	}
+}
@@ -0,0 +1,272 @@
+use super::IndexerBuilder;
+use crate::domain::*;
+use marketplace_domain::ContractAddress;
+use serde::Deserialize;
+use std::{collections::HashSet, fs, path::Path, str::FromStr, sync::Arc};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("unable to read the configuration file {path}: {source}")]
+	Read {
+		path: String,
+		source: std::io::Error,
+	},
+	#[error("invalid configuration: {0}")]
+	Parse(#[from] toml::de::Error),
+	#[error("no indexer is configured")]
+	NoIndexer,
+	#[error("indexer `{0}` is configured more than once")]
+	DuplicateIndexer(String),
+	#[error("{0} must be set")]
+	MissingVariable(String),
+	#[error("`{address}` of indexer `{indexer}` is not a valid contract address")]
+	InvalidContractAddress { indexer: String, address: String },
+	#[error(transparent)]
+	Build(#[from] crate::domain::Error),
+}
+
+/// Indexers to run, as described in the TOML configuration file
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+	pub indexers: Vec<IndexerConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IndexerConfig {
+	pub id: String,
+	#[serde(default)]
+	pub network: Network,
+	#[serde(default)]
+	pub start_block: u64,
+	#[serde(default)]
+	pub on_conflict: OnConflict,
+	pub contracts: Vec<ContractConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContractConfig {
+	/// Either the address itself, or `$VARIABLE` to read it from the environment
+	pub address: String,
+	/// Names of the events to index, all the events of the contract when empty
+	#[serde(default)]
+	pub events: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OnConflict {
+	#[default]
+	DoNothing,
+	Recreate,
+}
+
+impl Config {
+	pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+		let content = fs::read_to_string(&path).map_err(|source| Error::Read {
+			path: path.as_ref().display().to_string(),
+			source,
+		})?;
+		content.parse()
+	}
+}
+
+impl FromStr for Config {
+	type Err = Error;
+
+	fn from_str(content: &str) -> Result<Self, Self::Err> {
+		let config: Self = toml::from_str(content)?;
+
+		if config.indexers.is_empty() {
+			return Err(Error::NoIndexer);
+		}
+
+		let mut ids = HashSet::new();
+		for indexer in &config.indexers {
+			if !ids.insert(&indexer.id) {
+				return Err(Error::DuplicateIndexer(indexer.id.clone()));
+			}
+		}
+
+		Ok(config)
+	}
+}
+
+impl IndexerConfig {
+	/// Create the indexer, or find it if it already exists
+	pub async fn build(
+		&self,
+		indexer_repository: Arc<dyn IndexerRepository>,
+	) -> Result<Indexer, Error> {
+		let mut builder = IndexerBuilder::new(indexer_repository);
+		builder.network(self.network.clone()).start_at_block(self.start_block);
+
+		match self.on_conflict {
+			OnConflict::DoNothing => builder.on_conflict_do_nothing(),
+			OnConflict::Recreate => builder.on_conflict_recreate(),
+		};
+
+		for contract in &self.contracts {
+			let contract_address = self.contract_address(&contract.address)?;
+			if contract.events.is_empty() {
+				builder.filter(contract_address, "");
+			}
+			for event in &contract.events {
+				builder.filter(contract_address.clone(), event);
+			}
+		}
+
+		Ok(builder.build(self.id.as_str().into()).await?)
+	}
+
+	fn contract_address(&self, address: &str) -> Result<ContractAddress, Error> {
+		let address = match address.strip_prefix('$') {
+			Some(variable) =>
+				std::env::var(variable).map_err(|_| Error::MissingVariable(variable.to_string()))?,
+			None => address.to_string(),
+		};
+
+		address.parse().map_err(|_| Error::InvalidContractAddress {
+			indexer: self.id.clone(),
+			address,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use mockall::predicate::*;
+	use rstest::*;
+
+	const CONFIG: &str = r#"
+		[[indexers]]
+		id = "contribution-indexer"
+		network = "starknet"
+		start_block = 311611
+
+		[[indexers.contracts]]
+		address = "0x1234"
+		events = ["ContributionCreated", "ContributionValidated"]
+
+		[[indexers]]
+		id = "profile-indexer"
+		on_conflict = "recreate"
+
+		[[indexers.contracts]]
+		address = "$TEST_PROFILE_ADDRESS"
+	"#;
+
+	#[rstest]
+	fn configuration_can_be_parsed() {
+		let config = Config::from_str(CONFIG).unwrap();
+
+		assert_eq!(
+			vec![
+				IndexerConfig {
+					id: String::from("contribution-indexer"),
+					network: Network::Starknet,
+					start_block: 311611,
+					on_conflict: OnConflict::DoNothing,
+					contracts: vec![ContractConfig {
+						address: String::from("0x1234"),
+						events: vec![
+							String::from("ContributionCreated"),
+							String::from("ContributionValidated")
+						],
+					}],
+				},
+				IndexerConfig {
+					id: String::from("profile-indexer"),
+					network: Network::Starknet,
+					start_block: 0,
+					on_conflict: OnConflict::Recreate,
+					contracts: vec![ContractConfig {
+						address: String::from("$TEST_PROFILE_ADDRESS"),
+						events: vec![],
+					}],
+				},
+			],
+			config.indexers
+		);
+	}
+
+	#[rstest]
+	#[case("indexers = []", "no indexer is configured")]
+	#[case(
+		"[[indexers]]\nid = \"ID\"\ncontracts = []\n[[indexers]]\nid = \"ID\"\ncontracts = []",
+		"indexer `ID` is configured more than once"
+	)]
+	#[case(
+		"[[indexers]]\nid = \"ID\"\ncontracts = []\nnetwork = \"ethereum\"",
+		"invalid configuration"
+	)]
+	fn invalid_configurations_are_rejected(#[case] config: &str, #[case] message: &str) {
+		let error = Config::from_str(config).unwrap_err();
+		assert!(error.to_string().contains(message), "{error}");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn indexer_is_built_from_its_configuration() {
+		std::env::set_var("TEST_PROFILE_ADDRESS", "0x5678");
+		let config = Config::from_str(CONFIG).unwrap();
+
+		let mut indexer_repository = MockIndexerRepository::new();
+		indexer_repository.expect_by_id().returning(|_| Ok(None));
+		indexer_repository
+			.expect_create()
+			.with(eq(Indexer::new(
+				"contribution-indexer".into(),
+				Network::Starknet,
+				311611,
+				vec![
+					EventFilter::new(
+						ContractAddress::from_str("0x1234").unwrap(),
+						"ContributionCreated",
+					),
+					EventFilter::new(
+						ContractAddress::from_str("0x1234").unwrap(),
+						"ContributionValidated",
+					),
+				],
+			)))
+			.times(1)
+			.returning(|_| Ok(()));
+		indexer_repository
+			.expect_create()
+			.with(eq(Indexer::new(
+				"profile-indexer".into(),
+				Network::Starknet,
+				0,
+				vec![EventFilter::new(
+					ContractAddress::from_str("0x5678").unwrap(),
+					"",
+				)],
+			)))
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let indexer_repository = Arc::new(indexer_repository);
+		for indexer in config.indexers {
+			indexer.build(indexer_repository.clone()).await.unwrap();
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn missing_variables_are_reported() {
+		let config = Config::from_str(
+			"[[indexers]]\nid = \"ID\"\n[[indexers.contracts]]\naddress = \"$TEST_UNSET_ADDRESS\"",
+		)
+		.unwrap();
+
+		let result = config.indexers[0].build(Arc::new(MockIndexerRepository::new())).await;
+		assert!(
+			matches!(result, Err(Error::MissingVariable(variable)) if variable == "TEST_UNSET_ADDRESS")
+		);
+	}
+}
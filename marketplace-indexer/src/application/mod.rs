@@ -1,2 +1,11 @@
 mod builders;
 pub use builders::*;
+
+mod config;
+pub use config::{Config as IndexersConfig, IndexerConfig, OnConflict};
+
+mod supervisor;
+pub use supervisor::{IndexingFailures, Supervisor as IndexingSupervisor};
+
+mod status;
+pub use status::{ProcessedBlock, Progress as IndexingProgress, StatusTracker};
@@ -0,0 +1,186 @@
+use super::IndexingFailures;
+use crate::domain::*;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::{
+	sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+	time::SystemTime,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProcessedBlock {
+	pub number: u64,
+	pub hash: BlockHash,
+}
+
+/// What the indexing stream went through so far
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+	pub connected: bool,
+	pub last_block: Option<ProcessedBlock>,
+	/// Highest block number seen on the stream
+	pub highest_block_number: Option<u64>,
+	pub events_processed: u64,
+	pub last_error: Option<String>,
+	pub last_progress_at: Option<SystemTime>,
+}
+
+/// Keep track of the progress of an indexer, to be reported by the status server.
+/// It must observe the stream after the other observers, so that blocks are counted once
+/// processed.
+pub struct StatusTracker {
+	indexer_id: IndexerId,
+	network: Network,
+	failures: Arc<IndexingFailures>,
+	progress: RwLock<Progress>,
+}
+
+impl StatusTracker {
+	pub fn new(indexer_id: IndexerId, network: Network, failures: Arc<IndexingFailures>) -> Self {
+		Self {
+			indexer_id,
+			network,
+			failures,
+			progress: Default::default(),
+		}
+	}
+
+	pub fn indexer_id(&self) -> &IndexerId {
+		&self.indexer_id
+	}
+
+	pub fn network(&self) -> &Network {
+		&self.network
+	}
+
+	pub fn failures(&self) -> &IndexingFailures {
+		&self.failures
+	}
+
+	pub fn progress(&self) -> Progress {
+		self.read().clone()
+	}
+
+	fn read(&self) -> RwLockReadGuard<'_, Progress> {
+		self.progress
+			.read()
+			.expect("Could not acquire lock to read the indexing progress")
+	}
+
+	fn write(&self) -> RwLockWriteGuard<'_, Progress> {
+		self.progress
+			.write()
+			.expect("Could not acquire lock to update the indexing progress")
+	}
+
+	fn record_block(
+		&self,
+		block_hash: &BlockHash,
+		block_number: u64,
+	) -> RwLockWriteGuard<'_, Progress> {
+		let mut progress = self.write();
+		progress.last_block = Some(ProcessedBlock {
+			number: block_number,
+			hash: block_hash.clone(),
+		});
+		progress.highest_block_number =
+			Some(progress.highest_block_number.map_or(block_number, |n| n.max(block_number)));
+		progress.last_progress_at = Some(SystemTime::now());
+		progress
+	}
+}
+
+#[async_trait]
+impl BlockchainObserver for StatusTracker {
+	async fn on_connect(&self, _indexer_id: &IndexerId) {
+		self.write().connected = true;
+	}
+
+	async fn on_disconnect(&self, _indexer_id: &IndexerId, error: &IndexingServiceError) {
+		let mut progress = self.write();
+		progress.connected = false;
+		progress.last_error = Some(error.to_string());
+	}
+
+	async fn on_new_block_events(
+		&self,
+		events: &[ObservedEvent],
+		block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), BlockchainObserverError> {
+		self.record_block(block_hash, block_number).events_processed += events.len() as u64;
+		Ok(())
+	}
+
+	async fn on_new_block(&self, block_hash: &BlockHash, block_number: u64) {
+		self.record_block(block_hash, block_number);
+	}
+
+	async fn on_reorg(&self, block_hash: &BlockHash, block_number: u64) {
+		let mut progress = self.write();
+		progress.last_block = Some(ProcessedBlock {
+			number: block_number,
+			hash: block_hash.clone(),
+		});
+		progress.highest_block_number = Some(block_number);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	fn hash(value: &str) -> BlockHash {
+		BlockHash::from_str(value).unwrap()
+	}
+
+	#[tokio::test]
+	async fn progress_follows_the_stream() {
+		let tracker =
+			StatusTracker::new(IndexerId::from("ID"), Network::Starknet, Default::default());
+
+		tracker.on_connect(&IndexerId::from("ID")).await;
+		tracker
+			.on_new_block_events(
+				&[ObservedEvent::default(), ObservedEvent::default()],
+				&hash("0x2a"),
+				42,
+			)
+			.await
+			.unwrap();
+		tracker.on_new_block(&hash("0x2b"), 43).await;
+		tracker.on_reorg(&hash("0x29"), 41).await;
+
+		let progress = tracker.progress();
+		assert!(progress.connected);
+		assert_eq!(
+			Some(ProcessedBlock {
+				number: 41,
+				hash: hash("0x29")
+			}),
+			progress.last_block
+		);
+		assert_eq!(Some(41), progress.highest_block_number);
+		assert_eq!(2, progress.events_processed);
+		assert!(progress.last_progress_at.is_some());
+	}
+
+	#[tokio::test]
+	async fn failures_disconnect_the_indexer() {
+		let tracker =
+			StatusTracker::new(IndexerId::from("ID"), Network::Starknet, Default::default());
+
+		tracker.on_connect(&IndexerId::from("ID")).await;
+		tracker
+			.on_disconnect(&IndexerId::from("ID"), &IndexingServiceError::Closed)
+			.await;
+
+		let progress = tracker.progress();
+		assert!(!progress.connected);
+		assert_eq!(
+			Some(IndexingServiceError::Closed.to_string()),
+			progress.last_error
+		);
+	}
+}
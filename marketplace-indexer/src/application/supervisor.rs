@@ -0,0 +1,240 @@
+use crate::domain::*;
+use async_trait::async_trait;
+use log::{error, info};
+use rand::Rng;
+use std::{
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Failures of the indexing stream, for monitoring
+#[derive(Debug, Default)]
+pub struct IndexingFailures {
+	consecutive: AtomicU64,
+	total: AtomicU64,
+}
+
+impl IndexingFailures {
+	pub fn consecutive(&self) -> u64 {
+		self.consecutive.load(Ordering::Relaxed)
+	}
+
+	pub fn total(&self) -> u64 {
+		self.total.load(Ordering::Relaxed)
+	}
+
+	fn record(&self) -> u64 {
+		self.total.fetch_add(1, Ordering::Relaxed);
+		self.consecutive.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn reset(&self) {
+		self.consecutive.store(0, Ordering::Relaxed);
+	}
+}
+
+/**
+ * The Supervisor reconnects an indexing service whenever its stream fails, waiting longer after
+ * each consecutive failure. Indexing resumes where it stopped, be it from the last block
+ * acknowledged to Apibara or from the last checkpoint.
+ */
+pub struct Supervisor {
+	indexing_service: Arc<dyn IndexingService>,
+	failures: Arc<IndexingFailures>,
+	initial_backoff: Duration,
+	max_backoff: Duration,
+}
+
+impl Supervisor {
+	pub fn new(indexing_service: Arc<dyn IndexingService>) -> Self {
+		Self {
+			indexing_service,
+			failures: Default::default(),
+			initial_backoff: INITIAL_BACKOFF,
+			max_backoff: MAX_BACKOFF,
+		}
+	}
+
+	/// Modify the delays between reconnections
+	#[cfg(test)]
+	pub fn backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+		self.initial_backoff = initial_backoff;
+		self.max_backoff = max_backoff;
+		self
+	}
+
+	pub fn failures(&self) -> Arc<IndexingFailures> {
+		self.failures.clone()
+	}
+
+	/// Exponential delay before reconnecting, half of it being random to spread reconnections
+	fn delay(&self, consecutive_failures: u64) -> Duration {
+		let exponent = consecutive_failures.saturating_sub(1).min(31) as u32;
+		let delay = self.initial_backoff.saturating_mul(2u32.pow(exponent)).min(self.max_backoff);
+		let half = delay / 2;
+		half + half.mul_f64(rand::thread_rng().gen::<f64>())
+	}
+}
+
+#[async_trait]
+impl IndexingService for Supervisor {
+	/// Only returns when the indexing service runs out of events
+	async fn fetch_new_events(
+		&self,
+		indexer: &Indexer,
+		observer: Arc<dyn BlockchainObserver>,
+	) -> Result<(), IndexingServiceError> {
+		let observer: Arc<dyn BlockchainObserver> = Arc::new(ProgressObserver {
+			observer,
+			failures: self.failures.clone(),
+		});
+
+		loop {
+			match self.indexing_service.fetch_new_events(indexer, observer.clone()).await {
+				Ok(()) => return Ok(()),
+				Err(error) => {
+					observer.on_disconnect(&indexer.id, &error).await;
+					let consecutive_failures = self.failures.record();
+					let delay = self.delay(consecutive_failures);
+					error!(
+						"Indexer `{}` failed {consecutive_failures} time(s) in a row: {error}",
+						indexer.id
+					);
+					info!("Reconnecting indexer `{}` in {delay:?}", indexer.id);
+					tokio::time::sleep(delay).await;
+				},
+			}
+		}
+	}
+}
+
+/// Clear the consecutive failures as soon as the indexing makes progress again
+struct ProgressObserver {
+	observer: Arc<dyn BlockchainObserver>,
+	failures: Arc<IndexingFailures>,
+}
+
+#[async_trait]
+impl BlockchainObserver for ProgressObserver {
+	async fn on_connect(&self, indexer_id: &IndexerId) {
+		self.observer.on_connect(indexer_id).await;
+	}
+
+	async fn on_disconnect(&self, indexer_id: &IndexerId, error: &IndexingServiceError) {
+		self.observer.on_disconnect(indexer_id, error).await;
+	}
+
+	async fn on_new_event(&self, event: &ObservedEvent, block_hash: &BlockHash, block_number: u64) {
+		self.failures.reset();
+		self.observer.on_new_event(event, block_hash, block_number).await;
+	}
+
+	async fn on_new_block_events(
+		&self,
+		events: &[ObservedEvent],
+		block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), BlockchainObserverError> {
+		self.observer.on_new_block_events(events, block_hash, block_number).await?;
+		self.failures.reset();
+		Ok(())
+	}
+
+	async fn on_new_block(&self, block_hash: &BlockHash, block_number: u64) {
+		self.failures.reset();
+		self.observer.on_new_block(block_hash, block_number).await;
+	}
+
+	async fn on_reorg(&self, block_hash: &BlockHash, block_number: u64) {
+		self.observer.on_reorg(block_hash, block_number).await;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::*;
+
+	#[fixture]
+	fn indexer() -> Indexer {
+		Indexer::new("ID".into(), Network::Starknet, 0, vec![])
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn supervisor_reconnects_after_failures(indexer: Indexer) {
+		let mut indexing_service = MockIndexingService::new();
+		let mut attempts = 0;
+		indexing_service.expect_fetch_new_events().times(3).returning(move |_, _| {
+			attempts += 1;
+			match attempts {
+				3 => Ok(()),
+				_ => Err(IndexingServiceError::Closed),
+			}
+		});
+
+		let supervisor =
+			Supervisor::new(Arc::new(indexing_service)).backoff(Duration::ZERO, Duration::ZERO);
+		let mut observer = MockBlockchainObserver::new();
+		observer.expect_on_disconnect().times(2).return_const(());
+
+		let result = supervisor.fetch_new_events(&indexer, Arc::new(observer)).await;
+
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(2, supervisor.failures().total());
+		assert_eq!(2, supervisor.failures().consecutive());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn progress_clears_consecutive_failures(indexer: Indexer) {
+		let mut indexing_service = MockIndexingService::new();
+		let mut attempts = 0;
+		indexing_service
+			.expect_fetch_new_events()
+			.times(3)
+			.returning(move |_, observer| {
+				attempts += 1;
+				match attempts {
+					2 => {
+						futures::executor::block_on(observer.on_new_block(&Default::default(), 1));
+						Err(IndexingServiceError::Closed)
+					},
+					3 => Ok(()),
+					_ => Err(IndexingServiceError::Closed),
+				}
+			});
+
+		let mut observer = MockBlockchainObserver::new();
+		observer.expect_on_new_block().times(1).return_const(());
+		observer.expect_on_disconnect().times(2).return_const(());
+
+		let supervisor =
+			Supervisor::new(Arc::new(indexing_service)).backoff(Duration::ZERO, Duration::ZERO);
+		supervisor.fetch_new_events(&indexer, Arc::new(observer)).await.unwrap();
+
+		assert_eq!(2, supervisor.failures().total());
+		assert_eq!(1, supervisor.failures().consecutive());
+	}
+
+	#[rstest]
+	#[case(1, Duration::from_millis(500), Duration::from_secs(1))]
+	#[case(3, Duration::from_secs(2), Duration::from_secs(4))]
+	#[case(30, Duration::from_secs(30), Duration::from_secs(60))]
+	fn backoff_grows_exponentially_with_jitter(
+		#[case] consecutive_failures: u64,
+		#[case] min_delay: Duration,
+		#[case] max_delay: Duration,
+	) {
+		let supervisor = Supervisor::new(Arc::new(MockIndexingService::new()));
+
+		let delay = supervisor.delay(consecutive_failures);
+		assert!(min_delay <= delay && delay <= max_delay, "{delay:?}");
+	}
+}
@@ -0,0 +1,313 @@
+use crate::{application::IndexerBuilder, domain::*};
+use std::{io::Write, sync::Arc};
+use thiserror::Error;
+
+pub const USAGE: &str = "Usage: marketplace-indexer [COMMAND]
+
+Without command, run the configured indexers.
+
+Commands:
+  list                                        List the indexers
+  show <indexer-id>                           Show an indexer and its filters
+  delete <indexer-id>                         Delete an indexer along with its progress
+  recreate-from-block <indexer-id> <block>    Recreate an indexer to index again from a block
+  decode-only <indexer-id> [block]            Print the decoded events of a configured indexer,
+                                              without writing to the database";
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("{0}")]
+	Usage(String),
+	#[error("indexer `{0}` not found")]
+	NotFound(IndexerId),
+	#[error(transparent)]
+	Repository(#[from] IndexerRepositoryError),
+	#[error(transparent)]
+	Build(#[from] crate::domain::Error),
+	#[error("unable to write the output: {0}")]
+	Output(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+	/// Run the configured indexers
+	Run,
+	/// Stream the events of a configured indexer, from its start block unless given another one
+	DecodeOnly {
+		indexer_id: String,
+		from_block: Option<u64>,
+	},
+	Administrate(Administration),
+}
+
+/// Operations on the indexers known by the indexing service
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Administration {
+	List,
+	Show(IndexerId),
+	Delete(IndexerId),
+	RecreateFromBlock {
+		indexer_id: IndexerId,
+		block_number: u64,
+	},
+}
+
+impl Command {
+	pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, Error> {
+		let args: Vec<String> = args.into_iter().collect();
+		let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+		let command = match args.as_slice() {
+			[] => Self::Run,
+			["list"] => Self::Administrate(Administration::List),
+			["show", indexer_id] => Self::Administrate(Administration::Show((*indexer_id).into())),
+			["delete", indexer_id] =>
+				Self::Administrate(Administration::Delete((*indexer_id).into())),
+			["recreate-from-block", indexer_id, block_number] =>
+				Self::Administrate(Administration::RecreateFromBlock {
+					indexer_id: (*indexer_id).into(),
+					block_number: parse_block_number(block_number)?,
+				}),
+			["decode-only", indexer_id] => Self::DecodeOnly {
+				indexer_id: indexer_id.to_string(),
+				from_block: None,
+			},
+			["decode-only", indexer_id, block_number] => Self::DecodeOnly {
+				indexer_id: indexer_id.to_string(),
+				from_block: Some(parse_block_number(block_number)?),
+			},
+			_ =>
+				return Err(Error::Usage(format!(
+					"invalid arguments `{}`",
+					args.join(" ")
+				))),
+		};
+
+		Ok(command)
+	}
+}
+
+fn parse_block_number(block_number: &str) -> Result<u64, Error> {
+	block_number
+		.parse()
+		.map_err(|_| Error::Usage(format!("`{block_number}` is not a valid block number")))
+}
+
+impl Administration {
+	pub async fn execute(
+		&self,
+		indexer_repository: Arc<dyn IndexerRepository>,
+		output: &mut dyn Write,
+	) -> Result<(), Error> {
+		match self {
+			Self::List => {
+				let indexers = indexer_repository.list().await?;
+				if indexers.is_empty() {
+					writeln!(output, "No indexer")?;
+				}
+				for indexer in indexers {
+					writeln!(
+						output,
+						"{} ({}) from block {}, {} filter(s)",
+						indexer.id,
+						indexer.network.to_string(),
+						indexer.index_from_block,
+						indexer.filters.len()
+					)?;
+				}
+			},
+			Self::Show(indexer_id) => {
+				let indexer = find(indexer_repository.as_ref(), indexer_id).await?;
+				writeln!(output, "{}", indexer.id)?;
+				writeln!(output, "  network: {}", indexer.network.to_string())?;
+				writeln!(output, "  index from block: {}", indexer.index_from_block)?;
+				writeln!(output, "  filters:")?;
+				for filter in indexer.filters {
+					let event_name = match filter.event_name.as_str() {
+						"" => "all events",
+						event_name => event_name,
+					};
+					writeln!(output, "    {} {event_name}", filter.contract_address)?;
+				}
+			},
+			Self::Delete(indexer_id) => {
+				indexer_repository.delete(indexer_id).await?;
+				writeln!(output, "Indexer `{indexer_id}` deleted")?;
+			},
+			Self::RecreateFromBlock {
+				indexer_id,
+				block_number,
+			} => {
+				let indexer = find(indexer_repository.as_ref(), indexer_id).await?;
+
+				let mut builder = IndexerBuilder::new(indexer_repository);
+				builder
+					.network(indexer.network)
+					.start_at_block(*block_number)
+					.on_conflict_recreate();
+				for filter in indexer.filters {
+					builder.filter(filter.contract_address, filter.event_name);
+				}
+				builder.build(indexer.id).await?;
+
+				writeln!(
+					output,
+					"Indexer `{indexer_id}` recreated from block {block_number}"
+				)?;
+			},
+		}
+
+		Ok(())
+	}
+}
+
+async fn find(
+	indexer_repository: &dyn IndexerRepository,
+	indexer_id: &IndexerId,
+) -> Result<Indexer, Error> {
+	indexer_repository
+		.by_id(indexer_id)
+		.await?
+		.ok_or_else(|| Error::NotFound(indexer_id.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use marketplace_domain::ContractAddress;
+	use mockall::predicate::*;
+	use rstest::*;
+	use std::str::FromStr;
+
+	#[fixture]
+	fn indexer() -> Indexer {
+		Indexer::new(
+			"ID".into(),
+			Network::Starknet,
+			42,
+			vec![
+				EventFilter::new(ContractAddress::from_str("0x1234").unwrap(), ""),
+				EventFilter::new(
+					ContractAddress::from_str("0x5678").unwrap(),
+					"ContributionCreated",
+				),
+			],
+		)
+	}
+
+	#[rstest]
+	#[case(vec![], Command::Run)]
+	#[case(vec!["list"], Command::Administrate(Administration::List))]
+	#[case(vec!["show", "ID"], Command::Administrate(Administration::Show("ID".into())))]
+	#[case(vec!["delete", "ID"], Command::Administrate(Administration::Delete("ID".into())))]
+	#[case(
+		vec!["recreate-from-block", "ID", "42"],
+		Command::Administrate(Administration::RecreateFromBlock { indexer_id: "ID".into(), block_number: 42 })
+	)]
+	#[case(vec!["decode-only", "ID"], Command::DecodeOnly { indexer_id: String::from("ID"), from_block: None })]
+	#[case(vec!["decode-only", "ID", "42"], Command::DecodeOnly { indexer_id: String::from("ID"), from_block: Some(42) })]
+	fn commands_are_parsed(#[case] args: Vec<&str>, #[case] expected_command: Command) {
+		let command = Command::parse(args.into_iter().map(String::from)).unwrap();
+		assert_eq!(expected_command, command);
+	}
+
+	#[rstest]
+	#[case(vec!["unknown"], "invalid arguments `unknown`")]
+	#[case(vec!["show"], "invalid arguments `show`")]
+	#[case(vec!["recreate-from-block", "ID", "latest"], "`latest` is not a valid block number")]
+	fn invalid_commands_are_rejected(#[case] args: Vec<&str>, #[case] message: &str) {
+		let error = Command::parse(args.into_iter().map(String::from)).unwrap_err();
+		assert_eq!(message, error.to_string());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn indexers_can_be_listed(indexer: Indexer) {
+		let mut indexer_repository = MockIndexerRepository::new();
+		indexer_repository.expect_list().returning(move || Ok(vec![indexer.clone()]));
+
+		let mut output = Vec::new();
+		Administration::List
+			.execute(Arc::new(indexer_repository), &mut output)
+			.await
+			.unwrap();
+
+		assert_eq!(
+			"ID (starknet) from block 42, 2 filter(s)\n",
+			String::from_utf8(output).unwrap()
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn indexer_can_be_shown(indexer: Indexer) {
+		let mut indexer_repository = MockIndexerRepository::new();
+		indexer_repository
+			.expect_by_id()
+			.with(eq(IndexerId::from("ID")))
+			.returning(move |_| Ok(Some(indexer.clone())));
+
+		let mut output = Vec::new();
+		Administration::Show("ID".into())
+			.execute(Arc::new(indexer_repository), &mut output)
+			.await
+			.unwrap();
+
+		let output = String::from_utf8(output).unwrap();
+		assert!(output.contains("  index from block: 42\n"), "{output}");
+		assert!(output.contains(" all events\n"), "{output}");
+		assert!(output.contains(" ContributionCreated\n"), "{output}");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn unknown_indexer_is_reported() {
+		let mut indexer_repository = MockIndexerRepository::new();
+		indexer_repository.expect_by_id().returning(|_| Ok(None));
+
+		let result = Administration::Show("ID".into())
+			.execute(Arc::new(indexer_repository), &mut Vec::new())
+			.await;
+
+		assert!(
+			matches!(result, Err(Error::NotFound(indexer_id)) if indexer_id == IndexerId::from("ID"))
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn indexer_can_be_recreated_from_a_block(indexer: Indexer) {
+		let mut indexer_repository = MockIndexerRepository::new();
+		let existing_indexer = indexer.clone();
+		indexer_repository
+			.expect_by_id()
+			.returning(move |_| Ok(Some(existing_indexer.clone())));
+		indexer_repository
+			.expect_delete()
+			.with(eq(IndexerId::from("ID")))
+			.times(1)
+			.returning(|_| Ok(()));
+		indexer_repository
+			.expect_create()
+			.with(eq(Indexer {
+				index_from_block: 1000,
+				..indexer
+			}))
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let mut output = Vec::new();
+		Administration::RecreateFromBlock {
+			indexer_id: "ID".into(),
+			block_number: 1000,
+		}
+		.execute(Arc::new(indexer_repository), &mut output)
+		.await
+		.unwrap();
+
+		assert_eq!(
+			"Indexer `ID` recreated from block 1000\n",
+			String::from_utf8(output).unwrap()
+		);
+	}
+}
@@ -15,7 +15,9 @@ pub use error::Error;
 
 mod obervers;
 pub use obervers::{
-	ConfirmedObserver, ContributionObserver, Logger as BlockchainLogger,
-	MockObserver as MockBlockchainObserver, ObservedEvent, Observer as BlockchainObserver,
-	ObserverComposite as BlockchainObserverComposite,
+	ConfirmedObserver, ContributionObserver, ContributorObserver, Error as BlockchainObserverError,
+	EventStoreLogger, Logger as BlockchainLogger, MockObserver as MockBlockchainObserver,
+	ObservedEvent, Observer as BlockchainObserver,
+	ObserverComposite as BlockchainObserverComposite, Recorder as BlockchainRecorder,
+	ReorgObserver,
 };
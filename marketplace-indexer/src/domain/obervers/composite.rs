@@ -18,8 +18,30 @@ impl Observer for ObserverComposite {
 		join_all(self.0.iter().map(|observer| observer.on_connect(indexer_id))).await;
 	}
 
-	async fn on_new_event(&self, event: &ObservedEvent, block_number: u64) {
-		join_all(self.0.iter().map(|observer| observer.on_new_event(event, block_number))).await;
+	async fn on_disconnect(&self, indexer_id: &IndexerId, error: &IndexingServiceError) {
+		join_all(self.0.iter().map(|observer| observer.on_disconnect(indexer_id, error))).await;
+	}
+
+	async fn on_new_event(&self, event: &ObservedEvent, block_hash: &BlockHash, block_number: u64) {
+		join_all(
+			self.0
+				.iter()
+				.map(|observer| observer.on_new_event(event, block_hash, block_number)),
+		)
+		.await;
+	}
+
+	async fn on_new_block_events(
+		&self,
+		events: &[ObservedEvent],
+		block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), Error> {
+		// Observers are run one after the other, so that events are projected once stored
+		for observer in &self.0 {
+			observer.on_new_block_events(events, block_hash, block_number).await?;
+		}
+		Ok(())
 	}
 
 	async fn on_new_block(&self, block_hash: &BlockHash, block_number: u64) {
@@ -27,8 +49,8 @@ impl Observer for ObserverComposite {
 			.await;
 	}
 
-	async fn on_reorg(&self) {
-		join_all(self.0.iter().map(|observer| observer.on_reorg())).await;
+	async fn on_reorg(&self, block_hash: &BlockHash, block_number: u64) {
+		join_all(self.0.iter().map(|observer| observer.on_reorg(block_hash, block_number))).await;
 	}
 }
 
@@ -44,27 +66,66 @@ mod test {
 		ObservedEvent::default()
 	}
 
+	#[fixture]
+	fn block_hash() -> BlockHash {
+		BlockHash::from_str("0x1234").unwrap()
+	}
+
 	#[fixture]
 	fn block_number() -> u64 {
 		42
 	}
 
 	#[rstest]
-	async fn on_new_event(event: ObservedEvent, block_number: u64) {
+	async fn on_new_event(event: ObservedEvent, block_hash: BlockHash, block_number: u64) {
 		let mut observer1 = MockObserver::new();
 		observer1
 			.expect_on_new_event()
-			.with(eq(event.clone()), eq(block_number))
+			.with(eq(event.clone()), eq(block_hash.clone()), eq(block_number))
 			.return_const(());
 
 		let mut observer2 = MockObserver::new();
 		observer2
 			.expect_on_new_event()
-			.with(eq(event.clone()), eq(block_number))
+			.with(eq(event.clone()), eq(block_hash.clone()), eq(block_number))
 			.return_const(());
 
 		let composite = ObserverComposite::new(vec![Arc::new(observer1), Arc::new(observer2)]);
-		composite.on_new_event(&event, block_number).await;
+		composite.on_new_event(&event, &block_hash, block_number).await;
+	}
+
+	#[rstest]
+	async fn on_new_block_events_stops_at_first_failure(
+		event: ObservedEvent,
+		block_hash: BlockHash,
+		block_number: u64,
+	) {
+		let mut observer1 = MockObserver::new();
+		observer1
+			.expect_on_new_block_events()
+			.withf(|events, _, block_number| {
+				events == [ObservedEvent::default()] && *block_number == 42
+			})
+			.times(1)
+			.returning(|_, _, block_number| {
+				Err(Error::Store {
+					block_number,
+					details: String::from("oops"),
+				})
+			});
+
+		let mut observer2 = MockObserver::new();
+		observer2.expect_on_new_block_events().never();
+
+		let composite = ObserverComposite::new(vec![Arc::new(observer1), Arc::new(observer2)]);
+		let result = composite.on_new_block_events(&[event], &block_hash, block_number).await;
+		assert!(matches!(
+			result,
+			Err(Error::Store {
+				block_number: 42,
+				..
+			})
+		));
 	}
 
 	#[rstest]
@@ -101,14 +162,20 @@ mod test {
 	}
 
 	#[rstest]
-	async fn on_reorg() {
+	async fn on_reorg(block_hash: BlockHash, block_number: u64) {
 		let mut observer1 = MockObserver::new();
-		observer1.expect_on_reorg().return_const(());
+		observer1
+			.expect_on_reorg()
+			.with(eq(block_hash.clone()), eq(block_number))
+			.return_const(());
 
 		let mut observer2 = MockObserver::new();
-		observer2.expect_on_reorg().return_const(());
+		observer2
+			.expect_on_reorg()
+			.with(eq(block_hash.clone()), eq(block_number))
+			.return_const(());
 
 		let composite = ObserverComposite::new(vec![Arc::new(observer1), Arc::new(observer2)]);
-		composite.on_reorg().await;
+		composite.on_reorg(&block_hash, block_number).await;
 	}
 }
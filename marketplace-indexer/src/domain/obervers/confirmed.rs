@@ -1,65 +1,196 @@
-use super::{ObservedEvent, Observer};
+use super::{Error, ObservedEvent, Observer};
+use crate::domain::BlockHash;
 use async_trait::async_trait;
+use log::error;
+use marketplace_domain::{PendingEvent, PendingEventRepository};
 use std::{
 	collections::VecDeque,
 	sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 pub struct WithBockConfirmationCount {
+	indexer_id: String,
 	observer: Arc<dyn Observer>,
 	confirmation_blocks_count: u64,
-	events: RwLock<VecDeque<(ObservedEvent, u64)>>,
+	events: RwLock<VecDeque<(ObservedEvent, BlockHash, u64)>>,
+	repository: Arc<dyn PendingEventRepository>,
 }
 
 impl WithBockConfirmationCount {
-	pub fn new(observer: Arc<dyn Observer>, confirmation_blocks_count: u64) -> Self {
+	/// Events left pending for the indexer by a previous run are loaded back from the repository
+	pub fn new(
+		indexer_id: &str,
+		observer: Arc<dyn Observer>,
+		confirmation_blocks_count: u64,
+		repository: Arc<dyn PendingEventRepository>,
+	) -> Self {
+		let events = repository
+			.list(indexer_id)
+			.unwrap_or_else(|error| {
+				error!("Unable to load pending events: {error}");
+				Default::default()
+			})
+			.into_iter()
+			.rev()
+			.map(|pending_event| {
+				(
+					ObservedEvent {
+						event: pending_event.event,
+						deduplication_id: pending_event.deduplication_id,
+						transaction_hash: pending_event.transaction_hash,
+					},
+					pending_event.block_hash,
+					pending_event.block_number,
+				)
+			})
+			.collect();
+
 		Self {
+			indexer_id: indexer_id.to_string(),
 			observer,
 			confirmation_blocks_count,
-			events: RwLock::new(Default::default()),
+			events: RwLock::new(events),
+			repository,
 		}
 	}
 
-	fn events_mut(&self) -> RwLockWriteGuard<'_, VecDeque<(ObservedEvent, u64)>> {
+	fn events_mut(&self) -> RwLockWriteGuard<'_, VecDeque<(ObservedEvent, BlockHash, u64)>> {
 		self.events.write().expect("Could not acquire lock to push new events")
 	}
 
-	fn events(&self) -> RwLockReadGuard<'_, VecDeque<(ObservedEvent, u64)>> {
+	fn events(&self) -> RwLockReadGuard<'_, VecDeque<(ObservedEvent, BlockHash, u64)>> {
 		self.events.read().expect("Could not acquire lock to fetch new events")
 	}
 
-	fn peek(&self) -> Option<(ObservedEvent, u64)> {
+	fn peek(&self) -> Option<(ObservedEvent, BlockHash, u64)> {
 		self.events().back().map(|value| value.to_owned())
 	}
+
+	/// Take the oldest pending events, as long as they belong to the given block
+	fn pop_block(&self, block_number: u64) -> Vec<(ObservedEvent, BlockHash, u64)> {
+		let mut events = self.events_mut();
+		let mut block_events = Vec::new();
+		while events.back().map_or(false, |(_, _, event_block)| *event_block == block_number) {
+			block_events.extend(events.pop_back());
+		}
+		block_events
+	}
+
+	fn is_pending(&self, event: &ObservedEvent) -> bool {
+		self.events()
+			.iter()
+			.any(|(pending_event, _, _)| pending_event.deduplication_id == event.deduplication_id)
+	}
+
+	/// Events of a block redelivered after a restart are already pending, they must only be
+	/// released once
+	fn keep_pending(
+		&self,
+		event: &ObservedEvent,
+		block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), Error> {
+		if self.is_pending(event) {
+			return Ok(());
+		}
+
+		self.repository
+			.create(PendingEvent {
+				indexer_id: self.indexer_id.clone(),
+				deduplication_id: event.deduplication_id.to_owned(),
+				event: event.event.to_owned(),
+				transaction_hash: event.transaction_hash.to_owned(),
+				block_hash: block_hash.to_owned(),
+				block_number,
+			})
+			.map_err(|error| Error::Pending {
+				block_number,
+				details: error.to_string(),
+			})?;
+
+		self.events_mut()
+			.push_front((event.to_owned(), block_hash.to_owned(), block_number));
+		Ok(())
+	}
 }
 
 #[async_trait]
 impl Observer for WithBockConfirmationCount {
-	async fn on_new_block(&self, _block_hash: &crate::domain::BlockHash, block_number: u64) {
-		while let Some((_, event_block)) = self.peek() {
-			if block_number >= event_block + self.confirmation_blocks_count {
-				let (event, event_block) = self.events_mut().pop_back().unwrap();
-				self.observer.on_new_event(&event, event_block).await;
-			} else {
+	async fn on_new_block(&self, _block_hash: &BlockHash, block_number: u64) {
+		while let Some((_, event_block_hash, event_block)) = self.peek() {
+			if block_number < event_block + self.confirmation_blocks_count {
 				return;
 			}
+
+			let events = self.pop_block(event_block);
+			let observed_events =
+				events.iter().map(|(event, _, _)| event.to_owned()).collect::<Vec<_>>();
+
+			if let Err(error) = self
+				.observer
+				.on_new_block_events(&observed_events, &event_block_hash, event_block)
+				.await
+			{
+				error!("Unable to release confirmed events, will retry on next block: {error}");
+				self.events_mut().extend(events.into_iter().rev());
+				return;
+			}
+
+			for event in observed_events {
+				if let Err(error) = self.repository.delete(&event.deduplication_id) {
+					error!("Unable to delete confirmed pending event {event}: {error}");
+				}
+			}
+		}
+	}
+
+	async fn on_new_event(&self, event: &ObservedEvent, block_hash: &BlockHash, block_number: u64) {
+		if let Err(error) = self.keep_pending(event, block_hash, block_number) {
+			error!("{error}");
+		}
+	}
+
+	async fn on_new_block_events(
+		&self,
+		events: &[ObservedEvent],
+		block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), Error> {
+		for event in events {
+			self.keep_pending(event, block_hash, block_number)?;
 		}
+		Ok(())
 	}
 
-	async fn on_new_event(&self, event: &ObservedEvent, block_number: u64) {
-		self.events_mut().push_front((event.to_owned(), block_number));
+	async fn on_reorg(&self, _block_hash: &BlockHash, block_number: u64) {
+		self.events_mut().retain(|(_, _, event_block)| *event_block <= block_number);
+		if let Err(error) = self.repository.delete_after_block(&self.indexer_id, block_number) {
+			error!("Unable to delete pending events orphaned by the reorg: {error}");
+		}
 	}
 }
 
 pub trait ConfirmedObserver {
-	fn confirmed(self, confirmation_blocks_count: u64) -> Arc<WithBockConfirmationCount>;
+	fn confirmed(
+		self,
+		indexer_id: &str,
+		confirmation_blocks_count: u64,
+		repository: Arc<dyn PendingEventRepository>,
+	) -> Arc<WithBockConfirmationCount>;
 }
 
 impl<O: Observer + Sized + 'static> ConfirmedObserver for Arc<O> {
-	fn confirmed(self, confirmation_blocks_count: u64) -> Arc<WithBockConfirmationCount> {
+	fn confirmed(
+		self,
+		indexer_id: &str,
+		confirmation_blocks_count: u64,
+		repository: Arc<dyn PendingEventRepository>,
+	) -> Arc<WithBockConfirmationCount> {
 		Arc::new(WithBockConfirmationCount::new(
+			indexer_id,
 			self,
 			confirmation_blocks_count,
+			repository,
 		))
 	}
 }
@@ -68,15 +199,26 @@ impl<O: Observer + Sized + 'static> ConfirmedObserver for Arc<O> {
 mod test {
 	use super::*;
 	use crate::domain::MockBlockchainObserver;
-	use marketplace_domain::{ContributionEvent, Event};
+	use marketplace_domain::{ContributionEvent, Event, MockPendingEventRepository};
 	use mockall::predicate::eq;
 	use rstest::*;
 
+	const INDEXER_ID: &str = "contribution-indexer";
+
 	#[fixture]
 	fn observer() -> MockBlockchainObserver {
 		MockBlockchainObserver::new()
 	}
 
+	#[fixture]
+	fn repository() -> MockPendingEventRepository {
+		let mut repository = MockPendingEventRepository::new();
+		repository.expect_list().with(eq(INDEXER_ID)).returning(|_| Ok(vec![]));
+		repository.expect_create().returning(|_| Ok(()));
+		repository.expect_delete().returning(|_| Ok(()));
+		repository
+	}
+
 	#[fixture]
 	fn event() -> ObservedEvent {
 		ObservedEvent {
@@ -87,19 +229,31 @@ mod test {
 		}
 	}
 
+	fn other(event: ObservedEvent) -> ObservedEvent {
+		ObservedEvent {
+			deduplication_id: format!("{}_other", event.deduplication_id),
+			..event
+		}
+	}
+
 	#[rstest]
 	async fn should_call_observer_only_if_confirmed(
 		event: ObservedEvent,
 		mut observer: MockBlockchainObserver,
+		repository: MockPendingEventRepository,
 	) {
+		let expected_event = event.clone();
 		observer
-			.expect_on_new_event()
-			.with(eq(event.clone()), eq(1))
+			.expect_on_new_block_events()
+			.withf(move |events, _, block_number| {
+				events == [expected_event.clone()] && *block_number == 1
+			})
 			.times(1)
-			.return_const(());
+			.returning(|_, _, _| Ok(()));
 
-		let confirmed = WithBockConfirmationCount::new(Arc::new(observer), 3);
-		confirmed.on_new_event(&event, 1).await;
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_event(&event, &Default::default(), 1).await;
 		confirmed.on_new_block(&Default::default(), 4).await;
 	}
 
@@ -107,11 +261,13 @@ mod test {
 	async fn should_call_observer_if_confirmed_and_block_missed(
 		event: ObservedEvent,
 		mut observer: MockBlockchainObserver,
+		repository: MockPendingEventRepository,
 	) {
-		observer.expect_on_new_event().times(1).return_const(());
+		observer.expect_on_new_block_events().times(1).returning(|_, _, _| Ok(()));
 
-		let confirmed = WithBockConfirmationCount::new(Arc::new(observer), 3);
-		confirmed.on_new_event(&event, 1).await;
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_event(&event, &Default::default(), 1).await;
 		confirmed.on_new_block(&Default::default(), 42).await;
 	}
 
@@ -119,11 +275,13 @@ mod test {
 	async fn should_call_observer_only_once(
 		event: ObservedEvent,
 		mut observer: MockBlockchainObserver,
+		repository: MockPendingEventRepository,
 	) {
-		observer.expect_on_new_event().times(1).return_const(());
+		observer.expect_on_new_block_events().times(1).returning(|_, _, _| Ok(()));
 
-		let confirmed = WithBockConfirmationCount::new(Arc::new(observer), 3);
-		confirmed.on_new_event(&event, 1).await;
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_event(&event, &Default::default(), 1).await;
 		confirmed.on_new_block(&Default::default(), 4).await;
 		confirmed.on_new_block(&Default::default(), 5).await;
 		confirmed.on_new_block(&Default::default(), 6).await;
@@ -134,13 +292,171 @@ mod test {
 	async fn should_not_call_observer_before_confirmation(
 		event: ObservedEvent,
 		mut observer: MockBlockchainObserver,
+		repository: MockPendingEventRepository,
 	) {
-		observer.expect_on_new_event().never();
+		observer.expect_on_new_block_events().never();
 
-		let confirmed = WithBockConfirmationCount::new(Arc::new(observer), 3);
-		confirmed.on_new_event(&event, 1).await;
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_event(&event, &Default::default(), 1).await;
 		confirmed.on_new_block(&Default::default(), 1).await;
 		confirmed.on_new_block(&Default::default(), 2).await;
 		confirmed.on_new_block(&Default::default(), 3).await;
 	}
+
+	#[rstest]
+	async fn should_persist_pending_events(event: ObservedEvent, observer: MockBlockchainObserver) {
+		let mut repository = MockPendingEventRepository::new();
+		repository.expect_list().with(eq(INDEXER_ID)).returning(|_| Ok(vec![]));
+		repository
+			.expect_create()
+			.withf(|pending_event| {
+				pending_event.indexer_id == INDEXER_ID && pending_event.block_number == 1
+			})
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_event(&event, &Default::default(), 1).await;
+	}
+
+	#[rstest]
+	async fn should_confirm_events_pending_from_a_previous_run(
+		event: ObservedEvent,
+		mut observer: MockBlockchainObserver,
+	) {
+		let pending_event = PendingEvent {
+			indexer_id: String::from(INDEXER_ID),
+			deduplication_id: event.deduplication_id.clone(),
+			event: event.event.clone(),
+			transaction_hash: event.transaction_hash.clone(),
+			block_hash: Default::default(),
+			block_number: 1,
+		};
+		let deduplication_id = event.deduplication_id.clone();
+		let mut repository = MockPendingEventRepository::new();
+		repository.expect_list().returning(move |_| Ok(vec![pending_event.clone()]));
+		repository
+			.expect_delete()
+			.withf(move |id| id == deduplication_id)
+			.times(1)
+			.returning(|_| Ok(()));
+
+		observer
+			.expect_on_new_block_events()
+			.withf(move |events, _, block_number| events == [event.clone()] && *block_number == 1)
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_block(&Default::default(), 4).await;
+	}
+
+	#[rstest]
+	async fn should_not_release_twice_events_redelivered_after_a_restart(
+		event: ObservedEvent,
+		mut observer: MockBlockchainObserver,
+	) {
+		let pending_event = PendingEvent {
+			indexer_id: String::from(INDEXER_ID),
+			deduplication_id: event.deduplication_id.clone(),
+			event: event.event.clone(),
+			transaction_hash: event.transaction_hash.clone(),
+			block_hash: Default::default(),
+			block_number: 1,
+		};
+		let mut repository = MockPendingEventRepository::new();
+		repository.expect_list().returning(move |_| Ok(vec![pending_event.clone()]));
+		repository.expect_create().never();
+		repository.expect_delete().times(1).returning(|_| Ok(()));
+
+		observer
+			.expect_on_new_block_events()
+			.withf(|events, _, block_number| events.len() == 1 && *block_number == 1)
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_block_events(&[event], &Default::default(), 1).await.unwrap();
+		confirmed.on_new_block(&Default::default(), 4).await;
+	}
+
+	#[rstest]
+	async fn should_drop_pending_events_orphaned_by_a_reorg(
+		event: ObservedEvent,
+		mut observer: MockBlockchainObserver,
+		mut repository: MockPendingEventRepository,
+	) {
+		repository
+			.expect_delete_after_block()
+			.with(eq(INDEXER_ID), eq(1))
+			.times(1)
+			.returning(|_, _| Ok(()));
+
+		observer
+			.expect_on_new_block_events()
+			.withf(|events, _, block_number| events.len() == 1 && *block_number == 1)
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_event(&event, &Default::default(), 1).await;
+		confirmed.on_new_event(&other(event), &Default::default(), 2).await;
+		confirmed.on_reorg(&Default::default(), 1).await;
+		confirmed.on_new_block(&Default::default(), 10).await;
+	}
+
+	#[rstest]
+	async fn should_release_events_of_a_block_together(
+		event: ObservedEvent,
+		mut observer: MockBlockchainObserver,
+		repository: MockPendingEventRepository,
+	) {
+		observer
+			.expect_on_new_block_events()
+			.withf(|events, _, block_number| events.len() == 2 && *block_number == 1)
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed
+			.on_new_block_events(&[event.clone(), other(event)], &Default::default(), 1)
+			.await
+			.unwrap();
+		confirmed.on_new_block(&Default::default(), 4).await;
+	}
+
+	#[rstest]
+	async fn should_retry_releasing_events_on_failure(
+		event: ObservedEvent,
+		mut observer: MockBlockchainObserver,
+		repository: MockPendingEventRepository,
+	) {
+		let mut calls = 0;
+		observer
+			.expect_on_new_block_events()
+			.times(2)
+			.returning(move |_, _, block_number| {
+				calls += 1;
+				match calls {
+					1 => Err(Error::Store {
+						block_number,
+						details: String::from("oops"),
+					}),
+					_ => Ok(()),
+				}
+			});
+
+		let confirmed =
+			WithBockConfirmationCount::new(INDEXER_ID, Arc::new(observer), 3, Arc::new(repository));
+		confirmed.on_new_block_events(&[event], &Default::default(), 1).await.unwrap();
+		confirmed.on_new_block(&Default::default(), 4).await;
+		confirmed.on_new_block(&Default::default(), 5).await;
+		confirmed.on_new_block(&Default::default(), 6).await;
+	}
 }
@@ -1,4 +1,5 @@
 use super::*;
+use log::error;
 use std::sync::Arc;
 
 pub struct ContributionObserver {
@@ -13,29 +14,50 @@ impl ContributionObserver {
 
 #[async_trait]
 impl Observer for ContributionObserver {
-	async fn on_new_event(&self, event: &ObservedEvent, _block_number: u64) {
-		match &event.event {
-			Event::Contribution(event) => self.projector.project(event).await,
+	async fn on_new_event(
+		&self,
+		event: &ObservedEvent,
+		_block_hash: &BlockHash,
+		_block_number: u64,
+	) {
+		if let Event::Contribution(contribution_event) = &event.event {
+			if let Err(error) = self
+				.projector
+				.project_observed(contribution_event, &event.deduplication_id)
+				.await
+			{
+				error!("Unable to project {event}: {error}");
+			}
+		}
+	}
+
+	async fn on_new_block_events(
+		&self,
+		events: &[ObservedEvent],
+		_block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), Error> {
+		for event in events {
+			if let Event::Contribution(contribution_event) = &event.event {
+				self.projector
+					.project_observed(contribution_event, &event.deduplication_id)
+					.await
+					.map_err(|error| Error::Projection {
+						block_number,
+						details: error.to_string(),
+					})?;
+			}
 		}
+		Ok(())
 	}
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
-	use async_trait::async_trait;
-	use mockall::{mock, predicate::*};
+	use mockall::predicate::*;
 	use rstest::*;
 
-	mock! {
-		pub ContributionProjector {}
-
-		#[async_trait]
-		impl Projector<Contribution> for ContributionProjector {
-			async fn project(&self, event: &ContributionEvent);
-		}
-	}
-
 	#[fixture]
 	fn contribution_event() -> ContributionEvent {
 		ContributionEvent::Validated {
@@ -47,27 +69,72 @@ mod test {
 	fn event(contribution_event: ContributionEvent) -> ObservedEvent {
 		ObservedEvent {
 			event: Event::Contribution(contribution_event),
+			deduplication_id: String::from("0x1-1"),
 			..Default::default()
 		}
 	}
 
 	#[fixture]
-	fn contribution_projector() -> MockContributionProjector {
-		MockContributionProjector::new()
+	fn contribution_projector() -> MockProjector<Contribution> {
+		MockProjector::<Contribution>::new()
 	}
 
 	#[rstest]
 	async fn on_contribution_created_event(
-		mut contribution_projector: MockContributionProjector,
+		mut contribution_projector: MockProjector<Contribution>,
 		contribution_event: ContributionEvent,
 		event: ObservedEvent,
 	) {
 		contribution_projector
-			.expect_project()
-			.with(eq(contribution_event))
-			.return_const(());
+			.expect_project_observed()
+			.with(eq(contribution_event), eq("0x1-1"))
+			.once()
+			.returning(|_, _| Ok(()));
+
+		let observer = ContributionObserver::new(Arc::new(contribution_projector));
+		observer.on_new_event(&event, &Default::default(), 0).await;
+	}
+
+	#[rstest]
+	async fn block_events_fail_when_projection_fails(
+		mut contribution_projector: MockProjector<Contribution>,
+		event: ObservedEvent,
+	) {
+		contribution_projector
+			.expect_project_observed()
+			.once()
+			.returning(|_, _| Err(ProjectorError::Projection("oops".into())));
+
+		let observer = ContributionObserver::new(Arc::new(contribution_projector));
+		let result = observer
+			.on_new_block_events(&[event.clone(), event], &Default::default(), 12)
+			.await;
+		assert!(matches!(
+			result,
+			Err(Error::Projection {
+				block_number: 12,
+				..
+			})
+		));
+	}
+
+	#[rstest]
+	async fn ignore_contributor_events(mut contribution_projector: MockProjector<Contribution>) {
+		contribution_projector.expect_project_observed().never();
 
 		let observer = ContributionObserver::new(Arc::new(contribution_projector));
-		observer.on_new_event(&event, 0).await;
+		observer
+			.on_new_event(
+				&ObservedEvent {
+					event: Event::Contributor(ContributorEvent::GithubHandleRegistered {
+						id: Default::default(),
+						github_handle: String::from("43214"),
+					}),
+					..Default::default()
+				},
+				&Default::default(),
+				0,
+			)
+			.await;
 	}
 }
@@ -0,0 +1,102 @@
+use super::*;
+use log::error;
+use std::sync::Arc;
+
+pub struct ContributorObserver {
+	projector: Arc<dyn Projector<ContributorProfile>>,
+}
+
+impl ContributorObserver {
+	pub fn new(projector: Arc<dyn Projector<ContributorProfile>>) -> Self {
+		Self { projector }
+	}
+}
+
+#[async_trait]
+impl Observer for ContributorObserver {
+	async fn on_new_event(
+		&self,
+		event: &ObservedEvent,
+		_block_hash: &BlockHash,
+		_block_number: u64,
+	) {
+		if let Event::Contributor(contributor_event) = &event.event {
+			if let Err(error) = self.projector.project(contributor_event).await {
+				error!("Unable to project {event}: {error}");
+			}
+		}
+	}
+
+	async fn on_new_block_events(
+		&self,
+		events: &[ObservedEvent],
+		_block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), Error> {
+		for event in events {
+			if let Event::Contributor(contributor_event) = &event.event {
+				self.projector.project(contributor_event).await.map_err(|error| {
+					Error::Projection {
+						block_number,
+						details: error.to_string(),
+					}
+				})?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use mockall::predicate::*;
+	use rstest::*;
+
+	#[fixture]
+	fn contributor_event() -> ContributorEvent {
+		ContributorEvent::GithubHandleRegistered {
+			id: Default::default(),
+			github_handle: String::from("43214"),
+		}
+	}
+
+	#[fixture]
+	fn contributor_projector() -> MockProjector<ContributorProfile> {
+		MockProjector::<ContributorProfile>::new()
+	}
+
+	#[rstest]
+	async fn on_contributor_event(
+		mut contributor_projector: MockProjector<ContributorProfile>,
+		contributor_event: ContributorEvent,
+	) {
+		contributor_projector
+			.expect_project()
+			.with(eq(contributor_event.clone()))
+			.once()
+			.returning(|_| Ok(()));
+
+		let observer = ContributorObserver::new(Arc::new(contributor_projector));
+		observer
+			.on_new_event(
+				&ObservedEvent {
+					event: Event::Contributor(contributor_event),
+					..Default::default()
+				},
+				&Default::default(),
+				0,
+			)
+			.await;
+	}
+
+	#[rstest]
+	async fn ignore_contribution_events(
+		mut contributor_projector: MockProjector<ContributorProfile>,
+	) {
+		contributor_projector.expect_project().never();
+
+		let observer = ContributorObserver::new(Arc::new(contributor_projector));
+		observer.on_new_event(&ObservedEvent::default(), &Default::default(), 0).await;
+	}
+}
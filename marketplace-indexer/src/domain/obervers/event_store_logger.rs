@@ -0,0 +1,324 @@
+use log::error;
+
+use super::*;
+use marketplace_domain::{EventBatch, EventBatchStore};
+use std::{slice, sync::Arc};
+
+/// Append the observed events to the event store of their aggregate, a whole block at once.
+/// Only the events which were not stored yet are then passed on to the observer, so that
+/// redelivered events are not projected twice.
+pub struct EventStoreLogger {
+	indexer_id: String,
+	event_store: Arc<dyn EventBatchStore>,
+	observer: Arc<dyn Observer>,
+}
+
+impl EventStoreLogger {
+	pub fn new(
+		indexer_id: &str,
+		event_store: Arc<dyn EventBatchStore>,
+		observer: Arc<dyn Observer>,
+	) -> Self {
+		Self {
+			indexer_id: indexer_id.to_string(),
+			event_store,
+			observer,
+		}
+	}
+}
+
+fn storable_event<A: Aggregate>(
+	event: &ObservedEvent,
+	domain_event: A::Event,
+	block_hash: &BlockHash,
+	block_number: u64,
+) -> StorableEvent<A> {
+	StorableEvent {
+		event: domain_event,
+		deduplication_id: event.deduplication_id.to_owned(),
+		transaction_hash: Some(event.transaction_hash.to_owned()),
+		block_number: Some(block_number),
+		block_hash: Some(block_hash.to_owned()),
+	}
+}
+
+#[async_trait]
+impl Observer for EventStoreLogger {
+	async fn on_new_event(&self, event: &ObservedEvent, block_hash: &BlockHash, block_number: u64) {
+		if let Err(error) =
+			self.on_new_block_events(slice::from_ref(event), block_hash, block_number).await
+		{
+			error!("Failed to append {event} to the store: {error}");
+		}
+	}
+
+	async fn on_new_block_events(
+		&self,
+		events: &[ObservedEvent],
+		block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), Error> {
+		let mut batch = EventBatch {
+			indexer_id: Some(self.indexer_id.clone()),
+			..Default::default()
+		};
+		for event in events {
+			match &event.event {
+				Event::Contribution(domain_event) => batch.contribution_events.push((
+					domain_event.contribution_id().to_owned(),
+					storable_event(event, domain_event.to_owned(), block_hash, block_number),
+				)),
+				Event::Contributor(domain_event) => batch.contributor_events.push((
+					domain_event.contributor_id().to_owned(),
+					storable_event(event, domain_event.to_owned(), block_hash, block_number),
+				)),
+			}
+		}
+
+		if batch.is_empty() {
+			return Ok(());
+		}
+
+		let appended = self.event_store.append_batch(batch).map_err(|error| Error::Store {
+			block_number,
+			details: error.to_string(),
+		})?;
+
+		let appended_deduplication_ids: Vec<&String> = appended
+			.contribution_events
+			.iter()
+			.map(|(_, event)| &event.deduplication_id)
+			.chain(appended.contributor_events.iter().map(|(_, event)| &event.deduplication_id))
+			.collect();
+
+		let appended_events: Vec<ObservedEvent> = events
+			.iter()
+			.filter(|event| appended_deduplication_ids.contains(&&event.deduplication_id))
+			.cloned()
+			.collect();
+
+		if appended_events.is_empty() {
+			return Ok(());
+		}
+
+		self.observer
+			.on_new_block_events(&appended_events, block_hash, block_number)
+			.await
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use mockall::predicate::*;
+	use rstest::*;
+	use std::str::FromStr;
+
+	const INDEXER_ID: &str = "contribution-indexer";
+
+	#[fixture]
+	fn contribution_id() -> ContributionId {
+		Default::default()
+	}
+
+	#[fixture]
+	fn block_hash() -> BlockHash {
+		BlockHash::from_str("0x1234").unwrap()
+	}
+
+	#[fixture]
+	fn event_store() -> MockEventBatchStore {
+		MockEventBatchStore::new()
+	}
+
+	#[fixture]
+	fn observer() -> MockObserver {
+		MockObserver::new()
+	}
+
+	#[fixture]
+	fn contribution_event(contribution_id: ContributionId) -> ContributionEvent {
+		ContributionEvent::Validated {
+			id: contribution_id,
+		}
+	}
+
+	#[fixture]
+	fn event(contribution_event: ContributionEvent) -> ObservedEvent {
+		ObservedEvent {
+			event: Event::Contribution(contribution_event),
+			..Default::default()
+		}
+	}
+
+	#[fixture]
+	fn contributor_event() -> ContributorEvent {
+		ContributorEvent::ProfileMinted {
+			id: ContributorId::from(12),
+			owner: ContractAddress::from_str("0x1234").unwrap(),
+		}
+	}
+
+	#[rstest]
+	async fn on_new_event(
+		mut event_store: MockEventBatchStore,
+		mut observer: MockObserver,
+		contribution_id: ContributionId,
+		event: ObservedEvent,
+		contribution_event: ContributionEvent,
+		block_hash: BlockHash,
+	) {
+		let expected_event = event.clone();
+		event_store
+			.expect_append_batch()
+			.times(1)
+			.with(eq(EventBatch {
+				indexer_id: Some(String::from(INDEXER_ID)),
+				contribution_events: vec![(
+					contribution_id,
+					StorableEvent {
+						event: contribution_event,
+						deduplication_id: event.deduplication_id.to_owned(),
+						transaction_hash: Some(event.transaction_hash.to_owned()),
+						block_number: Some(42),
+						block_hash: Some(block_hash.clone()),
+					},
+				)],
+				contributor_events: vec![],
+			}))
+			.returning(Ok);
+		observer
+			.expect_on_new_block_events()
+			.withf(move |events, _, block_number| {
+				events == [expected_event.clone()] && *block_number == 42
+			})
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+
+		let logger = EventStoreLogger::new(INDEXER_ID, Arc::new(event_store), Arc::new(observer));
+		logger.on_new_event(&event, &block_hash, 42).await;
+	}
+
+	#[rstest]
+	async fn on_new_block_events_are_appended_at_once(
+		mut event_store: MockEventBatchStore,
+		mut observer: MockObserver,
+		contribution_id: ContributionId,
+		event: ObservedEvent,
+		contribution_event: ContributionEvent,
+		contributor_event: ContributorEvent,
+		block_hash: BlockHash,
+	) {
+		let contributor_observed_event = ObservedEvent {
+			event: Event::Contributor(contributor_event.clone()),
+			deduplication_id: String::from("dedup2"),
+			..Default::default()
+		};
+
+		event_store
+			.expect_append_batch()
+			.times(1)
+			.with(eq(EventBatch {
+				indexer_id: Some(String::from(INDEXER_ID)),
+				contribution_events: vec![(
+					contribution_id,
+					StorableEvent {
+						event: contribution_event,
+						deduplication_id: event.deduplication_id.to_owned(),
+						transaction_hash: Some(event.transaction_hash.to_owned()),
+						block_number: Some(42),
+						block_hash: Some(block_hash.clone()),
+					},
+				)],
+				contributor_events: vec![(
+					ContributorId::from(12),
+					StorableEvent {
+						event: contributor_event,
+						deduplication_id: String::from("dedup2"),
+						transaction_hash: Some(
+							contributor_observed_event.transaction_hash.to_owned(),
+						),
+						block_number: Some(42),
+						block_hash: Some(block_hash.clone()),
+					},
+				)],
+			}))
+			.returning(Ok);
+		observer.expect_on_new_block_events().times(1).returning(|_, _, _| Ok(()));
+
+		let logger = EventStoreLogger::new(INDEXER_ID, Arc::new(event_store), Arc::new(observer));
+		let result = logger
+			.on_new_block_events(&[event, contributor_observed_event], &block_hash, 42)
+			.await;
+		assert!(result.is_ok());
+	}
+
+	#[rstest]
+	async fn on_new_block_events_fail_when_not_stored(
+		mut event_store: MockEventBatchStore,
+		mut observer: MockObserver,
+		event: ObservedEvent,
+		block_hash: BlockHash,
+	) {
+		event_store
+			.expect_append_batch()
+			.returning(|_| Err(EventStoreError::Append(anyhow::anyhow!("oops"))));
+		observer.expect_on_new_block_events().never();
+
+		let logger = EventStoreLogger::new(INDEXER_ID, Arc::new(event_store), Arc::new(observer));
+		let result = logger.on_new_block_events(&[event], &block_hash, 42).await;
+		assert!(matches!(
+			result,
+			Err(Error::Store {
+				block_number: 42,
+				..
+			})
+		));
+	}
+
+	#[rstest]
+	async fn empty_blocks_are_not_appended(
+		mut event_store: MockEventBatchStore,
+		observer: MockObserver,
+		block_hash: BlockHash,
+	) {
+		event_store.expect_append_batch().never();
+
+		let logger = EventStoreLogger::new(INDEXER_ID, Arc::new(event_store), Arc::new(observer));
+		assert!(logger.on_new_block_events(&[], &block_hash, 42).await.is_ok());
+	}
+
+	#[rstest]
+	async fn events_already_stored_are_not_observed_again(
+		mut event_store: MockEventBatchStore,
+		mut observer: MockObserver,
+		event: ObservedEvent,
+		contributor_event: ContributorEvent,
+		block_hash: BlockHash,
+	) {
+		let contributor_observed_event = ObservedEvent {
+			event: Event::Contributor(contributor_event),
+			deduplication_id: String::from("dedup2"),
+			..Default::default()
+		};
+
+		event_store.expect_append_batch().returning(|batch| {
+			Ok(EventBatch {
+				contribution_events: vec![],
+				..batch
+			})
+		});
+		let expected_event = contributor_observed_event.clone();
+		observer
+			.expect_on_new_block_events()
+			.withf(move |events, _, _| events == [expected_event.clone()])
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+
+		let logger = EventStoreLogger::new(INDEXER_ID, Arc::new(event_store), Arc::new(observer));
+		let result = logger
+			.on_new_block_events(&[event, contributor_observed_event], &block_hash, 42)
+			.await;
+		assert!(result.is_ok());
+	}
+}
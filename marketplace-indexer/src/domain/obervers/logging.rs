@@ -17,7 +17,12 @@ impl Observer for Logger<'_> {
 		self.0(format!("🔗 Indexer `{indexer_id}` connected"));
 	}
 
-	async fn on_new_event(&self, event: &ObservedEvent, block_number: u64) {
+	async fn on_new_event(
+		&self,
+		event: &ObservedEvent,
+		_block_hash: &BlockHash,
+		block_number: u64,
+	) {
 		self.0(format!("⚡ New event [{block_number}]: {}", event));
 	}
 
@@ -25,8 +30,10 @@ impl Observer for Logger<'_> {
 		self.0(format!("⛏️ New block [{block_number}]: {block_hash}"));
 	}
 
-	async fn on_reorg(&self) {
-		self.0("🤕 Chain reorg".to_string());
+	async fn on_reorg(&self, block_hash: &BlockHash, block_number: u64) {
+		self.0(format!(
+			"🤕 Chain reorg, new head [{block_number}]: {block_hash}"
+		));
 	}
 }
 
@@ -67,7 +74,7 @@ mod test {
 		let logging_callback = move |message| logger.log(message);
 
 		let handler = Logger::new(&logging_callback);
-		handler.on_new_event(&event, 0).await;
+		handler.on_new_event(&event, &Default::default(), 0).await;
 	}
 
 	#[rstest]
@@ -96,16 +103,19 @@ mod test {
 
 	#[rstest]
 	async fn on_reorg(mut logger: MockLoggerCallback) {
-		logger.expect_log().with(eq(String::from("🤕 Chain reorg"))).return_const(());
+		logger
+			.expect_log()
+			.with(eq(String::from("🤕 Chain reorg, new head [2220]: 0x1234")))
+			.return_const(());
 		let logging_callback = move |message| logger.log(message);
 
 		let handler = Logger::new(&logging_callback);
-		handler.on_reorg().await;
+		handler.on_reorg(&BlockHash::from_str("0x1234").unwrap(), 2220).await;
 	}
 
 	#[rstest]
 	async fn handler_can_be_created_using_default(event: ObservedEvent) {
 		let handler = Logger::default();
-		handler.on_new_event(&event, 0).await;
+		handler.on_new_event(&event, &Default::default(), 0).await;
 	}
 }
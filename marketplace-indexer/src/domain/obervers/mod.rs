@@ -9,30 +9,74 @@ pub use logging::Logger;
 mod contribution;
 pub use contribution::ContributionObserver;
 
+mod contributor;
+pub use contributor::ContributorObserver;
+
 mod confirmed;
 pub use confirmed::{ConfirmedObserver, WithBockConfirmationCount};
 use serde::{Deserialize, Serialize};
 
-mod contribution_event_store_logger;
+mod event_store_logger;
+pub use event_store_logger::EventStoreLogger;
+
+mod reorg;
+pub use reorg::ReorgObserver;
+
+mod recorder;
+pub use recorder::Recorder;
 
 use crate::domain::*;
 use mockall::automock;
+use thiserror::Error;
 
 use marketplace_domain::*;
 
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("unable to append the events of block {block_number} to the store: {details}")]
+	Store { block_number: u64, details: String },
+	#[error("unable to project the events of block {block_number}: {details}")]
+	Projection { block_number: u64, details: String },
+	#[error("unable to keep the events of block {block_number} pending: {details}")]
+	Pending { block_number: u64, details: String },
+}
+
 #[automock]
 #[async_trait]
 pub trait Observer: Send + Sync {
 	async fn on_connect(&self, _indexer_id: &IndexerId) {}
-	async fn on_new_event(&self, _event: &ObservedEvent, _block_number: u64) {}
+	/// The indexing stream failed, it is about to be reconnected
+	async fn on_disconnect(&self, _indexer_id: &IndexerId, _error: &IndexingServiceError) {}
+	async fn on_new_event(
+		&self,
+		_event: &ObservedEvent,
+		_block_hash: &BlockHash,
+		_block_number: u64,
+	) {
+	}
+	/// Observe all the events of a block, in log order. The block must only be acknowledged
+	/// once this succeeded.
+	async fn on_new_block_events(
+		&self,
+		events: &[ObservedEvent],
+		block_hash: &BlockHash,
+		block_number: u64,
+	) -> Result<(), Error> {
+		for event in events {
+			self.on_new_event(event, block_hash, block_number).await;
+		}
+		Ok(())
+	}
 	async fn on_new_block(&self, _block_hash: &BlockHash, _block_number: u64) {}
-	async fn on_reorg(&self) {}
+	/// The chain has been reorganized, the given block being its new head
+	async fn on_reorg(&self, _block_hash: &BlockHash, _block_number: u64) {}
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ObservedEvent {
 	pub event: Event,
 	pub deduplication_id: String,
+	pub transaction_hash: HexPrefixedString,
 }
 
 impl Display for ObservedEvent {
@@ -53,6 +97,7 @@ impl Default for ObservedEvent {
 				id: Default::default(),
 			}),
 			deduplication_id: "dedup".to_string(),
+			transaction_hash: Default::default(),
 		}
 	}
 }
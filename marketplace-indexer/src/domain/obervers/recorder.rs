@@ -0,0 +1,141 @@
+use log::error;
+
+use super::*;
+use std::{
+	io::{self, Write},
+	sync::Mutex,
+};
+
+/// A message of the decoded indexing stream, as written by the Recorder
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordedMessage {
+	Connected {
+		indexer_id: String,
+	},
+	NewEvent {
+		event: ObservedEvent,
+		block_hash: BlockHash,
+		block_number: u64,
+	},
+	NewBlock {
+		block_hash: BlockHash,
+		block_number: u64,
+	},
+	Reorg {
+		block_hash: BlockHash,
+		block_number: u64,
+	},
+}
+
+/// Write the decoded indexing stream as JSON lines, to inspect what the indexers observe
+pub struct Recorder(Mutex<Box<dyn Write + Send>>);
+
+impl Recorder {
+	pub fn new(writer: Box<dyn Write + Send>) -> Self {
+		Self(Mutex::new(writer))
+	}
+
+	fn record(&self, message: RecordedMessage) {
+		let mut writer = self.0.lock().expect("Could not acquire lock to record a message");
+		let result = serde_json::to_string(&message)
+			.map_err(io::Error::from)
+			.and_then(|line| writeln!(writer, "{line}"))
+			.and_then(|_| writer.flush());
+
+		if let Err(error) = result {
+			error!("Unable to record {message:?}: {error}");
+		}
+	}
+}
+
+#[async_trait]
+impl Observer for Recorder {
+	async fn on_connect(&self, indexer_id: &IndexerId) {
+		self.record(RecordedMessage::Connected {
+			indexer_id: indexer_id.to_string(),
+		});
+	}
+
+	async fn on_new_event(&self, event: &ObservedEvent, block_hash: &BlockHash, block_number: u64) {
+		self.record(RecordedMessage::NewEvent {
+			event: event.clone(),
+			block_hash: block_hash.clone(),
+			block_number,
+		});
+	}
+
+	async fn on_new_block(&self, block_hash: &BlockHash, block_number: u64) {
+		self.record(RecordedMessage::NewBlock {
+			block_hash: block_hash.clone(),
+			block_number,
+		});
+	}
+
+	async fn on_reorg(&self, block_hash: &BlockHash, block_number: u64) {
+		self.record(RecordedMessage::Reorg {
+			block_hash: block_hash.clone(),
+			block_number,
+		});
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::{
+		str::FromStr,
+		sync::{Arc, Mutex},
+	};
+
+	#[derive(Clone, Default)]
+	struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+	impl Write for SharedBuffer {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			self.0.lock().unwrap().write(buf)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[tokio::test]
+	async fn stream_is_recorded_as_json_lines() {
+		let buffer = SharedBuffer::default();
+		let recorder = Recorder::new(Box::new(buffer.clone()));
+		let block_hash = BlockHash::from_str("0x1234").unwrap();
+
+		recorder.on_connect(&IndexerId::from("ID")).await;
+		recorder.on_new_event(&ObservedEvent::default(), &block_hash, 42).await;
+		recorder.on_new_block(&block_hash, 42).await;
+		recorder.on_reorg(&block_hash, 41).await;
+
+		let recording = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+		let messages: Vec<RecordedMessage> =
+			recording.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+
+		assert_eq!(
+			vec![
+				RecordedMessage::Connected {
+					indexer_id: String::from("ID")
+				},
+				RecordedMessage::NewEvent {
+					event: ObservedEvent::default(),
+					block_hash: block_hash.clone(),
+					block_number: 42
+				},
+				RecordedMessage::NewBlock {
+					block_hash: block_hash.clone(),
+					block_number: 42
+				},
+				RecordedMessage::Reorg {
+					block_hash,
+					block_number: 41
+				},
+			],
+			messages
+		);
+	}
+}
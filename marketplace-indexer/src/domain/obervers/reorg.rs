@@ -0,0 +1,133 @@
+use super::*;
+use log::{error, info};
+use std::sync::Arc;
+
+/// Roll back the events and projections of the blocks orphaned by a chain reorganization
+pub struct ReorgObserver {
+	indexer_id: String,
+	contribution_rollback: Arc<ReorgRollback<Contribution>>,
+	contributor_rollback: Arc<ReorgRollback<ContributorProfile>>,
+}
+
+impl ReorgObserver {
+	pub fn new(
+		indexer_id: &str,
+		contribution_rollback: Arc<ReorgRollback<Contribution>>,
+		contributor_rollback: Arc<ReorgRollback<ContributorProfile>>,
+	) -> Self {
+		Self {
+			indexer_id: indexer_id.to_string(),
+			contribution_rollback,
+			contributor_rollback,
+		}
+	}
+}
+
+#[async_trait]
+impl Observer for ReorgObserver {
+	async fn on_reorg(&self, _block_hash: &BlockHash, block_number: u64) {
+		match self
+			.contribution_rollback
+			.rollback_after_block(&self.indexer_id, block_number)
+			.await
+		{
+			Ok(contribution_ids) if !contribution_ids.is_empty() =>
+				info!("Rolled back contributions {contribution_ids:?} to block {block_number}"),
+			Ok(_) => (),
+			Err(error) =>
+				error!("Unable to roll back contributions to block {block_number}: {error}"),
+		}
+
+		match self
+			.contributor_rollback
+			.rollback_after_block(&self.indexer_id, block_number)
+			.await
+		{
+			Ok(contributor_ids) if !contributor_ids.is_empty() =>
+				info!("Rolled back contributors {contributor_ids:?} to block {block_number}"),
+			Ok(_) => (),
+			Err(error) =>
+				error!("Unable to roll back contributors to block {block_number}: {error}"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use mockall::predicate::*;
+	use rstest::*;
+
+	const INDEXER_ID: &str = "contribution-indexer";
+
+	#[fixture]
+	fn contribution_event_store() -> MockEventStore<Contribution> {
+		MockEventStore::<Contribution>::new()
+	}
+
+	#[fixture]
+	fn contributor_event_store() -> MockEventStore<ContributorProfile> {
+		MockEventStore::<ContributorProfile>::new()
+	}
+
+	#[rstest]
+	async fn on_reorg(
+		mut contribution_event_store: MockEventStore<Contribution>,
+		mut contributor_event_store: MockEventStore<ContributorProfile>,
+	) {
+		contribution_event_store
+			.expect_remove_after_block()
+			.with(eq(INDEXER_ID), eq(42))
+			.once()
+			.returning(|_, _| Ok(vec![]));
+		contributor_event_store
+			.expect_remove_after_block()
+			.with(eq(INDEXER_ID), eq(42))
+			.once()
+			.returning(|_, _| Ok(vec![]));
+
+		let observer = ReorgObserver::new(
+			INDEXER_ID,
+			Arc::new(ReorgRollback::new(
+				Arc::new(contribution_event_store),
+				Arc::new(MockDeadLetterEventRepository::new()),
+				vec![],
+			)),
+			Arc::new(ReorgRollback::new(
+				Arc::new(contributor_event_store),
+				Arc::new(MockDeadLetterEventRepository::new()),
+				vec![],
+			)),
+		);
+		observer.on_reorg(&Default::default(), 42).await;
+	}
+
+	#[rstest]
+	async fn contributors_are_rolled_back_when_contributions_fail(
+		mut contribution_event_store: MockEventStore<Contribution>,
+		mut contributor_event_store: MockEventStore<ContributorProfile>,
+	) {
+		contribution_event_store
+			.expect_remove_after_block()
+			.returning(|_, _| Err(EventStoreError::Remove(anyhow::anyhow!("Oops"))));
+		contributor_event_store
+			.expect_remove_after_block()
+			.once()
+			.returning(|_, _| Ok(vec![]));
+
+		let observer = ReorgObserver::new(
+			INDEXER_ID,
+			Arc::new(ReorgRollback::new(
+				Arc::new(contribution_event_store),
+				Arc::new(MockDeadLetterEventRepository::new()),
+				vec![],
+			)),
+			Arc::new(ReorgRollback::new(
+				Arc::new(contributor_event_store),
+				Arc::new(MockDeadLetterEventRepository::new()),
+				vec![],
+			)),
+		);
+		observer.on_reorg(&Default::default(), 42).await;
+	}
+}
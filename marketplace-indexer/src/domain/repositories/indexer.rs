@@ -13,6 +13,8 @@ pub enum Error {
 	GetIndexer { id: IndexerId, details: String },
 	#[error("unable to delete the indexer `{id}`: {details}")]
 	DeleteIndexer { id: IndexerId, details: String },
+	#[error("unable to list the indexers: {details}")]
+	ListIndexers { details: String },
 }
 
 type Result<T> = std::result::Result<T, Error>;
@@ -23,6 +25,7 @@ pub trait Repository {
 	async fn create(&self, indexer: &Indexer) -> Result<()>;
 	async fn by_id(&self, indexer_id: &IndexerId) -> Result<Option<Indexer>>;
 	async fn delete(&self, indexer_id: &IndexerId) -> Result<()>;
+	async fn list(&self) -> Result<Vec<Indexer>>;
 }
 
 #[cfg(test)]
@@ -34,6 +37,7 @@ mod test {
 	#[case(Error::CreateIndexer{id: IndexerId::from("ID"), details: String::from("details")}, "unable to create the indexer `ID`: details")]
 	#[case(Error::GetIndexer{id: IndexerId::from("ID"), details: String::from("details")}, "unable to get the indexer `ID`: details")]
 	#[case(Error::DeleteIndexer{id: IndexerId::from("ID"), details: String::from("details")}, "unable to delete the indexer `ID`: details")]
+	#[case(Error::ListIndexers{details: String::from("details")}, "unable to list the indexers: details")]
 	fn error_messages_are_well_formatted(#[case] error: Error, #[case] expected_message: &str) {
 		assert_eq!(expected_message, error.to_string());
 	}
@@ -0,0 +1,10 @@
+use crate::domain::*;
+use async_trait::async_trait;
+use mockall::automock;
+
+/// The latest block of the chain, to know how far behind the indexing is
+#[automock]
+#[async_trait]
+pub trait ChainHead: Send + Sync {
+	async fn head_block_number(&self) -> Result<u64, IndexingServiceError>;
+}
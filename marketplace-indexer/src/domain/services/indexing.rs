@@ -13,13 +13,21 @@ pub enum Error {
 	Send(String),
 	#[error("error while receiving message from indexing server: {0}")]
 	Receive(String),
+	#[error("the indexing stream was closed by the server")]
+	Closed,
+	#[error("the indexing server reported a chain reorganization without its new head")]
+	ReorgWithoutHead,
+	#[error("unable to checkpoint the indexing progress: {0}")]
+	Checkpoint(String),
+	#[error("observers failed to process a block: {0}")]
+	Observer(#[from] BlockchainObserverError),
 }
 
 type Result<T> = std::result::Result<T, Error>;
 
 #[automock]
 #[async_trait]
-pub trait Service {
+pub trait Service: Send + Sync {
 	async fn fetch_new_events(
 		&self,
 		indexer: &Indexer,
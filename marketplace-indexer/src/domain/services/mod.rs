@@ -1,2 +1,7 @@
 mod indexing;
-pub use indexing::{Error as IndexingServiceError, Service as IndexingService};
+pub use indexing::{
+	Error as IndexingServiceError, MockService as MockIndexingService, Service as IndexingService,
+};
+
+mod chain_head;
+pub use chain_head::{ChainHead, MockChainHead};
@@ -1,4 +1,7 @@
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Network {
 	#[default]
 	Starknet,
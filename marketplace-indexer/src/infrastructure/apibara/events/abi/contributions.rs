@@ -0,0 +1,62 @@
+use super::{Decoder, ToDomainEvent};
+use marketplace_domain::{ContributionEvent, Event as DomainEvent};
+use once_cell::sync::Lazy;
+
+/// ABI of the contributions contract, to be updated along with the contract
+const ABI: &str = include_str!("../../../../../abi/contributions.json");
+
+/// Domain events of the contributions contract, by name of the ABI event
+const MAPPINGS: [(&str, ToDomainEvent); 4] = [
+	("ContributionCreated", |event| {
+		Ok(DomainEvent::Contribution(ContributionEvent::Created {
+			id: event.hex("contribution_id")?.into(),
+			project_id: event.number("project_id")?,
+			issue_number: event.number("issue_number")?,
+			gate: event.number("gate")?,
+		}))
+	}),
+	("ContributionAssigned", |event| {
+		Ok(DomainEvent::Contribution(ContributionEvent::Assigned {
+			id: event.hex("contribution_id")?.into(),
+			contributor_id: event.uint256("contributor_id")?.into(),
+		}))
+	}),
+	("ContributionUnassigned", |event| {
+		Ok(DomainEvent::Contribution(ContributionEvent::Unassigned {
+			id: event.hex("contribution_id")?.into(),
+		}))
+	}),
+	("ContributionValidated", |event| {
+		Ok(DomainEvent::Contribution(ContributionEvent::Validated {
+			id: event.hex("contribution_id")?.into(),
+		}))
+	}),
+];
+
+pub static CONTRIBUTIONS: Lazy<Decoder> =
+	Lazy::new(|| Decoder::new(ABI, &MAPPINGS).expect("Invalid ABI of the contributions contract"));
+
+#[cfg(test)]
+mod test {
+	use super::{super::test::topics, *};
+	use marketplace_domain::ContributorId;
+	use rstest::*;
+	use starknet::core::utils::get_selector_from_name;
+
+	#[rstest]
+	#[case("ContributionCreated", vec![12, 23, 34, 1], ContributionEvent::Created { id: 12.into(), project_id: 23, issue_number: 34, gate: 1 })]
+	#[case("ContributionAssigned", vec![12, 24, 0], ContributionEvent::Assigned { id: 12.into(), contributor_id: ContributorId::from(24) })]
+	#[case("ContributionUnassigned", vec![12], ContributionEvent::Unassigned { id: 12.into() })]
+	#[case("ContributionValidated", vec![12], ContributionEvent::Validated { id: 12.into() })]
+	fn contribution_events_are_decoded(
+		#[case] event_name: &str,
+		#[case] data: Vec<u8>,
+		#[case] expected_event: ContributionEvent,
+	) {
+		let selector = get_selector_from_name(event_name).unwrap();
+
+		let result = CONTRIBUTIONS.decode(&selector, topics(&[]), topics(&data));
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(DomainEvent::Contribution(expected_event), result.unwrap());
+	}
+}
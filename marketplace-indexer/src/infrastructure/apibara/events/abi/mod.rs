@@ -0,0 +1,321 @@
+mod contributions;
+pub use contributions::CONTRIBUTIONS;
+
+use super::{FromEventError, StarknetTopics, Topics};
+use anyhow::anyhow;
+use crypto_bigint::U256;
+use marketplace_domain::{Event as DomainEvent, HexPrefixedString};
+use serde::Deserialize;
+use starknet::core::{types::FieldElement, utils::get_selector_from_name};
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AbiError {
+	#[error("invalid ABI: {0}")]
+	Invalid(#[from] serde_json::Error),
+	#[error("event `{0}` is not in the ABI")]
+	MissingEvent(String),
+	#[error("invalid event name `{0}`")]
+	InvalidEventName(String),
+	#[error("member `{member}` of event `{event}` has the unsupported type `{type_name}`")]
+	UnsupportedType {
+		event: String,
+		member: String,
+		type_name: String,
+	},
+}
+
+/// Entries of a Cairo ABI, only events matter to decode them
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Entry {
+	Event {
+		name: String,
+		#[serde(default)]
+		keys: Vec<Member>,
+		data: Vec<Member>,
+	},
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize)]
+struct Member {
+	name: String,
+	#[serde(rename = "type")]
+	type_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Type {
+	Felt,
+	Uint256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+	Felt(FieldElement),
+	Uint256(U256),
+}
+
+/// Members of an event, in the order they are emitted
+struct Schema {
+	keys: Vec<(String, Type)>,
+	data: Vec<(String, Type)>,
+}
+
+impl Schema {
+	fn new(event: &str, keys: Vec<Member>, data: Vec<Member>) -> Result<Self, AbiError> {
+		let typed = |members: Vec<Member>| -> Result<Vec<(String, Type)>, AbiError> {
+			members
+				.into_iter()
+				.map(|member| {
+					let member_type = match member.type_name.as_str() {
+						"felt" => Type::Felt,
+						"Uint256" => Type::Uint256,
+						_ =>
+							return Err(AbiError::UnsupportedType {
+								event: event.to_string(),
+								member: member.name,
+								type_name: member.type_name,
+							}),
+					};
+					Ok((member.name, member_type))
+				})
+				.collect()
+		};
+
+		Ok(Self {
+			keys: typed(keys)?,
+			data: typed(data)?,
+		})
+	}
+
+	fn decode(
+		&self,
+		name: &str,
+		mut keys: Topics,
+		mut data: Topics,
+	) -> Result<DecodedEvent, FromEventError> {
+		let mut members = HashMap::new();
+		for (topics, schema) in [(&mut keys, &self.keys), (&mut data, &self.data)] {
+			for (member, member_type) in schema {
+				let value = match member_type {
+					Type::Felt => Value::Felt(topics.pop_front_as()?),
+					Type::Uint256 => Value::Uint256(topics.pop_front_as()?),
+				};
+				members.insert(member.clone(), value);
+			}
+		}
+
+		Ok(DecodedEvent {
+			name: name.to_string(),
+			members,
+		})
+	}
+}
+
+/// An event decoded along the ABI, its members being found by name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEvent {
+	pub name: String,
+	members: HashMap<String, Value>,
+}
+
+impl DecodedEvent {
+	fn member(&self, member: &str) -> Result<&Value, FromEventError> {
+		self.members
+			.get(member)
+			.ok_or_else(|| anyhow!("`{}` has no member `{member}`", self.name).into())
+	}
+
+	pub fn felt(&self, member: &str) -> Result<FieldElement, FromEventError> {
+		match self.member(member)? {
+			Value::Felt(value) => Ok(*value),
+			Value::Uint256(_) => Err(anyhow!("`{}.{member}` is not a felt", self.name).into()),
+		}
+	}
+
+	pub fn uint256(&self, member: &str) -> Result<U256, FromEventError> {
+		match self.member(member)? {
+			Value::Uint256(value) => Ok(*value),
+			Value::Felt(_) => Err(anyhow!("`{}.{member}` is not a Uint256", self.name).into()),
+		}
+	}
+
+	pub fn hex(&self, member: &str) -> Result<HexPrefixedString, FromEventError> {
+		Ok(self.felt(member)?.to_bytes_be().to_vec().into())
+	}
+
+	/// A felt holding a number, which must fit in the requested type
+	pub fn number<T: TryFrom<u128>>(&self, member: &str) -> Result<T, FromEventError> {
+		let bytes = self.felt(member)?.to_bytes_be();
+		let (high, low) = bytes.split_at(16);
+
+		let mut value = [0; 16];
+		value.copy_from_slice(low);
+		let value = u128::from_be_bytes(value);
+
+		match high.iter().all(|byte| *byte == 0) {
+			true => T::try_from(value).ok(),
+			false => None,
+		}
+		.ok_or_else(|| anyhow!("`{}.{member}` is out of range", self.name).into())
+	}
+}
+
+pub type ToDomainEvent = fn(&DecodedEvent) -> Result<DomainEvent, FromEventError>;
+
+/// Decode the events of a contract along its ABI, then map them to domain events
+pub struct Decoder {
+	events: HashMap<[u8; 32], (String, Schema, ToDomainEvent)>,
+}
+
+impl Decoder {
+	pub fn new(abi: &str, mappings: &[(&str, ToDomainEvent)]) -> Result<Self, AbiError> {
+		let mut schemas = HashMap::new();
+		for entry in serde_json::from_str::<Vec<Entry>>(abi)? {
+			if let Entry::Event { name, keys, data } = entry {
+				let schema = Schema::new(&name, keys, data)?;
+				schemas.insert(name, schema);
+			}
+		}
+
+		let mut events = HashMap::new();
+		for (name, to_domain_event) in mappings {
+			let schema =
+				schemas.remove(*name).ok_or_else(|| AbiError::MissingEvent(name.to_string()))?;
+			let selector = get_selector_from_name(name)
+				.map_err(|_| AbiError::InvalidEventName(name.to_string()))?;
+			events.insert(
+				selector.to_bytes_be(),
+				(name.to_string(), schema, *to_domain_event),
+			);
+		}
+
+		Ok(Self { events })
+	}
+
+	pub fn decodes(&self, selector: &FieldElement) -> bool {
+		self.events.contains_key(&selector.to_bytes_be())
+	}
+
+	/// Decode the keys following the selector and the data of an event
+	pub fn decode(
+		&self,
+		selector: &FieldElement,
+		keys: Topics,
+		data: Topics,
+	) -> Result<DomainEvent, FromEventError> {
+		let (name, schema, to_domain_event) =
+			self.events.get(&selector.to_bytes_be()).ok_or(FromEventError::Unsupported)?;
+		to_domain_event(&schema.decode(name, keys, data)?)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{super::super::apibara::TopicValue, *};
+	use marketplace_domain::{ContributionEvent, ContributorId, Event};
+	use rstest::*;
+
+	const ABI: &str = r#"[
+		{ "type": "function", "name": "new_contribution", "inputs": [], "outputs": [] },
+		{
+			"type": "event",
+			"name": "Paid",
+			"keys": [{ "name": "id", "type": "felt" }],
+			"data": [{ "name": "to", "type": "Uint256" }, { "name": "fee", "type": "felt" }]
+		}
+	]"#;
+
+	pub(super) fn topics(values: &[u8]) -> Topics {
+		values
+			.iter()
+			.map(|value| {
+				let mut topic = vec![0; 32];
+				topic[31] = *value;
+				TopicValue { value: topic }
+			})
+			.collect::<Vec<_>>()
+			.into()
+	}
+
+	fn paid(event: &DecodedEvent) -> Result<Event, FromEventError> {
+		Ok(Event::Contribution(ContributionEvent::Assigned {
+			id: event.hex("id")?.into(),
+			contributor_id: event.uint256("to")?.into(),
+		}))
+	}
+
+	const MAPPINGS: [(&str, ToDomainEvent); 1] = [("Paid", paid)];
+
+	#[fixture]
+	fn decoder() -> Decoder {
+		Decoder::new(ABI, &MAPPINGS).unwrap()
+	}
+
+	#[rstest]
+	fn members_are_decoded_by_name(decoder: Decoder) {
+		let selector = get_selector_from_name("Paid").unwrap();
+
+		assert!(decoder.decodes(&selector));
+		assert_eq!(
+			Event::Contribution(ContributionEvent::Assigned {
+				id: 12.into(),
+				contributor_id: ContributorId::from(23),
+			}),
+			decoder.decode(&selector, topics(&[12]), topics(&[23, 0, 1])).unwrap()
+		);
+	}
+
+	#[rstest]
+	fn missing_members_are_rejected(decoder: Decoder) {
+		let selector = get_selector_from_name("Paid").unwrap();
+		let result = decoder.decode(&selector, topics(&[12]), topics(&[23, 0]));
+		assert!(matches!(result, Err(FromEventError::Invalid(_))));
+	}
+
+	#[rstest]
+	fn unmapped_events_are_unsupported(decoder: Decoder) {
+		let selector = get_selector_from_name("Refunded").unwrap();
+
+		assert!(!decoder.decodes(&selector));
+		let result = decoder.decode(&selector, topics(&[]), topics(&[]));
+		assert!(matches!(result, Err(FromEventError::Unsupported)));
+	}
+
+	#[rstest]
+	fn numbers_must_fit_in_their_type() {
+		let mut fee = [0; 32];
+		fee[30] = 1;
+		let event = DecodedEvent {
+			name: String::from("Paid"),
+			members: HashMap::from([(
+				String::from("fee"),
+				Value::Felt(FieldElement::from_bytes_be(&fee).unwrap()),
+			)]),
+		};
+
+		assert_eq!(256u64, event.number::<u64>("fee").unwrap());
+		assert!(event.number::<u8>("fee").is_err());
+		assert!(event.uint256("fee").is_err());
+		assert!(event.felt("to").is_err());
+	}
+
+	#[rstest]
+	#[case(
+		r#"[{ "type": "event", "name": "Other", "data": [] }]"#,
+		"event `Paid` is not in the ABI"
+	)]
+	#[case(
+		r#"[{ "type": "event", "name": "Paid", "data": [{ "name": "to", "type": "Account" }] }]"#,
+		"member `to` of event `Paid` has the unsupported type `Account`"
+	)]
+	#[case("{}", "invalid ABI")]
+	fn invalid_abis_are_rejected(#[case] abi: &str, #[case] message: &str) {
+		let error = Decoder::new(abi, &MAPPINGS).err().unwrap();
+		assert!(error.to_string().contains(message), "{error}");
+	}
+}
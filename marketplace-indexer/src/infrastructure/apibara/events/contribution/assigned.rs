@@ -1,76 +0,0 @@
-use super::{EventTranslator, FromEventError, StarknetTopics, Topics};
-use marketplace_domain::{
-	ContributionEvent, ContributorId, Event as DomainEvent, HexPrefixedString,
-};
-use starknet::core::{types::FieldElement, utils::get_selector_from_name};
-
-pub struct Assigned;
-
-impl EventTranslator for Assigned {
-	fn selector() -> FieldElement {
-		get_selector_from_name("ContributionAssigned").unwrap()
-	}
-
-	fn to_domain_event(mut topics: Topics) -> Result<DomainEvent, FromEventError> {
-		let contribution_id: HexPrefixedString = topics.pop_front_as()?;
-		let contributor_id: ContributorId = topics.pop_front_as()?;
-
-		Ok(DomainEvent::Contribution(ContributionEvent::Assigned {
-			id: contribution_id.into(),
-			contributor_id,
-		}))
-	}
-}
-
-#[cfg(test)]
-mod test {
-	use super::*;
-	use crate::infrastructure::apibara::proto::TopicValue;
-	use rstest::*;
-
-	#[fixture]
-	fn apibara_event_data() -> Topics {
-		vec![
-			TopicValue {
-				value: vec![
-					0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-					0, 0, 0, 0, 0, 12,
-				],
-			},
-			TopicValue {
-				value: vec![
-					0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-					0, 0, 0, 0, 0, 24,
-				],
-			},
-			TopicValue {
-				value: vec![
-					0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-					0, 0, 0, 0, 0, 0,
-				],
-			},
-		]
-		.into()
-	}
-
-	#[rstest]
-	fn selector() {
-		assert_eq!(
-			get_selector_from_name("ContributionAssigned").unwrap(),
-			<Assigned as EventTranslator>::selector()
-		);
-	}
-
-	#[rstest]
-	fn create_event_from_apibara(apibara_event_data: Topics) {
-		let result = <Assigned as EventTranslator>::to_domain_event(apibara_event_data);
-		assert!(result.is_ok(), "{}", result.err().unwrap());
-		assert_eq!(
-			DomainEvent::Contribution(ContributionEvent::Assigned {
-				id: 12.into(),
-				contributor_id: ContributorId::from(24)
-			},),
-			result.unwrap()
-		);
-	}
-}
@@ -1,13 +0,0 @@
-mod created;
-pub use created::Created;
-
-mod assigned;
-pub use assigned::Assigned;
-
-mod unassigned;
-pub use unassigned::Unassigned;
-
-mod validated;
-pub use validated::Validated;
-
-use super::{EventTranslator, FromEventError, StarknetTopics, Topics};
@@ -1,5 +1,8 @@
-mod contribution;
+mod abi;
+mod profile;
+mod registry;
 mod topics;
+use abi::CONTRIBUTIONS;
 use anyhow::anyhow;
 use topics::*;
 
@@ -48,19 +51,19 @@ impl TryFrom<ApibaraEvent> for ObservedEvent {
 				topics,
 				data,
 			})) => {
-				let selector: FieldElement =
-					Topics::from(topics).pop_front_as().map_err(anyhow::Error::msg)?;
+				let mut keys = Topics::from(topics);
+				let selector: FieldElement = keys.pop_front_as().map_err(anyhow::Error::msg)?;
 
 				let data = Topics::from(data);
 				let domain_event = match selector {
-					_ if selector == contribution::Created::selector() =>
-						Ok(contribution::Created::to_domain_event(data)?),
-					_ if selector == contribution::Assigned::selector() =>
-						Ok(contribution::Assigned::to_domain_event(data)?),
-					_ if selector == contribution::Unassigned::selector() =>
-						Ok(contribution::Unassigned::to_domain_event(data)?),
-					_ if selector == contribution::Validated::selector() =>
-						Ok(contribution::Validated::to_domain_event(data)?),
+					_ if CONTRIBUTIONS.decodes(&selector) =>
+						Ok(CONTRIBUTIONS.decode(&selector, keys, data)?),
+					_ if selector == profile::Transfer::selector() =>
+						Ok(profile::Transfer::to_domain_event(data)?),
+					_ if selector == registry::UserRegistered::selector() =>
+						Ok(registry::UserRegistered::to_domain_event(data)?),
+					_ if selector == registry::GithubHandleRegistered::selector() =>
+						Ok(registry::GithubHandleRegistered::to_domain_event(data)?),
 					_ => Err(Self::Error::Unsupported),
 				}?;
 
@@ -73,6 +76,7 @@ impl TryFrom<ApibaraEvent> for ObservedEvent {
 						"{:#x}_{:#x}_{log_index}",
 						address.0, transaction_hash.0
 					),
+					transaction_hash: transaction_hash.0.to_bytes_be().to_vec().into(),
 				})
 			},
 			None => Err(Self::Error::Invalid(anyhow!("Event missing data"))),
@@ -84,11 +88,14 @@ impl TryFrom<ApibaraEvent> for ObservedEvent {
 #[cfg(test)]
 mod test {
 	use super::{super::apibara::TopicValue, *};
-	use marketplace_domain::ContributionEvent;
+	use marketplace_domain::{ContributionEvent, HexPrefixedString};
 	use rstest::*;
+	use starknet::core::utils::get_selector_from_name;
+	use std::str::FromStr;
 
 	const LOG_INDEX: u64 = 666;
 	const DEDUPLICATION_ID: &str = "0xcb_0x64cb_666";
+	const TRANSACTION_HASH: &str = "0x64cb";
 
 	#[fixture]
 	fn contract_address() -> Vec<u8> {
@@ -110,9 +117,9 @@ mod test {
 		TopicValue { value: vec![0; 32] }
 	}
 
-	fn selector<T: EventTranslator>() -> TopicValue {
+	fn selector(event_name: &str) -> TopicValue {
 		TopicValue {
-			value: T::selector().to_bytes_be().to_vec(),
+			value: get_selector_from_name(event_name).unwrap().to_bytes_be().to_vec(),
 		}
 	}
 
@@ -136,7 +143,7 @@ mod test {
 	#[rstest]
 	fn contribution_created(contract_address: Vec<u8>, transaction_hash: Vec<u8>) {
 		let apibara_event = apibara_event(
-			selector::<contribution::Created>(),
+			selector("ContributionCreated"),
 			contract_address,
 			transaction_hash,
 		);
@@ -149,7 +156,8 @@ mod test {
 					issue_number: Default::default(),
 					gate: Default::default()
 				}),
-				deduplication_id: DEDUPLICATION_ID.to_string()
+				deduplication_id: DEDUPLICATION_ID.to_string(),
+				transaction_hash: HexPrefixedString::from_str(TRANSACTION_HASH).unwrap()
 			},
 			ObservedEvent::try_from(apibara_event).unwrap()
 		);
@@ -158,7 +166,7 @@ mod test {
 	#[rstest]
 	fn contribution_assigned(contract_address: Vec<u8>, transaction_hash: Vec<u8>) {
 		let apibara_event = apibara_event(
-			selector::<contribution::Assigned>(),
+			selector("ContributionAssigned"),
 			contract_address,
 			transaction_hash,
 		);
@@ -169,7 +177,8 @@ mod test {
 					id: Default::default(),
 					contributor_id: Default::default()
 				}),
-				deduplication_id: DEDUPLICATION_ID.to_string()
+				deduplication_id: DEDUPLICATION_ID.to_string(),
+				transaction_hash: HexPrefixedString::from_str(TRANSACTION_HASH).unwrap()
 			},
 			ObservedEvent::try_from(apibara_event).unwrap()
 		);
@@ -178,7 +187,7 @@ mod test {
 	#[rstest]
 	fn contribution_unassigned(contract_address: Vec<u8>, transaction_hash: Vec<u8>) {
 		let apibara_event = apibara_event(
-			selector::<contribution::Unassigned>(),
+			selector("ContributionUnassigned"),
 			contract_address,
 			transaction_hash,
 		);
@@ -188,7 +197,8 @@ mod test {
 				event: DomainEvent::Contribution(ContributionEvent::Unassigned {
 					id: Default::default(),
 				}),
-				deduplication_id: DEDUPLICATION_ID.to_string()
+				deduplication_id: DEDUPLICATION_ID.to_string(),
+				transaction_hash: HexPrefixedString::from_str(TRANSACTION_HASH).unwrap()
 			},
 			ObservedEvent::try_from(apibara_event).unwrap()
 		);
@@ -197,7 +207,7 @@ mod test {
 	#[rstest]
 	fn contribution_validated(contract_address: Vec<u8>, transaction_hash: Vec<u8>) {
 		let apibara_event = apibara_event(
-			selector::<contribution::Validated>(),
+			selector("ContributionValidated"),
 			contract_address,
 			transaction_hash,
 		);
@@ -207,7 +217,8 @@ mod test {
 				event: DomainEvent::Contribution(ContributionEvent::Validated {
 					id: Default::default(),
 				}),
-				deduplication_id: DEDUPLICATION_ID.to_string()
+				deduplication_id: DEDUPLICATION_ID.to_string(),
+				transaction_hash: HexPrefixedString::from_str(TRANSACTION_HASH).unwrap()
 			},
 			ObservedEvent::try_from(apibara_event).unwrap()
 		);
@@ -0,0 +1,4 @@
+mod transfer;
+pub use transfer::Transfer;
+
+use super::{EventTranslator, FromEventError, StarknetTopics, Topics};
@@ -0,0 +1,84 @@
+use super::{EventTranslator, FromEventError, StarknetTopics, Topics};
+use marketplace_domain::{ContractAddress, ContributorEvent, ContributorId, Event as DomainEvent};
+use starknet::core::{types::FieldElement, utils::get_selector_from_name};
+
+/// ERC721 transfer of a profile NFT, the token id being the contributor id.
+/// A transfer from the zero address is the mint of the profile.
+pub struct Transfer;
+
+impl EventTranslator for Transfer {
+	fn selector() -> FieldElement {
+		get_selector_from_name("Transfer").unwrap()
+	}
+
+	fn to_domain_event(mut topics: Topics) -> Result<DomainEvent, FromEventError> {
+		let from: FieldElement = topics.pop_front_as()?;
+		let owner: ContractAddress = topics.pop_front_as()?;
+		let contributor_id: ContributorId = topics.pop_front_as()?;
+
+		Ok(DomainEvent::Contributor(if from == FieldElement::ZERO {
+			ContributorEvent::ProfileMinted {
+				id: contributor_id,
+				owner,
+			}
+		} else {
+			ContributorEvent::ProfileTransferred {
+				id: contributor_id,
+				owner,
+			}
+		}))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::infrastructure::apibara::proto::TopicValue;
+	use rstest::*;
+	use std::str::FromStr;
+
+	fn topic(value: u64) -> TopicValue {
+		TopicValue {
+			value: FieldElement::from(value).to_bytes_be().to_vec(),
+		}
+	}
+
+	#[rstest]
+	fn selector() {
+		assert_eq!(
+			get_selector_from_name("Transfer").unwrap(),
+			<Transfer as EventTranslator>::selector()
+		);
+	}
+
+	#[rstest]
+	fn mint_event_from_apibara() {
+		let apibara_event_data: Topics = vec![topic(0), topic(0x1234), topic(12), topic(0)].into();
+
+		let result = <Transfer as EventTranslator>::to_domain_event(apibara_event_data);
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(
+			DomainEvent::Contributor(ContributorEvent::ProfileMinted {
+				id: ContributorId::from(12),
+				owner: ContractAddress::from_str("0x1234").unwrap()
+			}),
+			result.unwrap()
+		);
+	}
+
+	#[rstest]
+	fn transfer_event_from_apibara() {
+		let apibara_event_data: Topics =
+			vec![topic(0x1234), topic(0x5678), topic(12), topic(0)].into();
+
+		let result = <Transfer as EventTranslator>::to_domain_event(apibara_event_data);
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(
+			DomainEvent::Contributor(ContributorEvent::ProfileTransferred {
+				id: ContributorId::from(12),
+				owner: ContractAddress::from_str("0x5678").unwrap()
+			}),
+			result.unwrap()
+		);
+	}
+}
@@ -0,0 +1,60 @@
+use super::{EventTranslator, FromEventError, StarknetTopics, Topics};
+use marketplace_domain::{ContributorEvent, ContributorId, Event as DomainEvent};
+use starknet::core::{types::FieldElement, utils::get_selector_from_name};
+
+pub struct GithubHandleRegistered;
+
+impl EventTranslator for GithubHandleRegistered {
+	fn selector() -> FieldElement {
+		get_selector_from_name("GithubHandleRegistered").unwrap()
+	}
+
+	fn to_domain_event(mut topics: Topics) -> Result<DomainEvent, FromEventError> {
+		let contributor_id: ContributorId = topics.pop_front_as()?;
+		let github_handle: FieldElement = topics.pop_front_as()?;
+
+		Ok(DomainEvent::Contributor(
+			ContributorEvent::GithubHandleRegistered {
+				id: contributor_id,
+				github_handle: github_handle.to_string(),
+			},
+		))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::infrastructure::apibara::proto::TopicValue;
+	use rstest::*;
+
+	fn topic(value: u64) -> TopicValue {
+		TopicValue {
+			value: FieldElement::from(value).to_bytes_be().to_vec(),
+		}
+	}
+
+	#[rstest]
+	fn selector() {
+		assert_eq!(
+			get_selector_from_name("GithubHandleRegistered").unwrap(),
+			<GithubHandleRegistered as EventTranslator>::selector()
+		);
+	}
+
+	#[rstest]
+	fn create_event_from_apibara() {
+		let apibara_event_data: Topics = vec![topic(12), topic(0), topic(43214)].into();
+
+		let result =
+			<GithubHandleRegistered as EventTranslator>::to_domain_event(apibara_event_data);
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(
+			DomainEvent::Contributor(ContributorEvent::GithubHandleRegistered {
+				id: ContributorId::from(12),
+				github_handle: String::from("43214")
+			}),
+			result.unwrap()
+		);
+	}
+}
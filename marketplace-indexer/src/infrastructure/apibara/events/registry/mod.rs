@@ -0,0 +1,7 @@
+mod user_registered;
+pub use user_registered::UserRegistered;
+
+mod github_handle_registered;
+pub use github_handle_registered::GithubHandleRegistered;
+
+use super::{EventTranslator, FromEventError, StarknetTopics, Topics};
@@ -0,0 +1,58 @@
+use super::{EventTranslator, FromEventError, StarknetTopics, Topics};
+use marketplace_domain::{ContractAddress, ContributorEvent, ContributorId, Event as DomainEvent};
+use starknet::core::{types::FieldElement, utils::get_selector_from_name};
+
+pub struct UserRegistered;
+
+impl EventTranslator for UserRegistered {
+	fn selector() -> FieldElement {
+		get_selector_from_name("UserRegistered").unwrap()
+	}
+
+	fn to_domain_event(mut topics: Topics) -> Result<DomainEvent, FromEventError> {
+		let account: ContractAddress = topics.pop_front_as()?;
+		let contributor_id: ContributorId = topics.pop_front_as()?;
+
+		Ok(DomainEvent::Contributor(ContributorEvent::Registered {
+			id: contributor_id,
+			account,
+		}))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::infrastructure::apibara::proto::TopicValue;
+	use rstest::*;
+	use std::str::FromStr;
+
+	fn topic(value: u64) -> TopicValue {
+		TopicValue {
+			value: FieldElement::from(value).to_bytes_be().to_vec(),
+		}
+	}
+
+	#[rstest]
+	fn selector() {
+		assert_eq!(
+			get_selector_from_name("UserRegistered").unwrap(),
+			<UserRegistered as EventTranslator>::selector()
+		);
+	}
+
+	#[rstest]
+	fn create_event_from_apibara() {
+		let apibara_event_data: Topics = vec![topic(0x1234), topic(12), topic(0)].into();
+
+		let result = <UserRegistered as EventTranslator>::to_domain_event(apibara_event_data);
+		assert!(result.is_ok(), "{}", result.err().unwrap());
+		assert_eq!(
+			DomainEvent::Contributor(ContributorEvent::Registered {
+				id: ContributorId::from(12),
+				account: ContractAddress::from_str("0x1234").unwrap()
+			}),
+			result.unwrap()
+		);
+	}
+}
@@ -1,5 +1,7 @@
 use super::{
-	apibara::{self, CreateIndexerRequest, DeleteIndexerRequest, GetIndexerRequest},
+	apibara::{
+		self, CreateIndexerRequest, DeleteIndexerRequest, GetIndexerRequest, ListIndexerRequest,
+	},
 	Client,
 };
 use crate::domain::*;
@@ -13,7 +15,7 @@ use itertools::Itertools;
 impl IndexerRepository for Client {
 	async fn create(&self, indexer: &Indexer) -> Result<(), IndexerRepositoryError> {
 		let response = self
-			.0
+			.inner
 			.write()
 			.await
 			.create_indexer(CreateIndexerRequest {
@@ -44,7 +46,7 @@ impl IndexerRepository for Client {
 		indexer_id: &IndexerId,
 	) -> Result<Option<Indexer>, IndexerRepositoryError> {
 		let response = self
-			.0
+			.inner
 			.write()
 			.await
 			.get_indexer(GetIndexerRequest {
@@ -60,7 +62,7 @@ impl IndexerRepository for Client {
 	}
 
 	async fn delete(&self, indexer_id: &IndexerId) -> Result<(), IndexerRepositoryError> {
-		self.0
+		self.inner
 			.write()
 			.await
 			.delete_indexer(DeleteIndexerRequest {
@@ -74,6 +76,16 @@ impl IndexerRepository for Client {
 
 		Ok(())
 	}
+
+	async fn list(&self) -> Result<Vec<Indexer>, IndexerRepositoryError> {
+		let response = self.inner.write().await.list_indexer(ListIndexerRequest {}).await.map_err(
+			|status| IndexerRepositoryError::ListIndexers {
+				details: status.to_string(),
+			},
+		)?;
+
+		Ok(response.into_inner().indexers.into_iter().map_into().collect())
+	}
 }
 
 // Hardcoded strings are referenced in the server configuration.toml file
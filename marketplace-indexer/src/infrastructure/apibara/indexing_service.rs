@@ -1,5 +1,4 @@
 use async_trait::async_trait;
-use futures::future::join_all;
 use std::sync::Arc;
 use tokio::sync::mpsc::{self, Receiver, Sender};
 use tokio_stream::wrappers::ReceiverStream;
@@ -9,6 +8,7 @@ use super::{
 		connect_indexer_request::Message as RequestMessage,
 		connect_indexer_response::Message as ResponseMessage, AckBlock, ConnectIndexer,
 		ConnectIndexerRequest, ConnectIndexerResponse, IndexerConnected, NewBlock, NewEvents,
+		Reorg,
 	},
 	*,
 };
@@ -21,11 +21,25 @@ impl IndexingService for Client {
 		indexer: &Indexer,
 		observer: Arc<dyn BlockchainObserver>,
 	) -> Result<(), IndexingServiceError> {
+		let recorder = match &self.record_dir {
+			Some(record_dir) => {
+				let path = recording::path(record_dir, &indexer.id.to_string());
+				let recorder = recording::Recorder::open(&path).map_err(|error| {
+					IndexingServiceError::Connection {
+						id: indexer.id.clone(),
+						details: format!("unable to open {}: {error}", path.display()),
+					}
+				})?;
+				Some(recorder)
+			},
+			None => None,
+		};
+
 		let channel = Channel::new();
 		send_connect_request(&channel.tx, &indexer.id).await?;
 
 		let mut response_stream = self
-			.0
+			.inner
 			.write()
 			.await
 			.connect_indexer(ReceiverStream::new(channel.rx))
@@ -42,8 +56,13 @@ impl IndexingService for Client {
 				.await
 				.map_err(|error| IndexingServiceError::Receive(error.to_string()))?
 			{
-				Some(response) => handle_response(response, &channel.tx, &*observer).await?,
-				None => continue,
+				Some(response) => {
+					if let Some(recorder) = &recorder {
+						recorder.record(&response);
+					}
+					handle_response(response, &channel.tx, &*observer).await?
+				},
+				None => return Err(IndexingServiceError::Closed),
 			}
 		}
 	}
@@ -98,7 +117,7 @@ fn ack_block(block_hash: &BlockHash) -> ConnectIndexerRequest {
 	}
 }
 
-async fn handle_response(
+pub async fn handle_response(
 	response: ConnectIndexerResponse,
 	sender: &Sender<ConnectIndexerRequest>,
 	observer: &dyn BlockchainObserver,
@@ -120,21 +139,28 @@ async fn handle_response(
 			Ok(())
 		},
 
-		Some(ResponseMessage::Reorg(_)) => {
-			observer.on_reorg().await;
+		Some(ResponseMessage::Reorg(Reorg {
+			new_head: Some(new_head),
+		})) => {
+			let block_hash = BlockHash::from(new_head.hash);
+			observer.on_reorg(&block_hash, new_head.number).await;
 			Ok(())
 		},
 
+		// Without the fork point, the orphaned blocks cannot be rolled back
+		Some(ResponseMessage::Reorg(Reorg { new_head: None })) =>
+			Err(IndexingServiceError::ReorgWithoutHead),
+
 		Some(ResponseMessage::NewEvents(NewEvents { block, events })) => {
 			if let Some(block_head) = block {
-				join_all(events.into_iter().map(|event| async {
-					if let Ok(event) = event.try_into() {
-						observer.on_new_event(&event, block_head.number).await;
-					}
-				}))
-				.await;
+				let block_hash = BlockHash::from(block_head.hash);
+				let events: Vec<ObservedEvent> =
+					events.into_iter().filter_map(|event| event.try_into().ok()).collect();
+
+				// Unacknowledged, the block will be sent again once reconnected
+				observer.on_new_block_events(&events, &block_hash, block_head.number).await?;
 
-				send_ack_request(sender, &block_head.hash.into()).await
+				send_ack_request(sender, &block_hash).await
 			} else {
 				Ok(())
 			}
@@ -309,7 +335,14 @@ mod test {
 			})),
 		};
 
-		observer.expect_on_new_event().times(2).return_const(());
+		let expected_block_hash = block_hash.clone();
+		observer
+			.expect_on_new_block_events()
+			.withf(move |events, hash, number| {
+				events.len() == 2 && *hash == expected_block_hash && *number == block_number
+			})
+			.times(1)
+			.returning(|_, _, _| Ok(()));
 
 		let result = handle_response(response, &channel.tx, &observer).await;
 		assert!(result.is_ok(), "{}", result.err().unwrap());
@@ -323,23 +356,85 @@ mod test {
 		);
 	}
 
+	#[rstest]
+	async fn does_not_ack_a_block_whose_events_failed(
+		mut channel: Channel,
+		mut observer: MockBlockchainObserver,
+		apibara_event: apibara::Event,
+		block_hash: BlockHash,
+		block_number: u64,
+	) {
+		let response = ConnectIndexerResponse {
+			message: Some(ResponseMessage::NewEvents(apibara::NewEvents {
+				block: Some(BlockHeader {
+					hash: block_hash.to_bytes(),
+					number: block_number,
+					..Default::default()
+				}),
+				events: vec![apibara_event],
+			})),
+		};
+
+		observer.expect_on_new_block_events().returning(|_, _, block_number| {
+			Err(BlockchainObserverError::Store {
+				block_number,
+				details: String::from("oops"),
+			})
+		});
+
+		let result = handle_response(response, &channel.tx, &observer).await;
+		assert!(matches!(result, Err(IndexingServiceError::Observer(_))));
+		assert_eq!(TryRecvError::Empty, channel.rx.try_recv().unwrap_err());
+	}
+
 	#[rstest]
 	#[tokio::test]
 	async fn can_handle_a_new_reorg_response(
+		block_hash: BlockHash,
+		block_number: u64,
 		mut channel: Channel,
 		mut observer: MockBlockchainObserver,
 	) {
 		let response = ConnectIndexerResponse {
-			message: Some(ResponseMessage::Reorg(apibara::Reorg::default())),
+			message: Some(ResponseMessage::Reorg(apibara::Reorg {
+				new_head: Some(BlockHeader {
+					hash: block_hash.to_bytes(),
+					number: block_number,
+					..Default::default()
+				}),
+			})),
 		};
 
-		observer.expect_on_reorg().return_const(());
+		observer
+			.expect_on_reorg()
+			.with(eq(block_hash.clone()), eq(block_number))
+			.return_const(());
 
 		let result = handle_response(response, &channel.tx, &observer).await;
 		assert!(result.is_ok(), "{}", result.err().unwrap());
 		assert_eq!(TryRecvError::Empty, channel.rx.try_recv().unwrap_err());
 	}
 
+	#[rstest]
+	#[tokio::test]
+	async fn refuses_a_reorg_response_without_new_head(
+		mut channel: Channel,
+		mut observer: MockBlockchainObserver,
+	) {
+		let response = ConnectIndexerResponse {
+			message: Some(ResponseMessage::Reorg(apibara::Reorg { new_head: None })),
+		};
+
+		observer.expect_on_reorg().never();
+
+		let result = handle_response(response, &channel.tx, &observer).await;
+		assert!(matches!(
+			result,
+			Err(IndexingServiceError::ReorgWithoutHead)
+		));
+		assert_eq!(TryRecvError::Empty, channel.rx.try_recv().unwrap_err());
+	}
+
 	#[rstest]
 	#[tokio::test]
 	async fn can_handle_an_empty_response(mut channel: Channel, observer: MockBlockchainObserver) {
@@ -1,4 +1,4 @@
-mod proto;
+pub(super) mod proto;
 
 use proto as apibara;
 
@@ -8,20 +8,34 @@ use error::Error;
 mod events;
 mod indexer_repository;
 mod indexing_service;
+pub(super) use indexing_service::handle_response;
+
+pub(super) mod recording;
 
 use apibara::indexer_manager_client::IndexerManagerClient;
+use std::path::PathBuf;
 use tokio::sync::RwLock;
 
-pub struct Client(RwLock<IndexerManagerClient<tonic::transport::Channel>>);
+pub struct Client {
+	inner: RwLock<IndexerManagerClient<tonic::transport::Channel>>,
+	/// Directory the stream of each indexer is recorded into, to be replayed later
+	record_dir: Option<PathBuf>,
+}
 
 impl Client {
 	pub fn new(inner: IndexerManagerClient<tonic::transport::Channel>) -> Self {
-		Self(RwLock::new(inner))
+		Self {
+			inner: RwLock::new(inner),
+			record_dir: None,
+		}
 	}
 
 	pub async fn default() -> Result<Self, Error> {
 		let inner = IndexerManagerClient::connect(apibara_url()).await.map_err(Error::from)?;
-		Ok(Self::new(inner))
+		Ok(Self {
+			record_dir: record_dir(),
+			..Self::new(inner)
+		})
 	}
 }
 
@@ -29,6 +43,10 @@ fn apibara_url() -> String {
 	std::env::var("APIBARA_URL").expect("APIBARA_URL must be set")
 }
 
+fn record_dir() -> Option<PathBuf> {
+	std::env::var("RECORD_DIR").ok().map(PathBuf::from)
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
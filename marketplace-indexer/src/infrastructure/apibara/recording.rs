@@ -0,0 +1,112 @@
+use super::apibara::ConnectIndexerResponse;
+use log::error;
+use prost::Message;
+use std::{
+	fs::OpenOptions,
+	io::{self, BufWriter, Write},
+	path::{Path, PathBuf},
+	sync::Mutex,
+};
+
+/// Recording of the stream of an indexer in `directory`
+pub fn path(directory: &Path, indexer_id: &str) -> PathBuf {
+	directory.join(format!("{indexer_id}.stream"))
+}
+
+/// Write the raw responses of the Apibara stream, one base64 encoded protobuf message per line,
+/// so that replaying them goes through the same decoding
+pub struct Recorder(Mutex<Box<dyn Write + Send>>);
+
+impl Recorder {
+	pub fn new(writer: Box<dyn Write + Send>) -> Self {
+		Self(Mutex::new(writer))
+	}
+
+	/// Record after what is already in the file, so that the sessions of an indexer reconnecting
+	/// are all kept, in order
+	pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+		let file = OpenOptions::new().create(true).append(true).open(path)?;
+		Ok(Self::new(Box::new(BufWriter::new(file))))
+	}
+
+	pub fn record(&self, response: &ConnectIndexerResponse) {
+		let mut writer = self.0.lock().expect("Could not acquire lock to record a response");
+		let result = writeln!(writer, "{}", base64::encode(response.encode_to_vec()))
+			.and_then(|_| writer.flush());
+
+		if let Err(error) = result {
+			error!("Unable to record {response:?}: {error}");
+		}
+	}
+}
+
+/// Decode a line written by the Recorder
+pub fn decode(line: &str) -> Result<ConnectIndexerResponse, String> {
+	let bytes = base64::decode(line.trim()).map_err(|error| error.to_string())?;
+	ConnectIndexerResponse::decode(bytes.as_slice()).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod test {
+	use super::{
+		super::apibara::{
+			connect_indexer_response::Message as ResponseMessage, BlockHeader, NewBlock,
+		},
+		*,
+	};
+	use std::sync::Arc;
+
+	#[derive(Clone, Default)]
+	struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+	impl Write for SharedBuffer {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			self.0.lock().unwrap().write(buf)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn responses_are_recorded_as_they_were_received() {
+		let buffer = SharedBuffer::default();
+		let recorder = Recorder::new(Box::new(buffer.clone()));
+		let response = ConnectIndexerResponse {
+			message: Some(ResponseMessage::NewBlock(NewBlock {
+				new_head: Some(BlockHeader {
+					hash: vec![0x2a],
+					number: 42,
+					..Default::default()
+				}),
+			})),
+		};
+
+		recorder.record(&response);
+		recorder.record(&ConnectIndexerResponse { message: None });
+
+		let recording = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+		let responses: Vec<ConnectIndexerResponse> =
+			recording.lines().map(|line| decode(line).unwrap()).collect();
+
+		assert_eq!(
+			vec![response, ConnectIndexerResponse { message: None }],
+			responses
+		);
+	}
+
+	#[test]
+	fn invalid_line_is_refused() {
+		assert!(decode("not base64!").is_err());
+		assert!(decode("////").is_err());
+	}
+
+	#[test]
+	fn recordings_are_named_after_the_indexer() {
+		assert_eq!(
+			PathBuf::from("/records/contribution-indexer.stream"),
+			path(Path::new("/records"), "contribution-indexer")
+		);
+	}
+}
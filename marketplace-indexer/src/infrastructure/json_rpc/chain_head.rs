@@ -0,0 +1,12 @@
+use super::node::{HttpNode, Node};
+use crate::domain::*;
+use async_trait::async_trait;
+
+#[async_trait]
+impl ChainHead for HttpNode {
+	async fn head_block_number(&self) -> Result<u64, IndexingServiceError> {
+		self.block_number()
+			.await
+			.map_err(|error| IndexingServiceError::Receive(error.to_string()))
+	}
+}
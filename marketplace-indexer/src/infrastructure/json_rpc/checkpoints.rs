@@ -0,0 +1,123 @@
+use marketplace_domain::*;
+use std::sync::{Mutex, MutexGuard};
+
+/// Checkpoints only kept for the lifetime of the process, to index without touching the database
+#[derive(Default)]
+pub struct InMemoryCheckpoints(Mutex<Vec<IndexerCheckpoint>>);
+
+impl InMemoryCheckpoints {
+	fn checkpoints(&self) -> MutexGuard<'_, Vec<IndexerCheckpoint>> {
+		self.0.lock().expect("Could not acquire lock to access the checkpoints")
+	}
+
+	fn remove<F: Fn(&IndexerCheckpoint) -> bool>(&self, indexer_id: &str, predicate: F) {
+		self.checkpoints()
+			.retain(|checkpoint| checkpoint.indexer_id != indexer_id || !predicate(checkpoint));
+	}
+}
+
+impl IndexerCheckpointRepository for InMemoryCheckpoints {
+	fn create(
+		&self,
+		checkpoint: IndexerCheckpoint,
+	) -> Result<(), IndexerCheckpointRepositoryError> {
+		self.remove(&checkpoint.indexer_id, |existing| {
+			existing.block_number == checkpoint.block_number
+		});
+		self.checkpoints().push(checkpoint);
+		Ok(())
+	}
+
+	fn find(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<Option<IndexerCheckpoint>, IndexerCheckpointRepositoryError> {
+		Ok(self
+			.checkpoints()
+			.iter()
+			.find(|checkpoint| {
+				checkpoint.indexer_id == indexer_id && checkpoint.block_number == block_number
+			})
+			.cloned())
+	}
+
+	fn latest(
+		&self,
+		indexer_id: &str,
+	) -> Result<Option<IndexerCheckpoint>, IndexerCheckpointRepositoryError> {
+		Ok(self
+			.checkpoints()
+			.iter()
+			.filter(|checkpoint| checkpoint.indexer_id == indexer_id)
+			.max_by_key(|checkpoint| checkpoint.block_number)
+			.cloned())
+	}
+
+	fn delete_before_block(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<(), IndexerCheckpointRepositoryError> {
+		self.remove(indexer_id, |checkpoint| {
+			checkpoint.block_number < block_number
+		});
+		Ok(())
+	}
+
+	fn delete_after_block(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<(), IndexerCheckpointRepositoryError> {
+		self.remove(indexer_id, |checkpoint| {
+			checkpoint.block_number > block_number
+		});
+		Ok(())
+	}
+
+	fn delete(&self, indexer_id: &str) -> Result<(), IndexerCheckpointRepositoryError> {
+		self.remove(indexer_id, |_| true);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::str::FromStr;
+
+	fn checkpoint(indexer_id: &str, block_number: u64) -> IndexerCheckpoint {
+		IndexerCheckpoint {
+			indexer_id: indexer_id.to_string(),
+			block_number,
+			block_hash: HexPrefixedString::from_str(&format!("0x{block_number:x}")).unwrap(),
+		}
+	}
+
+	#[test]
+	fn checkpoints_are_kept_per_indexer() {
+		let checkpoints = InMemoryCheckpoints::default();
+		for block_number in 1..=4 {
+			checkpoints.create(checkpoint("ID", block_number)).unwrap();
+		}
+		checkpoints.create(checkpoint("OTHER", 10)).unwrap();
+
+		checkpoints.delete_before_block("ID", 2).unwrap();
+		checkpoints.delete_after_block("ID", 3).unwrap();
+
+		assert_eq!(None, checkpoints.find("ID", 1).unwrap());
+		assert_eq!(
+			Some(checkpoint("ID", 2)),
+			checkpoints.find("ID", 2).unwrap()
+		);
+		assert_eq!(Some(checkpoint("ID", 3)), checkpoints.latest("ID").unwrap());
+
+		checkpoints.delete("ID").unwrap();
+		assert_eq!(None, checkpoints.latest("ID").unwrap());
+		assert_eq!(
+			Some(checkpoint("OTHER", 10)),
+			checkpoints.latest("OTHER").unwrap()
+		);
+	}
+}
@@ -0,0 +1,95 @@
+use super::{
+	super::apibara::proto::{
+		event::Event as ApibaraEventInner, Event as ApibaraEvent, StarkNetEvent, TopicValue,
+	},
+	node::EmittedEvent,
+};
+use marketplace_domain::HexPrefixedString;
+use starknet::core::utils::get_selector_from_name;
+
+const WORD_SIZE: usize = 32;
+
+impl EmittedEvent {
+	/// Whether the event is one of the given ones, an empty name standing for any event
+	pub fn is_any_of(&self, event_names: &[&str]) -> bool {
+		event_names.iter().any(|event_name| {
+			event_name.is_empty()
+				|| get_selector_from_name(event_name).map_or(false, |selector| {
+					self.keys.first()
+						== Some(&HexPrefixedString::from(selector.to_bytes_be().to_vec()))
+				})
+		})
+	}
+
+	/// Shape the event the way Apibara streams it, so that both are decoded alike
+	pub fn into_apibara_event(self, log_index: u64) -> ApibaraEvent {
+		ApibaraEvent {
+			event: Some(ApibaraEventInner::Starknet(StarkNetEvent {
+				address: word(&self.from_address),
+				log_index,
+				topics: self.keys.iter().map(topic).collect(),
+				data: self.data.iter().map(topic).collect(),
+				transaction_hash: word(&self.transaction_hash),
+			})),
+		}
+	}
+}
+
+fn word(value: &HexPrefixedString) -> Vec<u8> {
+	let bytes = value.to_bytes();
+	let mut word = vec![0; WORD_SIZE.saturating_sub(bytes.len())];
+	word.extend(bytes);
+	word
+}
+
+fn topic(value: &HexPrefixedString) -> TopicValue {
+	TopicValue { value: word(value) }
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::domain::ObservedEvent;
+	use marketplace_domain::{ContractAddress, ContributionEvent, Event};
+	use rstest::*;
+	use std::str::FromStr;
+
+	#[fixture]
+	fn validated_event() -> EmittedEvent {
+		EmittedEvent {
+			from_address: ContractAddress::from_str("0xcb").unwrap(),
+			keys: vec![HexPrefixedString::from(
+				get_selector_from_name("ContributionValidated").unwrap().to_bytes_be().to_vec(),
+			)],
+			data: vec![
+				HexPrefixedString::from_str("0x0c").unwrap(),
+				HexPrefixedString::from_str("0x00").unwrap(),
+			],
+			transaction_hash: HexPrefixedString::from_str("0x64cb").unwrap(),
+		}
+	}
+
+	#[rstest]
+	fn event_is_filtered_by_name(validated_event: EmittedEvent) {
+		assert!(validated_event.is_any_of(&[""]));
+		assert!(validated_event.is_any_of(&["ContributionCreated", "ContributionValidated"]));
+		assert!(!validated_event.is_any_of(&["ContributionCreated"]));
+		assert!(!validated_event.is_any_of(&[]));
+	}
+
+	#[rstest]
+	fn event_is_decoded_like_apibara_events(validated_event: EmittedEvent) {
+		let event = ObservedEvent::try_from(validated_event.into_apibara_event(3)).unwrap();
+
+		assert_eq!(
+			ObservedEvent {
+				event: Event::Contribution(ContributionEvent::Validated {
+					id: HexPrefixedString::from_str("0x0c").unwrap().into(),
+				}),
+				deduplication_id: String::from("0xcb_0x64cb_3"),
+				transaction_hash: HexPrefixedString::from_str("0x64cb").unwrap(),
+			},
+			event
+		);
+	}
+}
@@ -0,0 +1,84 @@
+use super::Client;
+use crate::domain::*;
+use async_trait::async_trait;
+use itertools::Itertools;
+
+#[async_trait]
+impl IndexerRepository for Client {
+	async fn create(&self, indexer: &Indexer) -> Result<(), IndexerRepositoryError> {
+		self.indexers.write().await.insert(indexer.id.to_string(), indexer.clone());
+		Ok(())
+	}
+
+	async fn by_id(
+		&self,
+		indexer_id: &IndexerId,
+	) -> Result<Option<Indexer>, IndexerRepositoryError> {
+		Ok(self.indexers.read().await.get(&indexer_id.to_string()).cloned())
+	}
+
+	/// Forget the indexer along with its progress
+	async fn delete(&self, indexer_id: &IndexerId) -> Result<(), IndexerRepositoryError> {
+		self.checkpoint_repository.delete(&indexer_id.to_string()).map_err(|error| {
+			IndexerRepositoryError::DeleteIndexer {
+				id: indexer_id.clone(),
+				details: error.to_string(),
+			}
+		})?;
+
+		self.indexers.write().await.remove(&indexer_id.to_string());
+		Ok(())
+	}
+
+	async fn list(&self) -> Result<Vec<Indexer>, IndexerRepositoryError> {
+		let indexers = self.indexers.read().await;
+		Ok(indexers.keys().sorted().map(|id| indexers[id].clone()).collect())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{super::node::MockNode, *};
+	use marketplace_domain::MockIndexerCheckpointRepository;
+	use std::{sync::Arc, time::Duration};
+
+	fn indexer() -> Indexer {
+		Indexer::new("ID".into(), Network::Starknet, 42, vec![])
+	}
+
+	#[tokio::test]
+	async fn created_indexers_can_be_found() {
+		let client = Client::new(
+			Arc::new(MockNode::new()),
+			Arc::new(MockIndexerCheckpointRepository::new()),
+			Duration::ZERO,
+		);
+
+		client.create(&indexer()).await.unwrap();
+
+		assert_eq!(Some(indexer()), client.by_id(&"ID".into()).await.unwrap());
+		assert_eq!(None, client.by_id(&"OTHER".into()).await.unwrap());
+		assert_eq!(vec![indexer()], client.list().await.unwrap());
+	}
+
+	#[tokio::test]
+	async fn deleting_an_indexer_forgets_its_progress() {
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository
+			.expect_delete()
+			.withf(|indexer_id| indexer_id == "ID")
+			.times(1)
+			.returning(|_| Ok(()));
+
+		let client = Client::new(
+			Arc::new(MockNode::new()),
+			Arc::new(checkpoint_repository),
+			Duration::ZERO,
+		);
+
+		client.create(&indexer()).await.unwrap();
+		client.delete(&"ID".into()).await.unwrap();
+
+		assert_eq!(None, client.by_id(&"ID".into()).await.unwrap());
+	}
+}
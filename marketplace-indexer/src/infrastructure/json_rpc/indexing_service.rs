@@ -0,0 +1,501 @@
+use super::{node::Error as NodeError, Client};
+use crate::domain::*;
+use async_trait::async_trait;
+use log::info;
+use marketplace_domain::{IndexerCheckpoint, IndexerCheckpointRepositoryError};
+use std::sync::Arc;
+
+/// Number of blocks kept in the checkpoints to find where the chain forked
+const CHECKPOINT_HISTORY_SIZE: u64 = 100;
+
+#[async_trait]
+impl IndexingService for Client {
+	async fn fetch_new_events(
+		&self,
+		indexer: &Indexer,
+		observer: Arc<dyn BlockchainObserver>,
+	) -> Result<(), IndexingServiceError> {
+		observer.on_connect(&indexer.id).await;
+
+		loop {
+			if !self.index_next_block(indexer, observer.as_ref()).await? {
+				tokio::time::sleep(self.polling_interval).await;
+			}
+		}
+	}
+}
+
+impl Client {
+	/// Index the block following the last checkpoint, returning false when the chain has no new
+	/// block yet
+	async fn index_next_block(
+		&self,
+		indexer: &Indexer,
+		observer: &dyn BlockchainObserver,
+	) -> Result<bool, IndexingServiceError> {
+		let indexer_id = indexer.id.to_string();
+		let checkpoint =
+			self.checkpoint_repository.latest(&indexer_id).map_err(checkpoint_error)?;
+
+		let block_number = checkpoint
+			.as_ref()
+			.map(|checkpoint| checkpoint.block_number + 1)
+			.unwrap_or(indexer.index_from_block);
+		if block_number > self.node.block_number().await.map_err(receive_error)? {
+			return Ok(false);
+		}
+
+		let block = self.node.block(block_number).await.map_err(receive_error)?;
+		if let Some(checkpoint) = checkpoint {
+			if block.parent_hash != checkpoint.block_hash {
+				self.rollback(indexer, observer, checkpoint.block_number).await?;
+				return Ok(true);
+			}
+		}
+
+		// All the events of the block are fetched, so that they keep the block order across
+		// contracts and their log index is their position in the block, like Apibara's
+		let observed_events: Vec<_> = self
+			.node
+			.block_events(block_number)
+			.await
+			.map_err(receive_error)?
+			.into_iter()
+			.enumerate()
+			.filter(|(_, event)| {
+				indexer.filters.iter().any(|filter| {
+					filter.contract_address == event.from_address
+						&& event.is_any_of(&[filter.event_name.as_str()])
+				})
+			})
+			.filter_map(|(log_index, event)| {
+				ObservedEvent::try_from(event.into_apibara_event(log_index as u64)).ok()
+			})
+			.collect();
+
+		// The checkpoint is only saved once the events have been processed
+		observer
+			.on_new_block_events(&observed_events, &block.block_hash, block_number)
+			.await?;
+
+		observer.on_new_block(&block.block_hash, block_number).await;
+
+		self.checkpoint_repository
+			.create(IndexerCheckpoint {
+				indexer_id: indexer_id.clone(),
+				block_number,
+				block_hash: block.block_hash,
+			})
+			.map_err(checkpoint_error)?;
+		if block_number > CHECKPOINT_HISTORY_SIZE {
+			self.checkpoint_repository
+				.delete_before_block(&indexer_id, block_number - CHECKPOINT_HISTORY_SIZE)
+				.map_err(checkpoint_error)?;
+		}
+
+		Ok(true)
+	}
+
+	/// Walk back the checkpoints from an orphaned block until one is still part of the chain,
+	/// which becomes the new head. Indexing starts over if none is.
+	async fn rollback(
+		&self,
+		indexer: &Indexer,
+		observer: &dyn BlockchainObserver,
+		orphaned_block_number: u64,
+	) -> Result<(), IndexingServiceError> {
+		let indexer_id = indexer.id.to_string();
+		let mut block_number = orphaned_block_number;
+
+		while block_number > indexer.index_from_block {
+			block_number -= 1;
+
+			let checkpoint = match self
+				.checkpoint_repository
+				.find(&indexer_id, block_number)
+				.map_err(checkpoint_error)?
+			{
+				Some(checkpoint) => checkpoint,
+				None => break,
+			};
+
+			let block = self.node.block(block_number).await.map_err(receive_error)?;
+			if block.block_hash == checkpoint.block_hash {
+				info!("Chain forked after block {block_number}, rolling back indexer {indexer_id}");
+				self.checkpoint_repository
+					.delete_after_block(&indexer_id, block_number)
+					.map_err(checkpoint_error)?;
+				observer.on_reorg(&block.block_hash, block_number).await;
+				return Ok(());
+			}
+		}
+
+		info!("Chain forked beyond the checkpoints, indexer {indexer_id} starts over");
+		self.checkpoint_repository.delete(&indexer_id).map_err(checkpoint_error)?;
+		let start_block_number = indexer.index_from_block.saturating_sub(1);
+		let start_block = self.node.block(start_block_number).await.map_err(receive_error)?;
+		observer.on_reorg(&start_block.block_hash, start_block_number).await;
+		Ok(())
+	}
+}
+
+fn receive_error(error: NodeError) -> IndexingServiceError {
+	IndexingServiceError::Receive(error.to_string())
+}
+
+fn checkpoint_error(error: IndexerCheckpointRepositoryError) -> IndexingServiceError {
+	IndexingServiceError::Checkpoint(error.to_string())
+}
+
+#[cfg(test)]
+mod test {
+	use super::{
+		super::node::{BlockHeader, EmittedEvent, MockNode},
+		*,
+	};
+	use marketplace_domain::{ContractAddress, HexPrefixedString, MockIndexerCheckpointRepository};
+	use mockall::predicate::*;
+	use rstest::*;
+	use starknet::core::utils::get_selector_from_name;
+	use std::{str::FromStr, time::Duration};
+
+	fn hash(value: u64) -> HexPrefixedString {
+		HexPrefixedString::from_str(&format!("0x{value:x}")).unwrap()
+	}
+
+	fn block(block_number: u64, block_hash: u64, parent_hash: u64) -> BlockHeader {
+		BlockHeader {
+			block_hash: hash(block_hash),
+			parent_hash: hash(parent_hash),
+			block_number,
+		}
+	}
+
+	fn checkpoint(block_number: u64, block_hash: u64) -> IndexerCheckpoint {
+		IndexerCheckpoint {
+			indexer_id: String::from("ID"),
+			block_number,
+			block_hash: hash(block_hash),
+		}
+	}
+
+	#[fixture]
+	fn contract_address() -> ContractAddress {
+		ContractAddress::from_str("0xcb").unwrap()
+	}
+
+	#[fixture]
+	fn indexer(contract_address: ContractAddress) -> Indexer {
+		Indexer::new(
+			"ID".into(),
+			Network::Starknet,
+			10,
+			vec![EventFilter::new(contract_address, "ContributionValidated")],
+		)
+	}
+
+	#[fixture]
+	fn node() -> MockNode {
+		let mut node = MockNode::new();
+		node.expect_block_number().returning(|| Ok(20));
+		node
+	}
+
+	fn validated_event() -> EmittedEvent {
+		EmittedEvent {
+			from_address: ContractAddress::from_str("0xcb").unwrap(),
+			keys: vec![HexPrefixedString::from(
+				get_selector_from_name("ContributionValidated").unwrap().to_bytes_be().to_vec(),
+			)],
+			data: vec![hash(12), hash(0)],
+			transaction_hash: hash(0x64cb),
+		}
+	}
+
+	fn client(node: MockNode, checkpoint_repository: MockIndexerCheckpointRepository) -> Client {
+		Client::new(
+			Arc::new(node),
+			Arc::new(checkpoint_repository),
+			Duration::ZERO,
+		)
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn indexing_starts_at_the_indexer_first_block(indexer: Indexer, mut node: MockNode) {
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository.expect_latest().returning(|_| Ok(None));
+		checkpoint_repository
+			.expect_create()
+			.with(eq(checkpoint(10, 0xa)))
+			.times(1)
+			.returning(|_| Ok(()));
+
+		node.expect_block().with(eq(10)).returning(|_| Ok(block(10, 0xa, 0x9)));
+		node.expect_block_events()
+			.with(eq(10))
+			.returning(|_| Ok(vec![validated_event()]));
+
+		let mut observer = MockBlockchainObserver::new();
+		observer
+			.expect_on_new_block_events()
+			.withf(|events, block_hash, block_number| {
+				events.len() == 1
+					&& events[0].deduplication_id == "0xcb_0x64cb_0"
+					&& *block_hash == hash(0xa)
+					&& *block_number == 10
+			})
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+		observer
+			.expect_on_new_block()
+			.with(eq(hash(0xa)), eq(10))
+			.times(1)
+			.return_const(());
+
+		let indexed = client(node, checkpoint_repository)
+			.index_next_block(&indexer, &observer)
+			.await
+			.unwrap();
+		assert!(indexed);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn block_is_not_checkpointed_when_its_events_failed(
+		indexer: Indexer,
+		mut node: MockNode,
+	) {
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository.expect_latest().returning(|_| Ok(None));
+		checkpoint_repository.expect_create().never();
+
+		node.expect_block().with(eq(10)).returning(|_| Ok(block(10, 0xa, 0x9)));
+		node.expect_block_events()
+			.with(eq(10))
+			.returning(|_| Ok(vec![validated_event()]));
+
+		let mut observer = MockBlockchainObserver::new();
+		observer.expect_on_new_block_events().returning(|_, _, block_number| {
+			Err(BlockchainObserverError::Store {
+				block_number,
+				details: String::from("oops"),
+			})
+		});
+		observer.expect_on_new_block().never();
+
+		let result =
+			client(node, checkpoint_repository).index_next_block(&indexer, &observer).await;
+		assert!(matches!(result, Err(IndexingServiceError::Observer(_))));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn events_not_filtered_are_skipped(indexer: Indexer, mut node: MockNode) {
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository
+			.expect_latest()
+			.returning(|_| Ok(Some(checkpoint(14, 0xe))));
+		checkpoint_repository.expect_create().returning(|_| Ok(()));
+
+		node.expect_block().with(eq(15)).returning(|_| Ok(block(15, 0xf, 0xe)));
+		node.expect_block_events().with(eq(15)).returning(|_| {
+			Ok(vec![
+				EmittedEvent {
+					keys: vec![hash(0x1234)],
+					..validated_event()
+				},
+				EmittedEvent {
+					from_address: ContractAddress::from_str("0xdd").unwrap(),
+					..validated_event()
+				},
+			])
+		});
+
+		let mut observer = MockBlockchainObserver::new();
+		observer
+			.expect_on_new_block_events()
+			.withf(|events, _, _| events.is_empty())
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+		observer.expect_on_new_block().times(1).return_const(());
+
+		client(node, checkpoint_repository)
+			.index_next_block(&indexer, &observer)
+			.await
+			.unwrap();
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn events_keep_the_block_order_and_log_index_across_contracts(
+		contract_address: ContractAddress,
+		mut node: MockNode,
+	) {
+		let other_contract_address = ContractAddress::from_str("0xdd").unwrap();
+		let indexer = Indexer::new(
+			"ID".into(),
+			Network::Starknet,
+			10,
+			vec![
+				EventFilter::new(contract_address, "ContributionValidated"),
+				EventFilter::new(other_contract_address.clone(), ""),
+			],
+		);
+
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository.expect_latest().returning(|_| Ok(None));
+		checkpoint_repository.expect_create().returning(|_| Ok(()));
+
+		node.expect_block().with(eq(10)).returning(|_| Ok(block(10, 0xa, 0x9)));
+		node.expect_block_events().with(eq(10)).returning(move |_| {
+			Ok(vec![
+				EmittedEvent {
+					from_address: other_contract_address.clone(),
+					transaction_hash: hash(0x1),
+					..validated_event()
+				},
+				EmittedEvent {
+					from_address: ContractAddress::from_str("0xee").unwrap(),
+					..validated_event()
+				},
+				validated_event(),
+				EmittedEvent {
+					from_address: other_contract_address.clone(),
+					..validated_event()
+				},
+			])
+		});
+
+		let mut observer = MockBlockchainObserver::new();
+		observer
+			.expect_on_new_block_events()
+			.withf(|events, _, _| {
+				events.iter().map(|event| event.deduplication_id.as_str()).collect::<Vec<_>>()
+					== ["0xdd_0x1_0", "0xcb_0x64cb_2", "0xdd_0x64cb_3"]
+			})
+			.times(1)
+			.returning(|_, _, _| Ok(()));
+		observer.expect_on_new_block().return_const(());
+
+		client(node, checkpoint_repository)
+			.index_next_block(&indexer, &observer)
+			.await
+			.unwrap();
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn nothing_is_indexed_until_a_new_block(indexer: Indexer, mut node: MockNode) {
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository
+			.expect_latest()
+			.returning(|_| Ok(Some(checkpoint(20, 0x14))));
+
+		node.expect_block().never();
+
+		let observer = MockBlockchainObserver::new();
+
+		let indexed = client(node, checkpoint_repository)
+			.index_next_block(&indexer, &observer)
+			.await
+			.unwrap();
+		assert!(!indexed);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn old_checkpoints_are_pruned(indexer: Indexer) {
+		let mut node = MockNode::new();
+		node.expect_block_number().returning(|| Ok(200));
+		node.expect_block().returning(|_| Ok(block(150, 0x96, 0x95)));
+		node.expect_block_events().returning(|_| Ok(vec![]));
+
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository
+			.expect_latest()
+			.returning(|_| Ok(Some(checkpoint(149, 0x95))));
+		checkpoint_repository.expect_create().returning(|_| Ok(()));
+		checkpoint_repository
+			.expect_delete_before_block()
+			.withf(|indexer_id, block_number| indexer_id == "ID" && *block_number == 50)
+			.times(1)
+			.returning(|_, _| Ok(()));
+
+		let mut observer = MockBlockchainObserver::new();
+		observer.expect_on_new_block_events().returning(|_, _, _| Ok(()));
+		observer.expect_on_new_block().return_const(());
+
+		client(node, checkpoint_repository)
+			.index_next_block(&indexer, &observer)
+			.await
+			.unwrap();
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn reorg_is_detected_by_parent_hash_mismatch(indexer: Indexer, mut node: MockNode) {
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository
+			.expect_latest()
+			.returning(|_| Ok(Some(checkpoint(14, 0xe))));
+		checkpoint_repository
+			.expect_find()
+			.withf(|_, block_number| *block_number == 13)
+			.returning(|_, _| Ok(Some(checkpoint(13, 0xd))));
+		checkpoint_repository
+			.expect_find()
+			.withf(|_, block_number| *block_number == 12)
+			.returning(|_, _| Ok(Some(checkpoint(12, 0xc))));
+		checkpoint_repository
+			.expect_delete_after_block()
+			.withf(|indexer_id, block_number| indexer_id == "ID" && *block_number == 12)
+			.times(1)
+			.returning(|_, _| Ok(()));
+		checkpoint_repository.expect_create().never();
+
+		node.expect_block().with(eq(15)).returning(|_| Ok(block(15, 0xff, 0xee)));
+		node.expect_block().with(eq(13)).returning(|_| Ok(block(13, 0xdd, 0xc)));
+		node.expect_block().with(eq(12)).returning(|_| Ok(block(12, 0xc, 0xb)));
+		node.expect_block_events().never();
+
+		let mut observer = MockBlockchainObserver::new();
+		observer.expect_on_new_block_events().never();
+		observer.expect_on_reorg().with(eq(hash(0xc)), eq(12)).times(1).return_const(());
+
+		let indexed = client(node, checkpoint_repository)
+			.index_next_block(&indexer, &observer)
+			.await
+			.unwrap();
+		assert!(indexed);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn indexing_starts_over_when_the_fork_is_beyond_the_checkpoints(
+		indexer: Indexer,
+		mut node: MockNode,
+	) {
+		let mut checkpoint_repository = MockIndexerCheckpointRepository::new();
+		checkpoint_repository
+			.expect_latest()
+			.returning(|_| Ok(Some(checkpoint(14, 0xe))));
+		checkpoint_repository.expect_find().returning(|_, _| Ok(None));
+		checkpoint_repository
+			.expect_delete()
+			.withf(|indexer_id| indexer_id == "ID")
+			.times(1)
+			.returning(|_| Ok(()));
+
+		node.expect_block().with(eq(15)).returning(|_| Ok(block(15, 0xff, 0xee)));
+		node.expect_block().with(eq(9)).returning(|_| Ok(block(9, 0x9, 0x8)));
+
+		let mut observer = MockBlockchainObserver::new();
+		observer.expect_on_reorg().with(eq(hash(0x9)), eq(9)).times(1).return_const(());
+
+		client(node, checkpoint_repository)
+			.index_next_block(&indexer, &observer)
+			.await
+			.unwrap();
+	}
+}
@@ -0,0 +1,57 @@
+mod chain_head;
+mod checkpoints;
+mod events;
+mod indexer_repository;
+mod indexing_service;
+mod node;
+
+pub use checkpoints::InMemoryCheckpoints;
+use marketplace_domain::IndexerCheckpointRepository;
+pub use node::HttpNode;
+use node::Node;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+use url::Url;
+
+use crate::domain::Indexer;
+
+const POLLING_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Index events by polling a StarkNet JSON-RPC node.
+/// Indexers are defined by the configuration at each start, only their progress is stored.
+pub struct Client {
+	node: Arc<dyn Node>,
+	checkpoint_repository: Arc<dyn IndexerCheckpointRepository>,
+	indexers: RwLock<HashMap<String, Indexer>>,
+	polling_interval: Duration,
+}
+
+impl Client {
+	fn new(
+		node: Arc<dyn Node>,
+		checkpoint_repository: Arc<dyn IndexerCheckpointRepository>,
+		polling_interval: Duration,
+	) -> Self {
+		Self {
+			node,
+			checkpoint_repository,
+			indexers: Default::default(),
+			polling_interval,
+		}
+	}
+
+	pub fn default(checkpoint_repository: Arc<dyn IndexerCheckpointRepository>) -> Self {
+		Self::new(
+			Arc::new(HttpNode::new(json_rpc_uri())),
+			checkpoint_repository,
+			POLLING_INTERVAL,
+		)
+	}
+}
+
+fn json_rpc_uri() -> Url {
+	std::env::var("JSON_RPC_URI")
+		.expect("JSON_RPC_URI must be set")
+		.parse()
+		.expect("JSON_RPC_URI is not a valid URL")
+}
@@ -0,0 +1,206 @@
+use async_trait::async_trait;
+use marketplace_domain::{ContractAddress, HexPrefixedString};
+use mockall::automock;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+use url::Url;
+
+const EVENTS_PAGE_SIZE: u64 = 100;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("unable to reach the StarkNet node: {0}")]
+	Http(#[from] reqwest::Error),
+	#[error("the StarkNet node answered with an error: {message} ({code})")]
+	Rpc { code: i64, message: String },
+	#[error("the StarkNet node answered with an empty response")]
+	EmptyResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BlockHeader {
+	pub block_hash: HexPrefixedString,
+	pub parent_hash: HexPrefixedString,
+	pub block_number: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EmittedEvent {
+	pub from_address: ContractAddress,
+	pub keys: Vec<HexPrefixedString>,
+	pub data: Vec<HexPrefixedString>,
+	pub transaction_hash: HexPrefixedString,
+}
+
+/// The subset of the StarkNet JSON-RPC API needed to follow the chain
+#[automock]
+#[async_trait]
+pub trait Node: Send + Sync {
+	async fn block_number(&self) -> Result<u64, Error>;
+	async fn block(&self, block_number: u64) -> Result<BlockHeader, Error>;
+	/// All the events emitted in a block, in the order they were emitted
+	async fn block_events(&self, block_number: u64) -> Result<Vec<EmittedEvent>, Error>;
+}
+
+pub struct HttpNode {
+	json_rpc_uri: Url,
+	http_client: reqwest::Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcError {
+	code: i64,
+	message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcResponse<R> {
+	result: Option<R>,
+	error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventsPage {
+	events: Vec<EmittedEvent>,
+	is_last_page: bool,
+}
+
+impl HttpNode {
+	pub fn new(json_rpc_uri: Url) -> Self {
+		Self {
+			json_rpc_uri,
+			http_client: reqwest::Client::new(),
+		}
+	}
+
+	async fn call<R: DeserializeOwned>(&self, method: &str, params: Value) -> Result<R, Error> {
+		let response: JsonRpcResponse<R> = self
+			.http_client
+			.post(self.json_rpc_uri.clone())
+			.json(&json!({
+				"jsonrpc": "2.0",
+				"id": 0,
+				"method": method,
+				"params": params,
+			}))
+			.send()
+			.await?
+			.error_for_status()?
+			.json()
+			.await?;
+
+		match (response.result, response.error) {
+			(_, Some(error)) => Err(Error::Rpc {
+				code: error.code,
+				message: error.message,
+			}),
+			(Some(result), None) => Ok(result),
+			(None, None) => Err(Error::EmptyResponse),
+		}
+	}
+}
+
+#[async_trait]
+impl Node for HttpNode {
+	async fn block_number(&self) -> Result<u64, Error> {
+		self.call("starknet_blockNumber", json!([])).await
+	}
+
+	async fn block(&self, block_number: u64) -> Result<BlockHeader, Error> {
+		self.call(
+			"starknet_getBlockWithTxHashes",
+			json!([{ "block_number": block_number }]),
+		)
+		.await
+	}
+
+	async fn block_events(&self, block_number: u64) -> Result<Vec<EmittedEvent>, Error> {
+		let mut events = vec![];
+		let mut page_number = 0;
+
+		loop {
+			let page: EventsPage = self
+				.call(
+					"starknet_getEvents",
+					json!([{
+						"from_block": { "block_number": block_number },
+						"to_block": { "block_number": block_number },
+						"keys": [],
+						"page_size": EVENTS_PAGE_SIZE,
+						"page_number": page_number,
+					}]),
+				)
+				.await?;
+
+			events.extend(page.events);
+			if page.is_last_page {
+				return Ok(events);
+			}
+			page_number += 1;
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::str::FromStr;
+
+	#[test]
+	fn events_page_can_be_deserialized() {
+		let page: JsonRpcResponse<EventsPage> = serde_json::from_value(json!({
+			"jsonrpc": "2.0",
+			"id": 0,
+			"result": {
+				"events": [{
+					"from_address": "0x5cf5685a971769272adbb7ab355d305e4e76ffcbb795a4fcfb7d8bb92042d65",
+					"keys": ["0x12"],
+					"data": ["0x1", "0x0"],
+					"block_hash": "0xabc",
+					"block_number": 42,
+					"transaction_hash": "0x64cb",
+				}],
+				"page_number": 0,
+				"is_last_page": true,
+			},
+		}))
+		.unwrap();
+
+		let page = page.result.unwrap();
+		assert!(page.is_last_page);
+		assert_eq!(
+			vec![EmittedEvent {
+				from_address: ContractAddress::from_str(
+					"0x05cf5685a971769272adbb7ab355d305e4e76ffcbb795a4fcfb7d8bb92042d65"
+				)
+				.unwrap(),
+				keys: vec![HexPrefixedString::from_str("0x12").unwrap()],
+				data: vec![
+					HexPrefixedString::from_str("0x1").unwrap(),
+					HexPrefixedString::from_str("0x0").unwrap()
+				],
+				transaction_hash: HexPrefixedString::from_str("0x64cb").unwrap(),
+			}],
+			page.events
+		);
+	}
+
+	#[test]
+	fn block_header_can_be_deserialized() {
+		let block: BlockHeader = serde_json::from_value(json!({
+			"block_hash": "0xabc",
+			"parent_hash": "0xabb",
+			"block_number": 42,
+			"status": "ACCEPTED_ON_L2",
+			"transactions": [],
+		}))
+		.unwrap();
+
+		assert_eq!(42, block.block_number);
+		assert_eq!(
+			HexPrefixedString::from_str("0xabb").unwrap(),
+			block.parent_hash
+		);
+	}
+}
@@ -1,2 +1,8 @@
 mod apibara;
 pub use apibara::Client as ApibaraClient;
+
+mod json_rpc;
+pub use json_rpc::{Client as JsonRpcClient, HttpNode as JsonRpcNode, InMemoryCheckpoints};
+
+mod replay;
+pub use replay::Client as ReplayClient;
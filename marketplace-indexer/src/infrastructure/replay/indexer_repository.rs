@@ -0,0 +1,26 @@
+use super::Client;
+use crate::domain::*;
+use async_trait::async_trait;
+
+/// A recording does not depend on any indexer, there is nothing to store
+#[async_trait]
+impl IndexerRepository for Client {
+	async fn create(&self, _indexer: &Indexer) -> Result<(), IndexerRepositoryError> {
+		Ok(())
+	}
+
+	async fn by_id(
+		&self,
+		_indexer_id: &IndexerId,
+	) -> Result<Option<Indexer>, IndexerRepositoryError> {
+		Ok(None)
+	}
+
+	async fn delete(&self, _indexer_id: &IndexerId) -> Result<(), IndexerRepositoryError> {
+		Ok(())
+	}
+
+	async fn list(&self) -> Result<Vec<Indexer>, IndexerRepositoryError> {
+		Ok(vec![])
+	}
+}
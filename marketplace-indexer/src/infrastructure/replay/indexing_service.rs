@@ -0,0 +1,160 @@
+use super::{
+	super::apibara::{handle_response, recording},
+	Client,
+};
+use crate::domain::*;
+use async_trait::async_trait;
+use std::{
+	fs::File,
+	io::{BufRead, BufReader},
+	sync::Arc,
+};
+use tokio::sync::mpsc;
+
+#[async_trait]
+impl IndexingService for Client {
+	async fn fetch_new_events(
+		&self,
+		indexer: &Indexer,
+		observer: Arc<dyn BlockchainObserver>,
+	) -> Result<(), IndexingServiceError> {
+		let path = recording::path(&self.recordings, &indexer.id.to_string());
+		let file = File::open(&path).map_err(|error| IndexingServiceError::Connection {
+			id: indexer.id.clone(),
+			details: format!("unable to open {}: {error}", path.display()),
+		})?;
+
+		replay(BufReader::new(file), observer.as_ref()).await
+	}
+}
+
+/// Handle the recorded responses as if they were received from Apibara
+async fn replay<R: BufRead>(
+	recorded: R,
+	observer: &dyn BlockchainObserver,
+) -> Result<(), IndexingServiceError> {
+	// Acknowledgements are only meaningful to the Apibara server, they are dropped
+	let (sender, mut acknowledgements) = mpsc::channel(1);
+
+	for (index, line) in recorded.lines().enumerate() {
+		let line = line.map_err(|error| IndexingServiceError::Receive(error.to_string()))?;
+		if line.trim().is_empty() {
+			continue;
+		}
+
+		let response = recording::decode(&line).map_err(|error| {
+			IndexingServiceError::Receive(format!(
+				"invalid response on line {}: {error}",
+				index + 1
+			))
+		})?;
+
+		handle_response(response, &sender, observer).await?;
+		while acknowledgements.try_recv().is_ok() {}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use marketplace_domain::{ContributionEvent, Event, HexPrefixedString};
+	use mockall::{predicate::*, Sequence};
+	use std::str::FromStr;
+
+	const RECORDING: &str = include_str!("fixtures/reorg.stream");
+
+	#[tokio::test]
+	async fn recording_is_replayed_in_order() {
+		let block_hash = |hash| HexPrefixedString::from_str(hash).unwrap();
+		let mut observer = MockBlockchainObserver::new();
+		let mut sequence = Sequence::new();
+
+		observer
+			.expect_on_connect()
+			.with(eq(IndexerId::from("contribution-indexer")))
+			.times(1)
+			.in_sequence(&mut sequence)
+			.return_const(());
+		observer
+			.expect_on_new_block_events()
+			.withf(|events, _, block_number| {
+				events.len() == 1
+					&& events[0].event
+						== Event::Contribution(ContributionEvent::Validated {
+							id: HexPrefixedString::from_str("0x0c").unwrap().into(),
+						}) && events[0].deduplication_id == "0xcb_0x64cb_0"
+					&& *block_number == 42
+			})
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(|_, _, _| Ok(()));
+		observer
+			.expect_on_new_block()
+			.with(eq(block_hash("0x2a")), eq(42))
+			.times(1)
+			.in_sequence(&mut sequence)
+			.return_const(());
+		observer
+			.expect_on_reorg()
+			.with(eq(block_hash("0x29")), eq(41))
+			.times(1)
+			.in_sequence(&mut sequence)
+			.return_const(());
+
+		replay(RECORDING.as_bytes(), &observer).await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn recording_sessions_are_all_replayed_in_order() {
+		use crate::infrastructure::apibara::proto::{
+			connect_indexer_response::Message as ResponseMessage, BlockHeader,
+			ConnectIndexerResponse, NewBlock,
+		};
+
+		let new_block = |number| ConnectIndexerResponse {
+			message: Some(ResponseMessage::NewBlock(NewBlock {
+				new_head: Some(BlockHeader {
+					hash: vec![number as u8],
+					number,
+					..Default::default()
+				}),
+			})),
+		};
+		let path = std::env::temp_dir().join(format!("{}.stream", rand::random::<u64>()));
+
+		// The indexer reconnects after the first session
+		recording::Recorder::open(&path).unwrap().record(&new_block(41));
+		recording::Recorder::open(&path).unwrap().record(&new_block(42));
+
+		let mut observer = MockBlockchainObserver::new();
+		let mut sequence = Sequence::new();
+		for number in [41, 42] {
+			observer
+				.expect_on_new_block()
+				.with(always(), eq(number))
+				.times(1)
+				.in_sequence(&mut sequence)
+				.return_const(());
+		}
+
+		let result = replay(BufReader::new(File::open(&path).unwrap()), &observer).await;
+		std::fs::remove_file(&path).unwrap();
+		result.unwrap();
+	}
+
+	#[tokio::test]
+	async fn invalid_responses_stop_the_replay() {
+		let mut observer = MockBlockchainObserver::new();
+		observer.expect_on_connect().times(1).return_const(());
+
+		let recording = format!("{}\n\nnot a response\n", RECORDING.lines().next().unwrap());
+		let result = replay(recording.as_bytes(), &observer).await;
+
+		assert!(matches!(
+			result,
+			Err(IndexingServiceError::Receive(message)) if message.contains("line 3")
+		));
+	}
+}
@@ -0,0 +1,26 @@
+mod indexer_repository;
+mod indexing_service;
+
+use std::path::PathBuf;
+
+/// Feed the Apibara streams recorded into a directory through the observers, instead of indexing
+/// the chain
+pub struct Client {
+	recordings: PathBuf,
+}
+
+impl Client {
+	pub fn new<P: Into<PathBuf>>(recordings: P) -> Self {
+		Self {
+			recordings: recordings.into(),
+		}
+	}
+
+	pub fn from_env() -> Self {
+		Self::new(replay_dir())
+	}
+}
+
+fn replay_dir() -> String {
+	std::env::var("REPLAY_DIR").expect("REPLAY_DIR must be set")
+}
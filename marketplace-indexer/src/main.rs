@@ -1,18 +1,52 @@
 mod application;
+mod cli;
 mod domain;
 mod infrastructure;
+mod routes;
 
-use crate::{application::IndexerBuilder, domain::*, infrastructure::ApibaraClient};
+use crate::{
+	application::{IndexerConfig, IndexersConfig, IndexingSupervisor, OnConflict, StatusTracker},
+	cli::{Command, USAGE},
+	domain::*,
+	infrastructure::{
+		ApibaraClient, InMemoryCheckpoints, JsonRpcClient, JsonRpcNode, ReplayClient,
+	},
+	routes::ChainHeads,
+};
 use dotenv::dotenv;
+use futures::future::try_join_all;
+use log::error;
 use marketplace_domain::*;
 use marketplace_infrastructure::{database, github};
 use slog::{o, Drain, Logger};
-use std::sync::Arc;
+use std::{process::ExitCode, sync::Arc};
 
 fn channel_size() -> usize {
 	std::env::var("SLOG_CHANNEL_SIZE").unwrap_or_default().parse().unwrap_or(256)
 }
 
+fn confirmation_blocks_count() -> u64 {
+	std::env::var("CONFIRMATION_BLOCKS_COUNT")
+		.unwrap_or_default()
+		.parse()
+		.unwrap_or(0)
+}
+
+/// Indexers of the repository, run unless INDEXERS_CONFIG points to another file
+const DEFAULT_INDEXERS_CONFIG: &str = include_str!("../../indexers.toml");
+
+fn indexers_config() -> IndexersConfig {
+	match std::env::var("INDEXERS_CONFIG") {
+		Ok(path) => IndexersConfig::from_file(path),
+		Err(_) => DEFAULT_INDEXERS_CONFIG.parse(),
+	}
+	.expect("Invalid indexers configuration")
+}
+
+fn status_port() -> u16 {
+	std::env::var("STATUS_PORT").unwrap_or_default().parse().unwrap_or(8001)
+}
+
 fn get_root_logger() -> Logger {
 	let drain = match std::env::var("LOGS") {
 		Ok(logs) if &logs == "terminal" => slog_async::Async::new(slog_envlogger::new(
@@ -33,56 +67,221 @@ fn get_root_logger() -> Logger {
 }
 
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
 	dotenv().ok();
 	let _global_logger_guard = slog_scope::set_global_logger(get_root_logger());
 	_global_logger_guard.cancel_reset();
-	github::Client::initialize();
 
-	let apibara_client =
-		Arc::new(ApibaraClient::default().await.expect("Unable to connect to Apibara server"));
+	let command = match Command::parse(std::env::args().skip(1)) {
+		Ok(command) => command,
+		Err(error) => {
+			eprintln!("{error}\n\n{USAGE}");
+			return ExitCode::FAILURE;
+		},
+	};
 
-	let indexer = IndexerBuilder::new(apibara_client.clone())
-		.network(Network::Starknet)
-		.start_at_block(311611)
-		.on_conflict_do_nothing()
-		.filter(contributions_contract_address(), "")
-		.build("contribution-indexer".into())
-		.await
-		.expect("Unable to create the indexer");
+	match command {
+		Command::Run => run().await,
+		Command::DecodeOnly {
+			indexer_id,
+			from_block,
+		} => decode_only(&indexer_id, from_block).await,
+		Command::Administrate(administration) => {
+			let database = Arc::new(database::Client::new(database::init_pool()));
+			let (indexer_repository, _) = indexing_clients(database).await;
+
+			if let Err(error) =
+				administration.execute(indexer_repository, &mut std::io::stdout()).await
+			{
+				eprintln!("{error}");
+				return ExitCode::FAILURE;
+			}
+		},
+	}
+
+	ExitCode::SUCCESS
+}
+
+async fn run() {
+	github::Client::initialize();
 
 	let database = Arc::new(database::Client::new(database::init_pool()));
+	let (indexer_repository, indexing_service) = indexing_clients(database.clone()).await;
+
+	let config = indexers_config();
+	let mut indexers = Vec::new();
+	for indexer_config in &config.indexers {
+		indexers.push(
+			indexer_config
+				.build(indexer_repository.clone())
+				.await
+				.expect("Unable to create the indexer"),
+		);
+	}
+
 	let github = Arc::new(github::Client::new());
 	let uuid_generator = Arc::new(RandomUuidGenerator {});
 
-	let contribution_observer =
-		build_contribution_observers(database.clone(), github.clone(), uuid_generator);
+	let (status_trackers, indexings): (Vec<_>, Vec<_>) = indexers
+		.into_iter()
+		.map(|indexer| {
+			let supervisor = IndexingSupervisor::new(indexing_service.clone());
+			let status_tracker = Arc::new(StatusTracker::new(
+				indexer.id.clone(),
+				indexer.network.clone(),
+				supervisor.failures(),
+			));
+
+			// Each indexer has its own observers, so that its pending events and reorgs only
+			// touch the events it indexed. The tracker comes last, to only count the blocks once
+			// processed by the other observers.
+			let observer = Arc::new(BlockchainObserverComposite::new(vec![
+				build_contribution_observers(
+					&indexer.id,
+					database.clone(),
+					github.clone(),
+					uuid_generator.clone(),
+				),
+				status_tracker.clone(),
+			]));
+
+			(status_tracker, async move {
+				supervisor.fetch_new_events(&indexer, observer).await
+			})
+		})
+		.unzip();
+
+	let status_server = routes::status_server(status_port(), status_trackers, chain_heads());
+	tokio::spawn(async move {
+		if let Err(error) = status_server.launch().await {
+			error!("Status server stopped: {error}");
+		}
+	});
+
+	try_join_all(indexings).await.expect("Error while fetching events");
+}
+
+/// Print the events of a configured indexer as JSON lines. It is indexed under its own id, not to
+/// move the progress of the indexer itself, and its checkpoints are kept in memory.
+async fn decode_only(indexer_id: &str, from_block: Option<u64>) {
+	let config = indexers_config();
+	let indexer_config = config
+		.indexers
+		.iter()
+		.find(|indexer| indexer.id == indexer_id)
+		.unwrap_or_else(|| panic!("Indexer `{indexer_id}` is not configured"));
+
+	let (indexer_repository, indexing_service) =
+		indexing_clients(Arc::new(InMemoryCheckpoints::default())).await;
 
-	apibara_client
-		.fetch_new_events(&indexer, contribution_observer)
+	let indexer = IndexerConfig {
+		id: format!("{indexer_id}-decode-only"),
+		start_block: from_block.unwrap_or(indexer_config.start_block),
+		on_conflict: OnConflict::Recreate,
+		..indexer_config.clone()
+	}
+	.build(indexer_repository)
+	.await
+	.expect("Unable to create the indexer");
+
+	let printer = Arc::new(BlockchainRecorder::new(Box::new(std::io::stdout())));
+	indexing_service
+		.fetch_new_events(&indexer, printer)
 		.await
 		.expect("Error while fetching events");
 }
 
-fn contributions_contract_address() -> ContractAddress {
-	let address =
-		std::env::var("CONTRIBUTIONS_ADDRESS").expect("CONTRIBUTIONS_ADDRESS must be set");
-	address.parse().expect("CONTRIBUTIONS_ADDRESS is not a valid contract address")
+/// The JSON-RPC node, when configured, gives the head of the Starknet chain to measure the
+/// indexing lag of the indexers of that network
+fn chain_heads() -> ChainHeads {
+	std::env::var("JSON_RPC_URI")
+		.ok()
+		.map(|uri| {
+			let node = JsonRpcNode::new(uri.parse().expect("JSON_RPC_URI is not a valid URL"));
+			(Network::Starknet, Arc::new(node) as Arc<dyn ChainHead>)
+		})
+		.into_iter()
+		.collect()
+}
+
+async fn indexing_clients(
+	checkpoint_repository: Arc<dyn IndexerCheckpointRepository>,
+) -> (Arc<dyn IndexerRepository>, Arc<dyn IndexingService>) {
+	match std::env::var("INDEXING_SERVICE") {
+		Ok(mode) if mode == *"json-rpc" => {
+			let client = Arc::new(JsonRpcClient::default(checkpoint_repository));
+			(client.clone(), client)
+		},
+		Ok(mode) if mode == *"replay" => {
+			let client = Arc::new(ReplayClient::from_env());
+			(client.clone(), client)
+		},
+		Ok(mode) if mode != *"apibara" => panic!("Invalid value for INDEXING_SERVICE"),
+		_ => {
+			let client = Arc::new(
+				ApibaraClient::default().await.expect("Unable to connect to Apibara server"),
+			);
+			(client.clone(), client)
+		},
+	}
 }
 
 fn build_contribution_observers(
+	indexer_id: &str,
 	database: Arc<database::Client>,
 	github: Arc<github::Client>,
 	uuid_generator: Arc<dyn UuidGenerator>,
 ) -> Arc<dyn BlockchainObserver> {
-	let contribution_projector = ContributionProjector::new(database.clone(), github);
-	let application_projector = ApplicationProjector::new(database.clone(), uuid_generator);
+	let contribution_projector = Arc::new(ProjectionDispatcher::new(
+		"contributions",
+		Arc::new(ContributionProjector::new(database.clone(), github)),
+		database.clone(),
+		uuid_generator.clone(),
+	));
+	let application_projector = Arc::new(ProjectionDispatcher::new(
+		"applications",
+		Arc::new(ApplicationProjector::new(
+			database.clone(),
+			uuid_generator.clone(),
+		)),
+		database.clone(),
+		uuid_generator,
+	));
+	let contributor_projector = Arc::new(ContributorProjector::new(database.clone()));
+
+	let contribution_rollback = ReorgRollback::new(
+		database.clone(),
+		database.clone(),
+		vec![
+			contribution_projector.clone(),
+			application_projector.clone(),
+		],
+	);
+	let contributor_rollback = ReorgRollback::new(
+		database.clone(),
+		database.clone(),
+		vec![contributor_projector.clone()],
+	);
+
+	let projection_observers = Arc::new(BlockchainObserverComposite::new(vec![
+		Arc::new(ContributionObserver::new(contribution_projector)),
+		Arc::new(ContributionObserver::new(application_projector)),
+		Arc::new(ContributorObserver::new(contributor_projector)),
+	]));
+	let event_observers = Arc::new(EventStoreLogger::new(
+		indexer_id,
+		database.clone(),
+		projection_observers,
+	));
 
 	let observer = BlockchainObserverComposite::new(vec![
 		Arc::new(BlockchainLogger::default()),
-		database,
-		Arc::new(ContributionObserver::new(Arc::new(contribution_projector))),
-		Arc::new(ContributionObserver::new(Arc::new(application_projector))),
+		Arc::new(ReorgObserver::new(
+			indexer_id,
+			Arc::new(contribution_rollback),
+			Arc::new(contributor_rollback),
+		)),
+		event_observers.confirmed(indexer_id, confirmation_blocks_count(), database),
 	]);
 
 	Arc::new(observer)
@@ -0,0 +1,157 @@
+use super::status::{ChainHeads, IndexingStatus};
+use crate::application::StatusTracker;
+use rocket::{get, http::ContentType, State};
+use std::{fmt::Write, sync::Arc};
+
+/// Metrics in the Prometheus text format. Indexing has stalled when the time since the last
+/// progress keeps growing, or when the lag does.
+#[get("/metrics")]
+pub async fn metrics(
+	trackers: &State<Vec<Arc<StatusTracker>>>,
+	chain_heads: &State<ChainHeads>,
+) -> (ContentType, String) {
+	let statuses = IndexingStatus::collect(trackers, chain_heads).await;
+	(ContentType::Plain, render(&statuses))
+}
+
+struct Metric {
+	name: &'static str,
+	kind: &'static str,
+	help: &'static str,
+	value: fn(&IndexingStatus) -> Option<u64>,
+}
+
+const METRICS: [Metric; 9] = [
+	Metric {
+		name: "indexer_connected",
+		kind: "gauge",
+		help: "Whether the indexing stream is connected",
+		value: |status| Some(status.connected as u64),
+	},
+	Metric {
+		name: "indexer_last_block_number",
+		kind: "gauge",
+		help: "Number of the last processed block",
+		value: |status| status.last_block.as_ref().map(|block| block.number),
+	},
+	Metric {
+		name: "indexer_chain_head_block_number",
+		kind: "gauge",
+		help: "Number of the latest block of the chain",
+		value: |status| status.chain_head,
+	},
+	Metric {
+		name: "indexer_lag_blocks",
+		kind: "gauge",
+		help: "Number of blocks the indexing is behind the chain head",
+		value: |status| status.lag,
+	},
+	Metric {
+		name: "indexer_events_processed_total",
+		kind: "counter",
+		help: "Number of events processed since the indexer started",
+		value: |status| Some(status.events_processed),
+	},
+	Metric {
+		name: "indexer_consecutive_failures",
+		kind: "gauge",
+		help: "Number of failures of the indexing stream since it last made progress",
+		value: |status| Some(status.consecutive_failures),
+	},
+	Metric {
+		name: "indexer_failures_total",
+		kind: "counter",
+		help: "Number of failures of the indexing stream since the indexer started",
+		value: |status| Some(status.total_failures),
+	},
+	Metric {
+		name: "indexer_last_progress_timestamp_seconds",
+		kind: "gauge",
+		help: "Unix timestamp of the last processed block",
+		value: |status| status.last_progress_at,
+	},
+	Metric {
+		name: "indexer_seconds_since_last_progress",
+		kind: "gauge",
+		help: "Seconds elapsed since the last processed block",
+		value: IndexingStatus::seconds_since_last_progress,
+	},
+];
+
+fn render(statuses: &[IndexingStatus]) -> String {
+	let mut output = String::new();
+
+	// Writing to a String cannot fail
+	for metric in &METRICS {
+		let _ = writeln!(output, "# HELP {} {}", metric.name, metric.help);
+		let _ = writeln!(output, "# TYPE {} {}", metric.name, metric.kind);
+		for status in statuses {
+			if let Some(value) = (metric.value)(status) {
+				let _ = writeln!(
+					output,
+					"{}{{indexer=\"{}\"}} {value}",
+					metric.name,
+					escape_label_value(&status.indexer_id)
+				);
+			}
+		}
+	}
+	output
+}
+
+/// Label values are quoted, their backslashes, double quotes and line feeds must be escaped
+fn escape_label_value(value: &str) -> String {
+	value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::application::ProcessedBlock;
+	use std::str::FromStr;
+
+	#[test]
+	fn status_is_rendered_in_prometheus_format() {
+		let status = IndexingStatus {
+			indexer_id: String::from("contribution-indexer"),
+			connected: true,
+			last_block: Some(ProcessedBlock {
+				number: 40,
+				hash: FromStr::from_str("0x28").unwrap(),
+			}),
+			chain_head: Some(42),
+			lag: Some(2),
+			events_processed: 12,
+			..Default::default()
+		};
+
+		let other_status = IndexingStatus {
+			indexer_id: String::from("profile-indexer"),
+			..Default::default()
+		};
+
+		let metrics = render(&[status, other_status]);
+
+		assert!(metrics.contains("# TYPE indexer_lag_blocks gauge\n"));
+		assert!(metrics.contains("indexer_lag_blocks{indexer=\"contribution-indexer\"} 2\n"));
+		assert!(metrics.contains("indexer_connected{indexer=\"contribution-indexer\"} 1\n"));
+		assert!(metrics
+			.contains("indexer_events_processed_total{indexer=\"contribution-indexer\"} 12\n"));
+		assert!(metrics.contains("indexer_failures_total{indexer=\"contribution-indexer\"} 0\n"));
+		assert!(metrics.contains("indexer_connected{indexer=\"profile-indexer\"} 0\n"));
+		assert!(!metrics.contains("indexer_lag_blocks{indexer=\"profile-indexer\"}"));
+		assert_eq!(1, metrics.matches("# TYPE indexer_connected gauge").count());
+	}
+
+	#[test]
+	fn indexer_label_is_escaped() {
+		let status = IndexingStatus {
+			indexer_id: String::from("a\\b\"c\nd"),
+			..Default::default()
+		};
+
+		let metrics = render(&[status]);
+
+		assert!(metrics.contains("indexer_connected{indexer=\"a\\\\b\\\"c\\nd\"} 0\n"));
+	}
+}
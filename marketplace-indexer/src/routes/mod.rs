@@ -0,0 +1,23 @@
+mod health;
+mod metrics;
+mod status;
+pub use status::ChainHeads;
+
+use crate::application::StatusTracker;
+use rocket::{routes, Build, Config, Rocket};
+use std::sync::Arc;
+
+/// HTTP server exposing the health and progress of the indexers, for monitoring
+pub fn status_server(
+	port: u16,
+	trackers: Vec<Arc<StatusTracker>>,
+	chain_heads: ChainHeads,
+) -> Rocket<Build> {
+	rocket::custom(Config::figment().merge(("port", port)))
+		.manage(trackers)
+		.manage(chain_heads)
+		.mount(
+			"/",
+			routes![health::health_check, status::status, metrics::metrics],
+		)
+}
@@ -0,0 +1,141 @@
+use crate::{
+	application::{ProcessedBlock, StatusTracker},
+	domain::{ChainHead, Network},
+};
+use log::warn;
+use rocket::{get, serde::json::Json, State};
+use serde::Serialize;
+use std::{
+	collections::HashMap,
+	sync::Arc,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Source of the chain head of each network the indexers follow
+pub type ChainHeads = HashMap<Network, Arc<dyn ChainHead>>;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct IndexingStatus {
+	pub indexer_id: String,
+	pub connected: bool,
+	pub last_block: Option<ProcessedBlock>,
+	pub chain_head: Option<u64>,
+	/// Number of blocks the indexing is behind the chain head
+	pub lag: Option<u64>,
+	pub events_processed: u64,
+	pub consecutive_failures: u64,
+	pub total_failures: u64,
+	pub last_error: Option<String>,
+	/// Unix timestamp of the last processed block
+	pub last_progress_at: Option<u64>,
+}
+
+impl IndexingStatus {
+	/// Status of each indexer, the chain head of each network being fetched once for all of
+	/// its indexers
+	pub async fn collect(trackers: &[Arc<StatusTracker>], chain_heads: &ChainHeads) -> Vec<Self> {
+		let mut node_heads = HashMap::new();
+		for (network, chain_head) in chain_heads {
+			if trackers.iter().any(|tracker| tracker.network() == network) {
+				let node_head = chain_head
+					.head_block_number()
+					.await
+					.map_err(|error| {
+						warn!("Unable to fetch the chain head of {network:?}: {error}")
+					})
+					.ok();
+				node_heads.insert(network, node_head);
+			}
+		}
+
+		trackers
+			.iter()
+			.map(|tracker| {
+				Self::new(
+					tracker,
+					node_heads.get(tracker.network()).copied().flatten(),
+				)
+			})
+			.collect()
+	}
+
+	fn new(tracker: &StatusTracker, node_head: Option<u64>) -> Self {
+		let progress = tracker.progress();
+		let chain_head = node_head.max(progress.highest_block_number);
+
+		Self {
+			indexer_id: tracker.indexer_id().to_string(),
+			connected: progress.connected,
+			lag: chain_head
+				.zip(progress.last_block.as_ref())
+				.map(|(head, block)| head.saturating_sub(block.number)),
+			last_block: progress.last_block,
+			chain_head,
+			events_processed: progress.events_processed,
+			consecutive_failures: tracker.failures().consecutive(),
+			total_failures: tracker.failures().total(),
+			last_error: progress.last_error,
+			last_progress_at: progress
+				.last_progress_at
+				.and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+				.map(|duration| duration.as_secs()),
+		}
+	}
+
+	pub fn seconds_since_last_progress(&self) -> Option<u64> {
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+		self.last_progress_at.map(|time| now.saturating_sub(time))
+	}
+}
+
+#[get("/status")]
+pub async fn status(
+	trackers: &State<Vec<Arc<StatusTracker>>>,
+	chain_heads: &State<ChainHeads>,
+) -> Json<Vec<IndexingStatus>> {
+	Json(IndexingStatus::collect(trackers, chain_heads).await)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{IndexerId, MockChainHead};
+
+	fn tracker(indexer_id: &str) -> Arc<StatusTracker> {
+		Arc::new(StatusTracker::new(
+			IndexerId::from(indexer_id),
+			Network::Starknet,
+			Default::default(),
+		))
+	}
+
+	#[tokio::test]
+	async fn chain_head_is_fetched_once_per_network() {
+		let mut chain_head = MockChainHead::new();
+		chain_head.expect_head_block_number().once().returning(|| Ok(42));
+		let chain_heads = ChainHeads::from([(
+			Network::Starknet,
+			Arc::new(chain_head) as Arc<dyn ChainHead>,
+		)]);
+
+		let statuses = IndexingStatus::collect(
+			&[tracker("contribution-indexer"), tracker("profile-indexer")],
+			&chain_heads,
+		)
+		.await;
+
+		assert_eq!(
+			vec![Some(42), Some(42)],
+			statuses.iter().map(|status| status.chain_head).collect::<Vec<_>>()
+		);
+	}
+
+	#[tokio::test]
+	async fn chain_head_is_unknown_without_node_for_the_network() {
+		let statuses =
+			IndexingStatus::collect(&[tracker("contribution-indexer")], &ChainHeads::new()).await;
+
+		assert_eq!(None, statuses[0].chain_head);
+		assert_eq!(None, statuses[0].lag);
+	}
+}
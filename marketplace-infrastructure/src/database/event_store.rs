@@ -4,109 +4,350 @@ use crate::database::{
 	Client,
 };
 use diesel::prelude::*;
+use itertools::Itertools;
 use marketplace_domain::*;
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
+use std::{fmt::Display, str::FromStr, time::SystemTime};
 
 use super::schema::events::index;
 
-const CONTRIBUTION_AGGREGATE: &str = "CONTRIBUTION";
+/// Aggregate whose events are stored in the events table, under its own name
+pub trait NamedAggregate: Aggregate {
+	const NAME: &'static str;
+}
+
+impl NamedAggregate for Contribution {
+	const NAME: &'static str = "CONTRIBUTION";
+}
+
+impl NamedAggregate for ContributorProfile {
+	const NAME: &'static str = "CONTRIBUTOR";
+}
+
+type RecordedEventRow = (
+	i32,
+	SystemTime,
+	Value,
+	Option<String>,
+	Option<i64>,
+	Option<String>,
+);
 
-impl EventStore<Contribution> for Client {
+impl<A: NamedAggregate> EventStore<A> for Client
+where
+	A::Id: Display + FromStr,
+	<A::Id as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+	A::Event: Serialize + DeserializeOwned,
+{
 	fn append(
 		&self,
-		aggregate_id: &<Contribution as Aggregate>::Id,
-		storable_events: Vec<StorableEvent<Contribution>>,
+		aggregate_id: &A::Id,
+		storable_events: Vec<StorableEvent<A>>,
 	) -> Result<(), EventStoreError> {
 		let connection = self.connection().map_err(|e| EventStoreError::Connection(e.into()))?;
 
 		let events = storable_events
 			.iter()
-			.map(|event| {
-				Ok(models::Event {
-					aggregate_name: CONTRIBUTION_AGGREGATE.to_string(),
-					aggregate_id: aggregate_id.to_string(),
-					payload: serde_json::to_value(&event.event)
-						.map_err(|e| EventStoreError::InvalidEvent(e.into()))?,
-				})
-			})
+			.map(|event| event_row(aggregate_id, event, None))
 			.collect::<Result<Vec<_>, EventStoreError>>()?;
+		let deduplication_ids =
+			storable_events.iter().map(|event| event.deduplication_id.to_owned()).collect();
 
 		connection
-			.transaction(|| {
-				let inserted_events: Vec<i32> = diesel::insert_into(events::table)
-					.values(&events)
-					.returning(index)
-					.get_results(&*connection)?;
-
-				assert_eq!(inserted_events.len(), storable_events.len());
-
-				let deduplications = storable_events
-					.iter()
-					.zip(inserted_events)
-					.map(|event| models::EventDeduplication {
-						deduplication_id: event.0.deduplication_id.to_owned(),
-						event_index: event.1,
-					})
-					.collect::<Vec<_>>();
-
-				diesel::insert_into(event_deduplications::table)
-					.values(&deduplications)
-					.execute(&*connection)
-			})
+			.transaction(|| insert_events(&connection, &events, deduplication_ids))
 			.map_err(|e| EventStoreError::Append(e.into()))?;
 
 		Ok(())
 	}
 
-	fn list_by_id(
-		&self,
-		aggregate_id: &<Contribution as Aggregate>::Id,
-	) -> Result<Vec<<Contribution as Aggregate>::Event>, EventStoreError> {
+	fn list_by_id(&self, aggregate_id: &A::Id) -> Result<Vec<A::Event>, EventStoreError> {
 		let connection = self.connection().map_err(|e| EventStoreError::Connection(e.into()))?;
 
 		let events = events::dsl::events
 			.select(events::payload)
 			.filter(events::aggregate_id.eq(aggregate_id.to_string()))
-			.filter(events::aggregate_name.eq_all(CONTRIBUTION_AGGREGATE))
+			.filter(events::aggregate_name.eq_all(A::NAME))
 			.order_by(events::index)
 			.load::<Value>(&*connection)
 			.map_err(|e| EventStoreError::List(e.into()))?;
 
-		deserialize_events(events)
+		deserialize_events::<A>(events)
+	}
+
+	fn list_by_id_at(
+		&self,
+		aggregate_id: &A::Id,
+		point_in_time: &PointInTime,
+	) -> Result<Vec<A::Event>, EventStoreError> {
+		let connection = self.connection().map_err(|e| EventStoreError::Connection(e.into()))?;
+
+		let query = events::dsl::events
+			.select(events::payload)
+			.filter(events::aggregate_id.eq(aggregate_id.to_string()))
+			.filter(events::aggregate_name.eq_all(A::NAME))
+			.into_boxed();
+
+		let query = query.order_by(events::index);
+		let query = match point_in_time {
+			// Only the first events of the aggregate, whatever their index in the whole store
+			PointInTime::EventNumber(event_number) =>
+				query.limit(i64::try_from(*event_number).unwrap_or(i64::MAX)),
+			PointInTime::Timestamp(timestamp) => query.filter(events::timestamp.le(*timestamp)),
+		};
+
+		let events = query
+			.load::<Value>(&*connection)
+			.map_err(|e| EventStoreError::List(e.into()))?;
+
+		deserialize_events::<A>(events)
 	}
 
-	fn list(&self) -> Result<Vec<<Contribution as Aggregate>::Event>, EventStoreError> {
+	fn list_recorded_by_id(
+		&self,
+		aggregate_id: &A::Id,
+	) -> Result<Vec<RecordedEvent<A>>, EventStoreError> {
+		let connection = self.connection().map_err(|e| EventStoreError::Connection(e.into()))?;
+
+		let events = events::dsl::events
+			.select((
+				events::index,
+				events::timestamp,
+				events::payload,
+				events::transaction_hash,
+				events::block_number,
+				events::block_hash,
+			))
+			.filter(events::aggregate_id.eq(aggregate_id.to_string()))
+			.filter(events::aggregate_name.eq_all(A::NAME))
+			.order_by(events::index)
+			.load::<RecordedEventRow>(&*connection)
+			.map_err(|e| EventStoreError::List(e.into()))?;
+
+		events.into_iter().map(deserialize_recorded_event::<A>).collect()
+	}
+
+	fn list(&self) -> Result<Vec<A::Event>, EventStoreError> {
 		let connection = self.connection().map_err(|e| EventStoreError::Connection(e.into()))?;
 
 		let events = events::dsl::events
 			.select(events::payload)
-			.filter(events::aggregate_name.eq_all(CONTRIBUTION_AGGREGATE))
+			.filter(events::aggregate_name.eq_all(A::NAME))
 			.order_by(events::index)
 			.load::<Value>(&*connection)
 			.map_err(|e| EventStoreError::List(e.into()))?;
 
-		deserialize_events(events)
+		deserialize_events::<A>(events)
+	}
+
+	fn remove_after_block(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<Vec<(A::Id, String)>, EventStoreError> {
+		let connection = self.connection().map_err(|e| EventStoreError::Connection(e.into()))?;
+		let block_number =
+			i64::try_from(block_number).map_err(|e| EventStoreError::Remove(e.into()))?;
+
+		let removed_events: Vec<(i32, String, String)> = connection
+			.transaction(|| {
+				let removed_events: Vec<(i32, String, String)> = events::dsl::events
+					.inner_join(
+						event_deduplications::table
+							.on(event_deduplications::event_index.eq(events::index)),
+					)
+					.select((
+						events::index,
+						events::aggregate_id,
+						event_deduplications::deduplication_id,
+					))
+					.filter(events::aggregate_name.eq_all(A::NAME))
+					.filter(events::indexer_id.eq(indexer_id))
+					.filter(events::block_number.gt(block_number))
+					.order_by(events::index)
+					.load(&*connection)?;
+
+				let indexes = removed_events.iter().map(|(index, ..)| *index).collect_vec();
+
+				// Orphaned events may be observed again in another block, with the same deduplication id
+				diesel::delete(
+					event_deduplications::table
+						.filter(event_deduplications::event_index.eq_any(&indexes)),
+				)
+				.execute(&*connection)?;
+
+				diesel::delete(events::table.filter(events::index.eq_any(&indexes)))
+					.execute(&*connection)?;
+
+				Ok(removed_events)
+			})
+			.map_err(|e: diesel::result::Error| EventStoreError::Remove(e.into()))?;
+
+		removed_events
+			.into_iter()
+			.map(|(_, aggregate_id, deduplication_id)| {
+				let aggregate_id =
+					A::Id::from_str(&aggregate_id).map_err(|e| EventStoreError::Remove(e.into()))?;
+				Ok((aggregate_id, deduplication_id))
+			})
+			.collect()
 	}
 }
 
-fn deserialize_events(
+impl EventBatchStore for Client {
+	fn append_batch(&self, batch: EventBatch) -> Result<EventBatch, EventStoreError> {
+		let connection = self.connection().map_err(|e| EventStoreError::Connection(e.into()))?;
+
+		let indexer_id = batch.indexer_id.as_deref();
+		let mut events = Vec::new();
+		let mut deduplication_ids = Vec::new();
+		for (contribution_id, event) in &batch.contribution_events {
+			events.push(event_row(contribution_id, event, indexer_id)?);
+			deduplication_ids.push(event.deduplication_id.to_owned());
+		}
+		for (contributor_id, event) in &batch.contributor_events {
+			events.push(event_row(contributor_id, event, indexer_id)?);
+			deduplication_ids.push(event.deduplication_id.to_owned());
+		}
+
+		let appended_deduplication_ids: Vec<String> = connection
+			.transaction(|| {
+				let stored_deduplication_ids: Vec<String> = event_deduplications::table
+					.select(event_deduplications::deduplication_id)
+					.filter(event_deduplications::deduplication_id.eq_any(&deduplication_ids))
+					.load(&*connection)?;
+
+				// A batch may be appended again when its acknowledgement has been lost
+				let (events, deduplication_ids): (Vec<_>, Vec<_>) = events
+					.into_iter()
+					.zip(deduplication_ids)
+					.filter(|(_, deduplication_id)| {
+						!stored_deduplication_ids.contains(deduplication_id)
+					})
+					.unzip();
+
+				insert_events(&connection, &events, deduplication_ids.clone())?;
+				Ok(deduplication_ids)
+			})
+			.map_err(|e: diesel::result::Error| EventStoreError::Append(e.into()))?;
+
+		Ok(EventBatch {
+			indexer_id: batch.indexer_id,
+			contribution_events: batch
+				.contribution_events
+				.into_iter()
+				.filter(|(_, event)| appended_deduplication_ids.contains(&event.deduplication_id))
+				.collect(),
+			contributor_events: batch
+				.contributor_events
+				.into_iter()
+				.filter(|(_, event)| appended_deduplication_ids.contains(&event.deduplication_id))
+				.collect(),
+		})
+	}
+}
+
+fn event_row<A: NamedAggregate>(
+	aggregate_id: &A::Id,
+	event: &StorableEvent<A>,
+	indexer_id: Option<&str>,
+) -> Result<models::Event, EventStoreError>
+where
+	A::Id: Display,
+	A::Event: Serialize,
+{
+	Ok(models::Event {
+		aggregate_name: A::NAME.to_string(),
+		aggregate_id: aggregate_id.to_string(),
+		payload: serde_json::to_value(&event.event)
+			.map_err(|e| EventStoreError::InvalidEvent(e.into()))?,
+		transaction_hash: event.transaction_hash.as_ref().map(ToString::to_string),
+		block_number: event
+			.block_number
+			.map(i64::try_from)
+			.transpose()
+			.map_err(|e| EventStoreError::InvalidEvent(e.into()))?,
+		block_hash: event.block_hash.as_ref().map(ToString::to_string),
+		indexer_id: indexer_id.map(String::from),
+	})
+}
+
+fn insert_events(
+	connection: &PgConnection,
+	events: &[models::Event],
+	deduplication_ids: Vec<String>,
+) -> QueryResult<usize> {
+	if events.is_empty() {
+		return Ok(0);
+	}
+
+	let inserted_events: Vec<i32> = diesel::insert_into(events::table)
+		.values(events)
+		.returning(index)
+		.get_results(connection)?;
+
+	assert_eq!(inserted_events.len(), deduplication_ids.len());
+
+	let deduplications = deduplication_ids
+		.into_iter()
+		.zip(inserted_events)
+		.map(|(deduplication_id, event_index)| models::EventDeduplication {
+			deduplication_id,
+			event_index,
+		})
+		.collect::<Vec<_>>();
+
+	diesel::insert_into(event_deduplications::table)
+		.values(&deduplications)
+		.execute(connection)
+}
+
+fn deserialize_events<A: Aggregate>(
 	serialized_events: Vec<Value>,
-) -> Result<Vec<<Contribution as Aggregate>::Event>, EventStoreError> {
+) -> Result<Vec<A::Event>, EventStoreError>
+where
+	A::Event: DeserializeOwned,
+{
 	serialized_events
 		.iter()
-		.map(|event_value| {
-			serde_json::from_value::<<Contribution as Aggregate>::Event>(event_value.to_owned())
-		})
+		.map(|event_value| serde_json::from_value::<A::Event>(event_value.to_owned()))
 		.collect::<Result<Vec<_>, _>>()
 		.map_err(|e| EventStoreError::List(e.into()))
 }
 
+fn deserialize_recorded_event<A: Aggregate>(
+	row: RecordedEventRow,
+) -> Result<RecordedEvent<A>, EventStoreError>
+where
+	A::Event: DeserializeOwned,
+{
+	let (event_index, timestamp, payload, transaction_hash, block_number, block_hash) = row;
+
+	Ok(RecordedEvent {
+		index: event_index as u64,
+		timestamp,
+		event: serde_json::from_value(payload).map_err(|e| EventStoreError::List(e.into()))?,
+		transaction_hash: transaction_hash
+			.map(|hash| hash.parse())
+			.transpose()
+			.map_err(|e: ParseHexPrefixedStringError| EventStoreError::List(e.into()))?,
+		block_number: block_number.map(|number| number as u64),
+		block_hash: block_hash
+			.map(|hash| hash.parse())
+			.transpose()
+			.map_err(|e: ParseHexPrefixedStringError| EventStoreError::List(e.into()))?,
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::database::{init_pool, Client};
 	use rstest::{fixture, rstest};
-	use std::str::FromStr;
+	use std::{
+		str::FromStr,
+		time::{Duration, SystemTime, UNIX_EPOCH},
+	};
 
 	#[fixture]
 	fn event_store() -> Box<dyn marketplace_domain::EventStore<Contribution>> {
@@ -134,6 +375,9 @@ mod tests {
 				gate: Default::default(),
 			},
 			deduplication_id: "dedup1".to_string(),
+			transaction_hash: None,
+			block_number: None,
+			block_hash: None,
 		}
 	}
 
@@ -148,6 +392,9 @@ mod tests {
 				contributor_id,
 			},
 			deduplication_id: "dedup2".to_string(),
+			transaction_hash: None,
+			block_number: None,
+			block_hash: None,
 		}
 	}
 
@@ -177,6 +424,173 @@ mod tests {
 		assert_eq!(*contribution_events.last().unwrap(), assigned_event.event);
 	}
 
+	#[rstest]
+	#[cfg_attr(
+		not(feature = "with_infrastructure_tests"),
+		ignore = "infrastructure test"
+	)]
+	fn test_append_and_list_at(
+		event_store: Box<dyn EventStore<Contribution>>,
+		contribution_id: ContributionId,
+		creation_event: StorableEvent<Contribution>,
+		assigned_event: StorableEvent<Contribution>,
+	) {
+		assert!(
+			event_store
+				.append(
+					&contribution_id,
+					vec![creation_event.clone(), assigned_event.clone()]
+				)
+				.is_ok()
+		);
+
+		let contribution_events = event_store
+			.list_by_id_at(&contribution_id, &PointInTime::Timestamp(UNIX_EPOCH))
+			.unwrap();
+		assert_eq!(contribution_events.len(), 0);
+
+		let contribution_events = event_store
+			.list_by_id_at(
+				&contribution_id,
+				&PointInTime::Timestamp(SystemTime::now() + Duration::from_secs(60)),
+			)
+			.unwrap();
+		assert_eq!(contribution_events.len(), 2);
+
+		let contribution_events = event_store
+			.list_by_id_at(&contribution_id, &PointInTime::EventNumber(u64::MAX))
+			.unwrap();
+		assert_eq!(contribution_events.len(), 2);
+
+		let contribution_events = event_store
+			.list_by_id_at(&contribution_id, &PointInTime::EventNumber(1))
+			.unwrap();
+		assert_eq!(contribution_events, vec![creation_event.event]);
+	}
+
+	#[rstest]
+	#[cfg_attr(
+		not(feature = "with_infrastructure_tests"),
+		ignore = "infrastructure test"
+	)]
+	fn test_append_and_list_recorded(
+		event_store: Box<dyn EventStore<Contribution>>,
+		contribution_id: ContributionId,
+		creation_event: StorableEvent<Contribution>,
+		assigned_event: StorableEvent<Contribution>,
+	) {
+		let assigned_event = StorableEvent {
+			transaction_hash: Some(HexPrefixedString::from_str("0x789").unwrap()),
+			block_number: Some(12),
+			block_hash: Some(HexPrefixedString::from_str("0xabc").unwrap()),
+			..assigned_event
+		};
+
+		assert!(
+			event_store
+				.append(
+					&contribution_id,
+					vec![creation_event.clone(), assigned_event.clone()]
+				)
+				.is_ok()
+		);
+
+		let recorded_events = event_store.list_recorded_by_id(&contribution_id).unwrap();
+		assert_eq!(recorded_events.len(), 2);
+
+		let first = recorded_events.first().unwrap();
+		assert_eq!(first.event, creation_event.event);
+		assert_eq!(first.transaction_hash, None);
+		assert_eq!(first.block_number, None);
+
+		let last = recorded_events.last().unwrap();
+		assert_eq!(last.event, assigned_event.event);
+		assert_eq!(last.transaction_hash, assigned_event.transaction_hash);
+		assert_eq!(last.block_number, Some(12));
+		assert_eq!(last.block_hash, assigned_event.block_hash);
+		assert!(first.index < last.index);
+	}
+
+	#[rstest]
+	#[cfg_attr(
+		not(feature = "with_infrastructure_tests"),
+		ignore = "infrastructure test"
+	)]
+	fn test_remove_events_after_block(
+		contribution_id: ContributionId,
+		creation_event: StorableEvent<Contribution>,
+		assigned_event: StorableEvent<Contribution>,
+	) {
+		let client = Client::new(init_pool());
+		let creation_event = StorableEvent {
+			block_number: Some(10),
+			..creation_event
+		};
+		let assigned_event = StorableEvent {
+			block_number: Some(12),
+			..assigned_event
+		};
+		let other_contribution_id = ContributionId::from_str("0x789").unwrap();
+		let other_creation_event = StorableEvent {
+			event: ContributionEvent::Created {
+				id: other_contribution_id.clone(),
+				project_id: Default::default(),
+				issue_number: Default::default(),
+				gate: Default::default(),
+			},
+			deduplication_id: "dedup3".to_string(),
+			block_number: Some(12),
+			..creation_event.clone()
+		};
+
+		let batch = EventBatch {
+			indexer_id: Some(String::from("contribution-indexer")),
+			contribution_events: vec![
+				(contribution_id.clone(), creation_event.clone()),
+				(contribution_id.clone(), assigned_event.clone()),
+			],
+			..Default::default()
+		};
+		assert!(client.append_batch(batch.clone()).is_ok());
+		assert!(client
+			.append_batch(EventBatch {
+				indexer_id: Some(String::from("other-indexer")),
+				contribution_events: vec![(
+					other_contribution_id.clone(),
+					other_creation_event.clone()
+				)],
+				..Default::default()
+			})
+			.is_ok());
+
+		assert_eq!(
+			vec![(contribution_id.clone(), assigned_event.deduplication_id.clone())],
+			EventStore::<Contribution>::remove_after_block(&client, "contribution-indexer", 11)
+				.unwrap()
+		);
+		assert_eq!(
+			vec![creation_event.event],
+			EventStore::<Contribution>::list_by_id(&client, &contribution_id).unwrap()
+		);
+		assert!(
+			EventStore::<Contribution>::remove_after_block(&client, "contribution-indexer", 11)
+				.unwrap()
+				.is_empty()
+		);
+
+		// Events observed by other indexers are theirs to roll back
+		assert_eq!(
+			vec![other_creation_event.event],
+			EventStore::<Contribution>::list_by_id(&client, &other_contribution_id).unwrap()
+		);
+
+		// The orphaned event can be observed again in a new block
+		assert_eq!(
+			vec![(contribution_id, assigned_event)],
+			client.append_batch(batch).unwrap().contribution_events
+		);
+	}
+
 	#[rstest]
 	#[cfg_attr(
 		not(feature = "with_infrastructure_tests"),
@@ -213,4 +627,105 @@ mod tests {
 		assert!(event_store.append(&contribution_id, vec![creation_event.clone()]).is_ok());
 		assert!(event_store.append(&contribution_id, vec![creation_event.clone()]).is_err());
 	}
+
+	#[rstest]
+	#[cfg_attr(
+		not(feature = "with_infrastructure_tests"),
+		ignore = "infrastructure test"
+	)]
+	fn test_aggregates_are_stored_apart(contributor_id: ContributorId) {
+		let client = Client::new(init_pool());
+		let profile_minted_event = StorableEvent {
+			event: ContributorEvent::ProfileMinted {
+				id: contributor_id.clone(),
+				owner: HexPrefixedString::from_str("0x789").unwrap(),
+			},
+			deduplication_id: "dedup3".to_string(),
+			transaction_hash: None,
+			block_number: None,
+			block_hash: None,
+		};
+
+		assert!(
+			EventStore::<ContributorProfile>::append(
+				&client,
+				&contributor_id,
+				vec![profile_minted_event.clone()]
+			)
+			.is_ok()
+		);
+
+		let contributor_events =
+			EventStore::<ContributorProfile>::list_by_id(&client, &contributor_id).unwrap();
+		assert_eq!(contributor_events, vec![profile_minted_event.event]);
+
+		let contribution_events = EventStore::<Contribution>::list_by_id(
+			&client,
+			&HexPrefixedString::from_str("0x456").unwrap().into(),
+		)
+		.unwrap();
+		assert!(contribution_events.is_empty());
+	}
+
+	#[rstest]
+	#[cfg_attr(
+		not(feature = "with_infrastructure_tests"),
+		ignore = "infrastructure test"
+	)]
+	fn test_append_batch_skips_stored_events(
+		contribution_id: ContributionId,
+		contributor_id: ContributorId,
+		creation_event: StorableEvent<Contribution>,
+		assigned_event: StorableEvent<Contribution>,
+	) {
+		let client = Client::new(init_pool());
+		let profile_minted_event = StorableEvent {
+			event: ContributorEvent::ProfileMinted {
+				id: contributor_id.clone(),
+				owner: HexPrefixedString::from_str("0x789").unwrap(),
+			},
+			deduplication_id: "dedup3".to_string(),
+			transaction_hash: None,
+			block_number: None,
+			block_hash: None,
+		};
+
+		assert!(
+			EventStore::<Contribution>::append(
+				&client,
+				&contribution_id,
+				vec![creation_event.clone()]
+			)
+			.is_ok()
+		);
+
+		let batch = EventBatch {
+			indexer_id: Some(String::from("contribution-indexer")),
+			contribution_events: vec![
+				(contribution_id.clone(), creation_event.clone()),
+				(contribution_id.clone(), assigned_event.clone()),
+			],
+			contributor_events: vec![(contributor_id.clone(), profile_minted_event.clone())],
+		};
+		assert_eq!(
+			EventBatch {
+				indexer_id: Some(String::from("contribution-indexer")),
+				contribution_events: vec![(contribution_id.clone(), assigned_event.clone())],
+				contributor_events: vec![(contributor_id.clone(), profile_minted_event.clone())],
+			},
+			client.append_batch(batch.clone()).unwrap()
+		);
+		assert!(client.append_batch(batch).unwrap().is_empty());
+
+		let contribution_events =
+			EventStore::<Contribution>::list_by_id(&client, &contribution_id).unwrap();
+		assert_eq!(
+			contribution_events,
+			vec![creation_event.event, assigned_event.event]
+		);
+
+		let contributor_events =
+			EventStore::<ContributorProfile>::list_by_id(&client, &contributor_id).unwrap();
+		assert_eq!(contributor_events, vec![profile_minted_event.event]);
+	}
 }
@@ -0,0 +1,9 @@
+use crate::database::schema::*;
+
+#[derive(Identifiable, Queryable, Insertable, AsChangeset, Debug, Clone)]
+#[table_name = "contributors"]
+pub struct Contributor {
+	pub id: String,
+	pub account: Option<String>,
+	pub github_handle: Option<String>,
+}
@@ -0,0 +1,16 @@
+use crate::database::schema::*;
+use serde_json::Value;
+use std::time::SystemTime;
+use uuid::Uuid;
+
+#[derive(Identifiable, Queryable, Insertable, AsChangeset, Debug, Clone)]
+#[table_name = "dead_letter_events"]
+pub struct DeadLetterEvent {
+	pub id: Uuid,
+	pub projector: String,
+	pub payload: Value,
+	pub error: String,
+	pub attempts: i32,
+	pub failed_at: SystemTime,
+	pub deduplication_id: Option<String>,
+}
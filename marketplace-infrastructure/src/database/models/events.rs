@@ -8,6 +8,10 @@ pub struct Event {
 	pub aggregate_name: String,
 	pub aggregate_id: String,
 	pub payload: Value,
+	pub transaction_hash: Option<String>,
+	pub block_number: Option<i64>,
+	pub block_hash: Option<String>,
+	pub indexer_id: Option<String>,
 }
 
 #[derive(Insertable, Debug, Serialize, Deserialize)]
@@ -0,0 +1,9 @@
+use crate::database::schema::*;
+
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[table_name = "indexer_checkpoints"]
+pub struct IndexerCheckpoint {
+	pub indexer_id: String,
+	pub block_number: i64,
+	pub block_hash: String,
+}
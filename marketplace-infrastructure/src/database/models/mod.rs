@@ -12,3 +12,18 @@ pub use contact_informations::*;
 
 mod events;
 pub use events::*;
+
+mod dead_letter_events;
+pub use dead_letter_events::*;
+
+mod onchain_transactions;
+pub use onchain_transactions::*;
+
+mod contributors;
+pub use contributors::*;
+
+mod pending_events;
+pub use pending_events::*;
+
+mod indexer_checkpoints;
+pub use indexer_checkpoints::*;
@@ -0,0 +1,16 @@
+use crate::database::schema::*;
+use serde_json::Value;
+use std::time::SystemTime;
+
+#[derive(Identifiable, Queryable, Insertable, AsChangeset, Debug, Clone)]
+#[primary_key(hash)]
+#[table_name = "onchain_transactions"]
+pub struct OnchainTransaction {
+	pub hash: String,
+	pub command: String,
+	pub issuer: String,
+	pub actions: Value,
+	pub status: String,
+	pub submitted_at: SystemTime,
+	pub updated_at: SystemTime,
+}
@@ -0,0 +1,13 @@
+use crate::database::schema::*;
+use serde_json::Value;
+
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[table_name = "pending_events"]
+pub struct PendingEvent {
+	pub indexer_id: String,
+	pub deduplication_id: String,
+	pub payload: Value,
+	pub transaction_hash: String,
+	pub block_hash: String,
+	pub block_number: i64,
+}
@@ -40,6 +40,22 @@ impl ApplicationProjectionRepository for Client {
 		Ok(())
 	}
 
+	fn delete_by_contribution(
+		&self,
+		contribution_id: &ContributionId,
+	) -> Result<(), ApplicationProjectionRepositoryError> {
+		let connection = self.connection().map_err(ApplicationProjectionRepositoryError::from)?;
+
+		diesel::delete(
+			applications::table
+				.filter(applications::contribution_id.eq(contribution_id.to_string())),
+		)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
 	fn find(
 		&self,
 		id: &ApplicationId,
@@ -24,6 +24,17 @@ impl ContributionProjectionRepository for Client {
 		}
 	}
 
+	fn list(&self) -> Result<Vec<ContributionProjection>, ContributionProjectionRepositoryError> {
+		let connection = self.connection().map_err(ContributionProjectionRepositoryError::from)?;
+
+		let contributions = contributions::table
+			.order_by(contributions::id)
+			.load::<models::Contribution>(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		Ok(contributions.into_iter().map(ContributionProjection::from).collect())
+	}
+
 	fn create(
 		&self,
 		contribution: ContributionProjection,
@@ -41,6 +52,21 @@ impl ContributionProjectionRepository for Client {
 		Ok(())
 	}
 
+	fn delete(
+		&self,
+		contribution_id: &ContributionId,
+	) -> Result<(), ContributionProjectionRepositoryError> {
+		let connection = self.connection().map_err(ContributionProjectionRepositoryError::from)?;
+
+		diesel::delete(
+			contributions::table.filter(contributions::id.eq(contribution_id.to_string())),
+		)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
 	fn update_contributor_and_status(
 		&self,
 		contribution_id: ContributionId,
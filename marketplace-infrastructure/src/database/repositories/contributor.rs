@@ -0,0 +1,107 @@
+use marketplace_domain::*;
+
+use crate::database::{models, schema::contributors, Client, DatabaseError};
+use diesel::prelude::*;
+use std::str::FromStr;
+
+impl ContributorProjectionRepository for Client {
+	fn find_by_id(
+		&self,
+		contributor_id: &ContributorId,
+	) -> Result<Option<ContributorProjection>, ContributorProjectionRepositoryError> {
+		let connection = self.connection().map_err(ContributorProjectionRepositoryError::from)?;
+
+		match contributors::dsl::contributors
+			.find(contributor_id.to_string())
+			.get_result::<models::Contributor>(&*connection)
+		{
+			Ok(contributor) => ContributorProjection::try_from(contributor)
+				.map(Some)
+				.map_err(|e| ContributorProjectionRepositoryError::InvalidEntity(e.into())),
+			Err(diesel::NotFound) => Ok(None),
+			Err(e) => Err(ContributorProjectionRepositoryError::Infrastructure(
+				e.into(),
+			)),
+		}
+	}
+
+	fn find_by_ids(
+		&self,
+		contributor_ids: &[ContributorId],
+	) -> Result<Vec<ContributorProjection>, ContributorProjectionRepositoryError> {
+		let connection = self.connection().map_err(ContributorProjectionRepositoryError::from)?;
+
+		let contributors = contributors::dsl::contributors
+			.filter(contributors::id.eq_any(contributor_ids.iter().map(ToString::to_string)))
+			.load::<models::Contributor>(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		contributors
+			.into_iter()
+			.map(ContributorProjection::try_from)
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|e| ContributorProjectionRepositoryError::InvalidEntity(e.into()))
+	}
+
+	fn upsert(
+		&self,
+		contributor: ContributorProjection,
+	) -> Result<(), ContributorProjectionRepositoryError> {
+		let connection = self.connection().map_err(ContributorProjectionRepositoryError::from)?;
+
+		let contributor = models::Contributor::from(contributor);
+		diesel::insert_into(contributors::table)
+			.values(&contributor)
+			.on_conflict(contributors::id)
+			.do_update()
+			.set(&contributor)
+			.execute(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn delete(
+		&self,
+		contributor_id: &ContributorId,
+	) -> Result<(), ContributorProjectionRepositoryError> {
+		let connection = self.connection().map_err(ContributorProjectionRepositoryError::from)?;
+
+		diesel::delete(contributors::table.filter(contributors::id.eq(contributor_id.to_string())))
+			.execute(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+}
+
+impl From<DatabaseError> for ContributorProjectionRepositoryError {
+	fn from(error: DatabaseError) -> Self {
+		match error {
+			DatabaseError::Transaction(diesel::result::Error::NotFound) => Self::NotFound,
+			_ => Self::Infrastructure(Box::new(error)),
+		}
+	}
+}
+
+impl From<ContributorProjection> for models::Contributor {
+	fn from(contributor: ContributorProjection) -> Self {
+		Self {
+			id: contributor.id.to_string(),
+			account: contributor.account.as_ref().map(ToString::to_string),
+			github_handle: contributor.github_handle,
+		}
+	}
+}
+
+impl TryFrom<models::Contributor> for ContributorProjection {
+	type Error = ParseHexPrefixedStringError;
+
+	fn try_from(contributor: models::Contributor) -> Result<Self, Self::Error> {
+		Ok(Self {
+			id: ContributorId::from_str(&contributor.id)?,
+			account: contributor.account.as_deref().map(ContractAddress::from_str).transpose()?,
+			github_handle: contributor.github_handle,
+		})
+	}
+}
@@ -0,0 +1,133 @@
+use marketplace_domain::*;
+
+use crate::database::{models, schema::dead_letter_events, Client, DatabaseError};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+impl DeadLetterEventRepository for Client {
+	fn create(
+		&self,
+		dead_letter_event: DeadLetterEvent,
+	) -> Result<(), DeadLetterEventRepositoryError> {
+		let connection = self.connection().map_err(DeadLetterEventRepositoryError::from)?;
+
+		let dead_letter_event = models::DeadLetterEvent::try_from(dead_letter_event)
+			.map_err(|e| DeadLetterEventRepositoryError::Infrastructure(e.into()))?;
+		diesel::insert_into(dead_letter_events::table)
+			.values(&dead_letter_event)
+			.execute(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn update(
+		&self,
+		dead_letter_event: DeadLetterEvent,
+	) -> Result<(), DeadLetterEventRepositoryError> {
+		let connection = self.connection().map_err(DeadLetterEventRepositoryError::from)?;
+
+		let dead_letter_event = models::DeadLetterEvent::try_from(dead_letter_event)
+			.map_err(|e| DeadLetterEventRepositoryError::Infrastructure(e.into()))?;
+		let updated_rows = diesel::update(
+			dead_letter_events::table.filter(dead_letter_events::id.eq(dead_letter_event.id)),
+		)
+		.set(dead_letter_event)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		match updated_rows {
+			0 => Err(DeadLetterEventRepositoryError::NotFound),
+			_ => Ok(()),
+		}
+	}
+
+	fn delete(&self, id: &DeadLetterEventId) -> Result<(), DeadLetterEventRepositoryError> {
+		let connection = self.connection().map_err(DeadLetterEventRepositoryError::from)?;
+
+		let deleted_rows = diesel::delete(
+			dead_letter_events::table.filter(dead_letter_events::id.eq(Uuid::from(*id))),
+		)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		match deleted_rows {
+			0 => Err(DeadLetterEventRepositoryError::NotFound),
+			_ => Ok(()),
+		}
+	}
+
+	fn find_by_id(
+		&self,
+		id: &DeadLetterEventId,
+	) -> Result<Option<DeadLetterEvent>, DeadLetterEventRepositoryError> {
+		let connection = self.connection().map_err(DeadLetterEventRepositoryError::from)?;
+
+		match dead_letter_events::dsl::dead_letter_events
+			.find(Uuid::from(*id))
+			.get_result::<models::DeadLetterEvent>(&*connection)
+		{
+			Ok(dead_letter_event) => DeadLetterEvent::try_from(dead_letter_event)
+				.map(Some)
+				.map_err(|e| DeadLetterEventRepositoryError::Infrastructure(e.into())),
+			Err(diesel::NotFound) => Ok(None),
+			Err(e) => Err(DeadLetterEventRepositoryError::Infrastructure(e.into())),
+		}
+	}
+
+	fn list(&self) -> Result<Vec<DeadLetterEvent>, DeadLetterEventRepositoryError> {
+		let connection = self.connection().map_err(DeadLetterEventRepositoryError::from)?;
+
+		let dead_letter_events = dead_letter_events::dsl::dead_letter_events
+			.order_by(dead_letter_events::failed_at)
+			.load::<models::DeadLetterEvent>(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		dead_letter_events
+			.into_iter()
+			.map(DeadLetterEvent::try_from)
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|e| DeadLetterEventRepositoryError::Infrastructure(e.into()))
+	}
+}
+
+impl From<DatabaseError> for DeadLetterEventRepositoryError {
+	fn from(error: DatabaseError) -> Self {
+		match error {
+			DatabaseError::Transaction(diesel::result::Error::NotFound) => Self::NotFound,
+			_ => Self::Infrastructure(Box::new(error)),
+		}
+	}
+}
+
+impl TryFrom<DeadLetterEvent> for models::DeadLetterEvent {
+	type Error = serde_json::Error;
+
+	fn try_from(dead_letter_event: DeadLetterEvent) -> Result<Self, Self::Error> {
+		Ok(Self {
+			id: dead_letter_event.id.into(),
+			projector: dead_letter_event.projector,
+			payload: serde_json::to_value(dead_letter_event.event)?,
+			deduplication_id: dead_letter_event.deduplication_id,
+			error: dead_letter_event.error,
+			attempts: dead_letter_event.attempts as i32,
+			failed_at: dead_letter_event.failed_at,
+		})
+	}
+}
+
+impl TryFrom<models::DeadLetterEvent> for DeadLetterEvent {
+	type Error = serde_json::Error;
+
+	fn try_from(dead_letter_event: models::DeadLetterEvent) -> Result<Self, Self::Error> {
+		Ok(Self {
+			id: dead_letter_event.id.into(),
+			projector: dead_letter_event.projector,
+			event: serde_json::from_value(dead_letter_event.payload)?,
+			deduplication_id: dead_letter_event.deduplication_id,
+			error: dead_letter_event.error,
+			attempts: dead_letter_event.attempts as u32,
+			failed_at: dead_letter_event.failed_at,
+		})
+	}
+}
@@ -0,0 +1,141 @@
+use marketplace_domain::*;
+
+use crate::database::{models, schema::indexer_checkpoints, Client, DatabaseError};
+use diesel::{pg::upsert::excluded, prelude::*};
+use std::str::FromStr;
+
+impl IndexerCheckpointRepository for Client {
+	fn create(
+		&self,
+		checkpoint: IndexerCheckpoint,
+	) -> Result<(), IndexerCheckpointRepositoryError> {
+		let connection = self.connection().map_err(IndexerCheckpointRepositoryError::from)?;
+
+		diesel::insert_into(indexer_checkpoints::table)
+			.values(&models::IndexerCheckpoint::from(checkpoint))
+			.on_conflict((
+				indexer_checkpoints::indexer_id,
+				indexer_checkpoints::block_number,
+			))
+			.do_update()
+			.set(indexer_checkpoints::block_hash.eq(excluded(indexer_checkpoints::block_hash)))
+			.execute(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn find(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<Option<IndexerCheckpoint>, IndexerCheckpointRepositoryError> {
+		let connection = self.connection().map_err(IndexerCheckpointRepositoryError::from)?;
+
+		match indexer_checkpoints::table
+			.find((indexer_id, block_number as i64))
+			.get_result::<models::IndexerCheckpoint>(&*connection)
+		{
+			Ok(checkpoint) => IndexerCheckpoint::try_from(checkpoint)
+				.map(Some)
+				.map_err(|e| IndexerCheckpointRepositoryError::Infrastructure(e.into())),
+			Err(diesel::NotFound) => Ok(None),
+			Err(e) => Err(IndexerCheckpointRepositoryError::Infrastructure(e.into())),
+		}
+	}
+
+	fn latest(
+		&self,
+		indexer_id: &str,
+	) -> Result<Option<IndexerCheckpoint>, IndexerCheckpointRepositoryError> {
+		let connection = self.connection().map_err(IndexerCheckpointRepositoryError::from)?;
+
+		match indexer_checkpoints::table
+			.filter(indexer_checkpoints::indexer_id.eq(indexer_id))
+			.order_by(indexer_checkpoints::block_number.desc())
+			.first::<models::IndexerCheckpoint>(&*connection)
+		{
+			Ok(checkpoint) => IndexerCheckpoint::try_from(checkpoint)
+				.map(Some)
+				.map_err(|e| IndexerCheckpointRepositoryError::Infrastructure(e.into())),
+			Err(diesel::NotFound) => Ok(None),
+			Err(e) => Err(IndexerCheckpointRepositoryError::Infrastructure(e.into())),
+		}
+	}
+
+	fn delete_before_block(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<(), IndexerCheckpointRepositoryError> {
+		let connection = self.connection().map_err(IndexerCheckpointRepositoryError::from)?;
+
+		diesel::delete(
+			indexer_checkpoints::table
+				.filter(indexer_checkpoints::indexer_id.eq(indexer_id))
+				.filter(indexer_checkpoints::block_number.lt(block_number as i64)),
+		)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn delete_after_block(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<(), IndexerCheckpointRepositoryError> {
+		let connection = self.connection().map_err(IndexerCheckpointRepositoryError::from)?;
+
+		diesel::delete(
+			indexer_checkpoints::table
+				.filter(indexer_checkpoints::indexer_id.eq(indexer_id))
+				.filter(indexer_checkpoints::block_number.gt(block_number as i64)),
+		)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn delete(&self, indexer_id: &str) -> Result<(), IndexerCheckpointRepositoryError> {
+		let connection = self.connection().map_err(IndexerCheckpointRepositoryError::from)?;
+
+		diesel::delete(
+			indexer_checkpoints::table.filter(indexer_checkpoints::indexer_id.eq(indexer_id)),
+		)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+}
+
+impl From<DatabaseError> for IndexerCheckpointRepositoryError {
+	fn from(error: DatabaseError) -> Self {
+		Self::Infrastructure(Box::new(error))
+	}
+}
+
+impl From<IndexerCheckpoint> for models::IndexerCheckpoint {
+	fn from(checkpoint: IndexerCheckpoint) -> Self {
+		Self {
+			indexer_id: checkpoint.indexer_id,
+			block_number: checkpoint.block_number as i64,
+			block_hash: checkpoint.block_hash.to_string(),
+		}
+	}
+}
+
+impl TryFrom<models::IndexerCheckpoint> for IndexerCheckpoint {
+	type Error = ParseHexPrefixedStringError;
+
+	fn try_from(checkpoint: models::IndexerCheckpoint) -> Result<Self, Self::Error> {
+		Ok(Self {
+			indexer_id: checkpoint.indexer_id,
+			block_number: checkpoint.block_number as u64,
+			block_hash: HexPrefixedString::from_str(&checkpoint.block_hash)?,
+		})
+	}
+}
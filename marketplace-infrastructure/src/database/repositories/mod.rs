@@ -1,4 +1,9 @@
 mod application;
 mod contact_information;
 mod contribution;
+mod contributor;
+mod dead_letter_event;
+mod indexer_checkpoint;
+mod onchain_transaction;
+mod pending_event;
 mod project;
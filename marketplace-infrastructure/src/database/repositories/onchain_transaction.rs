@@ -0,0 +1,119 @@
+use marketplace_domain::*;
+
+use crate::database::{models, schema::onchain_transactions, Client, DatabaseError};
+use diesel::prelude::*;
+use std::str::FromStr;
+
+impl OnchainTransactionRepository for Client {
+	fn create(
+		&self,
+		transaction: OnchainTransaction,
+	) -> Result<(), OnchainTransactionRepositoryError> {
+		let connection = self.connection().map_err(OnchainTransactionRepositoryError::from)?;
+
+		let transaction = models::OnchainTransaction::try_from(transaction)
+			.map_err(|e| OnchainTransactionRepositoryError::Infrastructure(e.into()))?;
+		diesel::insert_into(onchain_transactions::table)
+			.values(&transaction)
+			.execute(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn update(
+		&self,
+		transaction: OnchainTransaction,
+	) -> Result<(), OnchainTransactionRepositoryError> {
+		let connection = self.connection().map_err(OnchainTransactionRepositoryError::from)?;
+
+		let transaction = models::OnchainTransaction::try_from(transaction)
+			.map_err(|e| OnchainTransactionRepositoryError::Infrastructure(e.into()))?;
+		let updated_rows = diesel::update(
+			onchain_transactions::table.filter(onchain_transactions::hash.eq(&transaction.hash)),
+		)
+		.set(&transaction)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		match updated_rows {
+			0 => Err(OnchainTransactionRepositoryError::NotFound),
+			_ => Ok(()),
+		}
+	}
+
+	fn find_by_hash(
+		&self,
+		hash: &HexPrefixedString,
+	) -> Result<Option<OnchainTransaction>, OnchainTransactionRepositoryError> {
+		let connection = self.connection().map_err(OnchainTransactionRepositoryError::from)?;
+
+		match onchain_transactions::dsl::onchain_transactions
+			.find(hash.to_string())
+			.get_result::<models::OnchainTransaction>(&*connection)
+		{
+			Ok(transaction) => OnchainTransaction::try_from(transaction)
+				.map(Some)
+				.map_err(|e| OnchainTransactionRepositoryError::Infrastructure(e.into())),
+			Err(diesel::NotFound) => Ok(None),
+			Err(e) => Err(OnchainTransactionRepositoryError::Infrastructure(e.into())),
+		}
+	}
+
+	fn list_pending(&self) -> Result<Vec<OnchainTransaction>, OnchainTransactionRepositoryError> {
+		let connection = self.connection().map_err(OnchainTransactionRepositoryError::from)?;
+
+		let transactions = onchain_transactions::dsl::onchain_transactions
+			.filter(onchain_transactions::status.eq(OnchainTransactionStatus::Pending.to_string()))
+			.order_by(onchain_transactions::submitted_at)
+			.load::<models::OnchainTransaction>(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		transactions
+			.into_iter()
+			.map(OnchainTransaction::try_from)
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|e| OnchainTransactionRepositoryError::Infrastructure(e.into()))
+	}
+}
+
+impl From<DatabaseError> for OnchainTransactionRepositoryError {
+	fn from(error: DatabaseError) -> Self {
+		match error {
+			DatabaseError::Transaction(diesel::result::Error::NotFound) => Self::NotFound,
+			_ => Self::Infrastructure(Box::new(error)),
+		}
+	}
+}
+
+impl TryFrom<OnchainTransaction> for models::OnchainTransaction {
+	type Error = serde_json::Error;
+
+	fn try_from(transaction: OnchainTransaction) -> Result<Self, Self::Error> {
+		Ok(Self {
+			hash: transaction.hash.to_string(),
+			command: transaction.command,
+			issuer: transaction.issuer,
+			actions: serde_json::to_value(transaction.actions)?,
+			status: transaction.status.to_string(),
+			submitted_at: transaction.submitted_at,
+			updated_at: transaction.updated_at,
+		})
+	}
+}
+
+impl TryFrom<models::OnchainTransaction> for OnchainTransaction {
+	type Error = anyhow::Error;
+
+	fn try_from(transaction: models::OnchainTransaction) -> Result<Self, Self::Error> {
+		Ok(Self {
+			hash: HexPrefixedString::from_str(&transaction.hash)?,
+			command: transaction.command,
+			issuer: transaction.issuer,
+			actions: serde_json::from_value(transaction.actions)?,
+			status: transaction.status.parse()?,
+			submitted_at: transaction.submitted_at,
+			updated_at: transaction.updated_at,
+		})
+	}
+}
@@ -0,0 +1,115 @@
+use marketplace_domain::*;
+
+use crate::database::{models, schema::pending_events, Client, DatabaseError};
+use diesel::prelude::*;
+use std::str::FromStr;
+
+impl PendingEventRepository for Client {
+	fn create(&self, pending_event: PendingEvent) -> Result<(), PendingEventRepositoryError> {
+		let connection = self.connection().map_err(PendingEventRepositoryError::from)?;
+
+		let pending_event = models::PendingEvent::try_from(pending_event)
+			.map_err(|e| PendingEventRepositoryError::Infrastructure(e.into()))?;
+		diesel::insert_into(pending_events::table)
+			.values(&pending_event)
+			.on_conflict_do_nothing()
+			.execute(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn delete(&self, deduplication_id: &str) -> Result<(), PendingEventRepositoryError> {
+		let connection = self.connection().map_err(PendingEventRepositoryError::from)?;
+
+		diesel::delete(
+			pending_events::table.filter(pending_events::deduplication_id.eq(deduplication_id)),
+		)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn delete_after_block(
+		&self,
+		indexer_id: &str,
+		block_number: u64,
+	) -> Result<(), PendingEventRepositoryError> {
+		let connection = self.connection().map_err(PendingEventRepositoryError::from)?;
+
+		diesel::delete(
+			pending_events::table
+				.filter(pending_events::indexer_id.eq(indexer_id))
+				.filter(pending_events::block_number.gt(block_number as i64)),
+		)
+		.execute(&*connection)
+		.map_err(DatabaseError::from)?;
+
+		Ok(())
+	}
+
+	fn list(&self, indexer_id: &str) -> Result<Vec<PendingEvent>, PendingEventRepositoryError> {
+		let connection = self.connection().map_err(PendingEventRepositoryError::from)?;
+
+		// Events of a block are listed in the order they were observed
+		let pending_events = pending_events::table
+			.select((
+				pending_events::indexer_id,
+				pending_events::deduplication_id,
+				pending_events::payload,
+				pending_events::transaction_hash,
+				pending_events::block_hash,
+				pending_events::block_number,
+			))
+			.filter(pending_events::indexer_id.eq(indexer_id))
+			.order_by((
+				pending_events::block_number,
+				pending_events::sequence_number,
+			))
+			.load::<models::PendingEvent>(&*connection)
+			.map_err(DatabaseError::from)?;
+
+		pending_events
+			.into_iter()
+			.map(PendingEvent::try_from)
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|e| PendingEventRepositoryError::Infrastructure(e.into()))
+	}
+}
+
+impl From<DatabaseError> for PendingEventRepositoryError {
+	fn from(error: DatabaseError) -> Self {
+		Self::Infrastructure(Box::new(error))
+	}
+}
+
+impl TryFrom<PendingEvent> for models::PendingEvent {
+	type Error = serde_json::Error;
+
+	fn try_from(pending_event: PendingEvent) -> Result<Self, Self::Error> {
+		Ok(Self {
+			indexer_id: pending_event.indexer_id,
+			deduplication_id: pending_event.deduplication_id,
+			payload: serde_json::to_value(pending_event.event)?,
+			transaction_hash: pending_event.transaction_hash.to_string(),
+			block_hash: pending_event.block_hash.to_string(),
+			block_number: pending_event.block_number as i64,
+		})
+	}
+}
+
+impl TryFrom<models::PendingEvent> for PendingEvent {
+	type Error = anyhow::Error;
+
+	fn try_from(pending_event: models::PendingEvent) -> Result<Self, Self::Error> {
+		Ok(Self {
+			indexer_id: pending_event.indexer_id,
+			deduplication_id: pending_event.deduplication_id,
+			event: serde_json::from_value(pending_event.payload)?,
+			transaction_hash: HexPrefixedString::from_str(&pending_event.transaction_hash)?,
+			block_hash: HexPrefixedString::from_str(&pending_event.block_hash)?,
+			block_number: pending_event.block_number as u64,
+		})
+	}
+}
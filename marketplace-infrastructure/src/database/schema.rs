@@ -65,6 +65,26 @@ table! {
     }
 }
 
+table! {
+    contributors (id) {
+        id -> Text,
+        account -> Nullable<Text>,
+        github_handle -> Nullable<Text>,
+    }
+}
+
+table! {
+    dead_letter_events (id) {
+        id -> Uuid,
+        projector -> Varchar,
+        payload -> Jsonb,
+        error -> Text,
+        attempts -> Int4,
+        failed_at -> Timestamp,
+        deduplication_id -> Nullable<Text>,
+    }
+}
+
 table! {
     event_deduplications (deduplication_id) {
         deduplication_id -> Text,
@@ -79,6 +99,42 @@ table! {
         aggregate_name -> Varchar,
         aggregate_id -> Varchar,
         payload -> Jsonb,
+        transaction_hash -> Nullable<Varchar>,
+        block_number -> Nullable<Int8>,
+        block_hash -> Nullable<Varchar>,
+        indexer_id -> Nullable<Varchar>,
+    }
+}
+
+table! {
+    indexer_checkpoints (indexer_id, block_number) {
+        indexer_id -> Varchar,
+        block_number -> Int8,
+        block_hash -> Varchar,
+    }
+}
+
+table! {
+    onchain_transactions (hash) {
+        hash -> Varchar,
+        command -> Varchar,
+        issuer -> Varchar,
+        actions -> Jsonb,
+        status -> Varchar,
+        submitted_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+table! {
+    pending_events (deduplication_id) {
+        deduplication_id -> Text,
+        payload -> Jsonb,
+        transaction_hash -> Varchar,
+        block_hash -> Varchar,
+        block_number -> Int8,
+        sequence_number -> Int8,
+        indexer_id -> Varchar,
     }
 }
 
@@ -100,7 +156,12 @@ allow_tables_to_appear_in_same_query!(
     contact_information,
     contributions,
     contributions_backup,
+    contributors,
+    dead_letter_events,
     event_deduplications,
     events,
+    indexer_checkpoints,
+    onchain_transactions,
+    pending_events,
     projects,
 );
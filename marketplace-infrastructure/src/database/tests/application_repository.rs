@@ -144,3 +144,29 @@ fn store_multiple_and_list() {
 
 	assert_eq!(applications, vec![application2])
 }
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn delete_by_contribution() {
+	let client = Client::new(init_pool());
+
+	let contribution = init_contribution(&client);
+
+	let application =
+		ApplicationProjection::new(Uuid::new_v4().into(), contribution.id.clone(), 0.into());
+	<Client as ApplicationProjectionRepository>::create(&client, application).unwrap();
+
+	<Client as ApplicationProjectionRepository>::delete_by_contribution(&client, &contribution.id)
+		.unwrap();
+
+	let applications = <Client as ApplicationProjectionRepository>::list_by_contribution(
+		&client,
+		&contribution.id,
+		None,
+	)
+	.unwrap();
+	assert!(applications.is_empty());
+}
@@ -36,3 +36,39 @@ fn store_and_find() {
 			.unwrap();
 	assert_eq!(found_contribution, Some(contribution2));
 }
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn list_and_delete() {
+	let client = Client::new(init_pool());
+
+	let project = init_project(&client);
+
+	let contribution1 = ContributionProjection {
+		id: 1.into(),
+		project_id: project.id,
+		..Default::default()
+	};
+	let contribution2 = ContributionProjection {
+		id: 2.into(),
+		project_id: project.id,
+		..Default::default()
+	};
+
+	<Client as ContributionProjectionRepository>::create(&client, contribution1.clone()).unwrap();
+	<Client as ContributionProjectionRepository>::create(&client, contribution2.clone()).unwrap();
+
+	let contributions = <Client as ContributionProjectionRepository>::list(&client).unwrap();
+	assert_eq!(
+		contributions,
+		vec![contribution1.clone(), contribution2.clone()]
+	);
+
+	<Client as ContributionProjectionRepository>::delete(&client, &contribution1.id).unwrap();
+
+	let contributions = <Client as ContributionProjectionRepository>::list(&client).unwrap();
+	assert_eq!(contributions, vec![contribution2]);
+}
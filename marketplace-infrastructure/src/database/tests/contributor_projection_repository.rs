@@ -0,0 +1,38 @@
+use crate::database::{init_pool, Client};
+use marketplace_domain::*;
+use std::str::FromStr;
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn upsert_and_find_contributors() {
+	let client = Client::new(init_pool());
+
+	let contributor = ContributorProjection {
+		id: ContributorId::from(12),
+		account: Some(ContractAddress::from_str("0x1234").unwrap()),
+		github_handle: None,
+	};
+	<Client as ContributorProjectionRepository>::upsert(&client, contributor.clone()).unwrap();
+
+	let contributor = ContributorProjection {
+		github_handle: Some(String::from("43214")),
+		..contributor
+	};
+	<Client as ContributorProjectionRepository>::upsert(&client, contributor.clone()).unwrap();
+
+	assert_eq!(
+		Some(contributor.clone()),
+		<Client as ContributorProjectionRepository>::find_by_id(&client, &contributor.id).unwrap()
+	);
+	assert_eq!(
+		vec![contributor.clone()],
+		<Client as ContributorProjectionRepository>::find_by_ids(
+			&client,
+			&[contributor.id, ContributorId::from(13)]
+		)
+		.unwrap()
+	);
+}
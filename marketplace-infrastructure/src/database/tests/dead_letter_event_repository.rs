@@ -0,0 +1,81 @@
+use marketplace_domain::*;
+use std::time::{Duration, SystemTime};
+use uuid::Uuid;
+
+use crate::database::{init_pool, Client};
+
+fn dead_letter_event(failed_at: SystemTime) -> DeadLetterEvent {
+	DeadLetterEvent {
+		id: Uuid::new_v4().into(),
+		projector: String::from("applications"),
+		event: Event::Contribution(ContributionEvent::Validated { id: 1.into() }),
+		deduplication_id: Some(String::from("0x1-1")),
+		error: String::from("Oops"),
+		attempts: 5,
+		failed_at,
+	}
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn create_find_and_list() {
+	let client = Client::new(init_pool());
+
+	let dead_letter_event1 = dead_letter_event(SystemTime::UNIX_EPOCH + Duration::from_secs(20));
+	let dead_letter_event2 = dead_letter_event(SystemTime::UNIX_EPOCH + Duration::from_secs(10));
+
+	<Client as DeadLetterEventRepository>::create(&client, dead_letter_event1.clone()).unwrap();
+	<Client as DeadLetterEventRepository>::create(&client, dead_letter_event2.clone()).unwrap();
+
+	let found =
+		<Client as DeadLetterEventRepository>::find_by_id(&client, &dead_letter_event1.id).unwrap();
+	assert_eq!(found, Some(dead_letter_event1.clone()));
+
+	let found =
+		<Client as DeadLetterEventRepository>::find_by_id(&client, &Uuid::new_v4().into()).unwrap();
+	assert_eq!(found, None);
+
+	let listed = <Client as DeadLetterEventRepository>::list(&client).unwrap();
+	assert_eq!(listed, vec![dead_letter_event2, dead_letter_event1]);
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn update_and_delete() {
+	let client = Client::new(init_pool());
+
+	let dead_letter_event = dead_letter_event(SystemTime::UNIX_EPOCH);
+	<Client as DeadLetterEventRepository>::create(&client, dead_letter_event.clone()).unwrap();
+
+	let updated_dead_letter_event = DeadLetterEvent {
+		attempts: 6,
+		error: String::from("Still failing"),
+		..dead_letter_event
+	};
+	<Client as DeadLetterEventRepository>::update(&client, updated_dead_letter_event.clone())
+		.unwrap();
+
+	let found =
+		<Client as DeadLetterEventRepository>::find_by_id(&client, &updated_dead_letter_event.id)
+			.unwrap();
+	assert_eq!(found, Some(updated_dead_letter_event.clone()));
+
+	<Client as DeadLetterEventRepository>::delete(&client, &updated_dead_letter_event.id).unwrap();
+	let found =
+		<Client as DeadLetterEventRepository>::find_by_id(&client, &updated_dead_letter_event.id)
+			.unwrap();
+	assert_eq!(found, None);
+
+	let result =
+		<Client as DeadLetterEventRepository>::delete(&client, &updated_dead_letter_event.id);
+	assert!(matches!(
+		result,
+		Err(DeadLetterEventRepositoryError::NotFound)
+	));
+}
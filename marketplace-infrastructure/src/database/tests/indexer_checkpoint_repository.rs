@@ -0,0 +1,80 @@
+use marketplace_domain::*;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::database::{init_pool, Client};
+
+fn checkpoint(indexer_id: &str, block_number: u64) -> IndexerCheckpoint {
+	IndexerCheckpoint {
+		indexer_id: indexer_id.to_string(),
+		block_number,
+		block_hash: HexPrefixedString::from_str(&format!("0x{block_number:x}")).unwrap(),
+	}
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn create_and_find() {
+	let client = Client::new(init_pool());
+	let indexer_id = Uuid::new_v4().to_string();
+
+	assert_eq!(
+		<Client as IndexerCheckpointRepository>::latest(&client, &indexer_id).unwrap(),
+		None
+	);
+
+	<Client as IndexerCheckpointRepository>::create(&client, checkpoint(&indexer_id, 2)).unwrap();
+	<Client as IndexerCheckpointRepository>::create(&client, checkpoint(&indexer_id, 1)).unwrap();
+
+	assert_eq!(
+		<Client as IndexerCheckpointRepository>::latest(&client, &indexer_id).unwrap(),
+		Some(checkpoint(&indexer_id, 2))
+	);
+	assert_eq!(
+		<Client as IndexerCheckpointRepository>::find(&client, &indexer_id, 1).unwrap(),
+		Some(checkpoint(&indexer_id, 1))
+	);
+	assert_eq!(
+		<Client as IndexerCheckpointRepository>::find(&client, &indexer_id, 3).unwrap(),
+		None
+	);
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn delete_around_blocks() {
+	let client = Client::new(init_pool());
+	let indexer_id = Uuid::new_v4().to_string();
+
+	for block_number in 1..=5 {
+		<Client as IndexerCheckpointRepository>::create(
+			&client,
+			checkpoint(&indexer_id, block_number),
+		)
+		.unwrap();
+	}
+
+	<Client as IndexerCheckpointRepository>::delete_before_block(&client, &indexer_id, 2).unwrap();
+	<Client as IndexerCheckpointRepository>::delete_after_block(&client, &indexer_id, 4).unwrap();
+
+	assert_eq!(
+		<Client as IndexerCheckpointRepository>::find(&client, &indexer_id, 1).unwrap(),
+		None
+	);
+	assert_eq!(
+		<Client as IndexerCheckpointRepository>::latest(&client, &indexer_id).unwrap(),
+		Some(checkpoint(&indexer_id, 4))
+	);
+
+	<Client as IndexerCheckpointRepository>::delete(&client, &indexer_id).unwrap();
+	assert_eq!(
+		<Client as IndexerCheckpointRepository>::latest(&client, &indexer_id).unwrap(),
+		None
+	);
+}
@@ -5,6 +5,11 @@ use marketplace_domain::{ContributionProjection, Project};
 mod application_repository;
 mod contact_information_repository;
 mod contribution_projection_repository;
+mod contributor_projection_repository;
+mod dead_letter_event_repository;
+mod indexer_checkpoint_repository;
+mod onchain_transaction_repository;
+mod pending_event_repository;
 mod project_repository;
 
 use marketplace_domain::*;
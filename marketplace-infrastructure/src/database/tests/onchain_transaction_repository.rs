@@ -0,0 +1,93 @@
+use marketplace_domain::*;
+use rand::prelude::random;
+use std::{
+	str::FromStr,
+	time::{Duration, SystemTime},
+};
+
+use crate::database::{init_pool, Client};
+
+fn onchain_transaction(submitted_at: SystemTime) -> OnchainTransaction {
+	OnchainTransaction {
+		hash: HexPrefixedString::from_str(&format!("0x{:x}", random::<u64>())).unwrap(),
+		command: String::from("execute_actions"),
+		issuer: String::from("admin"),
+		actions: vec![
+			Action::CreateContribution {
+				project_id: 1,
+				issue_number: 2,
+				gate: 0,
+			},
+			Action::ValidateContribution {
+				contribution_id: 12.into(),
+			},
+		],
+		status: OnchainTransactionStatus::Pending,
+		submitted_at,
+		updated_at: submitted_at,
+	}
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn create_and_find() {
+	let client = Client::new(init_pool());
+
+	let transaction = onchain_transaction(SystemTime::UNIX_EPOCH + Duration::from_secs(10));
+	<Client as OnchainTransactionRepository>::create(&client, transaction.clone()).unwrap();
+
+	let found =
+		<Client as OnchainTransactionRepository>::find_by_hash(&client, &transaction.hash).unwrap();
+	assert_eq!(found, Some(transaction));
+
+	let found = <Client as OnchainTransactionRepository>::find_by_hash(
+		&client,
+		&HexPrefixedString::from_str("0x00").unwrap(),
+	)
+	.unwrap();
+	assert_eq!(found, None);
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn update_and_list_pending() {
+	let client = Client::new(init_pool());
+
+	let accepted_transaction = onchain_transaction(SystemTime::UNIX_EPOCH);
+	let pending_transaction = onchain_transaction(SystemTime::UNIX_EPOCH);
+	<Client as OnchainTransactionRepository>::create(&client, accepted_transaction.clone())
+		.unwrap();
+	<Client as OnchainTransactionRepository>::create(&client, pending_transaction.clone()).unwrap();
+
+	let accepted_transaction = OnchainTransaction {
+		status: OnchainTransactionStatus::AcceptedOnL2,
+		updated_at: SystemTime::UNIX_EPOCH + Duration::from_secs(10),
+		..accepted_transaction
+	};
+	<Client as OnchainTransactionRepository>::update(&client, accepted_transaction.clone())
+		.unwrap();
+
+	let found =
+		<Client as OnchainTransactionRepository>::find_by_hash(&client, &accepted_transaction.hash)
+			.unwrap();
+	assert_eq!(found, Some(accepted_transaction.clone()));
+
+	let pending = <Client as OnchainTransactionRepository>::list_pending(&client).unwrap();
+	assert!(pending.contains(&pending_transaction));
+	assert!(!pending.contains(&accepted_transaction));
+
+	let result = <Client as OnchainTransactionRepository>::update(
+		&client,
+		onchain_transaction(SystemTime::UNIX_EPOCH),
+	);
+	assert!(matches!(
+		result,
+		Err(OnchainTransactionRepositoryError::NotFound)
+	));
+}
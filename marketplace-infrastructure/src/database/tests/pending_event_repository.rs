@@ -0,0 +1,119 @@
+use marketplace_domain::*;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::database::{init_pool, Client};
+
+const INDEXER_ID: &str = "contribution-indexer";
+
+fn pending_event(block_number: u64) -> PendingEvent {
+	PendingEvent {
+		indexer_id: INDEXER_ID.to_string(),
+		deduplication_id: Uuid::new_v4().to_string(),
+		event: Event::Contribution(ContributionEvent::Validated { id: 1.into() }),
+		transaction_hash: HexPrefixedString::from_str("0x1234").unwrap(),
+		block_hash: HexPrefixedString::from_str("0xabcd").unwrap(),
+		block_number,
+	}
+}
+
+fn pending_events(client: &Client, deduplication_ids: &[&str]) -> Vec<PendingEvent> {
+	<Client as PendingEventRepository>::list(client, INDEXER_ID)
+		.unwrap()
+		.into_iter()
+		.filter(|event| deduplication_ids.contains(&event.deduplication_id.as_str()))
+		.collect()
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn create_list_and_delete() {
+	let client = Client::new(init_pool());
+
+	let pending_event1 = pending_event(20);
+	let pending_event2 = pending_event(10);
+	let deduplication_ids = [
+		pending_event1.deduplication_id.as_str(),
+		pending_event2.deduplication_id.as_str(),
+	];
+
+	<Client as PendingEventRepository>::create(&client, pending_event1.clone()).unwrap();
+	<Client as PendingEventRepository>::create(&client, pending_event2.clone()).unwrap();
+	<Client as PendingEventRepository>::create(&client, pending_event1.clone()).unwrap();
+
+	assert_eq!(
+		pending_events(&client, &deduplication_ids),
+		vec![pending_event2.clone(), pending_event1.clone()]
+	);
+
+	<Client as PendingEventRepository>::delete(&client, &pending_event2.deduplication_id).unwrap();
+	assert_eq!(
+		pending_events(&client, &deduplication_ids),
+		vec![pending_event1]
+	);
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn events_of_a_block_are_listed_in_observation_order() {
+	let client = Client::new(init_pool());
+
+	let pending_events_of_block: Vec<PendingEvent> =
+		(0..5).map(|_| pending_event(2_000_000)).collect();
+	let deduplication_ids: Vec<&str> = pending_events_of_block
+		.iter()
+		.map(|event| event.deduplication_id.as_str())
+		.collect();
+
+	for pending_event in &pending_events_of_block {
+		<Client as PendingEventRepository>::create(&client, pending_event.clone()).unwrap();
+	}
+
+	assert_eq!(
+		pending_events(&client, &deduplication_ids),
+		pending_events_of_block
+	);
+}
+
+#[test]
+#[cfg_attr(
+	not(feature = "with_infrastructure_tests"),
+	ignore = "infrastructure test"
+)]
+fn delete_after_block() {
+	let client = Client::new(init_pool());
+
+	let pending_event1 = pending_event(1_000_000);
+	let pending_event2 = pending_event(1_000_001);
+	let other_pending_event = PendingEvent {
+		indexer_id: String::from("other-indexer"),
+		..pending_event(1_000_001)
+	};
+	let deduplication_ids = [
+		pending_event1.deduplication_id.as_str(),
+		pending_event2.deduplication_id.as_str(),
+	];
+
+	<Client as PendingEventRepository>::create(&client, pending_event1.clone()).unwrap();
+	<Client as PendingEventRepository>::create(&client, pending_event2).unwrap();
+	<Client as PendingEventRepository>::create(&client, other_pending_event.clone()).unwrap();
+
+	<Client as PendingEventRepository>::delete_after_block(&client, INDEXER_ID, 1_000_000).unwrap();
+	assert_eq!(
+		pending_events(&client, &deduplication_ids),
+		vec![pending_event1]
+	);
+
+	// Events pending for other indexers are listed and deleted apart
+	assert!(
+		<Client as PendingEventRepository>::list(&client, "other-indexer")
+			.unwrap()
+			.contains(&other_pending_event)
+	);
+}
@@ -0,0 +1,24 @@
+use super::{ContractError, ContractViewer};
+use starknet::core::types::FieldElement;
+use url::Url;
+
+pub struct Contract {
+	contract_viewer: ContractViewer,
+}
+
+impl Contract {
+	pub fn new(contract_address: FieldElement, json_rpc_uri: Url) -> Self {
+		Self {
+			contract_viewer: ContractViewer::new(contract_address, json_rpc_uri),
+		}
+	}
+
+	/// Public key the signatures of the account transactions are checked against
+	pub async fn get_public_key(&self) -> Result<FieldElement, ContractError> {
+		let result = self.contract_viewer.call("getPublicKey", vec![]).await?;
+		result
+			.first()
+			.copied()
+			.ok_or_else(|| ContractError::Call(String::from("getPublicKey returned nothing")))
+	}
+}
@@ -1,29 +1,28 @@
 use super::{ContractAdministrator, ContractError};
-use crate::starknet::model::OnChainContributorId;
+use crate::starknet::{model::OnChainContributorId, NetworkProfile};
 use itertools::Itertools;
-use log::{error, info};
+use log::info;
 use marketplace_domain::*;
 use starknet::{
 	accounts::{Account, Call},
 	core::{types::FieldElement, utils::get_selector_from_name},
 };
-use std::{str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc, time::Duration};
 
 pub struct Contract<A: Account + Sync> {
+	address: FieldElement,
 	administrator: ContractAdministrator<A>,
 }
 
-fn contributions_contract_address() -> FieldElement {
-	let contributions_contract_address =
-		std::env::var("CONTRIBUTIONS_ADDRESS").expect("CONTRIBUTIONS_ADDRESS must be set");
-	FieldElement::from_hex_be(&contributions_contract_address)
-		.expect("Invalid value for CONTRIBUTIONS_ADDRESS")
-}
-
 impl<A: Account + Sync> Contract<A> {
-	pub fn new(administrator_account: Arc<A>) -> Self {
+	pub fn new(administrator_account: Arc<A>, network_profile: &NetworkProfile) -> Self {
 		Self {
-			administrator: ContractAdministrator::new(administrator_account),
+			address: network_profile.contracts.contributions,
+			administrator: ContractAdministrator::new(
+				administrator_account,
+				network_profile.sequencer(),
+				network_profile.fee_policy,
+			),
 		}
 	}
 
@@ -35,21 +34,11 @@ impl<A: Account + Sync> Contract<A> {
 			.iter()
 			.map(|action| {
 				info!("{action}");
-				action.into_call()
+				action.into_call(self.address)
 			})
 			.collect_vec();
-		let transaction_result = self
-			.administrator
-			.send_transaction(&calls)
-			.await
-			.map_err(|e| ContractError::TransactionReverted(e.to_string()));
-
-		if let Err(error) = transaction_result {
-			error!("Error: {}", error);
-			return Err(error);
-		}
+		let transaction_result = self.administrator.send_transaction(&calls).await?;
 
-		let transaction_result = transaction_result.unwrap();
 		// Safe to unwrap because transaction hash is an hexa string and we add the prefix ourselves
 		let transaction_result =
 			HexPrefixedString::from_str(&format!("0x{:x}", transaction_result.transaction_hash))
@@ -59,21 +48,57 @@ impl<A: Account + Sync> Contract<A> {
 
 		Ok(transaction_result)
 	}
+
+	pub async fn rotate_administrator(
+		&self,
+		administrator_account: Arc<A>,
+		drain_timeout: Duration,
+	) -> Result<(), ContractError> {
+		self.administrator.rotate(administrator_account, drain_timeout).await
+	}
+
+	pub async fn estimate_fee(&self, actions: &[Action]) -> Result<FeeEstimate, ContractError> {
+		let calls = actions.iter().map(|action| action.into_call(self.address)).collect_vec();
+		let fee_estimate = self.administrator.estimate_fee(&calls).await?;
+
+		info!(
+			"Estimated fee of {} actions: {} wei, max fee: {} wei",
+			actions.len(),
+			fee_estimate.estimated_fee,
+			fee_estimate.max_fee
+		);
+
+		Ok(fee_estimate)
+	}
+
+	pub async fn simulate(
+		&self,
+		actions: &[Action],
+	) -> Result<TransactionSimulation, ContractError> {
+		let calls = actions.iter().map(|action| action.into_call(self.address)).collect_vec();
+		let simulation = self.administrator.simulate(&calls).await?;
+
+		if let TransactionSimulation::Reverted { reason } = &simulation {
+			info!("Simulation of {} actions reverted: {reason}", actions.len());
+		}
+
+		Ok(simulation)
+	}
 }
 
 trait IntoCall {
-	fn into_call(self) -> Call;
+	fn into_call(self, contract_address: FieldElement) -> Call;
 }
 
 impl IntoCall for &Action {
-	fn into_call(self) -> Call {
+	fn into_call(self, contract_address: FieldElement) -> Call {
 		match self {
 			Action::CreateContribution {
 				project_id,
 				issue_number,
 				gate,
 			} => Call {
-				to: contributions_contract_address(),
+				to: contract_address,
 				selector: get_selector_from_name("new_contribution").unwrap(),
 				calldata: vec![
 					FieldElement::from(*project_id),   // project_id
@@ -90,7 +115,7 @@ impl IntoCall for &Action {
 					contributor_id.to_owned().into();
 
 				Call {
-					to: contributions_contract_address(),
+					to: contract_address,
 					selector: get_selector_from_name("assign_contributor_to_contribution").unwrap(),
 					calldata: vec![
 						FieldElement::from_hex_be(&contribution_id.to_string()).unwrap(), /* id : felt */
@@ -101,7 +126,7 @@ impl IntoCall for &Action {
 			},
 
 			Action::UnassignContributor { contribution_id } => Call {
-				to: contributions_contract_address(),
+				to: contract_address,
 				selector: get_selector_from_name("unassign_contributor_from_contribution").unwrap(),
 				calldata: vec![
 					FieldElement::from_hex_be(&contribution_id.to_string()).unwrap(), // id : felt
@@ -109,7 +134,7 @@ impl IntoCall for &Action {
 			},
 
 			Action::ValidateContribution { contribution_id } => Call {
-				to: contributions_contract_address(),
+				to: contract_address,
 				selector: get_selector_from_name("validate_contribution").unwrap(),
 				calldata: vec![
 					FieldElement::from_hex_be(&contribution_id.to_string()).unwrap(), // id : felt
@@ -10,6 +10,18 @@ pub enum Error {
 	GetNonce(String),
 	#[error("Transaction was reverted: {0}")]
 	TransactionReverted(String),
+	#[error("Sequencer gateway still unavailable after {attempts} attempts: {message}")]
+	GatewayUnavailable { attempts: u32, message: String },
+	#[error("Nonce conflict still occurring after {attempts} attempts: {message}")]
+	NonceConflict { attempts: u32, message: String },
+	#[error("Transaction may have been sent although no response was received: {0}")]
+	UnknownOutcome(String),
+	#[error("Something happened when estimating the transaction fee: {0}")]
+	EstimateFee(String),
+	#[error("Estimated fee of {estimated_fee} wei is above the cap of {cap} wei")]
+	FeeAboveCap { estimated_fee: u64, cap: u64 },
+	#[error("{in_flight} transactions were still being sent with the previous account")]
+	DrainTimeout { in_flight: usize },
 }
 
 #[cfg(test)]
@@ -22,6 +34,10 @@ mod test {
 	#[case(Error::SendTransaction(String::from("message")))]
 	#[case(Error::GetNonce(String::from("message")))]
 	#[case(Error::TransactionReverted(String::from("message")))]
+	#[case(Error::GatewayUnavailable { attempts: 3, message: String::from("message") })]
+	#[case(Error::NonceConflict { attempts: 3, message: String::from("message") })]
+	#[case(Error::UnknownOutcome(String::from("message")))]
+	#[case(Error::EstimateFee(String::from("message")))]
 	fn error_description_contains_underlying_message(#[case] error: Error) {
 		assert!(error.to_string().ends_with("message"));
 	}
@@ -1,6 +1,10 @@
 mod utils;
+pub use utils::FeePolicy;
 use utils::*;
 
+mod account;
+pub use account::Contract as AccountContract;
+
 mod profile;
 pub use profile::Contract as ProfileContract;
 
@@ -1,28 +1,21 @@
 use crate::starknet::model::OnChainContributorId;
 
-use super::ContractViewer;
+use super::{ContractError, ContractViewer};
 use marketplace_domain::*;
 use starknet::core::types::FieldElement;
+use url::Url;
 
 pub struct Contract {
 	contract_viewer: ContractViewer,
 }
 
-fn profile_contract_address() -> FieldElement {
-	let profile_contract_address =
-		std::env::var("PROFILE_ADDRESS").expect("PROFILE_ADDRESS must be set");
-	FieldElement::from_hex_be(&profile_contract_address).expect("Invalid value for PROFILE_ADDRESS")
-}
-
-impl Default for Contract {
-	fn default() -> Self {
+impl Contract {
+	pub fn new(contract_address: FieldElement, json_rpc_uri: Url) -> Self {
 		Self {
-			contract_viewer: ContractViewer::new(profile_contract_address()),
+			contract_viewer: ContractViewer::new(contract_address, json_rpc_uri),
 		}
 	}
-}
 
-impl Contract {
 	pub async fn get_account(&self, contributor_id: &ContributorId) -> Option<FieldElement> {
 		let contributor_id: OnChainContributorId = contributor_id.to_owned().into();
 		self.contract_viewer
@@ -31,4 +24,21 @@ impl Contract {
 			.map(|c| c[0])
 			.ok()
 	}
+
+	/// Get the accounts of all the contributors at once, in the order of the contributor ids
+	pub async fn get_accounts(
+		&self,
+		contributor_ids: &[ContributorId],
+	) -> Result<Vec<Option<FieldElement>>, ContractError> {
+		let calldatas = contributor_ids
+			.iter()
+			.map(|contributor_id| {
+				let contributor_id: OnChainContributorId = contributor_id.to_owned().into();
+				vec![contributor_id.0, contributor_id.1]
+			})
+			.collect();
+
+		let accounts = self.contract_viewer.batch_call("ownerOf", calldatas).await?;
+		Ok(accounts.into_iter().map(|result| result.ok().map(|c| c[0])).collect())
+	}
 }
@@ -1,38 +1,49 @@
 use crate::starknet::model::OnChainContributorId;
 
-use super::ContractViewer;
+use super::{ContractError, ContractViewer};
 use marketplace_domain::*;
 use starknet::core::types::FieldElement;
+use url::Url;
 
 pub struct Contract {
 	contract_viewer: ContractViewer,
 }
 
-fn registry_contract_address() -> FieldElement {
-	let registry_contract_address =
-		std::env::var("REGISTRY_ADDRESS").expect("REGISTRY_ADDRESS must be set");
-	FieldElement::from_hex_be(&registry_contract_address)
-		.expect("Invalid value for REGISTRY_ADDRESS")
-}
-
-impl Default for Contract {
-	fn default() -> Self {
+impl Contract {
+	pub fn new(contract_address: FieldElement, json_rpc_uri: Url) -> Self {
 		Self {
-			contract_viewer: ContractViewer::new(registry_contract_address()),
+			contract_viewer: ContractViewer::new(contract_address, json_rpc_uri),
 		}
 	}
-}
 
-impl Contract {
 	pub async fn get_user_information(&self, account: FieldElement) -> Option<Contributor> {
 		self.contract_viewer
 			.call("get_user_information", vec![account])
 			.await
-			.map(|fields| Contributor {
-				id: OnChainContributorId(fields[1], fields[2]).into(),
-				github_handle: Some(fields[3].to_string()),
-				github_username: None,
-			})
+			.map(into_contributor)
 			.ok()
 	}
+
+	/// Get the information of all the accounts at once, in the order of the accounts
+	pub async fn get_users_information(
+		&self,
+		accounts: &[FieldElement],
+	) -> Result<Vec<Option<Contributor>>, ContractError> {
+		let calldatas = accounts.iter().map(|account| vec![*account]).collect();
+
+		let users_information =
+			self.contract_viewer.batch_call("get_user_information", calldatas).await?;
+		Ok(users_information
+			.into_iter()
+			.map(|result| result.ok().map(into_contributor))
+			.collect())
+	}
+}
+
+fn into_contributor(fields: Vec<FieldElement>) -> Contributor {
+	Contributor {
+		id: OnChainContributorId(fields[1], fields[2]).into(),
+		github_handle: Some(fields[3].to_string()),
+		github_username: None,
+	}
 }
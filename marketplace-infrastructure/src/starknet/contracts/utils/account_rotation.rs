@@ -0,0 +1,92 @@
+use std::{
+	sync::{Arc, PoisonError, RwLock},
+	time::{Duration, Instant},
+};
+
+/// Delay between two checks of the transactions still using a retired account
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Holds the account transactions are sent with, and swaps it for another one on rotation.
+/// Transactions take the current account when they start and keep it until they are sent, so
+/// that all their attempts are signed with the same key.
+pub struct AccountRotation<T> {
+	current: RwLock<Arc<T>>,
+}
+
+impl<T> AccountRotation<T> {
+	pub fn new(account: T) -> Self {
+		Self {
+			current: RwLock::new(Arc::new(account)),
+		}
+	}
+
+	pub fn current(&self) -> Arc<T> {
+		self.current.read().unwrap_or_else(PoisonError::into_inner).clone()
+	}
+
+	/// Make `account` the current one, then wait for the transactions still using the retired
+	/// account to be sent.
+	/// Returns the number of transactions still in flight if they are not all sent within
+	/// `drain_timeout`.
+	pub async fn rotate(&self, account: T, drain_timeout: Duration) -> Result<Arc<T>, usize> {
+		let retired = {
+			let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+			std::mem::replace(&mut *current, Arc::new(account))
+		};
+
+		let started_at = Instant::now();
+		loop {
+			let in_flight = Arc::strong_count(&retired) - 1;
+			if in_flight == 0 {
+				return Ok(retired);
+			}
+			if started_at.elapsed() >= drain_timeout {
+				return Err(in_flight);
+			}
+			tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[tokio::test]
+	async fn rotation_swaps_the_current_account() {
+		let rotation = AccountRotation::new("old");
+
+		let retired = rotation.rotate("new", Duration::from_secs(1)).await.unwrap();
+
+		assert_eq!("old", *retired);
+		assert_eq!("new", *rotation.current());
+	}
+
+	#[tokio::test]
+	async fn rotation_waits_for_in_flight_transactions() {
+		let rotation = Arc::new(AccountRotation::new("old"));
+		let in_flight = rotation.current();
+
+		tokio::spawn(async move {
+			tokio::time::sleep(Duration::from_millis(200)).await;
+			drop(in_flight);
+		});
+
+		let started_at = Instant::now();
+		let retired = rotation.rotate("new", Duration::from_secs(5)).await;
+
+		assert!(retired.is_ok());
+		assert!(started_at.elapsed() >= Duration::from_millis(200));
+	}
+
+	#[tokio::test]
+	async fn rotation_gives_up_draining_after_timeout() {
+		let rotation = AccountRotation::new("old");
+		let _in_flight = rotation.current();
+
+		let result = rotation.rotate("new", Duration::from_millis(200)).await;
+
+		assert_eq!(Err(1), result.map(|_| ()));
+		assert_eq!("new", *rotation.current());
+	}
+}
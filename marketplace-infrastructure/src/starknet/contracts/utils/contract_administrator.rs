@@ -1,7 +1,6 @@
-use super::ContractError;
-use crate::starknet::sequencer;
-use log::{error, info};
-use rand::prelude::random;
+use super::{AccountRotation, ContractError, FeePolicy, NonceManager};
+use log::{error, info, warn};
+use marketplace_domain::{FeeEstimate, RetryPolicy, TransactionSimulation};
 use starknet::{
 	accounts::{single_owner::GetNonceError, Account, AccountCall, Call},
 	core::{
@@ -10,51 +9,290 @@ use starknet::{
 	},
 	providers::{Provider, SequencerGatewayProvider},
 };
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
+
+/// Number of nonce keys used in turn by the administrator account
+const NONCE_KEY_COUNT: u64 = 16;
+
+/// Error code returned by the gateway when the nonce of a transaction was already used
+const INVALID_NONCE_ERROR_CODE: &str = "StarknetErrorCode.INVALID_TRANSACTION_NONCE";
+
+/// Failures reaching the gateway
+const TRANSIENT_FAILURE_MARKERS: [&str; 7] = [
+	"timed out",
+	"timeout",
+	"connection",
+	"error sending request",
+	"too many requests",
+	"bad gateway",
+	"service unavailable",
+];
+
+/// Failures after which a sent transaction may still have been received by the gateway
+const AMBIGUOUS_FAILURE_MARKERS: [&str; 4] = [
+	"timed out",
+	"timeout",
+	"connection",
+	"error sending request",
+];
+
+/// An administrator account along with the nonces it handed out
+struct AdministratorAccount<A: Account + Sync> {
+	account: Arc<A>,
+	nonce_manager: NonceManager,
+}
+
+impl<A: Account + Sync> AdministratorAccount<A> {
+	fn new(account: Arc<A>) -> Self {
+		Self {
+			account,
+			nonce_manager: NonceManager::new(NONCE_KEY_COUNT),
+		}
+	}
+}
 
 pub struct ContractAdministrator<A: Account + Sync> {
-	administrator_account: Arc<A>,
+	administrator_accounts: AccountRotation<AdministratorAccount<A>>,
 	sequencer: SequencerGatewayProvider,
+	retry_policy: RetryPolicy,
+	fee_policy: FeePolicy,
+}
+
+#[derive(Debug)]
+enum SubmissionFailure {
+	/// The gateway could not be reached or was overloaded
+	Transient(String),
+	/// The transaction was sent but no response was received, it may have been accepted
+	Ambiguous(String),
+	/// The nonce was already used by another transaction
+	NonceConflict(String),
+	Fatal(ContractError),
+}
+
+impl SubmissionFailure {
+	/// Classify a failure from its message, using `fatal` for failures that must not be retried
+	fn from_message(message: String, fatal: fn(String) -> ContractError) -> Self {
+		if message.contains(INVALID_NONCE_ERROR_CODE) {
+			return Self::NonceConflict(message);
+		}
+
+		let lowercase_message = message.to_lowercase();
+		match TRANSIENT_FAILURE_MARKERS
+			.iter()
+			.any(|marker| lowercase_message.contains(marker))
+		{
+			true => Self::Transient(message),
+			false => Self::Fatal(fatal(message)),
+		}
+	}
+
+	/// Classify a failure to send a transaction
+	fn from_send_message(message: String) -> Self {
+		let lowercase_message = message.to_lowercase();
+
+		match Self::from_message(message, ContractError::SendTransaction) {
+			Self::Transient(message)
+				if AMBIGUOUS_FAILURE_MARKERS
+					.iter()
+					.any(|marker| lowercase_message.contains(marker)) =>
+				Self::Ambiguous(message),
+			failure => failure,
+		}
+	}
+
+	fn into_contract_error(self, attempts: u32) -> ContractError {
+		match self {
+			Self::Transient(message) => ContractError::GatewayUnavailable { attempts, message },
+			Self::Ambiguous(message) => ContractError::UnknownOutcome(message),
+			Self::NonceConflict(message) => ContractError::NonceConflict { attempts, message },
+			Self::Fatal(error) => error,
+		}
+	}
 }
 
 impl<A: Account + Sync> ContractAdministrator<A> {
-	pub fn new(administrator_account: Arc<A>) -> Self {
+	pub fn new(
+		administrator_account: Arc<A>,
+		sequencer: SequencerGatewayProvider,
+		fee_policy: FeePolicy,
+	) -> Self {
 		Self {
-			administrator_account,
-			sequencer: sequencer(),
+			administrator_accounts: AccountRotation::new(AdministratorAccount::new(
+				administrator_account,
+			)),
+			sequencer,
+			retry_policy: RetryPolicy::default(),
+			fee_policy,
 		}
 	}
 
+	/// Send the next transactions with `administrator_account`.
+	/// Transactions being sent with the previous account are drained before returning.
+	pub async fn rotate(
+		&self,
+		administrator_account: Arc<A>,
+		drain_timeout: Duration,
+	) -> Result<(), ContractError> {
+		let new_address = administrator_account.address();
+		let previous_address = self.administrator_accounts.current().account.address();
+		info!("Rotating administrator account from 0x{previous_address:x} to 0x{new_address:x}");
+
+		self.administrator_accounts
+			.rotate(
+				AdministratorAccount::new(administrator_account),
+				drain_timeout,
+			)
+			.await
+			.map_err(|in_flight| {
+				let error = ContractError::DrainTimeout { in_flight };
+				error!("{error}");
+				error
+			})?;
+
+		info!("Administrator account 0x{previous_address:x} drained");
+		Ok(())
+	}
+
+	/// Estimate the fee of a transaction made of the calls, without sending it
+	pub async fn estimate_fee(&self, calls: &[Call]) -> Result<FeeEstimate, ContractError> {
+		let administrator = self.administrator_accounts.current();
+		let nonce_key = administrator.nonce_manager.next_key();
+		let nonce = self
+			.get_2d_nonce(&administrator.account, nonce_key)
+			.await
+			.map_err(|error| ContractError::GetNonce(error.to_string()))?;
+
+		let estimated_fee = administrator
+			.account
+			.execute(calls)
+			.nonce(nonce)
+			.estimate_fee()
+			.await
+			.map_err(|error| ContractError::EstimateFee(error.to_string()))?
+			.amount;
+
+		Ok(FeeEstimate {
+			estimated_fee,
+			max_fee: self.fee_policy.max_fee(estimated_fee)?,
+		})
+	}
+
+	/// Run the calls against the latest state of the network without sending them.
+	/// Failures that do not come from the network are reported as a revert of the transaction.
+	pub async fn simulate(&self, calls: &[Call]) -> Result<TransactionSimulation, ContractError> {
+		simulation(self.estimate_fee(calls).await)
+	}
+
+	/// Send the calls in a single transaction.
+	/// Transient gateway failures and nonce conflicts are retried as long as the retry policy
+	/// allows. Once an attempt may have been received by the gateway, the next ones are sent with
+	/// the same nonce, so that the transaction cannot be executed twice.
 	pub async fn send_transaction(
 		&self,
 		calls: &[Call],
 	) -> Result<AddTransactionResult, ContractError> {
 		info!("Sending transaction with {} calls", calls.len());
 
-		let nonce_key: u64 = random();
-		let nonce = self
-			.get_2d_nonce(nonce_key.into())
-			.await
-			.map_err(|error| ContractError::GetNonce(error.to_string()))?;
+		// All attempts are made with the same account, even if it gets rotated meanwhile
+		let administrator = self.administrator_accounts.current();
 
-		match self.administrator_account.execute(calls).nonce(nonce).send().await {
-			Ok(transaction_result) => Ok(transaction_result),
-			Err(error) => {
-				error!("{}", error);
-				Err(ContractError::SendTransaction(error.to_string()))
-			},
+		let mut nonce_reservation = None;
+		let mut possibly_sent = false;
+		let mut attempt = 1;
+		loop {
+			let failure = match self
+				.try_send_transaction(&administrator, calls, &mut nonce_reservation)
+				.await
+			{
+				Ok(transaction_result) => return Ok(transaction_result),
+				Err(failure) => failure,
+			};
+			possibly_sent |= matches!(failure, SubmissionFailure::Ambiguous(_));
+
+			let retry = match failure {
+				SubmissionFailure::Fatal(_) => false,
+				// The nonce was most likely consumed by a previous attempt
+				SubmissionFailure::NonceConflict(_) if possibly_sent => false,
+				_ => attempt < self.retry_policy.max_attempts,
+			};
+
+			if !(retry && possibly_sent) {
+				if let Some((nonce_key, _)) = nonce_reservation.take() {
+					// The reserved nonce was not consumed, or must be fetched on-chain again
+					administrator.nonce_manager.forget(nonce_key);
+				}
+			}
+
+			if !retry {
+				let error = match failure {
+					SubmissionFailure::Transient(message)
+					| SubmissionFailure::NonceConflict(message)
+						if possibly_sent =>
+						ContractError::UnknownOutcome(message),
+					failure => failure.into_contract_error(attempt),
+				};
+				error!("{error}");
+				return Err(error);
+			}
+
+			let backoff = self.retry_policy.backoff(attempt);
+			warn!("Attempt {attempt} to send transaction failed, retrying in {backoff:?}: {failure:?}");
+			tokio::time::sleep(backoff).await;
+			attempt += 1;
 		}
 	}
 
+	/// Send the calls with the reserved nonce, reserving one first if there is none
+	async fn try_send_transaction(
+		&self,
+		administrator: &AdministratorAccount<A>,
+		calls: &[Call],
+		nonce_reservation: &mut Option<(FieldElement, FieldElement)>,
+	) -> Result<AddTransactionResult, SubmissionFailure> {
+		let nonce = match *nonce_reservation {
+			Some((_, reserved_nonce)) => reserved_nonce,
+			None => {
+				let nonce_key = administrator.nonce_manager.next_key();
+				let reserved_nonce = administrator
+					.nonce_manager
+					.reserve(nonce_key, || {
+						self.get_2d_nonce(&administrator.account, nonce_key)
+					})
+					.await
+					.map_err(|error| SubmissionFailure::Transient(error.to_string()))?;
+				*nonce_reservation = Some((nonce_key, reserved_nonce));
+				reserved_nonce
+			},
+		};
+
+		let call = administrator.account.execute(calls).nonce(nonce);
+
+		let estimated_fee = call
+			.estimate_fee()
+			.await
+			.map_err(|error| {
+				SubmissionFailure::from_message(error.to_string(), ContractError::EstimateFee)
+			})?
+			.amount;
+		let max_fee = self.fee_policy.max_fee(estimated_fee).map_err(SubmissionFailure::Fatal)?;
+		info!("Estimated fee: {estimated_fee} wei, sending with max fee: {max_fee} wei");
+
+		call.max_fee(FieldElement::from(max_fee))
+			.send()
+			.await
+			.map_err(|error| SubmissionFailure::from_send_message(error.to_string()))
+	}
+
 	async fn get_2d_nonce(
 		&self,
+		account: &A,
 		nonce_key: FieldElement,
 	) -> Result<FieldElement, GetNonceError<<SequencerGatewayProvider as Provider>::Error>> {
 		let call_result = self
 			.sequencer
 			.call_contract(
 				InvokeFunctionTransactionRequest {
-					contract_address: self.administrator_account.address(),
+					contract_address: account.address(),
 					entry_point_selector: get_selector_from_name("get_nonce").unwrap(),
 					calldata: vec![nonce_key],
 					signature: vec![],
@@ -75,3 +313,148 @@ impl<A: Account + Sync> ContractAdministrator<A> {
 		}
 	}
 }
+
+/// Outcome of the simulation of a transaction whose fee was estimated
+fn simulation(
+	fee_estimate: Result<FeeEstimate, ContractError>,
+) -> Result<TransactionSimulation, ContractError> {
+	match fee_estimate {
+		Ok(fee_estimate) => Ok(TransactionSimulation::Succeeded(fee_estimate)),
+		Err(ContractError::EstimateFee(message)) =>
+			match SubmissionFailure::from_message(message, ContractError::EstimateFee) {
+				SubmissionFailure::Fatal(ContractError::EstimateFee(reason)) =>
+					Ok(TransactionSimulation::Reverted { reason }),
+				failure => Err(failure.into_contract_error(1)),
+			},
+		// The transaction would be refused before being sent
+		Err(error @ ContractError::FeeAboveCap { .. }) => Ok(TransactionSimulation::Reverted {
+			reason: error.to_string(),
+		}),
+		Err(error) => Err(error),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use rstest::*;
+
+	#[rstest]
+	fn fee_above_cap_is_simulated_as_a_revert() {
+		let simulation = simulation(Err(ContractError::FeeAboveCap {
+			estimated_fee: 1_001,
+			cap: 1_000,
+		}))
+		.unwrap();
+
+		assert!(matches!(
+			simulation,
+			TransactionSimulation::Reverted { reason } if reason.contains("above the cap")
+		));
+	}
+
+	#[rstest]
+	fn contract_errors_are_simulated_as_a_revert() {
+		let simulation = simulation(Err(ContractError::EstimateFee(String::from(
+			"Error in the called contract: assert_not_zero",
+		))))
+		.unwrap();
+
+		assert!(matches!(
+			simulation,
+			TransactionSimulation::Reverted { reason } if reason.contains("assert_not_zero")
+		));
+	}
+
+	#[rstest]
+	fn network_errors_are_not_simulated() {
+		assert!(matches!(
+			simulation(Err(ContractError::GetNonce(String::from(
+				"operation timed out"
+			)))),
+			Err(ContractError::GetNonce(_))
+		));
+	}
+
+	#[rstest]
+	fn nonce_errors_are_conflicts() {
+		let message = String::from(
+			"StarknetErrorCode.INVALID_TRANSACTION_NONCE: Invalid transaction nonce. Expected: 3, got: 2.",
+		);
+		assert!(matches!(
+			SubmissionFailure::from_message(message.clone(), ContractError::SendTransaction),
+			SubmissionFailure::NonceConflict(m) if m == message
+		));
+	}
+
+	#[rstest]
+	fn other_errors_mentioning_nonces_are_not_conflicts() {
+		let message = String::from("Error in the called contract: Unexpected nonce key");
+		assert!(matches!(
+			SubmissionFailure::from_message(message.clone(), ContractError::SendTransaction),
+			SubmissionFailure::Fatal(ContractError::SendTransaction(m)) if m == message
+		));
+	}
+
+	#[rstest]
+	#[case("error sending request for url (https://alpha4.starknet.io/gateway/add_transaction)")]
+	#[case("operation timed out")]
+	#[case("HTTP status client error (429 Too Many Requests)")]
+	#[case("HTTP status server error (503 Service Unavailable)")]
+	fn gateway_errors_are_transient(#[case] message: &str) {
+		assert!(matches!(
+			SubmissionFailure::from_message(message.to_string(), ContractError::SendTransaction),
+			SubmissionFailure::Transient(m) if m == message
+		));
+	}
+
+	#[rstest]
+	#[case("error sending request for url (https://alpha4.starknet.io/gateway/add_transaction)")]
+	#[case("operation timed out")]
+	fn send_errors_without_response_are_ambiguous(#[case] message: &str) {
+		assert!(matches!(
+			SubmissionFailure::from_send_message(message.to_string()),
+			SubmissionFailure::Ambiguous(m) if m == message
+		));
+	}
+
+	#[rstest]
+	#[case("HTTP status client error (429 Too Many Requests)")]
+	#[case("HTTP status server error (503 Service Unavailable)")]
+	fn send_errors_refused_by_gateway_are_transient(#[case] message: &str) {
+		assert!(matches!(
+			SubmissionFailure::from_send_message(message.to_string()),
+			SubmissionFailure::Transient(m) if m == message
+		));
+	}
+
+	#[rstest]
+	fn other_errors_are_fatal() {
+		let message = String::from("Error at pc=0:12: An ASSERT_EQ instruction failed");
+		assert!(matches!(
+			SubmissionFailure::from_message(message.clone(), ContractError::EstimateFee),
+			SubmissionFailure::Fatal(ContractError::EstimateFee(m)) if m == message
+		));
+	}
+
+	#[rstest]
+	fn failures_are_reported_with_their_attempts() {
+		assert!(matches!(
+			SubmissionFailure::Transient(String::from("timeout")).into_contract_error(5),
+			ContractError::GatewayUnavailable { attempts: 5, .. }
+		));
+		assert!(matches!(
+			SubmissionFailure::Ambiguous(String::from("timeout")).into_contract_error(5),
+			ContractError::UnknownOutcome(_)
+		));
+		assert!(matches!(
+			SubmissionFailure::NonceConflict(String::from("nonce")).into_contract_error(5),
+			ContractError::NonceConflict { attempts: 5, .. }
+		));
+		assert!(matches!(
+			SubmissionFailure::Fatal(ContractError::SendTransaction(String::from("oops")))
+				.into_contract_error(1),
+			ContractError::SendTransaction(_)
+		));
+	}
+}
@@ -1,4 +1,6 @@
 use super::ContractError;
+use serde::Deserialize;
+use serde_json::{json, Value};
 use starknet::{
 	core::{types::FieldElement, utils::get_selector_from_name},
 	providers::jsonrpc::{
@@ -10,14 +12,31 @@ use url::Url;
 
 pub struct ContractViewer {
 	contract_address: FieldElement,
+	json_rpc_uri: Url,
 	client: JsonRpcClient<HttpTransport>,
+	http_client: reqwest::Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcError {
+	code: i64,
+	message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchResponse {
+	id: usize,
+	result: Option<Vec<String>>,
+	error: Option<JsonRpcError>,
 }
 
 impl ContractViewer {
-	pub fn new(contract_address: FieldElement) -> Self {
+	pub fn new(contract_address: FieldElement, json_rpc_uri: Url) -> Self {
 		Self {
 			contract_address,
-			client: JsonRpcClient::new(HttpTransport::new(json_rpc_uri())),
+			client: JsonRpcClient::new(HttpTransport::new(json_rpc_uri.clone())),
+			json_rpc_uri,
+			http_client: reqwest::Client::new(),
 		}
 	}
 
@@ -28,19 +47,186 @@ impl ContractViewer {
 	) -> Result<Vec<FieldElement>, ContractError> {
 		self.client
 			.call(
-				&FunctionCall {
-					contract_address: self.contract_address,
-					entry_point_selector: get_selector_from_name(function_name).unwrap(),
-					calldata,
-				},
+				&self.function_call(function_name, calldata),
 				&BlockHashOrTag::Tag(BlockTag::Latest),
 			)
 			.await
 			.map_err(|e| ContractError::Call(e.to_string()))
 	}
+
+	/// Call the function once for each calldata, in a single JSON-RPC batch request.
+	/// Results are in the order of the calldatas, each call failing on its own.
+	pub async fn batch_call(
+		&self,
+		function_name: &str,
+		calldatas: Vec<Vec<FieldElement>>,
+	) -> Result<Vec<Result<Vec<FieldElement>, ContractError>>, ContractError> {
+		if calldatas.is_empty() {
+			return Ok(vec![]);
+		}
+
+		let call_count = calldatas.len();
+		let calls = calldatas
+			.into_iter()
+			.map(|calldata| self.function_call(function_name, calldata))
+			.collect::<Vec<_>>();
+
+		let responses: Vec<BatchResponse> = self
+			.http_client
+			.post(self.json_rpc_uri.clone())
+			.json(&batch_request(&calls)?)
+			.send()
+			.await
+			.map_err(|e| ContractError::Call(e.to_string()))?
+			.error_for_status()
+			.map_err(|e| ContractError::Call(e.to_string()))?
+			.json()
+			.await
+			.map_err(|e| ContractError::Call(e.to_string()))?;
+
+		Ok(batch_results(call_count, responses))
+	}
+
+	fn function_call(&self, function_name: &str, calldata: Vec<FieldElement>) -> FunctionCall {
+		FunctionCall {
+			contract_address: self.contract_address,
+			entry_point_selector: get_selector_from_name(function_name).unwrap(),
+			calldata,
+		}
+	}
+}
+
+fn batch_request(calls: &[FunctionCall]) -> Result<Value, ContractError> {
+	let block = serde_json::to_value(BlockHashOrTag::Tag(BlockTag::Latest))
+		.map_err(|e| ContractError::Call(e.to_string()))?;
+
+	calls
+		.iter()
+		.enumerate()
+		.map(|(id, call)| {
+			let call =
+				serde_json::to_value(call).map_err(|e| ContractError::Call(e.to_string()))?;
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": id,
+				"method": "starknet_call",
+				"params": [call, block],
+			}))
+		})
+		.collect::<Result<Vec<_>, _>>()
+		.map(Value::Array)
+}
+
+/// Match the responses, which may come in any order, with the calls they answer
+fn batch_results(
+	call_count: usize,
+	responses: Vec<BatchResponse>,
+) -> Vec<Result<Vec<FieldElement>, ContractError>> {
+	let mut results: Vec<Result<Vec<FieldElement>, ContractError>> = (0..call_count)
+		.map(|id| {
+			Err(ContractError::Call(format!(
+				"No response to call {id} of the batch"
+			)))
+		})
+		.collect();
+
+	for response in responses {
+		if response.id >= call_count {
+			continue;
+		}
+
+		results[response.id] = match (response.result, response.error) {
+			(_, Some(error)) => Err(ContractError::Call(format!(
+				"{} ({})",
+				error.message, error.code
+			))),
+			(Some(result), None) => result
+				.iter()
+				.map(|felt| {
+					FieldElement::from_hex_be(felt)
+						.map_err(|_| ContractError::Call(format!("Invalid field element: {felt}")))
+				})
+				.collect(),
+			(None, None) => Err(ContractError::Call(String::from("Empty response"))),
+		};
+	}
+
+	results
 }
 
-fn json_rpc_uri() -> Url {
-	Url::parse(&std::env::var("JSON_RPC_URI").expect("JSON_RPC_URI must be set"))
-		.expect("Invalid JSON_RPC_URI")
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+
+	fn response(id: usize, result: &[&str]) -> BatchResponse {
+		BatchResponse {
+			id,
+			result: Some(result.iter().map(|felt| felt.to_string()).collect()),
+			error: None,
+		}
+	}
+
+	#[test]
+	fn batch_request_calls_each_function() {
+		let calls = vec![
+			FunctionCall {
+				contract_address: FieldElement::from(1u64),
+				entry_point_selector: get_selector_from_name("ownerOf").unwrap(),
+				calldata: vec![FieldElement::from(12u64), FieldElement::ZERO],
+			},
+			FunctionCall {
+				contract_address: FieldElement::from(1u64),
+				entry_point_selector: get_selector_from_name("ownerOf").unwrap(),
+				calldata: vec![FieldElement::from(13u64), FieldElement::ZERO],
+			},
+		];
+
+		let request = batch_request(&calls).unwrap();
+		let requests = request.as_array().unwrap();
+
+		assert_eq!(2, requests.len());
+		assert_eq!(json!(1), requests[1]["id"]);
+		assert_eq!(json!("starknet_call"), requests[1]["method"]);
+		assert_eq!(
+			serde_json::to_value(&calls[1]).unwrap(),
+			requests[1]["params"][0]
+		);
+	}
+
+	#[test]
+	fn responses_are_matched_with_their_calls() {
+		let results = batch_results(2, vec![response(1, &["0x2"]), response(0, &["0x1", "0xa"])]);
+
+		assert_eq!(
+			vec![FieldElement::from(1u64), FieldElement::from(10u64)],
+			*results[0].as_ref().unwrap()
+		);
+		assert_eq!(
+			vec![FieldElement::from(2u64)],
+			*results[1].as_ref().unwrap()
+		);
+	}
+
+	#[test]
+	fn failed_calls_do_not_fail_the_batch() {
+		let results = batch_results(
+			3,
+			vec![
+				response(0, &["0x1"]),
+				BatchResponse {
+					id: 1,
+					result: None,
+					error: Some(JsonRpcError {
+						code: 40,
+						message: String::from("Contract error"),
+					}),
+				},
+			],
+		);
+
+		assert_matches!(results[0], Ok(_));
+		assert_matches!(&results[1], Err(ContractError::Call(message)) if message == "Contract error (40)");
+		assert_matches!(results[2], Err(ContractError::Call(_)));
+	}
 }
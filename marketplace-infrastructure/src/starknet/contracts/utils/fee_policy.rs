@@ -0,0 +1,91 @@
+use super::ContractError;
+
+/// Default multiplier applied to the estimated fee to get the max fee of a transaction
+const DEFAULT_FEE_MULTIPLIER: f64 = 1.5;
+/// Default cap of the max fee of a transaction, in wei (0.01 ETH)
+const DEFAULT_MAX_FEE: u64 = 10_000_000_000_000_000;
+
+/// Decides the max fee of administrator transactions from their estimated fee.
+/// A margin is taken over the estimate, and transactions estimated above the cap are refused.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeePolicy {
+	pub multiplier: f64,
+	pub cap: u64,
+}
+
+impl Default for FeePolicy {
+	fn default() -> Self {
+		Self {
+			multiplier: DEFAULT_FEE_MULTIPLIER,
+			cap: DEFAULT_MAX_FEE,
+		}
+	}
+}
+
+impl FeePolicy {
+	pub fn max_fee(&self, estimated_fee: u64) -> Result<u64, ContractError> {
+		if estimated_fee > self.cap {
+			return Err(ContractError::FeeAboveCap {
+				estimated_fee,
+				cap: self.cap,
+			});
+		}
+
+		let max_fee = (estimated_fee as f64 * self.multiplier.max(1.0)).ceil() as u64;
+		Ok(max_fee.min(self.cap))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use rstest::*;
+
+	#[fixture]
+	fn fee_policy() -> FeePolicy {
+		FeePolicy {
+			multiplier: 1.5,
+			cap: 1_000,
+		}
+	}
+
+	#[rstest]
+	#[case(0, 0)]
+	#[case(100, 150)]
+	#[case(101, 152)]
+	#[case(600, 900)]
+	fn max_fee_applies_the_multiplier(
+		fee_policy: FeePolicy,
+		#[case] estimated_fee: u64,
+		#[case] expected_max_fee: u64,
+	) {
+		assert_eq!(expected_max_fee, fee_policy.max_fee(estimated_fee).unwrap());
+	}
+
+	#[rstest]
+	#[case(700)]
+	#[case(1_000)]
+	fn max_fee_is_capped(fee_policy: FeePolicy, #[case] estimated_fee: u64) {
+		assert_eq!(1_000, fee_policy.max_fee(estimated_fee).unwrap());
+	}
+
+	#[rstest]
+	fn fee_above_cap_is_refused(fee_policy: FeePolicy) {
+		assert!(matches!(
+			fee_policy.max_fee(1_001),
+			Err(ContractError::FeeAboveCap {
+				estimated_fee: 1_001,
+				cap: 1_000
+			})
+		));
+	}
+
+	#[test]
+	fn multiplier_never_lowers_the_estimate() {
+		let fee_policy = FeePolicy {
+			multiplier: 0.5,
+			cap: 1_000,
+		};
+		assert_eq!(100, fee_policy.max_fee(100).unwrap());
+	}
+}
@@ -1,6 +1,15 @@
 mod contract_viewer;
 pub use contract_viewer::ContractViewer;
 
+mod account_rotation;
+use account_rotation::AccountRotation;
+
+mod nonce_manager;
+use nonce_manager::NonceManager;
+
+mod fee_policy;
+pub use fee_policy::FeePolicy;
+
 mod contract_administrator;
 pub use contract_administrator::ContractAdministrator;
 
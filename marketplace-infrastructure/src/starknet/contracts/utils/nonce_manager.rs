@@ -0,0 +1,131 @@
+use starknet::core::types::FieldElement;
+use std::{
+	collections::HashMap,
+	future::Future,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Mutex, PoisonError,
+	},
+};
+
+/// Hands out 2D nonces of the administrator account.
+/// Nonce keys are used in turn, and the next nonce of each key is tracked locally so that
+/// transactions still in flight on the same key do not collide.
+pub struct NonceManager {
+	key_count: u64,
+	next_key: AtomicU64,
+	next_nonces: Mutex<HashMap<FieldElement, FieldElement>>,
+}
+
+impl NonceManager {
+	pub fn new(key_count: u64) -> Self {
+		Self {
+			key_count: key_count.max(1),
+			next_key: AtomicU64::new(0),
+			next_nonces: Default::default(),
+		}
+	}
+
+	pub fn next_key(&self) -> FieldElement {
+		FieldElement::from(self.next_key.fetch_add(1, Ordering::Relaxed) % self.key_count)
+	}
+
+	/// Reserve the next nonce of `key`, using `fetch` to get it on-chain if the key is not tracked
+	pub async fn reserve<F, Fut, E>(&self, key: FieldElement, fetch: F) -> Result<FieldElement, E>
+	where
+		F: FnOnce() -> Fut,
+		Fut: Future<Output = Result<FieldElement, E>>,
+	{
+		if let Some(nonce) = self.reserve_tracked(key) {
+			return Ok(nonce);
+		}
+
+		let onchain_nonce = fetch().await?;
+
+		let mut next_nonces = self.next_nonces.lock().unwrap_or_else(PoisonError::into_inner);
+		// Another transaction may have reserved a nonce of this key in the meantime
+		let nonce = *next_nonces.entry(key).or_insert(onchain_nonce);
+		next_nonces.insert(key, nonce + FieldElement::ONE);
+
+		Ok(nonce)
+	}
+
+	/// Stop tracking `key`, so that its next nonce is fetched on-chain again.
+	/// To be called when a reserved nonce was not consumed.
+	pub fn forget(&self, key: FieldElement) {
+		self.next_nonces.lock().unwrap_or_else(PoisonError::into_inner).remove(&key);
+	}
+
+	fn reserve_tracked(&self, key: FieldElement) -> Option<FieldElement> {
+		let mut next_nonces = self.next_nonces.lock().unwrap_or_else(PoisonError::into_inner);
+		let nonce = next_nonces.get_mut(&key)?;
+		let reserved = *nonce;
+		*nonce = reserved + FieldElement::ONE;
+		Some(reserved)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	async fn onchain_nonce() -> Result<FieldElement, ()> {
+		Ok(FieldElement::from(7u64))
+	}
+
+	async fn unreachable_nonce() -> Result<FieldElement, ()> {
+		panic!("nonce should not be fetched on-chain")
+	}
+
+	#[test]
+	fn keys_are_used_in_turn() {
+		let nonce_manager = NonceManager::new(2);
+
+		assert_eq!(FieldElement::from(0u64), nonce_manager.next_key());
+		assert_eq!(FieldElement::from(1u64), nonce_manager.next_key());
+		assert_eq!(FieldElement::from(0u64), nonce_manager.next_key());
+	}
+
+	#[tokio::test]
+	async fn in_flight_nonces_are_tracked_per_key() {
+		let nonce_manager = NonceManager::new(2);
+		let key = FieldElement::from(1u64);
+
+		assert_eq!(
+			Ok(FieldElement::from(7u64)),
+			nonce_manager.reserve(key, onchain_nonce).await
+		);
+		assert_eq!(
+			Ok(FieldElement::from(8u64)),
+			nonce_manager.reserve(key, unreachable_nonce).await
+		);
+		assert_eq!(
+			Ok(FieldElement::from(7u64)),
+			nonce_manager.reserve(FieldElement::ZERO, onchain_nonce).await
+		);
+	}
+
+	#[tokio::test]
+	async fn forgotten_keys_are_fetched_again() {
+		let nonce_manager = NonceManager::new(1);
+		let key = nonce_manager.next_key();
+
+		nonce_manager.reserve(key, onchain_nonce).await.unwrap();
+		nonce_manager.reserve(key, unreachable_nonce).await.unwrap();
+		nonce_manager.forget(key);
+
+		assert_eq!(
+			Ok(FieldElement::from(7u64)),
+			nonce_manager.reserve(key, onchain_nonce).await
+		);
+	}
+
+	#[tokio::test]
+	async fn fetch_errors_are_forwarded() {
+		let nonce_manager = NonceManager::new(1);
+
+		let result = nonce_manager.reserve(FieldElement::ZERO, || async { Err("oops") }).await;
+
+		assert_eq!(Err("oops"), result);
+	}
+}
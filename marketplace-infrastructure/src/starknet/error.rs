@@ -1,12 +1,14 @@
 use std::sync::PoisonError;
 
-use super::contracts::ContractError;
+use super::{contracts::ContractError, SignerError};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum Error {
 	#[error("Something happened during contract invocation")]
 	Contract(#[from] ContractError),
+	#[error("Something happened with the signer of the admin account")]
+	Signer(#[from] SignerError),
 	#[error("Mutex lock cannot be acquired: {0}")]
 	Mutex(String),
 }
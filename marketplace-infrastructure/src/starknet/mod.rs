@@ -1,76 +1,81 @@
 mod contracts;
-use contracts::{ContributionContract, ProfileContract, RegistryContract};
+pub use contracts::FeePolicy;
+use contracts::{AccountContract, ContributionContract, ProfileContract, RegistryContract};
 
 mod model;
 
+mod network;
+pub use network::{ContractAddresses, Error as NetworkProfileError, Network, NetworkProfile};
+
+mod signers;
+pub use signers::{
+	Config as SignerConfig, DeclaredSigners, Error as SignerError, Keystore, KeystoreSigner,
+	LocalSigner, RemoteSigner, Signer as AdminSigner, SignerAdapter,
+};
+
 mod error;
 mod services;
 pub use error::Error as StarknetError;
 
 pub use starknet::accounts::Account;
 use starknet::{
-	accounts::SingleOwnerAccount,
-	core::{chain_id, types::FieldElement},
-	providers::SequencerGatewayProvider,
-	signers::{LocalWallet, SigningKey},
+	accounts::SingleOwnerAccount, core::types::FieldElement, providers::SequencerGatewayProvider,
 };
-use std::{env, sync::Arc};
-use url::Url;
+use std::{collections::HashMap, env, sync::Arc, time::Duration};
 
 use marketplace_domain::{Contributor, ContributorId};
 
-fn make_account_from_env() -> SingleOwnerAccount<SequencerGatewayProvider, LocalWallet> {
-	let private_key = env::var("PRIVATE_KEY").expect("PRIVATE_KEY must be set");
-	let account_address = env::var("ACCOUNT_ADDRESS").expect("ACCOUNT_ADDRESS must be set");
-	make_account(&private_key, &account_address)
-}
-
 fn make_account(
-	private_key: &str,
-	account_address: &str,
-) -> SingleOwnerAccount<SequencerGatewayProvider, LocalWallet> {
-	let signer = LocalWallet::from(SigningKey::from_secret_scalar(
-		FieldElement::from_hex_be(private_key).unwrap(),
-	));
-
-	// TODO: make chain_id configurable
+	signer: Arc<dyn AdminSigner>,
+	account_address: FieldElement,
+	network_profile: &NetworkProfile,
+) -> AdminAccount {
 	SingleOwnerAccount::new(
-		sequencer(),
-		signer,
-		FieldElement::from_hex_be(account_address).unwrap(),
-		chain_id::TESTNET,
+		network_profile.sequencer(),
+		SignerAdapter::new(signer),
+		account_address,
+		network_profile.chain_id,
 	)
 }
 
-fn sequencer() -> SequencerGatewayProvider {
-	match std::env::var("NETWORK") {
-		Ok(network) if network == *"devnet" => SequencerGatewayProvider::new(
-			Url::parse("http://127.0.0.1:5050/gateway").unwrap(),
-			Url::parse("http://127.0.0.1:5050/feeder_gateway").unwrap(),
-		),
-		Ok(network) if network == *"alpha-goerli" =>
-			SequencerGatewayProvider::starknet_alpha_goerli(),
-		Ok(network) if network == *"alpha-mainnet" =>
-			SequencerGatewayProvider::starknet_alpha_mainnet(),
-		_ => SequencerGatewayProvider::starknet_alpha_goerli(), // Default to goerli
-	}
-}
-
 pub struct Client<A: Account + Sync + Send> {
 	registry: RegistryContract,
 	contributions: Arc<ContributionContract<A>>,
 	profile: ProfileContract,
+	sequencer: SequencerGatewayProvider,
+	network_profile: NetworkProfile,
+	declared_signers: DeclaredSigners,
 }
 
 impl<A: Account + Sync + Send + 'static> Client<A> {
-	pub fn new(account: Arc<A>) -> Self {
+	pub fn new(account: Arc<A>, network_profile: &NetworkProfile) -> Self {
 		Self {
-			registry: RegistryContract::default(),
-			contributions: Arc::new(ContributionContract::new(account)),
-			profile: ProfileContract::default(),
+			registry: RegistryContract::new(
+				network_profile.contracts.registry,
+				network_profile.json_rpc_uri.clone(),
+			),
+			contributions: Arc::new(ContributionContract::new(account, network_profile)),
+			profile: ProfileContract::new(
+				network_profile.contracts.profile,
+				network_profile.json_rpc_uri.clone(),
+			),
+			sequencer: network_profile.sequencer(),
+			network_profile: network_profile.clone(),
+			declared_signers: DeclaredSigners::default(),
 		}
 	}
 
+	/// Sign the next transactions with `account`, once the transactions being sent with the
+	/// current account are drained
+	pub async fn rotate_account(
+		&self,
+		account: Arc<A>,
+		drain_timeout: Duration,
+	) -> Result<(), StarknetError> {
+		self.contributions.rotate_administrator(account, drain_timeout).await?;
+		Ok(())
+	}
+
 	pub async fn get_user_information(
 		&self,
 		contributor_id: &ContributorId,
@@ -78,13 +83,72 @@ impl<A: Account + Sync + Send + 'static> Client<A> {
 		let account = self.profile.get_account(contributor_id).await?;
 		self.registry.get_user_information(account).await
 	}
+
+	/// Resolve many contributors with two batched round trips, whatever their number.
+	/// Contributors without a profile are left out.
+	pub async fn get_users_information(
+		&self,
+		contributor_ids: &[ContributorId],
+	) -> Result<HashMap<ContributorId, Contributor>, StarknetError> {
+		let accounts = self.profile.get_accounts(contributor_ids).await?;
+		let (contributor_ids, accounts): (Vec<_>, Vec<_>) = contributor_ids
+			.iter()
+			.zip(accounts)
+			.filter_map(|(contributor_id, account)| Some((contributor_id.clone(), account?)))
+			.unzip();
+
+		let users_information = self.registry.get_users_information(&accounts).await?;
+		Ok(contributor_ids
+			.into_iter()
+			.zip(users_information)
+			.filter_map(|(contributor_id, contributor)| Some((contributor_id, contributor?)))
+			.collect())
+	}
 }
 
-pub type LocalSingleOwnerAccount = SingleOwnerAccount<SequencerGatewayProvider, LocalWallet>;
-pub type SingleAdminClient = Client<LocalSingleOwnerAccount>;
+pub type AdminAccount = SingleOwnerAccount<SequencerGatewayProvider, SignerAdapter>;
+pub type SingleAdminClient = Client<AdminAccount>;
+
+impl SingleAdminClient {
+	/// Build the client with the admin account from `ACCOUNT_ADDRESS`, signing with the signer
+	/// configured in the environment. The signers it can be rotated to are read from
+	/// `ADMIN_SIGNERS`.
+	pub fn from_profile(network_profile: &NetworkProfile) -> Result<Self, SignerError> {
+		let account_address = env::var("ACCOUNT_ADDRESS").expect("ACCOUNT_ADDRESS must be set");
+		let account_address = FieldElement::from_hex_be(&account_address)
+			.map_err(|_| SignerError::Configuration(String::from("Invalid ACCOUNT_ADDRESS")))?;
+		let signer = SignerConfig::from_env()?.build()?;
+
+		Ok(Self {
+			declared_signers: DeclaredSigners::from_env()?,
+			..Self::new(
+				Arc::new(make_account(signer, account_address, network_profile)),
+				network_profile,
+			)
+		})
+	}
+
+	/// Rotate to the admin account at `account_address`, signing with the declared signer named
+	/// `signer_name`, whose public key must be the one of the account.
+	/// The rotation only lasts until the process restarts, which goes back to `ACCOUNT_ADDRESS`
+	/// and `SIGNER`: update them to keep the new account.
+	pub async fn rotate_admin_account(
+		&self,
+		account_address: FieldElement,
+		signer_name: &str,
+		drain_timeout: Duration,
+	) -> Result<(), StarknetError> {
+		let signer = self.declared_signers.get(signer_name)?.build()?;
+
+		let account_public_key =
+			AccountContract::new(account_address, self.network_profile.json_rpc_uri.clone())
+				.get_public_key()
+				.await?;
+		if signer.public_key().await? != account_public_key {
+			return Err(SignerError::PublicKeyMismatch.into());
+		}
 
-impl Default for SingleAdminClient {
-	fn default() -> Self {
-		Self::new(Arc::new(make_account_from_env()))
+		let account = make_account(signer, account_address, &self.network_profile);
+		self.rotate_account(Arc::new(account), drain_timeout).await
 	}
 }
@@ -0,0 +1,365 @@
+use super::FeePolicy;
+use starknet::{
+	core::{chain_id, types::FieldElement},
+	providers::{
+		jsonrpc::{HttpTransport, JsonRpcClient},
+		SequencerGatewayProvider,
+	},
+};
+use std::{env, fmt::Display, str::FromStr};
+use thiserror::Error;
+use url::Url;
+
+const GOERLI_GATEWAY_HOST: &str = "alpha4.starknet.io";
+const MAINNET_GATEWAY_HOST: &str = "alpha-mainnet.starknet.io";
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("{0} must be set")]
+	Missing(&'static str),
+	#[error("Invalid value for {name}: {value}")]
+	Invalid { name: &'static str, value: String },
+	#[error("Chain id 0x{chain_id:x} cannot be used on {network}")]
+	ChainIdMismatch {
+		network: Network,
+		chain_id: FieldElement,
+	},
+	#[error("{name} points to {url}, which does not belong to {network}")]
+	UrlMismatch {
+		network: Network,
+		name: &'static str,
+		url: Url,
+	},
+	#[error("Unable to get the chain id of the JSON-RPC node: {0}")]
+	Node(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+	Devnet,
+	AlphaGoerli,
+	AlphaMainnet,
+}
+
+impl Network {
+	pub fn chain_id(&self) -> FieldElement {
+		match self {
+			Self::Devnet | Self::AlphaGoerli => chain_id::TESTNET,
+			Self::AlphaMainnet => chain_id::MAINNET,
+		}
+	}
+
+	fn gateway_host(&self) -> Option<&'static str> {
+		match self {
+			Self::Devnet => None,
+			Self::AlphaGoerli => Some(GOERLI_GATEWAY_HOST),
+			Self::AlphaMainnet => Some(MAINNET_GATEWAY_HOST),
+		}
+	}
+
+	fn default_gateway_urls(&self) -> (Url, Url) {
+		let base_url = match self.gateway_host() {
+			Some(host) => format!("https://{host}"),
+			None => String::from("http://127.0.0.1:5050"),
+		};
+		(
+			Url::parse(&format!("{base_url}/gateway")).unwrap(),
+			Url::parse(&format!("{base_url}/feeder_gateway")).unwrap(),
+		)
+	}
+}
+
+impl FromStr for Network {
+	type Err = Error;
+
+	fn from_str(network: &str) -> Result<Self, Self::Err> {
+		match network {
+			"devnet" => Ok(Self::Devnet),
+			"alpha-goerli" => Ok(Self::AlphaGoerli),
+			"alpha-mainnet" => Ok(Self::AlphaMainnet),
+			_ => Err(Error::Invalid {
+				name: "NETWORK",
+				value: network.to_string(),
+			}),
+		}
+	}
+}
+
+impl Display for Network {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Devnet => write!(f, "devnet"),
+			Self::AlphaGoerli => write!(f, "alpha-goerli"),
+			Self::AlphaMainnet => write!(f, "alpha-mainnet"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAddresses {
+	pub profile: FieldElement,
+	pub registry: FieldElement,
+	pub contributions: FieldElement,
+}
+
+/// Everything needed to talk to a StarkNet network: the chain id used to sign transactions,
+/// the sequencer gateway, the JSON-RPC node, the addresses of our contracts and the fees paid by
+/// the admin account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkProfile {
+	pub network: Network,
+	pub chain_id: FieldElement,
+	pub gateway_url: Url,
+	pub feeder_gateway_url: Url,
+	pub json_rpc_uri: Url,
+	pub contracts: ContractAddresses,
+	pub fee_policy: FeePolicy,
+}
+
+fn required_var(name: &'static str) -> Result<String, Error> {
+	env::var(name).map_err(|_| Error::Missing(name))
+}
+
+fn parse_url(name: &'static str, value: String) -> Result<Url, Error> {
+	Url::parse(&value).map_err(|_| Error::Invalid { name, value })
+}
+
+fn parse_felt(name: &'static str, value: String) -> Result<FieldElement, Error> {
+	FieldElement::from_hex_be(&value).map_err(|_| Error::Invalid { name, value })
+}
+
+fn parse_number<T: FromStr>(name: &'static str, value: String) -> Result<T, Error> {
+	value.parse().map_err(|_| Error::Invalid { name, value })
+}
+
+fn parse_chain_id(value: String) -> Result<FieldElement, Error> {
+	match value.as_str() {
+		"SN_GOERLI" => Ok(chain_id::TESTNET),
+		"SN_MAIN" => Ok(chain_id::MAINNET),
+		_ => parse_felt("CHAIN_ID", value),
+	}
+}
+
+impl NetworkProfile {
+	/// Read the profile from the environment and validate it.
+	/// Chain id and gateway URLs default to the ones of `NETWORK`.
+	pub fn from_env() -> Result<Self, Error> {
+		let network: Network = required_var("NETWORK")?.parse()?;
+		let (default_gateway_url, default_feeder_gateway_url) = network.default_gateway_urls();
+		let default_fee_policy = FeePolicy::default();
+
+		let profile = Self {
+			network,
+			chain_id: match env::var("CHAIN_ID") {
+				Ok(chain_id) => parse_chain_id(chain_id)?,
+				Err(_) => network.chain_id(),
+			},
+			gateway_url: match env::var("GATEWAY_URL") {
+				Ok(url) => parse_url("GATEWAY_URL", url)?,
+				Err(_) => default_gateway_url,
+			},
+			feeder_gateway_url: match env::var("FEEDER_GATEWAY_URL") {
+				Ok(url) => parse_url("FEEDER_GATEWAY_URL", url)?,
+				Err(_) => default_feeder_gateway_url,
+			},
+			json_rpc_uri: parse_url("JSON_RPC_URI", required_var("JSON_RPC_URI")?)?,
+			contracts: ContractAddresses {
+				profile: parse_felt("PROFILE_ADDRESS", required_var("PROFILE_ADDRESS")?)?,
+				registry: parse_felt("REGISTRY_ADDRESS", required_var("REGISTRY_ADDRESS")?)?,
+				contributions: parse_felt(
+					"CONTRIBUTIONS_ADDRESS",
+					required_var("CONTRIBUTIONS_ADDRESS")?,
+				)?,
+			},
+			fee_policy: FeePolicy {
+				multiplier: match env::var("FEE_MULTIPLIER") {
+					Ok(multiplier) => parse_number("FEE_MULTIPLIER", multiplier)?,
+					Err(_) => default_fee_policy.multiplier,
+				},
+				cap: match env::var("MAX_FEE") {
+					Ok(cap) => parse_number("MAX_FEE", cap)?,
+					Err(_) => default_fee_policy.cap,
+				},
+			},
+		};
+
+		profile.validate()?;
+		Ok(profile)
+	}
+
+	/// Make sure the chain id and gateways all belong to the network, so that transactions are
+	/// never signed for another network than the one they are sent to
+	pub fn validate(&self) -> Result<(), Error> {
+		if self.chain_id != self.network.chain_id() {
+			return Err(Error::ChainIdMismatch {
+				network: self.network,
+				chain_id: self.chain_id,
+			});
+		}
+
+		self.validate_gateway_url("GATEWAY_URL", &self.gateway_url)?;
+		self.validate_gateway_url("FEEDER_GATEWAY_URL", &self.feeder_gateway_url)
+	}
+
+	/// Make sure the JSON-RPC node serves the chain of the profile
+	pub async fn verify_node(&self) -> Result<(), Error> {
+		let node_chain_id = JsonRpcClient::new(HttpTransport::new(self.json_rpc_uri.clone()))
+			.chain_id()
+			.await
+			.map_err(|error| Error::Node(error.to_string()))?;
+
+		match node_chain_id == self.chain_id {
+			true => Ok(()),
+			false => Err(Error::ChainIdMismatch {
+				network: self.network,
+				chain_id: node_chain_id,
+			}),
+		}
+	}
+
+	pub fn sequencer(&self) -> SequencerGatewayProvider {
+		SequencerGatewayProvider::new(self.gateway_url.clone(), self.feeder_gateway_url.clone())
+	}
+
+	fn validate_gateway_url(&self, name: &'static str, url: &Url) -> Result<(), Error> {
+		let host = url.host_str();
+
+		let belongs_to_another_network = [GOERLI_GATEWAY_HOST, MAINNET_GATEWAY_HOST]
+			.into_iter()
+			.filter(|public_host| Some(*public_host) != self.network.gateway_host())
+			.any(|public_host| host == Some(public_host));
+
+		match belongs_to_another_network {
+			true => Err(Error::UrlMismatch {
+				network: self.network,
+				name,
+				url: url.clone(),
+			}),
+			false => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use rstest::*;
+
+	fn profile(network: Network) -> NetworkProfile {
+		let (gateway_url, feeder_gateway_url) = network.default_gateway_urls();
+		NetworkProfile {
+			network,
+			chain_id: network.chain_id(),
+			gateway_url,
+			feeder_gateway_url,
+			json_rpc_uri: Url::parse("http://127.0.0.1:5050/rpc").unwrap(),
+			contracts: ContractAddresses {
+				profile: FieldElement::from(1u64),
+				registry: FieldElement::from(2u64),
+				contributions: FieldElement::from(3u64),
+			},
+			fee_policy: FeePolicy::default(),
+		}
+	}
+
+	#[rstest]
+	#[case(Network::Devnet)]
+	#[case(Network::AlphaGoerli)]
+	#[case(Network::AlphaMainnet)]
+	fn default_profiles_are_valid(#[case] network: Network) {
+		assert_matches!(profile(network).validate(), Ok(()));
+	}
+
+	#[rstest]
+	#[case("devnet", Network::Devnet)]
+	#[case("alpha-goerli", Network::AlphaGoerli)]
+	#[case("alpha-mainnet", Network::AlphaMainnet)]
+	fn network_round_trips(#[case] name: &str, #[case] network: Network) {
+		assert_eq!(network, name.parse().unwrap());
+		assert_eq!(name, network.to_string());
+	}
+
+	#[test]
+	fn unknown_network_is_refused() {
+		assert_matches!(
+			"goerli".parse::<Network>(),
+			Err(Error::Invalid {
+				name: "NETWORK",
+				..
+			})
+		);
+	}
+
+	#[rstest]
+	#[case(Network::AlphaMainnet, chain_id::TESTNET)]
+	#[case(Network::AlphaGoerli, chain_id::MAINNET)]
+	#[case(Network::Devnet, chain_id::MAINNET)]
+	fn chain_id_of_another_network_is_refused(
+		#[case] network: Network,
+		#[case] chain_id: FieldElement,
+	) {
+		let profile = NetworkProfile {
+			chain_id,
+			..profile(network)
+		};
+		assert_matches!(profile.validate(), Err(Error::ChainIdMismatch { .. }));
+	}
+
+	#[rstest]
+	#[case(Network::AlphaMainnet, "https://alpha4.starknet.io/gateway")]
+	#[case(Network::AlphaGoerli, "https://alpha-mainnet.starknet.io/gateway")]
+	#[case(Network::Devnet, "https://alpha-mainnet.starknet.io/gateway")]
+	fn gateway_of_another_network_is_refused(#[case] network: Network, #[case] url: &str) {
+		let profile = NetworkProfile {
+			gateway_url: Url::parse(url).unwrap(),
+			..profile(network)
+		};
+		assert_matches!(
+			profile.validate(),
+			Err(Error::UrlMismatch {
+				name: "GATEWAY_URL",
+				..
+			})
+		);
+	}
+
+	#[test]
+	fn custom_gateway_is_accepted() {
+		let profile = NetworkProfile {
+			gateway_url: Url::parse("https://my-gateway.example.com/gateway").unwrap(),
+			..profile(Network::AlphaMainnet)
+		};
+		assert_matches!(profile.validate(), Ok(()));
+	}
+
+	#[test]
+	fn unparsable_fee_multiplier_is_refused() {
+		assert_matches!(
+			parse_number::<f64>("FEE_MULTIPLIER", String::from("1,5")),
+			Err(Error::Invalid {
+				name: "FEE_MULTIPLIER",
+				..
+			})
+		);
+	}
+
+	#[test]
+	fn unparsable_max_fee_is_refused() {
+		assert_matches!(
+			parse_number::<u64>("MAX_FEE", String::from("0.01")),
+			Err(Error::Invalid {
+				name: "MAX_FEE",
+				..
+			})
+		);
+	}
+
+	#[rstest]
+	#[case("SN_MAIN", chain_id::MAINNET)]
+	#[case("SN_GOERLI", chain_id::TESTNET)]
+	#[case("0x534e5f4d41494e", chain_id::MAINNET)]
+	fn chain_id_is_parsed(#[case] value: &str, #[case] expected: FieldElement) {
+		assert_eq!(expected, parse_chain_id(value.to_string()).unwrap());
+	}
+}
@@ -1,4 +1,4 @@
-use crate::starknet::{Account, Client, StarknetError};
+use crate::starknet::{contracts::ContractError, Account, Client, StarknetError};
 use async_trait::async_trait;
 use marketplace_domain::*;
 
@@ -63,10 +63,51 @@ impl<A: Account + Send + Sync + 'static> OnchainContributionService for Client<A
 
 		Ok(transaction_hash)
 	}
+
+	async fn execute_actions(
+		&self,
+		actions: Vec<Action>,
+	) -> Result<HexPrefixedString, OnchainContributionServiceError> {
+		let transaction_hash = self
+			.contributions
+			.execute_actions(&actions)
+			.await
+			.map_err(StarknetError::from)?;
+
+		Ok(transaction_hash)
+	}
+
+	async fn estimate_fee(
+		&self,
+		actions: Vec<Action>,
+	) -> Result<FeeEstimate, OnchainContributionServiceError> {
+		let fee_estimate =
+			self.contributions.estimate_fee(&actions).await.map_err(StarknetError::from)?;
+
+		Ok(fee_estimate)
+	}
+
+	async fn simulate(
+		&self,
+		actions: Vec<Action>,
+	) -> Result<TransactionSimulation, OnchainContributionServiceError> {
+		let simulation =
+			self.contributions.simulate(&actions).await.map_err(StarknetError::from)?;
+
+		Ok(simulation)
+	}
 }
 
 impl From<StarknetError> for OnchainContributionServiceError {
 	fn from(error: StarknetError) -> Self {
-		Self::Infrastructure(Box::new(error))
+		match error {
+			StarknetError::Contract(error @ ContractError::GatewayUnavailable { .. }) =>
+				Self::Unavailable(Box::new(error)),
+			StarknetError::Contract(error @ ContractError::NonceConflict { .. }) =>
+				Self::Conflict(Box::new(error)),
+			StarknetError::Contract(error @ ContractError::FeeAboveCap { .. }) =>
+				Self::FeeTooHigh(Box::new(error)),
+			_ => Self::Infrastructure(Box::new(error)),
+		}
 	}
 }
@@ -1 +1,2 @@
 mod contribution;
+mod transaction;
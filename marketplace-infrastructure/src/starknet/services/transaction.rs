@@ -0,0 +1,56 @@
+use crate::starknet::{Account, Client};
+use async_trait::async_trait;
+use log::{info, warn};
+use marketplace_domain::*;
+use starknet::{
+	core::types::{FieldElement, TransactionStatus},
+	providers::Provider,
+};
+
+#[async_trait]
+impl<A: Account + Send + Sync + 'static> OnchainTransactionService for Client<A> {
+	async fn status(
+		&self,
+		hash: &HexPrefixedString,
+	) -> Result<Option<OnchainTransactionStatus>, OnchainTransactionServiceError> {
+		let hash = FieldElement::from_hex_be(&hash.to_string())
+			.map_err(|e| OnchainTransactionServiceError::Infrastructure(Box::new(e)))?;
+
+		let status_info = self
+			.sequencer
+			.get_transaction_status(hash)
+			.await
+			.map_err(|e| OnchainTransactionServiceError::Infrastructure(Box::new(e)))?;
+
+		let status = match status_info.status {
+			TransactionStatus::NotReceived => return Ok(None),
+			TransactionStatus::Received | TransactionStatus::Pending =>
+				OnchainTransactionStatus::Pending,
+			TransactionStatus::AcceptedOnL2 | TransactionStatus::AcceptedOnL1 =>
+				OnchainTransactionStatus::AcceptedOnL2,
+			// A rejected transaction that made it into a block was executed and reverted
+			TransactionStatus::Rejected => match status_info.block_hash {
+				Some(_) => OnchainTransactionStatus::Reverted,
+				None => OnchainTransactionStatus::Rejected,
+			},
+		};
+
+		if status == OnchainTransactionStatus::AcceptedOnL2 {
+			self.log_actual_fee(hash).await;
+		}
+
+		Ok(Some(status))
+	}
+}
+
+impl<A: Account + Send + Sync + 'static> Client<A> {
+	async fn log_actual_fee(&self, hash: FieldElement) {
+		match self.sequencer.get_transaction_receipt(hash).await {
+			Ok(receipt) => match receipt.actual_fee {
+				Some(actual_fee) => info!("Transaction 0x{hash:x} was charged {actual_fee} wei"),
+				None => warn!("Transaction 0x{hash:x} has no actual fee"),
+			},
+			Err(error) => warn!("Failed to get the receipt of transaction 0x{hash:x}: {error}"),
+		}
+	}
+}
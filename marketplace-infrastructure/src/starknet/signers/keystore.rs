@@ -0,0 +1,154 @@
+use super::{Error, LocalSigner, Signer};
+use aes_gcm::{
+	aead::{Aead, NewAead},
+	Aes256Gcm, Key, Nonce,
+};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use starknet::core::{crypto::Signature, types::FieldElement};
+use std::{fs, path::Path};
+
+const KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+
+/// A private key encrypted with AES-256-GCM, as stored in a keystore file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+	/// Hex encoded nonce
+	pub nonce: String,
+	/// Hex encoded encrypted private key
+	pub ciphertext: String,
+}
+
+fn decode_key(key: &str) -> Result<Vec<u8>, Error> {
+	let key = hex::decode(key.trim().trim_start_matches("0x"))
+		.map_err(|error| Error::Keystore(error.to_string()))?;
+	match key.len() {
+		KEY_LENGTH => Ok(key),
+		length => Err(Error::Keystore(format!(
+			"Key must be {KEY_LENGTH} bytes long, got {length}"
+		))),
+	}
+}
+
+impl Keystore {
+	/// Encrypt `private_key` with the hex encoded `key`
+	pub fn seal(private_key: &str, key: &str) -> Result<Self, Error> {
+		let key = decode_key(key)?;
+		let nonce: [u8; NONCE_LENGTH] = rand::random();
+
+		let ciphertext = Aes256Gcm::new(Key::from_slice(&key))
+			.encrypt(Nonce::from_slice(&nonce), private_key.as_bytes())
+			.map_err(|error| Error::Keystore(error.to_string()))?;
+
+		Ok(Self {
+			nonce: hex::encode(nonce),
+			ciphertext: hex::encode(ciphertext),
+		})
+	}
+
+	/// Decrypt the private key with the hex encoded `key`
+	pub fn open(&self, key: &str) -> Result<String, Error> {
+		let key = decode_key(key)?;
+		let nonce = hex::decode(&self.nonce).map_err(|error| Error::Keystore(error.to_string()))?;
+		if nonce.len() != NONCE_LENGTH {
+			return Err(Error::Keystore(format!(
+				"Nonce must be {NONCE_LENGTH} bytes long, got {}",
+				nonce.len()
+			)));
+		}
+		let ciphertext =
+			hex::decode(&self.ciphertext).map_err(|error| Error::Keystore(error.to_string()))?;
+
+		let private_key = Aes256Gcm::new(Key::from_slice(&key))
+			.decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+			// Do not leak anything about the key or the content
+			.map_err(|_| Error::Keystore(String::from("Decryption failed")))?;
+
+		String::from_utf8(private_key).map_err(|error| Error::Keystore(error.to_string()))
+	}
+}
+
+/// Signs with a private key decrypted from a keystore file
+pub struct KeystoreSigner(LocalSigner);
+
+impl KeystoreSigner {
+	pub fn open<P: AsRef<Path>>(path: P, key: &str) -> Result<Self, Error> {
+		let content =
+			fs::read_to_string(path).map_err(|error| Error::Keystore(error.to_string()))?;
+		let keystore: Keystore =
+			serde_json::from_str(&content).map_err(|error| Error::Keystore(error.to_string()))?;
+
+		Ok(Self(LocalSigner::from_private_key(&keystore.open(key)?)?))
+	}
+}
+
+#[async_trait]
+impl Signer for KeystoreSigner {
+	async fn public_key(&self) -> Result<FieldElement, Error> {
+		self.0.public_key().await
+	}
+
+	async fn sign_hash(&self, hash: &FieldElement) -> Result<Signature, Error> {
+		self.0.sign_hash(hash).await
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use rstest::*;
+
+	const PRIVATE_KEY: &str = "0xeb1167b367a9c3787c65c1e582e2e662";
+
+	#[fixture]
+	fn key() -> String {
+		hex::encode([7u8; KEY_LENGTH])
+	}
+
+	#[rstest]
+	fn sealed_private_key_can_be_opened(key: String) {
+		let keystore = Keystore::seal(PRIVATE_KEY, &key).unwrap();
+
+		assert!(!keystore.ciphertext.contains("eb1167b367a9c3787c65c1e582e2e662"));
+		assert_eq!(PRIVATE_KEY, keystore.open(&key).unwrap());
+	}
+
+	#[rstest]
+	fn keystore_cannot_be_opened_with_another_key(key: String) {
+		let keystore = Keystore::seal(PRIVATE_KEY, &key).unwrap();
+
+		assert_matches!(
+			keystore.open(&hex::encode([8u8; KEY_LENGTH])),
+			Err(Error::Keystore(_))
+		);
+	}
+
+	#[test]
+	fn short_key_is_refused() {
+		assert_matches!(
+			Keystore::seal(PRIVATE_KEY, "0x1234"),
+			Err(Error::Keystore(_))
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn keystore_signer_signs_with_the_sealed_key(key: String) {
+		let path = std::env::temp_dir().join(format!("keystore-{}.json", rand::random::<u64>()));
+		fs::write(
+			&path,
+			serde_json::to_string(&Keystore::seal(PRIVATE_KEY, &key).unwrap()).unwrap(),
+		)
+		.unwrap();
+
+		let signer = KeystoreSigner::open(&path, &key).unwrap();
+		fs::remove_file(&path).unwrap();
+
+		assert_eq!(
+			LocalSigner::from_private_key(PRIVATE_KEY).unwrap().public_key().await.unwrap(),
+			signer.public_key().await.unwrap()
+		);
+	}
+}
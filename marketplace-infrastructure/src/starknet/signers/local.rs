@@ -0,0 +1,57 @@
+use super::{Error, Signer};
+use async_trait::async_trait;
+use starknet::{
+	core::{crypto::Signature, types::FieldElement},
+	signers::{LocalWallet, Signer as _, SigningKey},
+};
+
+/// Signs with a private key held in memory
+pub struct LocalSigner(LocalWallet);
+
+impl LocalSigner {
+	pub fn from_private_key(private_key: &str) -> Result<Self, Error> {
+		let private_key = FieldElement::from_hex_be(private_key.trim())
+			.map_err(|_| Error::Configuration(String::from("Invalid private key")))?;
+		Ok(Self(LocalWallet::from(SigningKey::from_secret_scalar(
+			private_key,
+		))))
+	}
+}
+
+#[async_trait]
+impl Signer for LocalSigner {
+	async fn public_key(&self) -> Result<FieldElement, Error> {
+		let public_key =
+			self.0.get_public_key().await.map_err(|error| Error::Sign(error.to_string()))?;
+		Ok(public_key.scalar())
+	}
+
+	async fn sign_hash(&self, hash: &FieldElement) -> Result<Signature, Error> {
+		self.0.sign_hash(hash).await.map_err(|error| Error::Sign(error.to_string()))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+
+	#[test]
+	fn invalid_private_key_is_refused() {
+		assert_matches!(
+			LocalSigner::from_private_key("not a key").err(),
+			Some(Error::Configuration(_))
+		);
+	}
+
+	#[tokio::test]
+	async fn public_key_is_derived_from_private_key() {
+		let signer = LocalSigner::from_private_key("0x1").unwrap();
+		let other_signer = LocalSigner::from_private_key("0x2").unwrap();
+
+		assert_ne!(
+			signer.public_key().await.unwrap(),
+			other_signer.public_key().await.unwrap()
+		);
+	}
+}
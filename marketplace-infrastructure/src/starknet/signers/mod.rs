@@ -0,0 +1,200 @@
+mod local;
+pub use local::LocalSigner;
+
+mod keystore;
+pub use keystore::{Keystore, KeystoreSigner};
+
+mod remote;
+pub use remote::RemoteSigner;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use starknet::{
+	core::{crypto::Signature, types::FieldElement},
+	signers::VerifyingKey,
+};
+use std::{collections::HashMap, env, path::PathBuf, sync::Arc};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+	#[error("Invalid signer configuration: {0}")]
+	Configuration(String),
+	#[error("No signer named `{0}` is declared")]
+	Unknown(String),
+	#[error("Public key of the signer does not match the one of the account")]
+	PublicKeyMismatch,
+	#[error("Unable to open the keystore: {0}")]
+	Keystore(String),
+	#[error("Remote signer failed: {0}")]
+	Remote(String),
+	#[error("Unable to sign: {0}")]
+	Sign(String),
+}
+
+/// Signs the transactions of the administrator account
+#[async_trait]
+pub trait Signer: Send + Sync {
+	async fn public_key(&self) -> Result<FieldElement, Error>;
+	async fn sign_hash(&self, hash: &FieldElement) -> Result<Signature, Error>;
+}
+
+/// Where the key of the administrator account lives
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Config {
+	/// Private key read from an environment variable
+	Env { variable: String },
+	/// Private key stored in an encrypted keystore file, opened with a key read from an
+	/// environment variable
+	Keystore { path: PathBuf, key_variable: String },
+	/// Hashes are sent to a remote signing service
+	Remote { url: String },
+}
+
+fn required_var(name: &str) -> Result<String, Error> {
+	env::var(name).map_err(|_| Error::Configuration(format!("{name} must be set")))
+}
+
+impl Config {
+	/// Read the configuration from `SIGNER`, which defaults to a private key in `PRIVATE_KEY`
+	pub fn from_env() -> Result<Self, Error> {
+		match env::var("SIGNER").as_deref() {
+			Err(_) | Ok("env") => Ok(Self::Env {
+				variable: String::from("PRIVATE_KEY"),
+			}),
+			Ok("keystore") => Ok(Self::Keystore {
+				path: required_var("KEYSTORE_PATH")?.into(),
+				key_variable: String::from("KEYSTORE_KEY"),
+			}),
+			Ok("remote") => Ok(Self::Remote {
+				url: required_var("REMOTE_SIGNER_URL")?,
+			}),
+			Ok(signer) => Err(Error::Configuration(format!("Unknown signer {signer}"))),
+		}
+	}
+
+	pub fn build(&self) -> Result<Arc<dyn Signer>, Error> {
+		Ok(match self {
+			Self::Env { variable } =>
+				Arc::new(LocalSigner::from_private_key(&required_var(variable)?)?),
+			Self::Keystore { path, key_variable } =>
+				Arc::new(KeystoreSigner::open(path, &required_var(key_variable)?)?),
+			Self::Remote { url } => Arc::new(RemoteSigner::new(url)?),
+		})
+	}
+}
+
+/// Signers the admin account can be rotated to, declared by name in `ADMIN_SIGNERS` as a JSON
+/// object of signer configurations
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct DeclaredSigners(HashMap<String, Config>);
+
+impl DeclaredSigners {
+	pub fn from_env() -> Result<Self, Error> {
+		match env::var("ADMIN_SIGNERS") {
+			Ok(signers) => serde_json::from_str(&signers)
+				.map_err(|error| Error::Configuration(format!("Invalid ADMIN_SIGNERS: {error}"))),
+			Err(_) => Ok(Self::default()),
+		}
+	}
+
+	pub fn get(&self, name: &str) -> Result<&Config, Error> {
+		self.0.get(name).ok_or_else(|| Error::Unknown(name.to_string()))
+	}
+}
+
+/// Lets any of our signers sign the transactions of a starknet account
+#[derive(Clone)]
+pub struct SignerAdapter(Arc<dyn Signer>);
+
+impl SignerAdapter {
+	pub fn new(signer: Arc<dyn Signer>) -> Self {
+		Self(signer)
+	}
+}
+
+#[async_trait]
+impl starknet::signers::Signer for SignerAdapter {
+	type GetPublicKeyError = Error;
+	type SignError = Error;
+
+	async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+		Ok(VerifyingKey::from_scalar(self.0.public_key().await?))
+	}
+
+	async fn sign_hash(&self, hash: &FieldElement) -> Result<Signature, Self::SignError> {
+		self.0.sign_hash(hash).await
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use rstest::*;
+	use starknet::signers::Signer as _;
+
+	const PRIVATE_KEY: &str = "0xeb1167b367a9c3787c65c1e582e2e662";
+
+	#[rstest]
+	#[case(
+		r#"{"type": "env", "variable": "NEXT_PRIVATE_KEY"}"#,
+		Config::Env { variable: String::from("NEXT_PRIVATE_KEY") }
+	)]
+	#[case(
+		r#"{"type": "keystore", "path": "/run/secrets/admin.json", "key_variable": "NEXT_KEYSTORE_KEY"}"#,
+		Config::Keystore {
+			path: "/run/secrets/admin.json".into(),
+			key_variable: String::from("NEXT_KEYSTORE_KEY")
+		}
+	)]
+	#[case(
+		r#"{"type": "remote", "url": "http://signer:8080"}"#,
+		Config::Remote { url: String::from("http://signer:8080") }
+	)]
+	fn config_is_deserialized(#[case] json: &str, #[case] expected: Config) {
+		assert_eq!(expected, serde_json::from_str::<Config>(json).unwrap());
+	}
+
+	#[test]
+	fn declared_signers_are_found_by_name() {
+		let signers: DeclaredSigners =
+			serde_json::from_str(r#"{"next": {"type": "env", "variable": "NEXT_PRIVATE_KEY"}}"#)
+				.unwrap();
+
+		assert_eq!(
+			&Config::Env {
+				variable: String::from("NEXT_PRIVATE_KEY")
+			},
+			signers.get("next").unwrap()
+		);
+		assert_matches!(signers.get("other"), Err(Error::Unknown(name)) if name == "other");
+	}
+
+	#[test]
+	fn missing_variable_is_reported() {
+		let config = Config::Env {
+			variable: String::from("SIGNER_TEST_UNSET_VARIABLE"),
+		};
+		assert_matches!(config.build().err(), Some(Error::Configuration(_)));
+	}
+
+	#[tokio::test]
+	async fn adapter_signs_like_the_underlying_signer() {
+		let stand_in = LocalSigner::from_private_key(PRIVATE_KEY).unwrap();
+		let hash = FieldElement::from(42u64);
+		let expected_signature = stand_in.sign_hash(&hash).await.unwrap();
+		let expected_public_key = stand_in.public_key().await.unwrap();
+
+		let adapter = SignerAdapter::new(Arc::new(stand_in));
+
+		let signature = adapter.sign_hash(&hash).await.unwrap();
+		assert_eq!(expected_signature.r, signature.r);
+		assert_eq!(expected_signature.s, signature.s);
+		assert_eq!(
+			expected_public_key,
+			adapter.get_public_key().await.unwrap().scalar()
+		);
+	}
+}
@@ -0,0 +1,240 @@
+use super::{Error, Signer};
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use starknet::core::{crypto::Signature, types::FieldElement};
+use std::env;
+use url::Url;
+
+/// Asks a signing service to sign hashes, so that the private key never reaches this process.
+///
+/// The service exposes:
+/// - `GET /public_key`, answering `{"public_key": "0x..."}`
+/// - `POST /sign` with `{"hash": "0x..."}`, answering `{"r": "0x...", "s": "0x..."}`
+///
+/// Requests carry the `REMOTE_SIGNER_TOKEN` bearer token when it is set.
+pub struct RemoteSigner {
+	url: Url,
+	token: Option<String>,
+	client: reqwest::Client,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PublicKeyResponse {
+	public_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SignRequest {
+	hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SignResponse {
+	r: String,
+	s: String,
+}
+
+fn parse_felt(name: &str, value: &str) -> Result<FieldElement, Error> {
+	FieldElement::from_hex_be(value)
+		.map_err(|_| Error::Remote(format!("Invalid {name} in response: {value}")))
+}
+
+impl SignResponse {
+	fn into_signature(self) -> Result<Signature, Error> {
+		Ok(Signature {
+			r: parse_felt("r", &self.r)?,
+			s: parse_felt("s", &self.s)?,
+		})
+	}
+}
+
+impl RemoteSigner {
+	pub fn new(url: &str) -> Result<Self, Error> {
+		let mut url = Url::parse(url).map_err(|error| Error::Configuration(error.to_string()))?;
+
+		// Endpoints are joined to the url, which would otherwise replace its last path segment
+		if !url.path().ends_with('/') {
+			url.set_path(&format!("{}/", url.path()));
+		}
+
+		Ok(Self {
+			url,
+			token: env::var("REMOTE_SIGNER_TOKEN").ok(),
+			client: reqwest::Client::new(),
+		})
+	}
+
+	fn endpoint(&self, path: &str) -> Result<Url, Error> {
+		self.url.join(path).map_err(|error| Error::Configuration(error.to_string()))
+	}
+
+	async fn send<R: DeserializeOwned>(
+		&self,
+		request: reqwest::RequestBuilder,
+	) -> Result<R, Error> {
+		let request = match &self.token {
+			Some(token) => request.bearer_auth(token),
+			None => request,
+		};
+
+		request
+			.send()
+			.await
+			.and_then(reqwest::Response::error_for_status)
+			.map_err(|error| Error::Remote(error.to_string()))?
+			.json()
+			.await
+			.map_err(|error| Error::Remote(error.to_string()))
+	}
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+	async fn public_key(&self) -> Result<FieldElement, Error> {
+		let response: PublicKeyResponse =
+			self.send(self.client.get(self.endpoint("public_key")?)).await?;
+		parse_felt("public_key", &response.public_key)
+	}
+
+	async fn sign_hash(&self, hash: &FieldElement) -> Result<Signature, Error> {
+		let response: SignResponse = self
+			.send(self.client.post(self.endpoint("sign")?).json(&SignRequest {
+				hash: format!("0x{hash:x}"),
+			}))
+			.await?;
+		response.into_signature()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert_matches::assert_matches;
+	use tokio::{
+		io::{AsyncReadExt, AsyncWriteExt},
+		net::TcpListener,
+		task::JoinHandle,
+	};
+
+	/// Stand in for the signing service: answer a single request with `body`, and hand back the
+	/// raw request received
+	async fn serve_once(body: &'static str) -> (String, JoinHandle<String>) {
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let url = format!("http://{}/", listener.local_addr().unwrap());
+
+		let server = tokio::spawn(async move {
+			let (mut stream, _) = listener.accept().await.unwrap();
+			let mut request = Vec::new();
+			let mut buffer = [0; 1024];
+			while !is_complete(&request) {
+				let read = stream.read(&mut buffer).await.unwrap();
+				assert!(
+					read > 0,
+					"connection closed before the request was complete"
+				);
+				request.extend_from_slice(&buffer[..read]);
+			}
+
+			let response = format!(
+				"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+				body.len(),
+				body
+			);
+			stream.write_all(response.as_bytes()).await.unwrap();
+			String::from_utf8(request).unwrap()
+		});
+
+		(url, server)
+	}
+
+	/// Whether the headers and the whole body announced by `Content-Length` were received
+	fn is_complete(request: &[u8]) -> bool {
+		let request = String::from_utf8_lossy(request);
+		match request.split_once("\r\n\r\n") {
+			Some((headers, body)) => {
+				let content_length = headers
+					.lines()
+					.find_map(|line| {
+						let (name, value) = line.split_once(':')?;
+						if name.eq_ignore_ascii_case("content-length") {
+							value.trim().parse().ok()
+						} else {
+							None
+						}
+					})
+					.unwrap_or(0);
+				body.len() >= content_length
+			},
+			None => false,
+		}
+	}
+
+	#[tokio::test]
+	async fn hash_is_signed_by_the_service() {
+		let (url, server) = serve_once(r#"{"r": "0x1234", "s": "0xabcd"}"#).await;
+		let signer = RemoteSigner::new(&url).unwrap();
+
+		let signature = signer.sign_hash(&FieldElement::from(0x2au64)).await.unwrap();
+
+		assert_eq!(FieldElement::from(0x1234u64), signature.r);
+		assert_eq!(FieldElement::from(0xabcdu64), signature.s);
+
+		let request = server.await.unwrap();
+		assert!(request.starts_with("POST /sign HTTP/1.1\r\n"), "{request}");
+		let (_, body) = request.split_once("\r\n\r\n").unwrap();
+		let sent: SignRequest = serde_json::from_str(body).unwrap();
+		assert_eq!(
+			FieldElement::from(0x2au64),
+			FieldElement::from_hex_be(&sent.hash).unwrap()
+		);
+	}
+
+	#[test]
+	fn endpoints_are_relative_to_the_signer_url() {
+		let signer = RemoteSigner::new("http://signer:8080/admin/").unwrap();
+		assert_eq!(
+			"http://signer:8080/admin/sign",
+			signer.endpoint("sign").unwrap().as_str()
+		);
+	}
+
+	#[test]
+	fn endpoints_keep_the_path_of_a_signer_url_without_trailing_slash() {
+		let signer = RemoteSigner::new("http://signer:8080/admin").unwrap();
+		assert_eq!(
+			"http://signer:8080/admin/sign",
+			signer.endpoint("sign").unwrap().as_str()
+		);
+		assert_eq!(
+			"http://signer:8080/admin/public_key",
+			signer.endpoint("public_key").unwrap().as_str()
+		);
+	}
+
+	#[test]
+	fn invalid_url_is_refused() {
+		assert_matches!(
+			RemoteSigner::new("signer").err(),
+			Some(Error::Configuration(_))
+		);
+	}
+
+	#[test]
+	fn sign_response_is_parsed() {
+		let response: SignResponse =
+			serde_json::from_str(r#"{"r": "0x1234", "s": "0xabcd"}"#).unwrap();
+		let signature = response.into_signature().unwrap();
+
+		assert_eq!(FieldElement::from(0x1234u64), signature.r);
+		assert_eq!(FieldElement::from(0xabcdu64), signature.s);
+	}
+
+	#[test]
+	fn invalid_sign_response_is_refused() {
+		let response = SignResponse {
+			r: String::from("not a felt"),
+			s: String::from("0xabcd"),
+		};
+		assert_matches!(response.into_signature(), Err(Error::Remote(_)));
+	}
+}
@@ -0,0 +1,3 @@
+ALTER TABLE events
+DROP COLUMN "transaction_hash",
+DROP COLUMN "block_number";
@@ -0,0 +1,10 @@
+ALTER TABLE events
+ADD "transaction_hash" VARCHAR,
+ADD "block_number" BIGINT;
+
+-- Events observed on-chain are deduplicated using "<contract address>_<transaction hash>_<log index>"
+UPDATE events
+SET transaction_hash = split_part(event_deduplications.deduplication_id, '_', 2)
+FROM event_deduplications
+WHERE event_deduplications.event_index = events.index
+AND event_deduplications.deduplication_id LIKE '0x%\_0x%\_%';
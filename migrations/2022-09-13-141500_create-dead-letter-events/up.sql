@@ -0,0 +1,8 @@
+CREATE TABLE dead_letter_events(
+    id UUID PRIMARY KEY,
+    projector VARCHAR NOT NULL,
+    payload JSONB NOT NULL,
+    error TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    failed_at TIMESTAMP NOT NULL
+);
@@ -0,0 +1 @@
+DROP TABLE onchain_transactions;
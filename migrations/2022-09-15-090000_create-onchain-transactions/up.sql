@@ -0,0 +1,11 @@
+CREATE TABLE onchain_transactions(
+    hash VARCHAR PRIMARY KEY,
+    command VARCHAR NOT NULL,
+    issuer VARCHAR NOT NULL,
+    actions JSONB NOT NULL,
+    status VARCHAR NOT NULL,
+    submitted_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX onchain_transactions_status_idx ON onchain_transactions(status);
@@ -0,0 +1,7 @@
+CREATE TABLE contributors(
+    id TEXT PRIMARY KEY,
+    account TEXT,
+    github_handle TEXT
+);
+
+CREATE INDEX contributors_account_idx ON contributors(account);
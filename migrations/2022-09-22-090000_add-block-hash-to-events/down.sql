@@ -0,0 +1,4 @@
+DROP INDEX events_block_number_idx;
+
+ALTER TABLE events
+DROP COLUMN "block_hash";
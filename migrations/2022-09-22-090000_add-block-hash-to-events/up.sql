@@ -0,0 +1,4 @@
+ALTER TABLE events
+ADD "block_hash" VARCHAR;
+
+CREATE INDEX events_block_number_idx ON events(block_number);
@@ -0,0 +1,9 @@
+CREATE TABLE pending_events(
+    deduplication_id TEXT PRIMARY KEY,
+    payload JSONB NOT NULL,
+    transaction_hash VARCHAR NOT NULL,
+    block_hash VARCHAR NOT NULL,
+    block_number BIGINT NOT NULL
+);
+
+CREATE INDEX pending_events_block_number_idx ON pending_events(block_number);
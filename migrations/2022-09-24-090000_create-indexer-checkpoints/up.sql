@@ -0,0 +1,6 @@
+CREATE TABLE indexer_checkpoints(
+    indexer_id VARCHAR NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_hash VARCHAR NOT NULL,
+    PRIMARY KEY (indexer_id, block_number)
+);
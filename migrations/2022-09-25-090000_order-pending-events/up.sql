@@ -0,0 +1,2 @@
+-- Pending events of a block are released in the order they were observed
+ALTER TABLE pending_events ADD "sequence_number" BIGSERIAL NOT NULL;
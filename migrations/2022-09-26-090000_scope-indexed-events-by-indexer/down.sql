@@ -0,0 +1,11 @@
+DROP INDEX pending_events_indexer_id_block_number_idx;
+CREATE INDEX pending_events_block_number_idx ON pending_events(block_number);
+
+ALTER TABLE pending_events
+DROP COLUMN "indexer_id";
+
+DROP INDEX events_indexer_id_block_number_idx;
+CREATE INDEX events_block_number_idx ON events(block_number);
+
+ALTER TABLE events
+DROP COLUMN "indexer_id";
@@ -0,0 +1,19 @@
+-- Events observed on-chain are rolled back by the indexer which observed them
+ALTER TABLE events
+ADD "indexer_id" VARCHAR;
+
+-- They were all observed by the only indexer so far
+UPDATE events
+SET "indexer_id" = 'contribution-indexer'
+WHERE block_number IS NOT NULL;
+
+DROP INDEX events_block_number_idx;
+CREATE INDEX events_indexer_id_block_number_idx ON events(indexer_id, block_number);
+
+ALTER TABLE pending_events
+ADD "indexer_id" VARCHAR NOT NULL DEFAULT 'contribution-indexer';
+ALTER TABLE pending_events
+ALTER COLUMN "indexer_id" DROP DEFAULT;
+
+DROP INDEX pending_events_block_number_idx;
+CREATE INDEX pending_events_indexer_id_block_number_idx ON pending_events(indexer_id, block_number);
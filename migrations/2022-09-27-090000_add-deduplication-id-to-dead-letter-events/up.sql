@@ -0,0 +1,3 @@
+-- Dead letters of events observed on-chain are discarded when their block is orphaned
+ALTER TABLE dead_letter_events
+ADD "deduplication_id" TEXT;